@@ -0,0 +1,107 @@
+//! Cursor rules derived from installed skills.
+//!
+//! Cursor reads `.mdc` rules from `.cursor/rules/` rather than `SKILL.md`
+//! skills. Each skill still installs as its own managed folder there, so the
+//! marker-based stale cleanup applies unchanged, and gets a `<folder>.mdc`
+//! rule next to its `SKILL.md`. The skill's `description` becomes the rule's
+//! description with `alwaysApply: false` — an "agent requested" rule, which
+//! Cursor attaches when the description fits the task, the way other agents
+//! load skills. Files the skill body refers to sit beside the rule.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// The rule file derived from the skill installed in `skill_dir`.
+pub(super) fn rule_file(skill_dir: &Path) -> PathBuf {
+    let name = skill_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "skill".to_string());
+    skill_dir.join(format!("{name}.mdc"))
+}
+
+/// Write (or refresh) the rule for the skill installed in `skill_dir`.
+pub(super) fn write_rule(skill_dir: &Path) -> Result<()> {
+    let skill_md = skill_dir.join("SKILL.md");
+    let content =
+        fs::read_to_string(&skill_md).with_context(|| format!("read {}", skill_md.display()))?;
+    let rule = render_rule(&content)
+        .with_context(|| format!("convert {} to a Cursor rule", skill_md.display()))?;
+
+    let path = rule_file(skill_dir);
+    if fs::read_to_string(&path).is_ok_and(|existing| existing == rule) {
+        return Ok(());
+    }
+    fs::write(&path, rule).with_context(|| format!("write {}", path.display()))
+}
+
+/// Render SKILL.md content as an `.mdc` rule.
+fn render_rule(skill_md: &str) -> Result<String> {
+    let (fields, body) = crate::skills::parse_skill_md(skill_md)?;
+    // Cursor reads rule metadata line by line, so the description is kept on
+    // a single unquoted line.
+    let description = fields
+        .get("description")
+        .map(|d| d.split_whitespace().collect::<Vec<_>>().join(" "))
+        .unwrap_or_default();
+    Ok(format!(
+        "---\ndescription: {description}\nglobs:\nalwaysApply: false\n---\n{body}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn render_rule_carries_description_and_body() {
+        let rule = render_rule(indoc! {"
+            ---
+            name: serde-guidance
+            description: >
+              Guidance for serde
+              derive macros
+            depends-on: serde
+            ---
+
+            # Serde
+
+            Prefer `#[serde(rename_all)]`.
+        "})
+        .unwrap();
+        assert_eq!(
+            rule,
+            indoc! {"
+                ---
+                description: Guidance for serde derive macros
+                globs:
+                alwaysApply: false
+                ---
+
+                # Serde
+
+                Prefer `#[serde(rename_all)]`.
+            "}
+        );
+    }
+
+    #[test]
+    fn write_rule_names_rule_after_skill_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("serde-guidance");
+        fs::create_dir(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: serde-guidance\ndescription: Serde tips\n---\nBody\n",
+        )
+        .unwrap();
+
+        write_rule(&skill_dir).unwrap();
+        let rule = fs::read_to_string(skill_dir.join("serde-guidance.mdc")).unwrap();
+        assert!(rule.starts_with("---\ndescription: Serde tips\n"), "{rule}");
+        assert!(rule.ends_with("---\nBody\n"), "{rule}");
+    }
+}
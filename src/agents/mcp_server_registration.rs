@@ -2,7 +2,8 @@
 //!
 //! Each agent has its own config format and file location. This module
 //! provides per-agent `register_*` and `unregister_*` functions that
-//! are called from the `Agent` methods in the parent module.
+//! are called from the `Agent` methods in the parent module. Agent profiles
+//! reuse the JSON, TOML and YAML writers with a configurable key.
 //!
 //! Registration is idempotent: existing entries with correct values are
 //! left untouched, while stale entries are updated in place.
@@ -15,9 +16,10 @@ use indoc::formatdoc;
 use sacp::schema::McpServer;
 use serde_json::json;
 
+use crate::config::{McpFormat, ProfileMcp};
 use crate::output::{Output, display_path};
 
-use super::{load_json_or_empty, save_json};
+use super::{load_json_or_empty, save_json, write_config};
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -144,7 +146,7 @@ fn register_json_mcp_servers(
     }
 
     if changed {
-        save_json(config_path, &config)?;
+        save_json(config_path, &config, out)?;
     }
     Ok(())
 }
@@ -182,7 +184,7 @@ fn unregister_json_mcp_servers(
     }
 
     if changed {
-        save_json(config_path, &config)?;
+        save_json(config_path, &config, out)?;
     }
     Ok(())
 }
@@ -213,6 +215,24 @@ pub(super) fn register_codex_mcp_servers(
     config_path: &Path,
     servers: &[McpServer],
     out: &Output,
+) -> Result<()> {
+    register_toml_mcp_servers(config_path, servers, "mcp_servers", out)
+}
+
+pub(super) fn unregister_codex_mcp_servers(
+    config_path: &Path,
+    names: &[&str],
+    out: &Output,
+) -> Result<()> {
+    unregister_toml_mcp_servers(config_path, names, "mcp_servers", out)
+}
+
+/// Register stdio MCP servers as `[<key>.<name>]` tables in a TOML file.
+fn register_toml_mcp_servers(
+    config_path: &Path,
+    servers: &[McpServer],
+    key: &str,
+    out: &Output,
 ) -> Result<()> {
     let display = display_path(config_path);
 
@@ -226,8 +246,8 @@ pub(super) fn register_codex_mcp_servers(
         .parse()
         .unwrap_or_else(|_| toml_edit::DocumentMut::new());
 
-    if !doc.contains_key("mcp_servers") {
-        doc["mcp_servers"] = toml_edit::Item::Table(toml_edit::Table::new());
+    if !doc.contains_key(key) {
+        doc[key] = toml_edit::Item::Table(toml_edit::Table::new());
     }
 
     let mut changed = false;
@@ -235,13 +255,13 @@ pub(super) fn register_codex_mcp_servers(
         let name = server_name(server);
         let McpServer::Stdio(stdio) = server else {
             out.info(format!(
-                "{display}: skipping non-stdio MCP server {name} (Codex only supports stdio)"
+                "{display}: skipping non-stdio MCP server {name} (only stdio is supported here)"
             ));
             continue;
         };
 
         let cmd = stdio.command.to_string_lossy().to_string();
-        let needs_update = if let Some(existing) = doc["mcp_servers"].get(name) {
+        let needs_update = if let Some(existing) = doc[key].get(name) {
             let cmd_ok = existing.get("command").and_then(|v| v.as_str()) == Some(&cmd);
             let args_ok = existing
                 .get("args")
@@ -270,8 +290,8 @@ pub(super) fn register_codex_mcp_servers(
                 args.push(arg.as_str());
             }
             server_table["args"] = toml_edit::value(args);
-            let is_new = doc["mcp_servers"].get(name).is_none();
-            doc["mcp_servers"][name] = toml_edit::Item::Table(server_table);
+            let is_new = doc[key].get(name).is_none();
+            doc[key][name] = toml_edit::Item::Table(server_table);
             let verb = if is_new { "added" } else { "updated" };
             out.done(format!("{display}: {verb} {name} MCP server"));
             changed = true;
@@ -279,17 +299,16 @@ pub(super) fn register_codex_mcp_servers(
     }
 
     if changed {
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        fs::write(config_path, doc.to_string())?;
+        write_config(config_path, &doc.to_string(), out)?;
     }
     Ok(())
 }
 
-pub(super) fn unregister_codex_mcp_servers(
+/// Remove `[<key>.<name>]` tables from a TOML file.
+fn unregister_toml_mcp_servers(
     config_path: &Path,
     names: &[&str],
+    key: &str,
     out: &Output,
 ) -> Result<()> {
     let display = display_path(config_path);
@@ -300,7 +319,7 @@ pub(super) fn unregister_codex_mcp_servers(
     let content = fs::read_to_string(config_path)?;
     let mut doc: toml_edit::DocumentMut = content.parse()?;
 
-    let Some(mcp_servers) = doc.get_mut("mcp_servers").and_then(|v| v.as_table_mut()) else {
+    let Some(mcp_servers) = doc.get_mut(key).and_then(|v| v.as_table_mut()) else {
         return Ok(());
     };
 
@@ -313,7 +332,7 @@ pub(super) fn unregister_codex_mcp_servers(
     }
 
     if changed {
-        fs::write(config_path, doc.to_string())?;
+        write_config(config_path, &doc.to_string(), out)?;
     }
     Ok(())
 }
@@ -335,6 +354,23 @@ pub(super) fn unregister_copilot_mcp_servers(
     unregister_json_mcp_servers(path, names, None, out)
 }
 
+/// Cursor: same format as Claude (`mcpServers.<name>`) in mcp.json
+pub(super) fn register_cursor_mcp_servers(
+    path: &Path,
+    servers: &[McpServer],
+    out: &Output,
+) -> Result<()> {
+    register_claude_mcp_servers(path, servers, out)
+}
+
+pub(super) fn unregister_cursor_mcp_servers(
+    path: &Path,
+    names: &[&str],
+    out: &Output,
+) -> Result<()> {
+    unregister_claude_mcp_servers(path, names, out)
+}
+
 /// Gemini CLI: same format as Claude (`mcpServers.<name>`)
 pub(super) fn register_gemini_mcp_servers(
     path: &Path,
@@ -370,6 +406,17 @@ pub(super) fn register_goose_mcp_servers(
     config_path: &Path,
     servers: &[McpServer],
     out: &Output,
+) -> Result<()> {
+    register_yaml_mcp_servers(config_path, servers, "extensions", out)
+}
+
+/// Register stdio MCP servers as Goose-style extension entries under
+/// `<key>:` in a YAML file.
+fn register_yaml_mcp_servers(
+    config_path: &Path,
+    servers: &[McpServer],
+    key: &str,
+    out: &Output,
 ) -> Result<()> {
     let display = display_path(config_path);
 
@@ -384,7 +431,7 @@ pub(super) fn register_goose_mcp_servers(
         let name = server_name(server);
         let McpServer::Stdio(stdio) = server else {
             out.info(format!(
-                "{display}: skipping non-stdio MCP server {name} (Goose extensions use stdio)"
+                "{display}: skipping non-stdio MCP server {name} (extensions use stdio)"
             ));
             continue;
         };
@@ -452,12 +499,13 @@ pub(super) fn register_goose_mcp_servers(
             content = new_lines.join("\n");
         }
 
+        let header = format!("{key}:");
         content = if content.trim().is_empty() {
-            format!("extensions:\n  {}", snippet.trim())
-        } else if content.contains("extensions:") {
-            content.replace("extensions:", &format!("extensions:\n  {}", snippet.trim()))
+            format!("{header}\n  {}", snippet.trim())
+        } else if content.contains(&header) {
+            content.replace(&header, &format!("{header}\n  {}", snippet.trim()))
         } else {
-            format!("{}\nextensions:\n  {}", content.trim(), snippet.trim())
+            format!("{}\n{header}\n  {}", content.trim(), snippet.trim())
         };
 
         let verb = if already_exists { "updated" } else { "added" };
@@ -466,10 +514,7 @@ pub(super) fn register_goose_mcp_servers(
     }
 
     if changed {
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        fs::write(config_path, content)?;
+        write_config(config_path, &content, out)?;
     }
     Ok(())
 }
@@ -513,7 +558,7 @@ pub(super) fn unregister_goose_mcp_servers(
     }
 
     if changed {
-        fs::write(config_path, new_lines.join("\n"))?;
+        write_config(config_path, &new_lines.join("\n"), out)?;
     }
     Ok(())
 }
@@ -535,6 +580,46 @@ pub(super) fn unregister_opencode_mcp_servers(
     unregister_json_mcp_servers(path, names, Some("mcp"), out)
 }
 
+/// Agent profiles: whichever of the formats above the profile describes,
+/// nested under its `key`.
+pub(super) fn register_profile_mcp_servers(
+    path: &Path,
+    mcp: &ProfileMcp,
+    servers: &[McpServer],
+    out: &Output,
+) -> Result<()> {
+    let key = profile_mcp_key(mcp);
+    match mcp.format {
+        McpFormat::Json => {
+            register_json_mcp_servers(path, servers, Some(key).filter(|k| !k.is_empty()), out)
+        }
+        McpFormat::Toml => register_toml_mcp_servers(path, servers, key, out),
+        McpFormat::Yaml => register_yaml_mcp_servers(path, servers, key, out),
+    }
+}
+
+pub(super) fn unregister_profile_mcp_servers(
+    path: &Path,
+    mcp: &ProfileMcp,
+    names: &[&str],
+    out: &Output,
+) -> Result<()> {
+    let key = profile_mcp_key(mcp);
+    match mcp.format {
+        McpFormat::Json => {
+            unregister_json_mcp_servers(path, names, Some(key).filter(|k| !k.is_empty()), out)
+        }
+        McpFormat::Toml => unregister_toml_mcp_servers(path, names, key, out),
+        McpFormat::Yaml => unregister_goose_mcp_servers(path, names, out),
+    }
+}
+
+fn profile_mcp_key(mcp: &ProfileMcp) -> &str {
+    mcp.key
+        .as_deref()
+        .unwrap_or_else(|| mcp.format.default_key())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -585,7 +670,7 @@ mod tests {
         let tmp = tempfile::tempdir().unwrap();
         let path = tmp.path().join("settings.json");
         let stale = json!({"mcpServers": {"symposium": {"command": "/old/path", "args": ["mcp"]}}});
-        save_json(&path, &stale).unwrap();
+        save_json(&path, &stale, &Output::quiet()).unwrap();
 
         register_claude_mcp_servers(&path, &test_servers(), &Output::quiet()).unwrap();
 
@@ -602,7 +687,7 @@ mod tests {
         let tmp = tempfile::tempdir().unwrap();
         let path = tmp.path().join("settings.json");
         // mcpServers is a string instead of an object
-        save_json(&path, &json!({"mcpServers": "corrupted"})).unwrap();
+        save_json(&path, &json!({"mcpServers": "corrupted"}), &Output::quiet()).unwrap();
 
         register_claude_mcp_servers(&path, &test_servers(), &Output::quiet()).unwrap();
 
@@ -737,7 +822,7 @@ mod tests {
         let tmp = tempfile::tempdir().unwrap();
         let path = tmp.path().join("mcp.json");
         let stale = json!({"symposium": {"command": "/old/path", "args": ["mcp"]}});
-        save_json(&path, &stale).unwrap();
+        save_json(&path, &stale, &Output::quiet()).unwrap();
 
         register_copilot_mcp_servers(&path, &test_servers(), &Output::quiet()).unwrap();
 
@@ -885,7 +970,7 @@ mod tests {
         let tmp = tempfile::tempdir().unwrap();
         let path = tmp.path().join("opencode.json");
         let stale = json!({"mcp": {"symposium": {"command": "/old/path", "args": ["mcp"]}}});
-        save_json(&path, &stale).unwrap();
+        save_json(&path, &stale, &Output::quiet()).unwrap();
 
         register_opencode_mcp_servers(&path, &test_servers(), &Output::quiet()).unwrap();
 
@@ -908,4 +993,76 @@ mod tests {
             serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
         assert!(config["mcp"].get("symposium").is_none());
     }
+
+    // -- Agent profiles --
+
+    fn profile_mcp(format: McpFormat, key: Option<&str>) -> ProfileMcp {
+        ProfileMcp {
+            path: "unused".into(),
+            format,
+            key: key.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn register_profile_json_uses_key() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("mcp.json");
+        let mcp = profile_mcp(McpFormat::Json, Some("servers"));
+        register_profile_mcp_servers(&path, &mcp, &test_servers(), &Output::quiet()).unwrap();
+
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(config["servers"]["symposium"]["args"][0], "mcp");
+
+        unregister_profile_mcp_servers(&path, &mcp, &test_server_names(), &Output::quiet())
+            .unwrap();
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(config["servers"].get("symposium").is_none());
+    }
+
+    #[test]
+    fn register_profile_json_empty_key_is_top_level() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("mcp.json");
+        let mcp = profile_mcp(McpFormat::Json, Some(""));
+        register_profile_mcp_servers(&path, &mcp, &test_servers(), &Output::quiet()).unwrap();
+
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            config["symposium"]["command"],
+            "/usr/local/bin/cargo-agents"
+        );
+    }
+
+    #[test]
+    fn register_profile_toml_defaults_to_mcp_servers() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        let mcp = profile_mcp(McpFormat::Toml, None);
+        register_profile_mcp_servers(&path, &mcp, &test_servers(), &Output::quiet()).unwrap();
+
+        let doc: toml_edit::DocumentMut = fs::read_to_string(&path).unwrap().parse().unwrap();
+        assert_eq!(
+            doc["mcp_servers"]["symposium"]["command"].as_str().unwrap(),
+            "/usr/local/bin/cargo-agents"
+        );
+    }
+
+    #[test]
+    fn register_profile_yaml_uses_key() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.yaml");
+        let mcp = profile_mcp(McpFormat::Yaml, Some("tools"));
+        register_profile_mcp_servers(&path, &mcp, &test_servers(), &Output::quiet()).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("tools:\n  symposium:"), "{content}");
+
+        unregister_profile_mcp_servers(&path, &mcp, &test_server_names(), &Output::quiet())
+            .unwrap();
+        assert!(!fs::read_to_string(&path).unwrap().contains("symposium"));
+    }
 }
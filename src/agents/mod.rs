@@ -2,29 +2,36 @@
 //!
 //! Each supported agent has different conventions for where hooks are
 //! configured and where skill files are placed. This module centralizes
-//! that knowledge.
+//! that knowledge. Agents without built-in support are described by an
+//! `[[agent-profile]]` in the user config and reuse the built-in writers.
 
+mod cursor_rule;
 mod mcp_server_registration;
 
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::{Result, bail};
 use serde_json::json;
 
-use crate::config::Symposium;
+use crate::config::{AgentProfile, Symposium};
+use crate::hook_schema::HookAgent;
 use crate::output::{Output, display_path};
 
 /// Supported AI agents.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Agent {
     Claude,
     Codex,
     Copilot,
+    Cursor,
     Gemini,
     Goose,
     Kiro,
     OpenCode,
+    /// An agent described by an `[[agent-profile]]` config entry.
+    Profile(Arc<AgentProfile>),
 }
 
 impl Agent {
@@ -34,53 +41,111 @@ impl Agent {
             "claude" => Ok(Agent::Claude),
             "codex" => Ok(Agent::Codex),
             "copilot" => Ok(Agent::Copilot),
+            "cursor" => Ok(Agent::Cursor),
             "gemini" => Ok(Agent::Gemini),
             "goose" => Ok(Agent::Goose),
             "kiro" => Ok(Agent::Kiro),
             "opencode" => Ok(Agent::OpenCode),
             other => bail!(
-                "unknown agent: {other} (expected claude, codex, copilot, gemini, goose, kiro, or opencode)"
+                "unknown agent: {other} (expected claude, codex, copilot, cursor, gemini, goose, kiro, or opencode)"
+            ),
+        }
+    }
+
+    /// Resolve an agent name against the built-in agents and the
+    /// `[[agent-profile]]` entries of the user config.
+    pub fn resolve(name: &str, profiles: &[AgentProfile]) -> Result<Self> {
+        let profile = profiles.iter().find(|p| p.name == name);
+        match (Agent::from_config_name(name), profile) {
+            (Ok(_), Some(_)) => {
+                bail!("agent profile `{name}` has the same name as a built-in agent")
+            }
+            (Ok(agent), None) => Ok(agent),
+            (Err(_), Some(profile)) => Ok(Agent::Profile(Arc::new(profile.clone()))),
+            (Err(e), None) if profiles.is_empty() => Err(e),
+            (Err(_), None) => bail!(
+                "unknown agent: {name} (expected a built-in agent or one of the agent profiles: {})",
+                profiles
+                    .iter()
+                    .map(|p| p.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
             ),
         }
     }
 
     /// Config name as stored in TOML.
-    pub fn config_name(&self) -> &'static str {
+    pub fn config_name(&self) -> &str {
         match self {
             Agent::Claude => "claude",
             Agent::Codex => "codex",
             Agent::Copilot => "copilot",
+            Agent::Cursor => "cursor",
             Agent::Gemini => "gemini",
             Agent::Goose => "goose",
             Agent::Kiro => "kiro",
             Agent::OpenCode => "opencode",
+            Agent::Profile(profile) => &profile.name,
+        }
+    }
+
+    /// The hook payload schema this agent sends: its own for a built-in, the
+    /// profile's `schema` for a profile.
+    pub fn hook_schema(&self) -> HookAgent {
+        match self {
+            Agent::Claude => HookAgent::Claude,
+            Agent::Codex => HookAgent::Codex,
+            Agent::Copilot => HookAgent::Copilot,
+            Agent::Cursor => HookAgent::Cursor,
+            Agent::Gemini => HookAgent::Gemini,
+            Agent::Goose => HookAgent::Goose,
+            Agent::Kiro => HookAgent::Kiro,
+            Agent::OpenCode => HookAgent::OpenCode,
+            Agent::Profile(profile) => profile.schema,
         }
     }
 
     /// Human-readable display name.
-    pub fn display_name(&self) -> &'static str {
+    pub fn display_name(&self) -> &str {
         match self {
             Agent::Claude => "Claude Code",
             Agent::Codex => "Codex CLI",
             Agent::Copilot => "GitHub Copilot",
+            Agent::Cursor => "Cursor",
             Agent::Gemini => "Gemini CLI",
             Agent::Goose => "Goose",
             Agent::Kiro => "Kiro",
             Agent::OpenCode => "OpenCode",
+            Agent::Profile(profile) => profile.display_name.as_deref().unwrap_or(&profile.name),
         }
     }
 
-    /// All supported agents for interactive prompts.
+    /// All built-in agents.
     pub fn all() -> &'static [Agent] {
-        &[
+        const ALL: &[Agent] = &[
             Agent::Claude,
             Agent::Codex,
             Agent::Copilot,
+            Agent::Cursor,
             Agent::Gemini,
             Agent::Goose,
             Agent::Kiro,
             Agent::OpenCode,
-        ]
+        ];
+        ALL
+    }
+
+    /// Built-in agents followed by the agents described in `profiles`, for
+    /// interactive prompts and cleanup after agents removed from config.
+    /// Profiles shadowing a built-in name are left out.
+    pub fn known(profiles: &[AgentProfile]) -> Vec<Agent> {
+        let mut agents = Agent::all().to_vec();
+        for profile in profiles {
+            if Agent::from_config_name(&profile.name).is_err() {
+                agents.push(Agent::Profile(Arc::new(profile.clone())));
+            }
+        }
+        agents
     }
 
     // -----------------------------------------------------------------------
@@ -90,7 +155,10 @@ impl Agent {
     /// Project-level skill directory for a given skill name.
     ///
     /// Claude Code requires `.claude/skills/`, while Copilot and Gemini
-    /// support the vendor-neutral `.agents/skills/` path.
+    /// support the vendor-neutral `.agents/skills/` path. Cursor reads rules
+    /// rather than skills, so each skill becomes a folder under
+    /// `.cursor/rules/` holding the skill and a derived `.mdc` rule (see
+    /// [`Agent::derive_skill_files`]).
     pub fn project_skill_dir(&self, project_root: &Path, skill_name: &str) -> PathBuf {
         match self {
             Agent::Claude => project_root.join(".claude").join("skills").join(skill_name),
@@ -98,8 +166,10 @@ impl Agent {
                 project_root.join(".agents").join("skills").join(skill_name)
             }
             Agent::Goose => project_root.join(".agents").join("skills").join(skill_name),
+            Agent::Cursor => project_root.join(".cursor").join("rules").join(skill_name),
             Agent::Kiro => project_root.join(".kiro").join("skills").join(skill_name),
             Agent::OpenCode => project_root.join(".agents").join("skills").join(skill_name),
+            Agent::Profile(profile) => project_root.join(&profile.skills).join(skill_name),
         }
     }
 
@@ -109,10 +179,34 @@ impl Agent {
             Agent::Claude => Some(home.join(".claude").join("skills").join(skill_name)),
             Agent::Codex => Some(home.join(".agents").join("skills").join(skill_name)),
             Agent::Copilot => None, // no global skills path
+            Agent::Cursor => None,  // user rules live in Cursor's settings, not on disk
             Agent::Gemini => Some(home.join(".gemini").join("skills").join(skill_name)),
             Agent::Goose => Some(home.join(".agents").join("skills").join(skill_name)),
             Agent::Kiro => Some(home.join(".kiro").join("skills").join(skill_name)),
             Agent::OpenCode => Some(home.join(".agents").join("skills").join(skill_name)),
+            Agent::Profile(profile) => profile
+                .global_skills
+                .as_ref()
+                .map(|dir| home.join(dir).join(skill_name)),
+        }
+    }
+
+    /// Write the files this agent derives from an installed skill, e.g.
+    /// Cursor's `.mdc` rule. Called after each install or re-check of
+    /// `skill_dir`; a no-op for agents that read `SKILL.md` directly.
+    pub fn derive_skill_files(&self, skill_dir: &Path) -> Result<()> {
+        match self {
+            Agent::Cursor => cursor_rule::write_rule(skill_dir),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `rel` (relative to `skill_dir`) is a file written by
+    /// [`Agent::derive_skill_files`] rather than copied from the skill source.
+    pub fn is_derived_skill_file(&self, skill_dir: &Path, rel: &Path) -> bool {
+        match self {
+            Agent::Cursor => cursor_rule::rule_file(skill_dir) == skill_dir.join(rel),
+            _ => false,
         }
     }
 
@@ -137,6 +231,9 @@ impl Agent {
             Agent::Copilot => {
                 register_copilot_hooks(&project_root.join(".github").join("hooks"), out)
             }
+            Agent::Cursor => {
+                register_cursor_hooks(&project_root.join(".cursor").join("hooks.json"), out)
+            }
             Agent::Gemini => {
                 register_gemini_hooks(&project_root.join(".gemini").join("settings.json"), out)
             }
@@ -148,9 +245,9 @@ impl Agent {
                 Ok(())
             }
             Agent::OpenCode => {
-                out.info("OpenCode uses JS/TS plugins for hooks; skipping hook registration (skills only)");
-                Ok(())
+                register_opencode_hooks(&project_root.join(".opencode").join("plugins"), out)
             }
+            Agent::Profile(profile) => register_profile_hooks(profile, project_root, out),
         }?;
 
         Ok(())
@@ -168,6 +265,7 @@ impl Agent {
             Agent::Copilot => {
                 register_copilot_hooks_global(&home.join(".copilot").join("settings.json"), out)
             }
+            Agent::Cursor => register_cursor_hooks(&home.join(".cursor").join("hooks.json"), out),
             Agent::Gemini => {
                 register_gemini_hooks(&home.join(".gemini").join("settings.json"), out)
             }
@@ -178,10 +276,8 @@ impl Agent {
                 );
                 Ok(())
             }
-            Agent::OpenCode => {
-                out.info("OpenCode uses JS/TS plugins for hooks; skipping hook registration (skills only)");
-                Ok(())
-            }
+            Agent::OpenCode => register_opencode_hooks(&opencode_global_plugins_dir(home), out),
+            Agent::Profile(profile) => register_profile_hooks(profile, home, out),
         }?;
 
         Ok(())
@@ -214,6 +310,11 @@ impl Agent {
                 servers,
                 out,
             ),
+            Agent::Cursor => mcp_server_registration::register_cursor_mcp_servers(
+                &project_root.join(".cursor").join("mcp.json"),
+                servers,
+                out,
+            ),
             Agent::Gemini => mcp_server_registration::register_gemini_mcp_servers(
                 &project_root.join(".gemini").join("settings.json"),
                 servers,
@@ -234,6 +335,15 @@ impl Agent {
                 servers,
                 out,
             ),
+            Agent::Profile(profile) => match &profile.mcp {
+                Some(mcp) => mcp_server_registration::register_profile_mcp_servers(
+                    &project_root.join(&mcp.path),
+                    mcp,
+                    servers,
+                    out,
+                ),
+                None => Ok(()),
+            },
         }
     }
 
@@ -261,6 +371,11 @@ impl Agent {
                 servers,
                 out,
             ),
+            Agent::Cursor => mcp_server_registration::register_cursor_mcp_servers(
+                &home.join(".cursor").join("mcp.json"),
+                servers,
+                out,
+            ),
             Agent::Gemini => mcp_server_registration::register_gemini_mcp_servers(
                 &home.join(".gemini").join("settings.json"),
                 servers,
@@ -281,6 +396,15 @@ impl Agent {
                 servers,
                 out,
             ),
+            Agent::Profile(profile) => match &profile.mcp {
+                Some(mcp) => mcp_server_registration::register_profile_mcp_servers(
+                    &home.join(&mcp.path),
+                    mcp,
+                    servers,
+                    out,
+                ),
+                None => Ok(()),
+            },
         }
     }
 
@@ -307,6 +431,11 @@ impl Agent {
                 names,
                 out,
             ),
+            Agent::Cursor => mcp_server_registration::unregister_cursor_mcp_servers(
+                &project_root.join(".cursor").join("mcp.json"),
+                names,
+                out,
+            ),
             Agent::Gemini => mcp_server_registration::unregister_gemini_mcp_servers(
                 &project_root.join(".gemini").join("settings.json"),
                 names,
@@ -327,6 +456,15 @@ impl Agent {
                 names,
                 out,
             ),
+            Agent::Profile(profile) => match &profile.mcp {
+                Some(mcp) => mcp_server_registration::unregister_profile_mcp_servers(
+                    &project_root.join(&mcp.path),
+                    mcp,
+                    names,
+                    out,
+                ),
+                None => Ok(()),
+            },
         }
     }
 
@@ -353,6 +491,11 @@ impl Agent {
                 names,
                 out,
             ),
+            Agent::Cursor => mcp_server_registration::unregister_cursor_mcp_servers(
+                &home.join(".cursor").join("mcp.json"),
+                names,
+                out,
+            ),
             Agent::Gemini => mcp_server_registration::unregister_gemini_mcp_servers(
                 &home.join(".gemini").join("settings.json"),
                 names,
@@ -373,6 +516,15 @@ impl Agent {
                 names,
                 out,
             ),
+            Agent::Profile(profile) => match &profile.mcp {
+                Some(mcp) => mcp_server_registration::unregister_profile_mcp_servers(
+                    &home.join(&mcp.path),
+                    mcp,
+                    names,
+                    out,
+                ),
+                None => Ok(()),
+            },
         }
     }
 
@@ -388,12 +540,18 @@ impl Agent {
             Agent::Copilot => {
                 unregister_copilot_hooks(&project_root.join(".github").join("hooks"), out)
             }
+            Agent::Cursor => {
+                unregister_cursor_hooks(&project_root.join(".cursor").join("hooks.json"), out)
+            }
             Agent::Gemini => {
                 unregister_gemini_hooks(&project_root.join(".gemini").join("settings.json"), out)
             }
             Agent::Kiro => unregister_kiro_hooks(&project_root.join(".kiro").join("agents"), out),
-            Agent::Goose => {}    // no hooks to unregister
-            Agent::OpenCode => {} // no hooks to unregister
+            Agent::Goose => {} // no hooks to unregister
+            Agent::OpenCode => {
+                unregister_opencode_hooks(&project_root.join(".opencode").join("plugins"), out)
+            }
+            Agent::Profile(profile) => unregister_profile_hooks(profile, project_root, out),
         }
     }
 
@@ -407,16 +565,68 @@ impl Agent {
             Agent::Copilot => {
                 unregister_copilot_hooks_global(&home.join(".copilot").join("settings.json"), out)
             }
+            Agent::Cursor => unregister_cursor_hooks(&home.join(".cursor").join("hooks.json"), out),
             Agent::Gemini => {
                 unregister_gemini_hooks(&home.join(".gemini").join("settings.json"), out)
             }
             Agent::Kiro => unregister_kiro_hooks(&home.join(".kiro").join("agents"), out),
-            Agent::Goose => {}    // no hooks to unregister
-            Agent::OpenCode => {} // no hooks to unregister
+            Agent::Goose => {} // no hooks to unregister
+            Agent::OpenCode => unregister_opencode_hooks(&opencode_global_plugins_dir(home), out),
+            Agent::Profile(profile) => unregister_profile_hooks(profile, home, out),
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Agent profile hook registration
+// ---------------------------------------------------------------------------
+
+/// Register hooks for a profile agent with the writer of its schema, at the
+/// profile's `hooks` path under `root`.
+fn register_profile_hooks(profile: &AgentProfile, root: &Path, out: &Output) -> Result<()> {
+    let Some(hooks) = &profile.hooks else {
+        out.info(format!(
+            "{}: no `hooks` path in its agent profile; skipping hook registration",
+            profile.name
+        ));
+        return Ok(());
+    };
+    let path = root.join(hooks);
+    match profile.schema {
+        HookAgent::Claude => register_claude_hooks(&path, out),
+        HookAgent::Codex => register_codex_hooks(&path, out),
+        HookAgent::Copilot => register_copilot_hooks(&path, out),
+        HookAgent::Cursor => register_cursor_hooks(&path, out),
+        HookAgent::Gemini => register_gemini_hooks(&path, out),
+        HookAgent::Kiro => register_kiro_hooks(&path, out),
+        HookAgent::OpenCode => register_opencode_hooks(&path, out),
+        HookAgent::Goose => {
+            out.info(format!(
+                "{}: the goose schema has no hooks; skipping hook registration",
+                profile.name
+            ));
+            Ok(())
+        }
+    }
+}
+
+fn unregister_profile_hooks(profile: &AgentProfile, root: &Path, out: &Output) {
+    let Some(hooks) = &profile.hooks else {
+        return;
+    };
+    let path = root.join(hooks);
+    match profile.schema {
+        HookAgent::Claude => unregister_claude_hooks(&path, out),
+        HookAgent::Codex => unregister_codex_hooks(&path, out),
+        HookAgent::Copilot => unregister_copilot_hooks(&path, out),
+        HookAgent::Cursor => unregister_cursor_hooks(&path, out),
+        HookAgent::Gemini => unregister_gemini_hooks(&path, out),
+        HookAgent::Kiro => unregister_kiro_hooks(&path, out),
+        HookAgent::OpenCode => unregister_opencode_hooks(&path, out),
+        HookAgent::Goose => {}
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Claude Code hook registration
 // ---------------------------------------------------------------------------
@@ -451,7 +661,7 @@ fn register_claude_hooks(settings_path: &Path, out: &Output) -> Result<()> {
     if added.is_empty() {
         out.already_ok(format!("{display}: hooks already registered"));
     } else {
-        save_json(settings_path, &settings)?;
+        save_json(settings_path, &settings, out)?;
         out.done(format!("{display}: added hooks ({})", added.join(", ")));
     }
 
@@ -521,6 +731,7 @@ fn register_codex_hooks(hooks_path: &Path, out: &Output) -> Result<()> {
         "PostToolUse",
         "UserPromptSubmit",
         "SessionStart",
+        "Stop",
     ] {
         let command = format!("cargo-agents hook codex {}", event_to_cli_arg(event));
         if ensure_codex_hook_entry(hooks_obj, event, &command) {
@@ -531,7 +742,7 @@ fn register_codex_hooks(hooks_path: &Path, out: &Output) -> Result<()> {
     if added.is_empty() {
         out.already_ok(format!("{display}: hooks already registered"));
     } else {
-        save_json(hooks_path, &settings)?;
+        save_json(hooks_path, &settings, out)?;
         out.done(format!("{display}: added hooks ({})", added.join(", ")));
     }
 
@@ -624,14 +835,13 @@ fn register_copilot_hooks_global(config_path: &Path, out: &Output) -> Result<()>
         }
     }
 
-    save_json(config_path, &config)?;
+    save_json(config_path, &config, out)?;
     out.done(format!("{display}: added hooks"));
     Ok(())
 }
 
 /// Register hooks in a project-level Copilot hooks directory (`.github/hooks/`).
 fn register_copilot_hooks(hooks_dir: &Path, out: &Output) -> Result<()> {
-    fs::create_dir_all(hooks_dir)?;
     let hook_file = hooks_dir.join("symposium.json");
     let display = display_path(&hook_file);
 
@@ -653,7 +863,7 @@ fn register_copilot_hooks(hooks_dir: &Path, out: &Output) -> Result<()> {
         "hooks": hooks_obj
     });
 
-    save_json(&hook_file, &hooks)?;
+    save_json(&hook_file, &hooks, out)?;
     out.done(format!("{display}: added hooks"));
     Ok(())
 }
@@ -693,9 +903,67 @@ fn copilot_hook_entries() -> Vec<(&'static str, serde_json::Value)> {
                 "timeoutSec": 10
             }),
         ),
+        // Only VS Code fires this (as `Stop`); the Copilot CLI has no stop event.
+        (
+            "stop",
+            json!({
+                "type": "command",
+                "bash": "cargo-agents hook copilot stop",
+                "timeoutSec": 10
+            }),
+        ),
     ]
 }
 
+// ---------------------------------------------------------------------------
+// Cursor hook registration
+// ---------------------------------------------------------------------------
+
+/// Register hooks in a Cursor `hooks.json` (`.cursor/hooks.json` or
+/// `~/.cursor/hooks.json`). Cursor fires one event per kind of action, so
+/// several Cursor events route to the same symposium event; the payload's
+/// `hook_event_name` tells them apart.
+fn register_cursor_hooks(hooks_path: &Path, out: &Output) -> Result<()> {
+    let mut config = load_json_or_empty(hooks_path)?;
+    let display = display_path(hooks_path);
+
+    let obj = config.as_object_mut().unwrap();
+    obj.entry("version").or_insert_with(|| json!(1));
+    let hooks = obj.entry("hooks").or_insert_with(|| json!({}));
+    let hooks_obj = hooks.as_object_mut().unwrap();
+
+    let mut added = Vec::new();
+    for (event, cli_arg) in [
+        ("beforeShellExecution", "pre-tool-use"),
+        ("beforeMCPExecution", "pre-tool-use"),
+        ("beforeReadFile", "pre-tool-use"),
+        ("afterShellExecution", "post-tool-use"),
+        ("afterMCPExecution", "post-tool-use"),
+        ("afterFileEdit", "post-tool-use"),
+        ("beforeSubmitPrompt", "user-prompt-submit"),
+        ("stop", "stop"),
+    ] {
+        let entry = json!({ "command": format!("cargo-agents hook cursor {cli_arg}") });
+        // Cursor entries are flat `{ "command": ... }` objects, like Kiro's.
+        if ensure_kiro_hook_entry(hooks_obj, event, &entry) {
+            added.push(event);
+        }
+    }
+
+    if added.is_empty() {
+        out.already_ok(format!("{display}: hooks already registered"));
+    } else {
+        save_json(hooks_path, &config, out)?;
+        out.done(format!("{display}: added hooks ({})", added.join(", ")));
+    }
+
+    Ok(())
+}
+
+fn unregister_cursor_hooks(hooks_path: &Path, out: &Output) {
+    unregister_flat_hooks(hooks_path, "command", out);
+}
+
 // ---------------------------------------------------------------------------
 // Gemini CLI hook registration
 // ---------------------------------------------------------------------------
@@ -719,6 +987,7 @@ fn register_gemini_hooks(settings_path: &Path, out: &Output) -> Result<()> {
         ("AfterTool", "post-tool-use"),
         ("BeforeAgent", "user-prompt-submit"),
         ("SessionStart", "session-start"),
+        ("AfterAgent", "stop"),
     ];
 
     for (gemini_event, cli_arg) in events {
@@ -731,7 +1000,7 @@ fn register_gemini_hooks(settings_path: &Path, out: &Output) -> Result<()> {
     if added.is_empty() {
         out.already_ok(format!("{display}: hooks already registered"));
     } else {
-        save_json(settings_path, &settings)?;
+        save_json(settings_path, &settings, out)?;
         out.done(format!("{display}: added hooks ({})", added.join(", ")));
     }
 
@@ -831,7 +1100,6 @@ fn merge_kiro_hooks(
 
 /// Register hooks by creating a Kiro agent file (`.kiro/agents/symposium.json`).
 fn register_kiro_hooks(agents_dir: &Path, out: &Output) -> Result<()> {
-    fs::create_dir_all(agents_dir)?;
     let hook_file = agents_dir.join("symposium.json");
     let display = display_path(&hook_file);
 
@@ -841,7 +1109,7 @@ fn register_kiro_hooks(agents_dir: &Path, out: &Output) -> Result<()> {
     if !changed {
         out.already_ok(format!("{display}: hooks already registered"));
     } else {
-        save_json(&hook_file, &config)?;
+        save_json(&hook_file, &config, out)?;
         if added.is_empty() {
             out.done(format!("{display}: updated agent config"));
         } else {
@@ -909,6 +1177,12 @@ fn kiro_hook_entries() -> Vec<(&'static str, serde_json::Value)> {
                 "command": "cargo-agents hook kiro session-start"
             }),
         ),
+        (
+            "stop",
+            json!({
+                "command": "cargo-agents hook kiro stop"
+            }),
+        ),
     ]
 }
 
@@ -917,7 +1191,58 @@ fn unregister_kiro_hooks(agents_dir: &Path, out: &Output) {
     let hook_file = agents_dir.join("symposium.json");
     if hook_file.exists() {
         let display = display_path(&hook_file);
-        if fs::remove_file(&hook_file).is_ok() {
+        if remove_config(&hook_file, out) {
+            out.removed(format!("{display}: removed hooks"));
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// OpenCode hook registration
+// ---------------------------------------------------------------------------
+
+/// The managed OpenCode plugin that bridges OpenCode's JS plugin hooks to
+/// `cargo-agents hook opencode <event>`.
+const OPENCODE_PLUGIN: &str = include_str!("opencode-plugin.js");
+
+/// The file name symposium owns inside an OpenCode plugins directory.
+const OPENCODE_PLUGIN_FILE: &str = "symposium.js";
+
+fn opencode_global_plugins_dir(home: &Path) -> PathBuf {
+    home.join(".config").join("opencode").join("plugins")
+}
+
+/// Register hooks by writing the bridge plugin into an OpenCode plugins
+/// directory. The file is fully managed, so a stale copy is overwritten.
+fn register_opencode_hooks(plugins_dir: &Path, out: &Output) -> Result<()> {
+    let plugin_file = plugins_dir.join(OPENCODE_PLUGIN_FILE);
+    let display = display_path(&plugin_file);
+
+    match fs::read_to_string(&plugin_file) {
+        Ok(existing) if existing == OPENCODE_PLUGIN => {
+            out.already_ok(format!("{display}: hooks already registered"));
+        }
+        Ok(_) => {
+            write_config(&plugin_file, OPENCODE_PLUGIN, out)?;
+            out.done(format!("{display}: updated hook plugin"));
+        }
+        Err(_) => {
+            write_config(&plugin_file, OPENCODE_PLUGIN, out)?;
+            out.done(format!(
+                "{display}: added hooks (tool.execute.before, tool.execute.after, session.created, stop)"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove the symposium bridge plugin from an OpenCode plugins directory.
+fn unregister_opencode_hooks(plugins_dir: &Path, out: &Output) {
+    let plugin_file = plugins_dir.join(OPENCODE_PLUGIN_FILE);
+    if plugin_file.exists() {
+        let display = display_path(&plugin_file);
+        if remove_config(&plugin_file, out) {
             out.removed(format!("{display}: removed hooks"));
         }
     }
@@ -962,7 +1287,7 @@ fn unregister_settings_hooks(settings_path: &Path, command_prefix: &str, out: &O
         }
     }
 
-    if changed && let Ok(()) = save_json(settings_path, &settings) {
+    if changed && let Ok(()) = save_json(settings_path, &settings, out) {
         out.removed(format!("{display}: removed hooks"));
     }
 }
@@ -980,7 +1305,7 @@ fn unregister_copilot_hooks(hooks_dir: &Path, out: &Output) {
     let hook_file = hooks_dir.join("symposium.json");
     if hook_file.exists() {
         let display = display_path(&hook_file);
-        if fs::remove_file(&hook_file).is_ok() {
+        if remove_config(&hook_file, out) {
             out.removed(format!("{display}: removed hooks"));
         }
     }
@@ -1023,7 +1348,7 @@ fn unregister_flat_hooks(config_path: &Path, command_key: &str, out: &Output) {
         }
     }
 
-    if changed && let Ok(()) = save_json(config_path, &config) {
+    if changed && let Ok(()) = save_json(config_path, &config, out) {
         out.removed(format!("{display}: removed hooks"));
     }
 }
@@ -1055,15 +1380,49 @@ fn load_json_or_empty(path: &Path) -> Result<serde_json::Value> {
     }
 }
 
-fn save_json(path: &Path, value: &serde_json::Value) -> Result<()> {
+fn save_json(path: &Path, value: &serde_json::Value, out: &Output) -> Result<()> {
+    let contents = serde_json::to_string_pretty(value)?;
+    write_config(path, &contents, out)
+}
+
+/// Write an agent config file, creating missing parent directories. Under a
+/// dry-run [`Output`] the write is reported as planned and the disk is left
+/// alone.
+fn write_config(path: &Path, contents: &str, out: &Output) -> Result<()> {
+    if out.is_dry_run() {
+        plan_config_change(path, "write", out);
+        return Ok(());
+    }
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
-    let contents = serde_json::to_string_pretty(value)?;
     fs::write(path, contents)?;
     Ok(())
 }
 
+/// Remove an agent config file (or, under a dry-run [`Output`], report that
+/// it would be removed). Returns whether it was (or would be) removed.
+fn remove_config(path: &Path, out: &Output) -> bool {
+    if out.is_dry_run() {
+        plan_config_change(path, "remove", out);
+        return true;
+    }
+    fs::remove_file(path).is_ok()
+}
+
+fn plan_config_change(path: &Path, action: &str, out: &Output) {
+    if !out.plan_change(path) || out.is_probe() {
+        return;
+    }
+    tracing::info!(
+        report = %crate::report::ReportEvent::SyncPlanned {
+            action: action.to_string(),
+            path: display_path(path),
+            detail: Some("agent configuration".into()),
+        },
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1252,6 +1611,45 @@ mod tests {
         assert!(!hook_file.exists());
     }
 
+    #[test]
+    fn register_opencode_hooks_writes_bridge_plugin() {
+        let tmp = tempfile::tempdir().unwrap();
+        let plugins_dir = tmp.path().join("plugins");
+        register_opencode_hooks(&plugins_dir, &Output::quiet()).unwrap();
+
+        let plugin_file = plugins_dir.join("symposium.js");
+        let content = fs::read_to_string(&plugin_file).unwrap();
+        assert!(content.contains(r#""cargo-agents", "hook", "opencode""#));
+        assert!(content.contains(r#""tool.execute.before""#));
+        assert!(content.contains(r#""tool.execute.after""#));
+    }
+
+    #[test]
+    fn register_opencode_hooks_overwrites_stale_plugin() {
+        let tmp = tempfile::tempdir().unwrap();
+        let plugins_dir = tmp.path().join("plugins");
+        fs::create_dir_all(&plugins_dir).unwrap();
+        fs::write(plugins_dir.join("symposium.js"), "// old bridge").unwrap();
+
+        register_opencode_hooks(&plugins_dir, &Output::quiet()).unwrap();
+        assert_eq!(
+            fs::read_to_string(plugins_dir.join("symposium.js")).unwrap(),
+            OPENCODE_PLUGIN
+        );
+    }
+
+    #[test]
+    fn unregister_opencode_hooks_removes_plugin() {
+        let tmp = tempfile::tempdir().unwrap();
+        let plugins_dir = tmp.path().join("plugins");
+        register_opencode_hooks(&plugins_dir, &Output::quiet()).unwrap();
+        fs::write(plugins_dir.join("user-plugin.js"), "// theirs").unwrap();
+
+        unregister_opencode_hooks(&plugins_dir, &Output::quiet());
+        assert!(!plugins_dir.join("symposium.js").exists());
+        assert!(plugins_dir.join("user-plugin.js").exists());
+    }
+
     #[test]
     fn agent_from_config_name_opencode() {
         assert_eq!(
@@ -1314,4 +1712,166 @@ mod tests {
         assert!(settings["hooks"]["BeforeAgent"].is_array());
         assert!(settings["hooks"]["SessionStart"].is_array());
     }
+
+    #[test]
+    fn agent_from_config_name_cursor() {
+        assert_eq!(Agent::from_config_name("cursor").unwrap(), Agent::Cursor);
+    }
+
+    #[test]
+    fn cursor_skills_install_under_rules() {
+        assert_eq!(
+            Agent::Cursor.project_skill_dir(Path::new("/project"), "tokio"),
+            PathBuf::from("/project/.cursor/rules/tokio")
+        );
+        assert_eq!(
+            Agent::Cursor.global_skill_dir(Path::new("/home/user"), "tokio"),
+            None
+        );
+    }
+
+    #[test]
+    fn cursor_rule_is_a_derived_skill_file() {
+        let dir = Path::new("/project/.cursor/rules/tokio");
+        assert!(Agent::Cursor.is_derived_skill_file(dir, Path::new("tokio.mdc")));
+        assert!(!Agent::Cursor.is_derived_skill_file(dir, Path::new("SKILL.md")));
+        assert!(!Agent::Claude.is_derived_skill_file(dir, Path::new("tokio.mdc")));
+    }
+
+    #[test]
+    fn register_cursor_hooks_creates_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let hooks_path = tmp.path().join("hooks.json");
+        register_cursor_hooks(&hooks_path, &Output::quiet()).unwrap();
+
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&hooks_path).unwrap()).unwrap();
+        assert_eq!(config["version"], 1);
+        assert_eq!(
+            config["hooks"]["beforeShellExecution"][0]["command"],
+            "cargo-agents hook cursor pre-tool-use"
+        );
+        assert_eq!(
+            config["hooks"]["afterFileEdit"][0]["command"],
+            "cargo-agents hook cursor post-tool-use"
+        );
+        assert_eq!(
+            config["hooks"]["stop"][0]["command"],
+            "cargo-agents hook cursor stop"
+        );
+    }
+
+    #[test]
+    fn register_cursor_hooks_idempotent_and_preserves_user_hooks() {
+        let tmp = tempfile::tempdir().unwrap();
+        let hooks_path = tmp.path().join("hooks.json");
+        let user = json!({
+            "version": 1,
+            "hooks": { "afterFileEdit": [{ "command": "./format.sh" }] }
+        });
+        save_json(&hooks_path, &user, &Output::quiet()).unwrap();
+
+        register_cursor_hooks(&hooks_path, &Output::quiet()).unwrap();
+        register_cursor_hooks(&hooks_path, &Output::quiet()).unwrap();
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&hooks_path).unwrap()).unwrap();
+        assert_eq!(
+            config["hooks"]["afterFileEdit"].as_array().unwrap().len(),
+            2
+        );
+
+        unregister_cursor_hooks(&hooks_path, &Output::quiet());
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&hooks_path).unwrap()).unwrap();
+        assert_eq!(
+            config["hooks"]["afterFileEdit"],
+            json!([{ "command": "./format.sh" }])
+        );
+    }
+
+    fn windsurf_profile() -> AgentProfile {
+        toml::from_str(indoc::indoc! {r#"
+            name = "windsurf"
+            display-name = "Windsurf"
+            schema = "claude"
+            skills = ".windsurf/skills"
+            global-skills = ".codeium/windsurf/skills"
+            hooks = ".windsurf/hooks.json"
+            mcp = { path = ".codeium/windsurf/mcp_config.json" }
+        "#})
+        .unwrap()
+    }
+
+    #[test]
+    fn resolve_finds_builtins_and_profiles() {
+        let profiles = [windsurf_profile()];
+        assert_eq!(Agent::resolve("kiro", &profiles).unwrap(), Agent::Kiro);
+
+        let agent = Agent::resolve("windsurf", &profiles).unwrap();
+        assert_eq!(agent.config_name(), "windsurf");
+        assert_eq!(agent.display_name(), "Windsurf");
+
+        let err = Agent::resolve("amp", &profiles).unwrap_err();
+        assert!(err.to_string().contains("windsurf"), "{err}");
+    }
+
+    #[test]
+    fn resolve_rejects_profile_shadowing_builtin() {
+        let mut profile = windsurf_profile();
+        profile.name = "claude".into();
+        let profiles = [profile];
+        assert!(Agent::resolve("claude", &profiles).is_err());
+        assert_eq!(Agent::known(&profiles).len(), Agent::all().len());
+    }
+
+    #[test]
+    fn profile_skill_dirs() {
+        let agent = Agent::Profile(Arc::new(windsurf_profile()));
+        assert_eq!(
+            agent.project_skill_dir(Path::new("/project"), "tokio"),
+            PathBuf::from("/project/.windsurf/skills/tokio")
+        );
+        assert_eq!(
+            agent.global_skill_dir(Path::new("/home/user"), "tokio"),
+            Some(PathBuf::from("/home/user/.codeium/windsurf/skills/tokio"))
+        );
+    }
+
+    #[test]
+    fn register_profile_hooks_uses_schema_writer() {
+        let tmp = tempfile::tempdir().unwrap();
+        let profile = windsurf_profile();
+        register_profile_hooks(&profile, tmp.path(), &Output::quiet()).unwrap();
+
+        let settings_path = tmp.path().join(".windsurf/hooks.json");
+        let settings: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        assert_eq!(
+            settings["hooks"]["PreToolUse"][0]["hooks"][0]["command"],
+            "cargo-agents hook claude pre-tool-use"
+        );
+
+        unregister_profile_hooks(&profile, tmp.path(), &Output::quiet());
+        let settings: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        assert!(
+            !settings.to_string().contains("cargo-agents hook"),
+            "{settings}"
+        );
+    }
+
+    #[test]
+    fn register_profile_mcp_servers_at_profile_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        let agent = Agent::Profile(Arc::new(windsurf_profile()));
+        let servers = [crate::mcp_serve::registration_entry()];
+        agent
+            .register_global_mcp_servers(tmp.path(), &servers, &Output::quiet())
+            .unwrap();
+
+        let config_path = tmp.path().join(".codeium/windsurf/mcp_config.json");
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert!(config["mcpServers"][crate::mcp_serve::SERVER_NAME].is_object());
+    }
 }
@@ -0,0 +1,95 @@
+// Managed by symposium (`cargo agents sync`); changes will be overwritten.
+//
+// Forwards OpenCode plugin hooks to `cargo-agents hook opencode <event>` and
+// maps the result back: a `deny` decision throws (blocking the tool), `args`
+// replaces the tool arguments, and `additionalContext` is appended to the
+// tool's output.
+
+export const SymposiumPlugin = async ({ client, directory }) => {
+  // Context from `tool.execute.before`, held until the call's result exists.
+  const pending = new Map();
+
+  const forward = async (event, payload) => {
+    try {
+      const proc = Bun.spawn(["cargo-agents", "hook", "opencode", event], {
+        cwd: directory,
+        stdin: new Blob([JSON.stringify({ ...payload, directory })]),
+        stdout: "pipe",
+        stderr: "ignore",
+      });
+      const [stdout, code] = await Promise.all([
+        new Response(proc.stdout).text(),
+        proc.exited,
+      ]);
+      if (code !== 0 || !stdout.trim()) return {};
+      return JSON.parse(stdout);
+    } catch {
+      // Symposium must never break the agent; a failed hook is a no-op.
+      return {};
+    }
+  };
+
+  return {
+    "tool.execute.before": async (input, output) => {
+      const result = await forward("pre-tool-use", {
+        hook: "tool.execute.before",
+        tool: input.tool,
+        sessionID: input.sessionID,
+        callID: input.callID,
+        args: output.args,
+      });
+      if (result.decision === "deny") {
+        throw new Error(result.reason || "blocked by a symposium plugin hook");
+      }
+      if (result.args && typeof result.args === "object") {
+        for (const key of Object.keys(output.args)) delete output.args[key];
+        Object.assign(output.args, result.args);
+      }
+      if (result.additionalContext) {
+        pending.set(input.callID, result.additionalContext);
+      }
+    },
+
+    "tool.execute.after": async (input, output) => {
+      const result = await forward("post-tool-use", {
+        hook: "tool.execute.after",
+        tool: input.tool,
+        sessionID: input.sessionID,
+        callID: input.callID,
+        args: input.args,
+        result: output,
+      });
+      const context = [pending.get(input.callID), result.additionalContext]
+        .filter(Boolean)
+        .join("\n\n");
+      pending.delete(input.callID);
+      if (context) {
+        output.output = `${output.output ?? ""}\n\n${context}`;
+      }
+    },
+
+    event: async ({ event }) => {
+      if (event.type !== "session.created") return;
+      const sessionID = event.properties?.info?.id;
+      const result = await forward("session-start", {
+        hook: "session.created",
+        sessionID,
+      });
+      if (result.additionalContext && sessionID) {
+        await client.session
+          .prompt({
+            path: { id: sessionID },
+            body: {
+              noReply: true,
+              parts: [{ type: "text", text: result.additionalContext }],
+            },
+          })
+          .catch(() => {});
+      }
+    },
+
+    stop: async (input) => {
+      await forward("stop", { hook: "stop", sessionID: input?.sessionID });
+    },
+  };
+};
@@ -54,7 +54,11 @@ async fn main() -> ExitCode {
 
     // Always install the report layer. Mode determines output format:
     // --json → accumulate JSON array; -v → stderr trace; default → stdout.
-    let (mode, level) = if cli.json {
+    // `mcp-serve` speaks MCP on stdout, so its report output goes to stderr.
+    let is_mcp_serve = matches!(cli.command, Some(Commands::McpServe));
+    let (mode, level) = if is_mcp_serve {
+        (report::ReportMode::Verbose, tracing::Level::INFO)
+    } else if cli.json {
         let level = if cli.verbose {
             tracing::Level::DEBUG
         } else {
@@ -72,7 +76,7 @@ async fn main() -> ExitCode {
     // Log the command being invoked
     match &cli.command {
         Some(Commands::Init { .. }) => tracing::info!("cargo agents init"),
-        Some(Commands::Sync) => tracing::info!("cargo agents sync"),
+        Some(Commands::Sync { .. }) => tracing::info!("cargo agents sync"),
         Some(Commands::Search { query }) => tracing::info!(%query, "cargo agents search"),
         Some(Commands::Use {
             name,
@@ -80,12 +84,21 @@ async fn main() -> ExitCode {
             remove,
         }) => tracing::info!(%name, global, remove, "cargo agents use"),
         Some(Commands::Status) => tracing::info!("cargo agents status"),
+        Some(Commands::Doctor) => tracing::info!("cargo agents doctor"),
+        Some(Commands::Uninstall { .. }) => tracing::info!("cargo agents uninstall"),
         Some(Commands::Plugin { command }) => {
             tracing::info!(subcommand = ?command, "cargo agents plugin");
         }
+        Some(Commands::Registry { command }) => {
+            tracing::info!(subcommand = ?command, "cargo agents registry");
+        }
+        Some(Commands::Config { command }) => {
+            tracing::info!(subcommand = ?command, "cargo agents config");
+        }
         Some(Commands::Hook { agent, event }) => {
             tracing::debug!(?agent, ?event, "cargo agents hook");
         }
+        Some(Commands::McpServe) => tracing::debug!("cargo agents mcp-serve"),
         Some(Commands::SelfUpdate) => tracing::info!("cargo agents self-update"),
         Some(Commands::CrateInfo { name, version }) => {
             tracing::debug!(%name, version = ?version, "cargo agents crate-info");
@@ -105,7 +118,7 @@ async fn main() -> ExitCode {
     // Hook commands are quiet by default (they're invoked by the agent, not the user).
     // JSON mode also suppresses human output (only JSON goes to stdout).
     let is_hook = matches!(cli.command, Some(Commands::Hook { .. }));
-    let out = if cli.quiet || is_hook || cli.json {
+    let out = if cli.quiet || is_hook || is_mcp_serve || cli.json {
         Output::quiet()
     } else {
         Output::normal()
@@ -122,13 +135,20 @@ async fn main() -> ExitCode {
         }
         _ => cli.update,
     };
-    plugins::ensure_registries(&sym, source_update).await;
+    // A dry run promises to change nothing, the cached registries included.
+    let dry_run = matches!(
+        cli.command,
+        Some(Commands::Sync { dry_run: true, .. } | Commands::Uninstall { dry_run: true, .. })
+    );
+    if !dry_run {
+        plugins::ensure_registries(&sym, source_update).await;
+    }
 
     // Auto-update = "on": check for updates and re-exec if a new binary was
     // installed.  Skipped for self-update (which always checks explicitly)
     // and for hooks (session-start injects the warn nudge into hook output;
     // the "on" re-exec for hooks is handled here).
-    if !matches!(cli.command, Some(Commands::SelfUpdate)) && !is_hook {
+    if !matches!(cli.command, Some(Commands::SelfUpdate)) && !is_hook && !is_mcp_serve {
         if self_update::maybe_check_for_update(&sym, &out).await {
             self_update::re_exec();
         }
@@ -142,6 +162,7 @@ async fn main() -> ExitCode {
     match cli.command {
         // Commands that need direct I/O (stdin/stdout) stay in the binary
         Some(Commands::Hook { agent, event }) => hook::run(&sym, agent, event).await,
+        Some(Commands::McpServe) => symposium::mcp_serve::run(sym, &cwd).await,
 
         Some(Commands::Plugin { command }) => {
             let code = handle_plugin_command(&sym, command).await;
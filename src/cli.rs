@@ -6,21 +6,26 @@
 use std::ffi::OsString;
 use std::path::Path;
 
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 use clap::{Parser, Subcommand};
 
 use crate::config::Symposium;
+use crate::config_command;
 use crate::crate_command::{self, DispatchResult};
 use crate::discovery;
+use crate::doctor_command;
 use crate::hook;
 use crate::init::{self, InitOpts};
+use crate::lockfile::LockMode;
 use crate::output::Output;
 use crate::plugins::Audience;
+use crate::registry_command;
 use crate::search_command;
 use crate::self_update;
 use crate::status_command;
 use crate::subcommand_dispatch::dispatch_external;
 use crate::sync;
+use crate::uninstall_command::{self, UninstallOptions, UninstallScope};
 use crate::use_command;
 
 /// Parsed CLI arguments.
@@ -78,7 +83,21 @@ pub enum Commands {
     },
 
     /// Synchronize skills with workspace dependencies
-    Sync,
+    Sync {
+        /// Write `symposium.lock` in the workspace root; once it exists,
+        /// every sync keeps it up to date
+        #[arg(long, conflicts_with = "locked")]
+        lock: bool,
+
+        /// Refuse to install anything that differs from `symposium.lock`
+        #[arg(long)]
+        locked: bool,
+
+        /// Show what sync would install, remove, and register without
+        /// changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
 
     /// Search configured registries for plugins
     Search {
@@ -103,6 +122,32 @@ pub enum Commands {
     /// Show which plugins are enabled for this workspace, and why
     Status,
 
+    /// Diagnose hooks, MCP registrations, caches and plugins
+    Doctor,
+
+    /// Remove symposium's hooks, MCP registrations and skills
+    Uninstall {
+        /// Only clean up the current workspace
+        #[arg(long, conflicts_with = "global")]
+        workspace: bool,
+
+        /// Only clean up the agent configs in your home directory
+        #[arg(long)]
+        global: bool,
+
+        /// Also delete symposium's config and cache directories
+        #[arg(long, conflicts_with = "workspace")]
+        purge: bool,
+
+        /// Show what would be removed without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Don't ask for confirmation
+        #[arg(long, short)]
+        yes: bool,
+    },
+
     /// Hook entry point invoked by your agent (internal)
     #[command(hide = true)]
     Hook {
@@ -113,12 +158,28 @@ pub enum Commands {
         event: hook::HookEvent,
     },
 
+    /// MCP meta-server registered with your agent (internal)
+    #[command(hide = true)]
+    McpServe,
+
     /// Manage plugins
     Plugin {
         #[command(subcommand)]
         command: PluginCommand,
     },
 
+    /// Manage plugin registries (list, add, remove, pin)
+    Registry {
+        #[command(subcommand)]
+        command: RegistryCommand,
+    },
+
+    /// Read and edit the user config (get, set, unset, list)
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+
     /// Update symposium to the latest version
     SelfUpdate,
 
@@ -132,7 +193,7 @@ pub enum Commands {
         version: Option<String>,
     },
 
-    /// Manage opt-in usage telemetry (status, enable, disable, show)
+    /// Manage opt-in usage telemetry (status, enable, disable, show, summary, export)
     Telemetry {
         #[command(subcommand)]
         command: Option<TelemetryCommand>,
@@ -160,6 +221,34 @@ pub enum TelemetryCommand {
         #[arg(long, default_value_t = 50)]
         count: usize,
     },
+
+    /// Aggregate recorded events into per-plugin, per-agent, per-tool and
+    /// per-day tables (`--json` for machine-readable output)
+    Summary {
+        /// First day to include (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        since: Option<chrono::NaiveDate>,
+
+        /// Last day to include (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        until: Option<chrono::NaiveDate>,
+    },
+
+    /// Write recorded events to a file you can share with plugin authors
+    Export {
+        /// File format
+        #[arg(long, value_enum, default_value = "jsonl")]
+        format: crate::telemetry::ExportFormat,
+
+        /// First day to include (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        since: Option<chrono::NaiveDate>,
+
+        /// Where to write; defaults to `symposium-telemetry.<format>` in the
+        /// current directory
+        #[arg(long, short)]
+        output: Option<std::path::PathBuf>,
+    },
 }
 
 /// Audience section a built-in subcommand belongs to in `--help`.
@@ -169,9 +258,8 @@ pub enum TelemetryCommand {
 /// this only covers the static `Commands` variants above.
 pub fn builtin_audience(name: &str) -> Option<Audience> {
     match name {
-        "init" | "sync" | "search" | "use" | "status" | "self-update" | "plugin" | "telemetry" => {
-            Some(Audience::Humans)
-        }
+        "init" | "sync" | "search" | "use" | "status" | "doctor" | "uninstall" | "self-update"
+        | "plugin" | "registry" | "config" | "telemetry" => Some(Audience::Humans),
         "crate-info" => Some(Audience::Agents),
         _ => None,
     }
@@ -205,6 +293,77 @@ pub enum PluginCommand {
     },
 }
 
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Print the value of a key
+    Get {
+        /// Dotted key, e.g. `telemetry.enabled`
+        key: String,
+    },
+
+    /// Set a key, keeping the rest of the file as it is
+    Set {
+        /// Dotted key, e.g. `plugins.auto-enable`
+        key: String,
+
+        /// A TOML value (`true`, `30`, `["serde"]`); anything else is a string
+        value: String,
+    },
+
+    /// Remove a key, returning it to its default
+    Unset {
+        /// Dotted key
+        key: String,
+    },
+
+    /// Print every key set in the config file
+    List,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RegistryCommand {
+    /// List every registry, including disabled built-in ones
+    List,
+
+    /// Add a registry, or turn a disabled built-in one back on
+    Add {
+        /// Registry name
+        name: String,
+
+        /// Git repository URL
+        #[arg(long, conflicts_with = "path")]
+        git: Option<String>,
+
+        /// Local directory
+        #[arg(long)]
+        path: Option<String>,
+
+        /// Don't check for updates on startup
+        #[arg(long)]
+        no_auto_update: bool,
+    },
+
+    /// Remove a registry, or turn a built-in one off
+    Remove {
+        /// Registry name
+        name: String,
+    },
+
+    /// Pin a git registry to a branch, tag or commit
+    Pin {
+        /// Registry name
+        name: String,
+
+        /// The ref to pin to
+        #[arg(name = "ref", required_unless_present = "unpin")]
+        git_ref: Option<String>,
+
+        /// Go back to the default branch
+        #[arg(long, conflicts_with = "ref")]
+        unpin: bool,
+    },
+}
+
 /// Run a parsed CLI command.
 ///
 /// `cwd` is the working directory for commands that need it (sync, start, crate).
@@ -240,14 +399,37 @@ pub async fn run(
             init::init(sym, out, &opts).await
         }
 
-        Commands::Sync => {
+        Commands::Sync {
+            lock,
+            locked,
+            dry_run,
+        } => {
             let deps = sym.workspace_deps(cwd);
             // The consent prompt belongs to a human running `cargo agents
             // sync`; it is inert unless `out` is interactive, and the
             // hook-triggered auto-sync path calls `sync::sync` directly and
-            // never reaches here at all.
-            discovery::prompt_for_consent(sym, &deps, out).await?;
-            sync::sync(sym, &deps, update).await
+            // never reaches here at all. A dry run records no answers.
+            if !dry_run {
+                if let Some(root) = deps.workspace_root() {
+                    crate::workspace_config::prompt_for_registry_consent(sym, root, out)?;
+                }
+                discovery::prompt_for_consent(sym, &deps, out).await?;
+            }
+            let options = sync::SyncOptions {
+                lock: if locked {
+                    LockMode::Locked
+                } else if lock {
+                    LockMode::Write
+                } else {
+                    LockMode::Update
+                },
+                dry_run,
+            };
+            sync::sync_with(sym, &deps, update, &options).await?;
+            if !dry_run && let Some(root) = deps.workspace_root() {
+                crate::workspace_state::record_sync(sym, root);
+            }
+            Ok(())
         }
 
         Commands::Search { query } => search_command::search(sym, &query).await,
@@ -266,6 +448,31 @@ pub async fn run(
 
         Commands::Status => status_command::status(sym, cwd).await,
 
+        Commands::Doctor => doctor_command::doctor(sym, cwd).await,
+
+        Commands::Uninstall {
+            workspace,
+            global,
+            purge,
+            dry_run,
+            yes,
+        } => {
+            let scope = if workspace {
+                UninstallScope::Workspace
+            } else if global {
+                UninstallScope::Global
+            } else {
+                UninstallScope::Everything
+            };
+            let options = UninstallOptions {
+                scope,
+                purge,
+                dry_run,
+                yes,
+            };
+            uninstall_command::uninstall(sym, cwd, out, &options).await
+        }
+
         Commands::SelfUpdate => self_update::self_update(sym, out),
 
         Commands::CrateInfo { name, version } => {
@@ -293,7 +500,7 @@ pub async fn run(
                 }
                 TelemetryCommand::Enable => {
                     sym.config.telemetry.enabled = true;
-                    sym.save_config()?;
+                    sym.save_config_keys(&["telemetry.enabled"])?;
                     out.println(
                         "Telemetry enabled. Events are stored locally under the telemetry \
                          directory and are never uploaded automatically — review them with \
@@ -302,7 +509,7 @@ pub async fn run(
                 }
                 TelemetryCommand::Disable => {
                     sym.config.telemetry.enabled = false;
-                    sym.save_config()?;
+                    sym.save_config_keys(&["telemetry.enabled"])?;
                     out.println("Telemetry disabled.");
                 }
                 TelemetryCommand::Show { count } => {
@@ -315,6 +522,35 @@ pub async fn run(
                         }
                     }
                 }
+                TelemetryCommand::Summary { since, until } => {
+                    let events = crate::telemetry::events_between(sym.config_dir(), since, until);
+                    tracing::info!(
+                        report = %crate::report::ReportEvent::TelemetrySummary(
+                            crate::telemetry::Summary::of(&events, since, until),
+                        ),
+                    );
+                }
+                TelemetryCommand::Export {
+                    format,
+                    since,
+                    output,
+                } => {
+                    let events = crate::telemetry::events_between(sym.config_dir(), since, None);
+                    let path = output.unwrap_or_else(|| {
+                        cwd.join(format!("symposium-telemetry.{}", format.extension()))
+                    });
+                    std::fs::write(&path, crate::telemetry::export(&events, format))
+                        .with_context(|| format!("failed to write {}", path.display()))?;
+                    tracing::info!(
+                        report = %crate::report::ReportEvent::Info {
+                            message: format!(
+                                "exported {} event(s) to {}",
+                                events.len(),
+                                path.display()
+                            ),
+                        },
+                    );
+                }
             }
             Ok(())
         }
@@ -332,9 +568,41 @@ pub async fn run(
                 code => bail!("subcommand exited with status: {code}"),
             }
         }
+        Commands::Registry { command } => match command {
+            RegistryCommand::List => {
+                registry_command::list(sym);
+                Ok(())
+            }
+            RegistryCommand::Add {
+                name,
+                git,
+                path,
+                no_auto_update,
+            } => {
+                let options = registry_command::AddOptions {
+                    git,
+                    path,
+                    no_auto_update,
+                };
+                registry_command::add(sym, cwd, &name, &options).await
+            }
+            RegistryCommand::Remove { name } => registry_command::remove(sym, &name),
+            RegistryCommand::Pin { name, git_ref, .. } => {
+                registry_command::pin(sym, &name, git_ref.as_deref()).await
+            }
+        },
+
+        Commands::Config { command } => match command {
+            ConfigCommand::Get { key } => config_command::get(sym, &key),
+            ConfigCommand::Set { key, value } => config_command::set(sym, &key, &value),
+            ConfigCommand::Unset { key } => config_command::unset(sym, &key),
+            ConfigCommand::List => config_command::list(sym),
+        },
+
         // These commands can't easily be extracted since they do I/O
-        // (stdin/stdout for hooks). The binary handles them directly.
-        Commands::Hook { .. } | Commands::Plugin { .. } => {
+        // (stdin/stdout for hooks and the MCP server). The binary handles
+        // them directly.
+        Commands::Hook { .. } | Commands::McpServe | Commands::Plugin { .. } => {
             anyhow::bail!("command not supported in library dispatch (use binary)")
         }
     }
@@ -73,6 +73,32 @@ pub struct Config {
     #[serde(default = "default_sync_debounce_secs", rename = "sync-debounce-secs")]
     pub sync_debounce_secs: u64,
 
+    /// How long a script passed to the MCP meta-server's `execute` tool may
+    /// run before it is stopped.
+    #[serde(
+        default = "default_mcp_execute_timeout_secs",
+        rename = "mcp-execute-timeout-secs"
+    )]
+    pub mcp_execute_timeout_secs: u64,
+
+    /// How long a plugin hook may run before it is killed, for hooks that do
+    /// not set their own `timeout`.
+    #[serde(default = "default_hook_timeout_secs", rename = "hook-timeout-secs")]
+    pub hook_timeout_secs: u64,
+
+    /// How long an external package manager may take to answer one request
+    /// before it is killed.
+    #[serde(default = "default_pm_timeout_secs", rename = "pm-timeout-secs")]
+    pub pm_timeout_secs: u64,
+
+    /// How many characters of crate-aware context the `UserPromptSubmit` hook
+    /// may add to a prompt. Set to 0 to disable the context.
+    #[serde(
+        default = "default_prompt_context_budget",
+        rename = "prompt-context-budget"
+    )]
+    pub prompt_context_budget: usize,
+
     /// Where to install agent hooks.
     #[serde(
         default,
@@ -97,10 +123,28 @@ pub struct Config {
     #[serde(default, skip_serializing_if = "PluginsConfig::is_default")]
     pub plugins: PluginsConfig,
 
+    /// Which registries recommended by workspace team configs the user has
+    /// agreed to load.
+    #[serde(
+        default,
+        rename = "workspace-registries",
+        skip_serializing_if = "WorkspaceRegistriesConfig::is_default"
+    )]
+    pub workspace_registries: WorkspaceRegistriesConfig,
+
     /// Agents configured for this user.
     #[serde(default, rename = "agent")]
     pub agents: Vec<AgentEntry>,
 
+    /// User-described agents that `[[agent]]` entries can name alongside
+    /// the built-in ones.
+    #[serde(
+        default,
+        rename = "agent-profile",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub agent_profiles: Vec<AgentProfile>,
+
     #[serde(default)]
     pub logging: LoggingConfig,
 
@@ -187,6 +231,50 @@ impl PluginsConfig {
     pub fn has_enablement_entries(&self) -> bool {
         !self.auto_enable.is_empty() || !self.used.is_empty()
     }
+
+    /// Does an entry name `name` explicitly — through `use`, `disable`, or
+    /// `auto-enable` by name rather than `"*"`?
+    pub fn names_explicitly(&self, name: &str, workspace_root: &Path) -> bool {
+        let explicit = |entry: &String| entry != "*" && name_matches(entry, name);
+        self.is_used_in(name, workspace_root)
+            || self.auto_enable.iter().any(explicit)
+            || self.disable.iter().any(explicit)
+    }
+
+    /// This (user) section layered over a workspace's team `[plugins]`: the
+    /// team's entries are added unless the user decided otherwise. A name the
+    /// user disables stays disabled; a name the user enables by name stays
+    /// enabled.
+    ///
+    /// A named team `auto-enable` entry is pre-consent: whoever committed the
+    /// manifest agreed to that plugin by name. No other team entry consents
+    /// for the user, so two are left out: `auto-enable = ["*"]`, which names
+    /// nothing, and `use` entries, which only recommend — a dependency they
+    /// name is still offered for consent, and they wake registry plugins
+    /// through [`Symposium::used_names_in`].
+    pub(crate) fn layered_over(&self, team: &PluginsConfig, workspace_root: &Path) -> Self {
+        let enabled_by_user = |name: &str| {
+            self.is_used_in(name, workspace_root)
+                || self
+                    .auto_enable
+                    .iter()
+                    .any(|entry| entry != "*" && name_matches(entry, name))
+        };
+        let mut merged = self.clone();
+        merged.auto_enable.extend(
+            team.auto_enable
+                .iter()
+                .filter(|name| *name != "*" && !self.is_disabled(name))
+                .cloned(),
+        );
+        merged.disable.extend(
+            team.disable
+                .iter()
+                .filter(|name| !enabled_by_user(name))
+                .cloned(),
+        );
+        merged
+    }
 }
 
 /// Does a configured entry name `name`? `"*"` matches everything; otherwise
@@ -198,6 +286,32 @@ fn name_matches(entry: &str, name: &str) -> bool {
             == crate::crate_sources::normalize_crate_name(name)
 }
 
+/// The `[workspace-registries]` section: consent for the registries a
+/// workspace's team config recommends.
+///
+/// A registry is a trust root, so a checked-in `Cargo.toml` cannot add one on
+/// its own — cloning a repository must not be enough to inject agent context.
+/// Entries are the registry's source (its git URL, or the absolute path of a
+/// path registry) rather than its name, which the workspace chooses.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceRegistriesConfig {
+    /// Sources the user agreed to load while working in a workspace that
+    /// recommends them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trust: Vec<String>,
+
+    /// Sources the user declined (so they are not offered again).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decline: Vec<String>,
+}
+
+impl WorkspaceRegistriesConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// One `[plugins] use` entry: a plugin name enabled deliberately, scoped
 /// either to a single workspace or to every workspace.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -237,6 +351,93 @@ pub struct AgentEntry {
     pub name: String,
 }
 
+/// An `[[agent-profile]]` entry: an agent symposium has no built-in support
+/// for, described by where it keeps its files and which built-in hook
+/// payload schema it speaks. Once described, an `[[agent]]` entry can name
+/// it like any built-in agent.
+///
+/// Relative paths are resolved against the project root (`skills`), the
+/// home directory (`global-skills`), or the hook root selected by
+/// `hook-scope` (`hooks`, `mcp.path`).
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AgentProfile {
+    /// Name used by `[[agent]]` entries and `--add-agent`.
+    pub name: String,
+
+    /// Human-readable name for prompts and messages; defaults to `name`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Built-in agent whose hook payloads this agent sends, e.g. `claude`.
+    /// Hooks are registered as `cargo-agents hook <schema> <event>`.
+    pub schema: crate::hook_schema::HookAgent,
+
+    /// Project skills directory.
+    #[serde(default = "default_profile_skills")]
+    pub skills: PathBuf,
+
+    /// Global skills directory, if the agent has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global_skills: Option<PathBuf>,
+
+    /// Where hooks are registered, in the layout of `schema`: a settings
+    /// file for claude, codex and gemini; a directory for copilot, kiro and
+    /// opencode. No hooks are registered when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hooks: Option<PathBuf>,
+
+    /// The agent's MCP server config. No MCP server is registered when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp: Option<ProfileMcp>,
+}
+
+fn default_profile_skills() -> PathBuf {
+    PathBuf::from(".agents/skills")
+}
+
+/// The `mcp` table of an `[[agent-profile]]`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileMcp {
+    /// Config file holding the agent's MCP servers.
+    pub path: PathBuf,
+
+    /// File format of `path`.
+    #[serde(default)]
+    pub format: McpFormat,
+
+    /// Key the servers are nested under. Defaults to `mcpServers` (JSON),
+    /// `mcp_servers` (TOML) or `extensions` (YAML); an empty key places
+    /// JSON entries at the top level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+/// MCP config file formats an agent profile can describe.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum McpFormat {
+    /// `<key>.<name> = { command, args, env }`, as Claude Code writes it.
+    #[default]
+    Json,
+    /// `[<key>.<name>]` tables, as Codex CLI writes it (stdio servers only).
+    Toml,
+    /// `<key>: <name>:` extension entries, as Goose writes it (stdio only).
+    Yaml,
+}
+
+impl McpFormat {
+    /// The key servers are nested under when the profile does not set one.
+    pub fn default_key(self) -> &'static str {
+        match self {
+            McpFormat::Json => "mcpServers",
+            McpFormat::Toml => "mcp_servers",
+            McpFormat::Yaml => "extensions",
+        }
+    }
+}
+
 /// Opt-in usage telemetry settings.
 ///
 /// Telemetry is recorded as a local, append-only JSON-lines event log under
@@ -276,11 +477,17 @@ impl Default for Config {
             auto_sync: true,
             agents_syncing: true,
             sync_debounce_secs: default_sync_debounce_secs(),
+            mcp_execute_timeout_secs: default_mcp_execute_timeout_secs(),
+            hook_timeout_secs: default_hook_timeout_secs(),
+            pm_timeout_secs: default_pm_timeout_secs(),
+            prompt_context_budget: default_prompt_context_budget(),
             hook_scope: HookScope::default(),
             auto_update: AutoUpdate::default(),
             telemetry: TelemetryConfig::default(),
             plugins: PluginsConfig::default(),
+            workspace_registries: WorkspaceRegistriesConfig::default(),
             agents: Vec::new(),
+            agent_profiles: Vec::new(),
             logging: LoggingConfig::default(),
             defaults: DefaultsConfig::default(),
             registries: Vec::new(),
@@ -297,6 +504,20 @@ struct RawConfig {
     agents_syncing: bool,
     #[serde(default = "default_sync_debounce_secs", rename = "sync-debounce-secs")]
     sync_debounce_secs: u64,
+    #[serde(
+        default = "default_mcp_execute_timeout_secs",
+        rename = "mcp-execute-timeout-secs"
+    )]
+    mcp_execute_timeout_secs: u64,
+    #[serde(default = "default_hook_timeout_secs", rename = "hook-timeout-secs")]
+    hook_timeout_secs: u64,
+    #[serde(default = "default_pm_timeout_secs", rename = "pm-timeout-secs")]
+    pm_timeout_secs: u64,
+    #[serde(
+        default = "default_prompt_context_budget",
+        rename = "prompt-context-budget"
+    )]
+    prompt_context_budget: usize,
     #[serde(default, rename = "hook-scope")]
     hook_scope: HookScope,
     #[serde(default, rename = "auto-update")]
@@ -305,8 +526,12 @@ struct RawConfig {
     telemetry: TelemetryConfig,
     #[serde(default)]
     plugins: PluginsConfig,
+    #[serde(default, rename = "workspace-registries")]
+    workspace_registries: WorkspaceRegistriesConfig,
     #[serde(default, rename = "agent")]
     agents: Vec<AgentEntry>,
+    #[serde(default, rename = "agent-profile")]
+    agent_profiles: Vec<AgentProfile>,
     #[serde(default)]
     logging: LoggingConfig,
     #[serde(default)]
@@ -327,11 +552,17 @@ impl RawConfig {
             auto_sync: self.auto_sync,
             agents_syncing: self.agents_syncing,
             sync_debounce_secs: self.sync_debounce_secs,
+            mcp_execute_timeout_secs: self.mcp_execute_timeout_secs,
+            hook_timeout_secs: self.hook_timeout_secs,
+            pm_timeout_secs: self.pm_timeout_secs,
+            prompt_context_budget: self.prompt_context_budget,
             hook_scope: self.hook_scope,
             auto_update: self.auto_update,
             telemetry: self.telemetry,
             plugins: self.plugins,
+            workspace_registries: self.workspace_registries,
             agents: self.agents,
+            agent_profiles: self.agent_profiles,
             logging: self.logging,
             defaults: self.defaults,
             registries: self.registries,
@@ -345,11 +576,17 @@ impl From<Config> for RawConfig {
             auto_sync: config.auto_sync,
             agents_syncing: config.agents_syncing,
             sync_debounce_secs: config.sync_debounce_secs,
+            mcp_execute_timeout_secs: config.mcp_execute_timeout_secs,
+            hook_timeout_secs: config.hook_timeout_secs,
+            pm_timeout_secs: config.pm_timeout_secs,
+            prompt_context_budget: config.prompt_context_budget,
             hook_scope: config.hook_scope,
             auto_update: config.auto_update,
             telemetry: config.telemetry,
             plugins: config.plugins,
+            workspace_registries: config.workspace_registries,
             agents: config.agents,
+            agent_profiles: config.agent_profiles,
             logging: config.logging,
             defaults: config.defaults,
             registries: config.registries,
@@ -369,6 +606,18 @@ pub struct DefaultsConfig {
     pub user_plugins: bool,
 }
 
+impl DefaultsConfig {
+    /// Is `name` a built-in registry that is turned on? Each built-in
+    /// registry's `[defaults]` key is its name.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        match name {
+            BUILTIN_RECOMMENDATIONS => self.symposium_recommendations,
+            BUILTIN_USER_PLUGINS => self.user_plugins,
+            _ => false,
+        }
+    }
+}
+
 impl Default for DefaultsConfig {
     fn default() -> Self {
         Self {
@@ -379,7 +628,7 @@ impl Default for DefaultsConfig {
 }
 
 /// A configured registry — a git repository or a local path offering plugins.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct RegistryConfig {
     /// Display name for this registry. Plugins loaded from it are attributed
@@ -409,16 +658,57 @@ pub const REGISTRY_CACHE_SUBDIR: &str = "plugin-sources";
 
 const BUILTIN_RECOMMENDATIONS_URL: &str = "https://github.com/symposium-dev/recommendations";
 
+/// The name of the built-in recommendations registry.
+pub const BUILTIN_RECOMMENDATIONS: &str = "symposium-recommendations";
+
+/// The name of the built-in `~/.symposium/plugins/` registry.
+pub const BUILTIN_USER_PLUGINS: &str = "user-plugins";
+
+/// The built-in registries, whether or not `[defaults]` turns them on.
+pub fn builtin_registries() -> [RegistryConfig; 2] {
+    [
+        RegistryConfig {
+            name: BUILTIN_RECOMMENDATIONS.to_string(),
+            git: Some(BUILTIN_RECOMMENDATIONS_URL.to_string()),
+            path: None,
+            auto_update: true,
+        },
+        RegistryConfig {
+            name: BUILTIN_USER_PLUGINS.to_string(),
+            git: None,
+            path: Some("plugins".to_string()),
+            auto_update: true,
+        },
+    ]
+}
+
 /// Full application context: parsed config + resolved directory paths.
 ///
 /// Thread `&Symposium` through all call sites instead of using global state.
 #[derive(Clone)]
 pub struct Symposium {
     pub config: Config,
+    /// The machine-wide config an administrator sets; outranks `config`.
+    pub system: crate::policy::SystemConfig,
     dirs: crate::dirs::SymposiumDirs,
     home_dir: PathBuf,
+    /// Plugin-provided package managers already built, keyed by PM name and
+    /// working directory, so every [`package_managers`](Self::package_managers)
+    /// call in a process shares one instance — and one child process — per PM.
+    external_pms: Arc<std::sync::Mutex<ExternalPmCache>>,
+    /// The package-manager declarations scanned from each registry's content
+    /// directory, keyed by directory and registry name, so manifests are
+    /// parsed once per process rather than on every
+    /// [`package_managers`](Self::package_managers) call. Dropped when a
+    /// registry's content is refreshed.
+    pm_declarations: Arc<std::sync::Mutex<PmDeclarationCache>>,
 }
 
+type ExternalPmCache = std::collections::HashMap<(String, PathBuf), Arc<crate::pm::ExternalPm>>;
+
+type PmDeclarationCache =
+    std::collections::HashMap<(PathBuf, String), Arc<crate::plugins::PackageManagerDeclarations>>;
+
 impl Symposium {
     /// Production constructor: resolves paths from environment.
     ///
@@ -433,14 +723,18 @@ impl Symposium {
         let _ = fs::create_dir_all(&dirs.cache_dir);
 
         let config = load_config_from(&dirs.config_dir);
+        let system = crate::policy::load_system_config();
 
         // Note: can't use tracing here — logging isn't initialized yet.
         // init_logging() is called after construction.
 
         Self {
             config,
+            system,
             dirs,
             home_dir,
+            external_pms: Default::default(),
+            pm_declarations: Default::default(),
         }
     }
 
@@ -462,13 +756,22 @@ impl Symposium {
 
         let dirs = crate::dirs::SymposiumDirs::new(config_dir, cache_dir, None);
 
+        // Tests set `system` directly rather than reading the machine's.
         Self {
             config,
+            system: Default::default(),
             dirs,
             home_dir,
+            external_pms: Default::default(),
+            pm_declarations: Default::default(),
         }
     }
 
+    /// The system policy in effect.
+    pub fn policy(&self) -> &crate::policy::Policy {
+        &self.system.policy
+    }
+
     /// The resolved directory paths.
     pub fn dirs(&self) -> &crate::dirs::SymposiumDirs {
         &self.dirs
@@ -509,7 +812,9 @@ impl Symposium {
     /// ([`registries`](Self::registries)) over its content directory —
     /// including git registries, whose repository is unpacked into the cache
     /// before it is read. Each instance is named for its registry, since that
-    /// name is what its plugins are attributed to.
+    /// name is what its plugins are attributed to. Last come the package
+    /// managers registry plugins declare with `[[package-manager]]`
+    /// ([`declared_instances`](Self::declared_instances)).
     pub fn package_managers(
         &self,
         workspace: &Arc<crate::pm::WorkspaceDeps>,
@@ -520,11 +825,124 @@ impl Symposium {
             trusted: false,
             pm: Box::new(crate::pm::CargoPm::new(Arc::clone(workspace))),
         }];
-        // One registry instance per configured registry — trust roots.
+        // One registry instance per configured registry — trust roots — then
+        // the ones the workspace recommends and the user agreed to.
         instances.extend(self.registry_instances());
+        if let Some(root) = workspace.workspace_root() {
+            instances.extend(self.workspace_registry_instances(root));
+        }
+        let declared = self.declared_instances(&instances, workspace.cwd());
+        instances.extend(declared);
         crate::pm::PmRegistry::new(instances)
     }
 
+    /// One [`ExternalPm`](crate::pm::ExternalPm) instance per
+    /// `[[package-manager]]` entry declared by a plugin in one of the
+    /// `registries`. Declarations register whether or not the declaring plugin
+    /// is active, like custom predicates, but only once some registry
+    /// plugin's `[[plugins]] source.<pm>` names them: nothing could load
+    /// through an unnamed one, so its binary is never spawned. A name already
+    /// taken by a built-in instance, or declared by two plugins, is skipped
+    /// with a warning. The instances are trusted: they come from a trust root,
+    /// so the plugins they surface are trusted too.
+    fn declared_instances(
+        &self,
+        registries: &[crate::pm::PmInstance],
+        cwd: &Path,
+    ) -> Vec<crate::pm::PmInstance> {
+        let taken: std::collections::HashSet<&str> =
+            registries.iter().map(|i| i.name.as_str()).collect();
+        let mut declared: std::collections::BTreeMap<
+            String,
+            Option<(String, crate::plugins::Installation)>,
+        > = std::collections::BTreeMap::new();
+        let mut named = std::collections::BTreeSet::new();
+        for inst in registries.iter().filter(|i| i.trusted) {
+            let Some(dir) = inst.pm.content_dir() else {
+                continue;
+            };
+            let declarations = self.package_manager_declarations(dir, &inst.name);
+            named.extend(declarations.named.iter().cloned());
+            for (plugin, pm, installation) in declarations.declared.iter().cloned() {
+                if taken.contains(pm.name.as_str()) {
+                    tracing::warn!(
+                        plugin = %plugin,
+                        pm = %pm.name,
+                        "package manager name is already taken by a built-in instance, skipping"
+                    );
+                    continue;
+                }
+                match declared.get_mut(&pm.name) {
+                    None => {
+                        declared.insert(pm.name, Some((plugin, installation)));
+                    }
+                    Some(slot) => {
+                        if let Some((first, _)) = slot.take() {
+                            tracing::warn!(
+                                pm = %pm.name,
+                                "package manager defined by both `{first}` and `{plugin}`, skipping both"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut cache = self.external_pms.lock().unwrap();
+        declared
+            .into_iter()
+            .filter(|(name, _)| named.contains(name))
+            .filter_map(|(name, slot)| slot.map(|(_, installation)| (name, installation)))
+            .map(|(name, installation)| {
+                let pm = cache
+                    .entry((name.clone(), cwd.to_path_buf()))
+                    .or_insert_with(|| {
+                        Arc::new(crate::pm::ExternalPm::new(
+                            name.clone(),
+                            self.install_context(),
+                            installation,
+                            cwd,
+                            std::time::Duration::from_secs(self.config.pm_timeout_secs),
+                        ))
+                    });
+                crate::pm::PmInstance {
+                    name,
+                    trusted: true,
+                    pm: Box::new(Arc::clone(pm)),
+                }
+            })
+            .collect()
+    }
+
+    /// The [`PackageManagerDeclarations`](crate::plugins::PackageManagerDeclarations)
+    /// of the registry `source_name` whose content is at `dir`, scanned once
+    /// and then served from the cache.
+    pub(crate) fn package_manager_declarations(
+        &self,
+        dir: &Path,
+        source_name: &str,
+    ) -> Arc<crate::plugins::PackageManagerDeclarations> {
+        let key = (dir.to_path_buf(), source_name.to_string());
+        if let Some(cached) = self.pm_declarations.lock().unwrap().get(&key) {
+            return Arc::clone(cached);
+        }
+        let scanned = Arc::new(crate::plugins::package_manager_declarations(
+            dir,
+            source_name,
+        ));
+        self.pm_declarations
+            .lock()
+            .unwrap()
+            .insert(key, Arc::clone(&scanned));
+        scanned
+    }
+
+    /// Forget the cached package-manager declarations, after a registry's
+    /// content changed on disk.
+    pub(crate) fn forget_package_manager_declarations(&self) {
+        self.pm_declarations.lock().unwrap().clear();
+    }
+
     /// The package managers for a workspace-independent operation (registry
     /// listing, crates.io search): the cargo transport is built over a detached
     /// resolver that never runs `cargo metadata`.
@@ -602,45 +1020,134 @@ impl Symposium {
         &self.home_dir
     }
 
-    /// The registry package-manager instances, in effect order: the builtin
-    /// recommendations repo, the builtin `user-plugins` directory, then the
-    /// configured `[[registry]]` entries. Each is a trust root — a git entry is
+    /// The registry package-manager instances, in effect order: the system
+    /// config's required registries, the builtin recommendations repo, the
+    /// builtin `user-plugins` directory, then the configured `[[registry]]`
+    /// entries not named like a required one. Each is a trust root — a git entry is
     /// a [`GitPm`](crate::pm::GitPm), a path entry a [`PathPm`](crate::pm::PathPm)
     /// — so refreshing (pulling git content) is the PM's own concern rather than
     /// a separate step. A registry whose source can't be resolved (a malformed
     /// git URL, or an entry naming neither `git` nor `path`) is skipped with a
     /// warning.
     pub fn registry_instances(&self) -> Vec<crate::pm::PmInstance> {
-        let mut configs: Vec<RegistryConfig> = Vec::new();
-
-        if self.config.defaults.symposium_recommendations {
-            configs.push(RegistryConfig {
-                name: "symposium-recommendations".to_string(),
-                git: Some(BUILTIN_RECOMMENDATIONS_URL.to_string()),
-                path: None,
-                auto_update: true,
-            });
-        }
-
-        if self.config.defaults.user_plugins {
-            configs.push(RegistryConfig {
-                name: "user-plugins".to_string(),
-                git: None,
-                path: Some("plugins".to_string()),
-                auto_update: true,
-            });
-        }
+        // Required registries first; a user registry can't shadow one.
+        let mut configs: Vec<RegistryConfig> = self.system.registries.clone();
+        let required = |name: &str| self.system.registries.iter().any(|r| r.name == name);
+
+        configs.extend(
+            builtin_registries()
+                .into_iter()
+                .filter(|cfg| self.config.defaults.is_enabled(&cfg.name)),
+        );
 
-        configs.extend(self.config.registries.iter().cloned());
+        configs.extend(
+            self.config
+                .registries
+                .iter()
+                .filter(|cfg| !required(&cfg.name))
+                .cloned(),
+        );
         configs
             .into_iter()
             .filter_map(|cfg| self.registry_instance(cfg))
             .collect()
     }
 
+    /// One registry instance per registry the workspace at `workspace_root`
+    /// recommends and the user has agreed to load. A name already taken by a
+    /// user-configured registry is skipped with a warning.
+    pub fn workspace_registry_instances(
+        &self,
+        workspace_root: &Path,
+    ) -> Vec<crate::pm::PmInstance> {
+        let taken: Vec<String> = self
+            .registry_instances()
+            .into_iter()
+            .map(|i| i.name)
+            .collect();
+        crate::workspace_config::trusted_registries(self, workspace_root)
+            .into_iter()
+            .filter(|cfg| {
+                let free = !taken.contains(&cfg.name);
+                if !free {
+                    tracing::warn!(
+                        registry = %cfg.name,
+                        "workspace registry name is already taken by a configured registry, skipping"
+                    );
+                }
+                free
+            })
+            .filter_map(|cfg| self.registry_instance(cfg))
+            .collect()
+    }
+
+    /// The `[plugins]` decisions in effect in `workspace_root`: the user's
+    /// own, layered over the workspace's team config if it has one. An
+    /// `auto-enable = ["*"]` the system policy forbids is dropped.
+    pub fn plugins_in(&self, workspace_root: &Path) -> PluginsConfig {
+        let mut plugins = match crate::workspace_config::load(workspace_root) {
+            Some(team) => self
+                .config
+                .plugins
+                .layered_over(&team.plugins, workspace_root),
+            None => self.config.plugins.clone(),
+        };
+        if !self.policy().allow_auto_enable_all {
+            plugins.auto_enable.retain(|name| name != "*");
+        }
+        plugins
+    }
+
+    /// Names that wake `use`-gated registry plugins in `workspace_root`: the
+    /// `use` entries in effect (see [`plugins_in`](Self::plugins_in)) plus the
+    /// workspace's own, unless the user disables them. Registries are trust
+    /// roots, so a team `use` needs no per-user consent here; it never enables
+    /// a dependency plugin.
+    pub fn used_names_in(&self, workspace_root: &Path) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .plugins_in(workspace_root)
+            .used_names_in(workspace_root)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        if let Some(team) = crate::workspace_config::load(workspace_root) {
+            names.extend(
+                team.plugins
+                    .used_names_in(workspace_root)
+                    .into_iter()
+                    .filter(|name| !self.config.plugins.is_disabled(name))
+                    .map(str::to_string),
+            );
+        }
+        names
+    }
+
+    /// Which layer decided about `name` in `workspace_root`: the user's config
+    /// when it names `name` explicitly, else the workspace's when that does.
+    pub fn plugin_layer(
+        &self,
+        name: &str,
+        workspace_root: &Path,
+    ) -> crate::workspace_config::ConfigLayer {
+        use crate::workspace_config::ConfigLayer;
+        if self.config.plugins.names_explicitly(name, workspace_root) {
+            return ConfigLayer::User;
+        }
+        match crate::workspace_config::load(workspace_root) {
+            Some(team)
+                if team.plugins.is_used_in(name, workspace_root)
+                    || team.plugins.is_disabled(name)
+                    || team.plugins.is_auto_enabled(name) =>
+            {
+                ConfigLayer::Workspace
+            }
+            _ => ConfigLayer::User,
+        }
+    }
+
     /// Build the registry instance for one config entry. Relative `path` values
     /// resolve against the config dir.
-    fn registry_instance(&self, cfg: RegistryConfig) -> Option<crate::pm::PmInstance> {
+    pub(crate) fn registry_instance(&self, cfg: RegistryConfig) -> Option<crate::pm::PmInstance> {
         let name = cfg.name.clone();
         let pm: Box<dyn crate::pm::PackageManager + Send + Sync> = if let Some(url) = &cfg.git {
             match crate::pm::GitPm::new(
@@ -674,14 +1181,93 @@ impl Symposium {
         })
     }
 
-    /// Write the user config to disk.
-    pub fn save_config(&self) -> anyhow::Result<()> {
-        let path = self.dirs.config_dir.join("config.toml");
-        let contents = toml::to_string_pretty(&self.config)?;
-        fs::write(&path, contents)?;
+    /// Write the given dotted keys (e.g. `plugins.use`) of the in-memory
+    /// [`Config`] back to the user config file, leaving everything else in it
+    /// as it was. A key whose value is the default is removed from the file.
+    ///
+    /// This is how commands persist a change: update `self.config`, then
+    /// save the keys they touched. Unlike the other edits, this doesn't reload
+    /// `self.config`, which already holds the values written.
+    pub fn save_config_keys(&self, keys: &[&str]) -> anyhow::Result<()> {
+        let current = toml::Table::try_from(&self.config)?;
+        let mut values = Vec::new();
+        for key in keys {
+            let path = parse_key(key)?;
+            let value = lookup(&current, &path).map(to_edit_value).transpose()?;
+            values.push((path, value));
+        }
+        self.write_config(|doc| {
+            for (path, value) in values {
+                match value {
+                    Some(value) => set_item(doc, &path, value)?,
+                    None => {
+                        remove_item(doc, &path);
+                    }
+                }
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    /// Set the dotted `key` in the user config file to `value`, creating
+    /// tables as needed. A value already there keeps its trailing comment.
+    pub fn set_config_value(&mut self, key: &str, value: toml_edit::Value) -> anyhow::Result<()> {
+        let path = parse_key(key)?;
+        self.edit_config(|doc| set_item(doc, &path, toml_edit::Item::Value(value)))
+    }
+
+    /// Remove the dotted `key` from the user config file, along with any
+    /// table that leaves empty. Returns whether the key was set.
+    pub fn unset_config_value(&mut self, key: &str) -> anyhow::Result<bool> {
+        let path = parse_key(key)?;
+        let mut removed = false;
+        self.edit_config(|doc| {
+            removed = remove_item(doc, &path);
+            Ok(())
+        })?;
+        Ok(removed)
+    }
+
+    /// Edit the user config file in place, then reload it. `edit` works on
+    /// the document itself, so the file's formatting and comments survive.
+    /// The result must still load as a config, and may not introduce a key
+    /// this version doesn't know; otherwise nothing is written.
+    pub fn edit_config(
+        &mut self,
+        edit: impl FnOnce(&mut toml_edit::DocumentMut) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        self.config = self.write_config(edit)?;
         Ok(())
     }
 
+    /// [`edit_config`](Self::edit_config) without the reload: returns the
+    /// config the file now holds.
+    fn write_config(
+        &self,
+        edit: impl FnOnce(&mut toml_edit::DocumentMut) -> anyhow::Result<()>,
+    ) -> anyhow::Result<Config> {
+        use anyhow::Context;
+        let path = self.dirs.config_dir.join("config.toml");
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+        let mut doc: toml_edit::DocumentMut = contents
+            .parse()
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        edit(&mut doc)?;
+        let edited = doc.to_string();
+        let raw: RawConfig = toml::from_str(&edited).context("the edited config is invalid")?;
+        let original = toml::from_str(&contents).unwrap_or_default();
+        if let Some(key) = unknown_new_keys(&original, &toml::from_str(&edited)?).first() {
+            anyhow::bail!("unknown config key `{key}`");
+        }
+        fs::write(&path, &edited).with_context(|| format!("failed to write {}", path.display()))?;
+        Ok(raw.validate())
+    }
+
     #[cfg(test)]
     pub fn plugins_dir(&self) -> PathBuf {
         let dir = self.dirs.config_dir.join("plugins");
@@ -737,6 +1323,187 @@ fn load_config_from(config_dir: &Path) -> Config {
     }
 }
 
+/// Split a dotted config key (`plugins.auto-enable`) into its parts. Quoted
+/// parts follow TOML syntax.
+pub(crate) fn parse_key(key: &str) -> anyhow::Result<Vec<String>> {
+    let keys =
+        toml_edit::Key::parse(key).map_err(|e| anyhow::anyhow!("invalid key `{key}`: {e}"))?;
+    Ok(keys.iter().map(|k| k.get().to_string()).collect())
+}
+
+/// The value at `path` in a parsed TOML table.
+pub(crate) fn lookup<'a>(table: &'a toml::Table, path: &[String]) -> Option<&'a toml::Value> {
+    let (first, rest) = path.split_first()?;
+    rest.iter()
+        .try_fold(table.get(first)?, |value, key| value.get(key.as_str()))
+}
+
+/// One step of a path into a TOML value: a table key or an array index.
+#[derive(Debug, Clone, PartialEq)]
+enum PathStep {
+    Key(String),
+    Index(usize),
+}
+
+/// The leaf values of `edited` that `original` doesn't have, and that
+/// `RawConfig` doesn't read.
+///
+/// Loading ignores keys it doesn't know, so an edit is checked here instead:
+/// a key is read if putting a value no setting accepts — a table with a key
+/// no table has — in its place makes the config fail to parse. Keys the file
+/// already had are left alone, so a key from a newer version doesn't block
+/// other edits.
+fn unknown_new_keys(original: &toml::Table, edited: &toml::Table) -> Vec<String> {
+    let probe = toml::Value::Table(toml::Table::from_iter([(
+        "symposium-unknown-key-probe".to_string(),
+        toml::Value::Integer(0),
+    )]));
+    let mut leaves = Vec::new();
+    collect_leaves(
+        &toml::Value::Table(edited.clone()),
+        &mut Vec::new(),
+        &mut leaves,
+    );
+    let original = toml::Value::Table(original.clone());
+    leaves
+        .into_iter()
+        .filter(|path| value_at_mut(&mut original.clone(), path).is_none())
+        .filter(|path| {
+            let mut probed = toml::Value::Table(edited.clone());
+            if let Some(slot) = value_at_mut(&mut probed, path) {
+                *slot = probe.clone();
+            }
+            probed.try_into::<RawConfig>().is_ok()
+        })
+        .map(|path| display_path(&path))
+        .collect()
+}
+
+/// The paths of every leaf under `value`: scalars and arrays of scalars.
+/// Tables, and arrays holding tables, are descended into.
+fn collect_leaves(value: &toml::Value, path: &mut Vec<PathStep>, out: &mut Vec<Vec<PathStep>>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, child) in table {
+                path.push(PathStep::Key(key.clone()));
+                collect_leaves(child, path, out);
+                path.pop();
+            }
+        }
+        toml::Value::Array(items) if items.iter().any(toml::Value::is_table) => {
+            for (index, child) in items.iter().enumerate() {
+                path.push(PathStep::Index(index));
+                collect_leaves(child, path, out);
+                path.pop();
+            }
+        }
+        _ if !path.is_empty() => out.push(path.clone()),
+        _ => {}
+    }
+}
+
+fn value_at_mut<'a>(value: &'a mut toml::Value, path: &[PathStep]) -> Option<&'a mut toml::Value> {
+    path.iter().try_fold(value, |value, step| match step {
+        PathStep::Key(key) => value.as_table_mut()?.get_mut(key),
+        PathStep::Index(index) => value.as_array_mut()?.get_mut(*index),
+    })
+}
+
+fn display_path(path: &[PathStep]) -> String {
+    let mut out = String::new();
+    for step in path {
+        match step {
+            PathStep::Key(key) if out.is_empty() => out.push_str(key),
+            PathStep::Key(key) => {
+                out.push('.');
+                out.push_str(key);
+            }
+            PathStep::Index(index) => out.push_str(&format!("[{index}]")),
+        }
+    }
+    out
+}
+
+/// A serialized config value as a document item.
+fn to_edit_value(value: &toml::Value) -> anyhow::Result<toml_edit::Item> {
+    let edit_value: toml_edit::Value = value
+        .to_string()
+        .parse()
+        .map_err(|e| anyhow::anyhow!("failed to convert `{value}`: {e}"))?;
+    Ok(toml_edit::Item::Value(edit_value))
+}
+
+/// Set `path` in `doc` to `item`. Missing tables are created; an existing
+/// value keeps its surrounding whitespace and comments. Top-level arrays of
+/// tables (`[[agent]]`, `[[registry]]`) keep that form.
+fn set_item(
+    doc: &mut toml_edit::DocumentMut,
+    path: &[String],
+    item: toml_edit::Item,
+) -> anyhow::Result<()> {
+    let mut slot = doc.as_item_mut();
+    for (depth, key) in path.iter().enumerate() {
+        if !(slot.is_none() || slot.is_table_like()) {
+            anyhow::bail!("`{}` is not a table", path[..depth].join("."));
+        }
+        if slot.is_none() {
+            // A `[header]` table, rather than the inline one indexing creates.
+            *slot = toml_edit::table();
+        }
+        slot = &mut slot[key.as_str()];
+    }
+    let mut item = item;
+    if slot.is_array_of_tables() || (path.len() == 1 && slot.is_none()) {
+        // Keep `[[name]]` blocks in that form rather than inlining them.
+        if let Some(tables) = item
+            .as_array()
+            .filter(|array| !array.is_empty())
+            .and_then(|array| {
+                array
+                    .iter()
+                    .map(|v| v.as_inline_table().cloned())
+                    .collect::<Option<Vec<_>>>()
+            })
+        {
+            let mut array = toml_edit::ArrayOfTables::new();
+            for table in tables {
+                array.push(table.into_table());
+            }
+            item = toml_edit::Item::ArrayOfTables(array);
+        }
+    }
+    if let (Some(old), toml_edit::Item::Value(new)) = (slot.as_value(), &mut item) {
+        *new.decor_mut() = old.decor().clone();
+    }
+    *slot = item;
+    Ok(())
+}
+
+/// Remove `path` from `doc`, then any table that leaves empty. Returns
+/// whether there was anything to remove.
+fn remove_item(doc: &mut toml_edit::DocumentMut, path: &[String]) -> bool {
+    fn remove(table: &mut dyn toml_edit::TableLike, path: &[String]) -> bool {
+        let Some((first, rest)) = path.split_first() else {
+            return false;
+        };
+        if rest.is_empty() {
+            return table.remove(first).is_some();
+        }
+        let Some(child) = table
+            .get_mut(first)
+            .and_then(|item| item.as_table_like_mut())
+        else {
+            return false;
+        };
+        let removed = remove(child, rest);
+        if removed && child.is_empty() {
+            table.remove(first);
+        }
+        removed
+    }
+    remove(doc.as_table_mut(), path)
+}
+
 fn default_true() -> bool {
     true
 }
@@ -745,6 +1512,22 @@ fn default_sync_debounce_secs() -> u64 {
     5
 }
 
+fn default_mcp_execute_timeout_secs() -> u64 {
+    30
+}
+
+fn default_hook_timeout_secs() -> u64 {
+    60
+}
+
+fn default_pm_timeout_secs() -> u64 {
+    120
+}
+
+fn default_prompt_context_budget() -> usize {
+    400
+}
+
 fn default_level() -> String {
     "info".to_string()
 }
@@ -877,6 +1660,114 @@ mod tests {
         assert!(!sym.config.defaults.symposium_recommendations);
     }
 
+    #[test]
+    fn edits_preserve_comments_and_ordering() {
+        let tmp = tempfile::tempdir().unwrap();
+        let original = indoc! {r#"
+            # My symposium setup.
+            [plugins]
+            disable = ["noisy"] # too chatty
+            auto-enable = ["serde"]
+
+            [[registry]]
+            name = "acme"
+            path = "/opt/acme"
+
+            # Logs go to the usual place.
+            [logging]
+            level = "debug"
+        "#};
+        std::fs::write(tmp.path().join("config.toml"), original).unwrap();
+        let mut sym = Symposium::from_dir(tmp.path());
+
+        sym.config.plugins.auto_enable.push("tokio".into());
+        sym.config.telemetry.enabled = true;
+        sym.save_config_keys(&["plugins.auto-enable", "telemetry.enabled"])
+            .unwrap();
+        let edited = std::fs::read_to_string(tmp.path().join("config.toml")).unwrap();
+        assert_eq!(
+            edited,
+            indoc! {r#"
+                # My symposium setup.
+                [plugins]
+                disable = ["noisy"] # too chatty
+                auto-enable = ["serde", "tokio"]
+
+                [[registry]]
+                name = "acme"
+                path = "/opt/acme"
+
+                # Logs go to the usual place.
+                [logging]
+                level = "debug"
+
+                [telemetry]
+                enabled = true
+            "#}
+        );
+        // The reloaded config is the validated view of the edited file.
+        assert_eq!(sym.config.plugins.auto_enable, ["serde", "tokio"]);
+        assert!(sym.config.telemetry.enabled);
+        assert_eq!(sym.config.registries[0].name, "acme");
+
+        // Back to the default: the key goes, and so does the emptied table.
+        sym.config.telemetry.enabled = false;
+        sym.save_config_keys(&["telemetry.enabled"]).unwrap();
+        assert!(sym.unset_config_value("plugins.auto-enable").unwrap());
+        let edited = std::fs::read_to_string(tmp.path().join("config.toml")).unwrap();
+        assert_eq!(edited, original.replace("auto-enable = [\"serde\"]\n", ""));
+    }
+
+    #[test]
+    fn loading_tolerates_unknown_nested_keys() {
+        let config = parse_config(indoc! {r#"
+            [[agent]]
+            name = "claude"
+            color = "blue"
+
+            [telemetry]
+            enabled = true
+            upload = false
+
+            [plugins]
+            auto-enable = ["serde"]
+        "#});
+        assert_eq!(config.agents[0].name, "claude");
+        assert!(config.telemetry.enabled);
+        assert_eq!(config.plugins.auto_enable, ["serde"]);
+    }
+
+    #[test]
+    fn edits_are_validated() {
+        let tmp = tempfile::tempdir().unwrap();
+        let original =
+            "# legacy\nretired-key = 1\n\n[logging]\nlevel = \"info\"\nformat = \"old\"\n";
+        std::fs::write(tmp.path().join("config.toml"), original).unwrap();
+        let mut sym = Symposium::from_dir(tmp.path());
+
+        let rejected = [
+            ("hook-timeout-secs", toml_edit::Value::from("soon")),
+            ("telemetry.enabld", true.into()),
+            ("logging.colour", "red".into()),
+            ("not-a-key", true.into()),
+        ];
+        for (key, value) in rejected {
+            assert!(sym.set_config_value(key, value).is_err(), "{key}");
+        }
+        assert!(
+            sym.set_config_value("plugins.use.name", "x".into())
+                .is_err()
+        );
+        assert_eq!(
+            std::fs::read_to_string(tmp.path().join("config.toml")).unwrap(),
+            original
+        );
+
+        // A key the file already had doesn't block other edits.
+        sym.set_config_value("hook-timeout-secs", 5.into()).unwrap();
+        assert_eq!(sym.config.hook_timeout_secs, 5);
+    }
+
     #[test]
     fn parse_agents() {
         let config = parse_config(indoc! {r#"
@@ -894,6 +1785,63 @@ mod tests {
         assert!(config.auto_sync);
     }
 
+    #[test]
+    fn parse_agent_profiles() {
+        let config = parse_config(indoc! {r#"
+            [[agent]]
+            name = "windsurf"
+
+            [[agent-profile]]
+            name = "windsurf"
+            display-name = "Windsurf"
+            schema = "claude"
+            skills = ".windsurf/skills"
+            hooks = ".windsurf/hooks.json"
+            mcp = { path = ".codeium/windsurf/mcp_config.json" }
+
+            [[agent-profile]]
+            name = "amp"
+            schema = "codex"
+            mcp = { path = ".config/amp/config.toml", format = "toml", key = "amp_mcp" }
+        "#});
+        assert_eq!(config.agent_profiles.len(), 2);
+
+        let windsurf = &config.agent_profiles[0];
+        assert_eq!(windsurf.display_name.as_deref(), Some("Windsurf"));
+        assert_eq!(windsurf.schema, crate::hook_schema::HookAgent::Claude);
+        assert_eq!(windsurf.skills, PathBuf::from(".windsurf/skills"));
+        let mcp = windsurf.mcp.as_ref().unwrap();
+        assert_eq!(mcp.format, McpFormat::Json);
+        assert_eq!(mcp.key, None);
+
+        let amp = &config.agent_profiles[1];
+        assert_eq!(amp.skills, PathBuf::from(".agents/skills")); // default
+        assert_eq!(amp.hooks, None);
+        let mcp = amp.mcp.as_ref().unwrap();
+        assert_eq!(mcp.format, McpFormat::Toml);
+        assert_eq!(mcp.key.as_deref(), Some("amp_mcp"));
+
+        // Profiles survive a round trip through the writer.
+        let raw: RawConfig = config.clone().into();
+        assert_eq!(raw.agent_profiles, config.agent_profiles);
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        assert_eq!(
+            parse_config(&serialized).agent_profiles,
+            config.agent_profiles
+        );
+    }
+
+    #[test]
+    fn parse_agent_profile_rejects_unknown_keys() {
+        let result = toml::from_str::<RawConfig>(indoc! {r#"
+            [[agent-profile]]
+            name = "cursor"
+            schema = "claude"
+            skill-dir = ".cursor/skills"
+        "#});
+        assert!(result.is_err());
+    }
+
     #[test]
     fn parse_config_defaults() {
         let config = parse_config("");
@@ -1028,4 +1976,46 @@ mod tests {
 
         assert_eq!(result, xdg_state.join("symposium").join("logs"));
     }
+
+    #[test]
+    fn package_manager_declarations_are_scanned_once() {
+        let tmp = tempfile::tempdir().unwrap();
+        let sym = Symposium::from_dir(&tmp.path().join("home"));
+        let registry = tmp.path().join("registry");
+        let manifest = registry.join("pm-plugin/SYMPOSIUM.toml");
+        std::fs::create_dir_all(manifest.parent().unwrap()).unwrap();
+        let write = |pm: &str| {
+            let contents = format!(
+                indoc! {r#"
+                    name = "pm-plugin"
+
+                    [[package-manager]]
+                    name = "{pm}"
+                    command = {{ script = "/bin/pm" }}
+
+                    [[plugins]]
+                    source.{pm} = "widgets"
+                "#},
+                pm = pm
+            );
+            std::fs::write(&manifest, contents).unwrap();
+        };
+        let names = |sym: &Symposium| -> Vec<String> {
+            let declarations = sym.package_manager_declarations(&registry, "acme");
+            let mut names: Vec<String> = declarations
+                .declared
+                .iter()
+                .map(|(_, pm, _)| pm.name.clone())
+                .collect();
+            names.extend(declarations.named.iter().cloned());
+            names
+        };
+
+        write("internal");
+        assert_eq!(names(&sym), ["internal", "internal"]);
+        write("npm");
+        assert_eq!(names(&sym), ["internal", "internal"]);
+        sym.forget_package_manager_declarations();
+        assert_eq!(names(&sym), ["npm", "npm"]);
+    }
 }
@@ -0,0 +1,97 @@
+//! `cargo agents config` — read and edit the user's `config.toml` by dotted
+//! key (`telemetry.enabled`, `plugins.auto-enable`).
+//!
+//! `get` and `list` show what the file says, not the effective defaults. `set`
+//! and `unset` go through [`Symposium::edit_config`]: the rest of the file is
+//! left as it was, and an edit that would leave an invalid config — a wrong
+//! type, an unknown key — is refused before anything is written.
+
+use anyhow::{Context, Result, bail};
+
+use crate::config::{Symposium, lookup, parse_key};
+use crate::report::ReportEvent;
+
+/// `cargo agents config get`.
+pub fn get(sym: &Symposium, key: &str) -> Result<()> {
+    let table = read_config(sym)?;
+    let Some(value) = lookup(&table, &parse_key(key)?) else {
+        bail!("`{key}` is not set in the config file");
+    };
+    emit(key.to_string(), value.clone());
+    Ok(())
+}
+
+/// `cargo agents config list`: every value set in the file, one dotted key
+/// each. Arrays, including arrays of tables, are listed whole.
+pub fn list(sym: &Symposium) -> Result<()> {
+    fn walk(prefix: &mut Vec<String>, table: &toml::Table) {
+        for (key, value) in table {
+            prefix.push(key.clone());
+            match value {
+                toml::Value::Table(inner) => walk(prefix, inner),
+                _ => emit(dotted(prefix), value.clone()),
+            }
+            prefix.pop();
+        }
+    }
+    walk(&mut Vec::new(), &read_config(sym)?);
+    Ok(())
+}
+
+/// `cargo agents config set`. `value` is read as a TOML value (`true`, `30`,
+/// `["serde"]`); anything that doesn't parse as one is taken as a string.
+pub fn set(sym: &mut Symposium, key: &str, value: &str) -> Result<()> {
+    let value = value
+        .parse::<toml_edit::Value>()
+        .unwrap_or_else(|_| value.into());
+    let shown = value.to_string().trim().to_string();
+    sym.set_config_value(key, value)
+        .with_context(|| format!("failed to set `{key}`"))?;
+    report(format!("set `{key}` = {shown}"));
+    Ok(())
+}
+
+/// `cargo agents config unset`: the key goes back to its default.
+pub fn unset(sym: &mut Symposium, key: &str) -> Result<()> {
+    let removed = sym
+        .unset_config_value(key)
+        .with_context(|| format!("failed to unset `{key}`"))?;
+    report(if removed {
+        format!("unset `{key}`")
+    } else {
+        format!("`{key}` was not set; nothing changed")
+    });
+    Ok(())
+}
+
+fn read_config(sym: &Symposium) -> Result<toml::Table> {
+    let path = sym.config_dir().join("config.toml");
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => {
+            toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(toml::Table::new()),
+        Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Join key parts, quoting any that aren't bare TOML keys.
+fn dotted(parts: &[String]) -> String {
+    parts
+        .iter()
+        .map(|part| {
+            toml_edit::Key::new(part.as_str())
+                .display_repr()
+                .into_owned()
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn emit(key: String, value: toml::Value) {
+    tracing::info!(report = %ReportEvent::ConfigValue { key, value });
+}
+
+fn report(message: String) {
+    tracing::info!(report = %ReportEvent::Info { message });
+}
@@ -1,4 +1,5 @@
-//! Extract the `[package.metadata.symposium]` table from a crate `Cargo.toml`.
+//! Extract the `[package.metadata.symposium]` table from a crate `Cargo.toml`,
+//! and the `[workspace.metadata.symposium]` team config from a workspace root.
 //!
 //! The table uses the *same schema* as a `SYMPOSIUM.toml` plugin manifest — a
 //! crate can define its plugin inline in `Cargo.toml` instead of (or in
@@ -31,11 +32,33 @@ pub(crate) fn symposium_metadata_str(content: &str) -> Result<Option<toml::Table
         .and_then(|m| m.symposium))
 }
 
+/// Read a workspace root `Cargo.toml` and return its
+/// `[workspace.metadata.symposium]` table, if present. Validation against
+/// [`WorkspaceConfig`](crate::workspace_config::WorkspaceConfig) happens in
+/// `workspace_config`.
+pub fn workspace_symposium_metadata(cargo_toml_path: &Path) -> Result<Option<toml::Table>> {
+    let content = std::fs::read_to_string(cargo_toml_path)
+        .map_err(|e| anyhow::anyhow!("failed to read {}: {e}", cargo_toml_path.display()))?;
+    let doc: CargoToml = toml::from_str(&content)?;
+    Ok(doc
+        .workspace
+        .and_then(|w| w.metadata)
+        .and_then(|m| m.symposium))
+}
+
 // --- serde types for the Cargo.toml structure we navigate ---
 
 #[derive(Deserialize)]
 struct CargoToml {
     package: Option<CargoPackage>,
+    workspace: Option<CargoWorkspace>,
+}
+
+#[derive(Deserialize)]
+struct CargoWorkspace {
+    metadata: Option<PackageMetadata>,
+    #[serde(flatten)]
+    _rest: toml::Table,
 }
 
 #[derive(Deserialize)]
@@ -9,7 +9,8 @@
 //!
 //! Each such offer is then classified against the `[plugins]` config:
 //! already enabled, auto-enabled, declined, or a candidate still awaiting
-//! consent. Discovery itself neither fetches nor writes.
+//! consent — unless the [system policy](crate::policy) denies it first.
+//! Discovery itself neither fetches nor writes.
 //!
 //! On top of that read side sits the consent write side:
 //! [`prompt_for_consent`] asks about each candidate and [`apply_consent`]
@@ -39,11 +40,13 @@ use crate::pm::WorkspaceDeps;
 use anyhow::{Context, Result};
 use std::sync::Arc;
 
-use crate::config::Symposium;
+use crate::config::{PluginsConfig, Symposium};
 use crate::crate_sources::normalize_crate_name;
 use crate::output::Output;
 use crate::pm::{CARGO_PM, PackageId};
+use crate::policy::Policy;
 use crate::report::ReportEvent;
+use crate::workspace_config::ConfigLayer;
 
 /// Why a discovered offer is (or is not) enabled.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -56,6 +59,8 @@ pub enum Enablement {
     Declined,
     /// Nobody has decided yet — this is what a consent prompt would ask about.
     Candidate,
+    /// The [system policy](crate::policy) forbids it, whatever the config says.
+    Denied,
 }
 
 impl Enablement {
@@ -80,6 +85,9 @@ pub struct DiscoveredPlugin {
     pub description: Option<String>,
     /// How the `[plugins]` config decided this offer.
     pub enablement: Enablement,
+    /// Whether the decision came from the user's config or the workspace's
+    /// team config.
+    pub layer: ConfigLayer,
 }
 
 impl DiscoveredPlugin {
@@ -100,6 +108,8 @@ pub struct Discovery {
     pub candidates: Vec<DiscoveredPlugin>,
     /// Declined previously, recorded in `[plugins] disable`.
     pub declined: Vec<DiscoveredPlugin>,
+    /// Forbidden by the system policy; never offered for consent.
+    pub denied: Vec<DiscoveredPlugin>,
 }
 
 impl Discovery {
@@ -123,6 +133,7 @@ pub async fn discover(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Discovery {
     let pms = sym.package_managers(deps);
     let dep_ids = pms.list_deps().await.unwrap_or_default();
 
+    let plugins = sym.plugins_in(&workspace_root);
     let mut discovery = Discovery::default();
     // Untrusted instances = the cargo transport: its `active_plugins` are the
     // plugins embedded in dependencies, which run only with consent. Classify
@@ -131,19 +142,22 @@ pub async fn discover(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Discovery {
         for plugin in inst.pm.active_plugins(&dep_ids).await {
             let name = plugin.canonical.name.clone();
             let description = Some(describe_plugin(&plugin.plugin));
-            let enablement = decide(sym, &name, &workspace_root);
+            let enablement = decide(sym.policy(), &plugins, &name, &workspace_root);
+            let layer = sym.plugin_layer(&name, &workspace_root);
             let discovered = DiscoveredPlugin {
                 registry: inst.name.clone(),
                 id: plugin.canonical,
                 recommends: name,
                 description,
                 enablement,
+                layer,
             };
             match enablement {
                 Enablement::Used => discovery.active.push(discovered),
                 Enablement::AutoEnabled => discovery.auto_enabled.push(discovered),
                 Enablement::Declined => discovery.declined.push(discovered),
                 Enablement::Candidate => discovery.candidates.push(discovered),
+                Enablement::Denied => discovery.denied.push(discovered),
             }
         }
     }
@@ -161,19 +175,20 @@ pub async fn discover(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Discovery {
 ///
 /// `auto-enable` intentionally contributes only (1): it is consent for what a
 /// dependency you already have carries, not a way to add crates. Declined
-/// names are pruned. This reads the config rather than the offer list, so a
-/// `use`d crate that isn't a dependency at all (source not resolved yet) still
-/// works.
+/// names are pruned, and so are names the system policy denies. This reads
+/// the config rather than the offer list, so a `use`d crate that isn't a
+/// dependency at all (source not resolved yet) still works.
 pub fn enabled_dependencies(
     sym: &Symposium,
     dep_ids: &[PackageId],
     workspace_root: &Path,
 ) -> Vec<String> {
-    let plugins = &sym.config.plugins;
+    let plugins = &sym.plugins_in(workspace_root);
     let mut names: Vec<String> = dep_ids
         .iter()
         .filter(|id| id.pm == CARGO_PM)
         .filter(|id| !plugins.is_disabled(&id.name))
+        .filter(|id| sym.policy().permits_plugin(&id.name))
         .filter(|id| {
             plugins.is_auto_enabled(&id.name) || plugins.is_used_in(&id.name, workspace_root)
         })
@@ -183,6 +198,7 @@ pub fn enabled_dependencies(
     for used in plugins.used_names_in(workspace_root) {
         let norm = normalize_crate_name(used);
         let known = plugins.is_disabled(used)
+            || !sym.policy().permits_plugin(used)
             || names.iter().any(|n| normalize_crate_name(n) == norm)
             || dep_ids
                 .iter()
@@ -236,7 +252,27 @@ pub fn apply_consent(sym: &mut Symposium, approved: &[String], declined: &[Strin
             plugins.disable.push(name.clone());
         }
     }
-    sym.save_config().context("failed to write user config")?;
+    sym.save_config_keys(&["plugins.auto-enable", "plugins.disable"])
+        .context("failed to write user config")?;
+
+    use crate::telemetry::{ConsentDecision, EventKind};
+    let decisions = approved
+        .iter()
+        .map(|name| (name, ConsentDecision::Granted))
+        .chain(
+            declined
+                .iter()
+                .map(|name| (name, ConsentDecision::Declined)),
+        );
+    for (name, decision) in decisions {
+        crate::telemetry::record_if_enabled(
+            sym,
+            EventKind::Consent {
+                plugin: name.clone(),
+                decision,
+            },
+        );
+    }
 
     if !approved.is_empty() {
         tracing::info!(
@@ -269,6 +305,10 @@ pub fn apply_consent(sym: &mut Symposium, approved: &[String], declined: &[Strin
 /// Only explicit answers are recorded — the default ("ask me later") leaves
 /// the dependency undecided, so reflexively hitting Enter never permanently
 /// declines anything, and Escape leaves the remaining offers undecided too.
+///
+/// A dependency the workspace's team config names in `auto-enable` is
+/// pre-consented, so it is already enabled and never offered here; one its
+/// `use` entries name is offered, marked as recommended.
 pub async fn prompt_for_consent(
     sym: &mut Symposium,
     deps: &Arc<WorkspaceDeps>,
@@ -294,8 +334,16 @@ pub async fn prompt_for_consent(
             .description
             .as_deref()
             .unwrap_or("agent extensions");
+        // A team `use` entry only recommends; say so, since it is why the
+        // user is asked.
+        let recommended = match candidate.layer {
+            ConfigLayer::Workspace => " The workspace recommends it.",
+            ConfigLayer::User => "",
+        };
         let answer = dialoguer::Select::new()
-            .with_prompt(format!("Dependency `{name}` provides {what}. Enable it?"))
+            .with_prompt(format!(
+                "Dependency `{name}` provides {what}.{recommended} Enable it?"
+            ))
             .items(["Ask me later", "Enable", "No — don't ask again"])
             .default(0)
             .interact_opt()
@@ -336,12 +384,19 @@ fn count_phrase(n: usize, singular: &str, plural: &str) -> Option<String> {
     (n > 0).then(|| format!("{n} {}", if n == 1 { singular } else { plural }))
 }
 
-/// Classify one offer against the `[plugins]` config. An explicit decision —
-/// `use`, then `disable` — outranks the standing `auto-enable`, so a name the
-/// user declined stays declined.
-fn decide(sym: &Symposium, name: &str, workspace_root: &Path) -> Enablement {
-    let plugins = &sym.config.plugins;
-    if plugins.is_used_in(name, workspace_root) {
+/// Classify one offer against the `[plugins]` config in effect (the user's,
+/// over the workspace's team config). The system policy outranks everything;
+/// then an explicit decision — `use`, then `disable` — outranks the standing
+/// `auto-enable`, so a name the user declined stays declined.
+fn decide(
+    policy: &Policy,
+    plugins: &PluginsConfig,
+    name: &str,
+    workspace_root: &Path,
+) -> Enablement {
+    if !policy.permits_plugin(name) {
+        Enablement::Denied
+    } else if plugins.is_used_in(name, workspace_root) {
         Enablement::Used
     } else if plugins.is_disabled(name) {
         Enablement::Declined
@@ -0,0 +1,572 @@
+//! `cargo agents doctor` — diagnose a setup that isn't doing anything.
+//!
+//! Each check produces [`Finding`]s, reported one per line as
+//! [`DoctorFinding`](ReportEvent::DoctorFinding) events with a suggested fix.
+//! Nothing is changed: agent configs are checked by running the same
+//! registration `sync` performs against a [probe](Output::probe) output,
+//! which only records the files it would have written.
+//!
+//! The checks, in order:
+//!
+//! - **path** — `cargo-agents` is on `PATH`; every registered hook and the
+//!   `symposium` MCP entry invoke it by name.
+//! - **agents** — at least one agent is configured.
+//! - **hooks** / **mcp** — each agent's config at the configured hook scope
+//!   holds the entries `sync` would write.
+//! - **mcp** — the applicable plugin MCP servers' commands exist.
+//! - **registry** — every plugin and skill manifest loaded
+//!   ([`LoadWarning`](crate::plugins::LoadWarning)s).
+//! - **cache** — the workspace's dependency and predicate caches are readable
+//!   JSON.
+//! - **skills** — no skill directory looks symposium-managed but has lost its
+//!   `.symposium` marker, which makes `sync` leave it alone.
+//! - **version** — the binary is the latest release.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::agents::Agent;
+use crate::config::{HookScope, Symposium};
+use crate::output::{Output, display_path};
+use crate::pm::WorkspaceDeps;
+use crate::report::ReportEvent;
+
+/// The name every hook and MCP registration invokes.
+const BINARY_NAME: &str = "cargo-agents";
+
+/// How serious a finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The check passed.
+    Ok,
+    /// Something is off but symposium still works.
+    Warning,
+    /// Symposium cannot work as configured.
+    Error,
+}
+
+impl Severity {
+    /// The wire/report spelling.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// One result of a check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// Which check produced it (`path`, `hooks`, `mcp`, ...).
+    pub check: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    /// What to do about it; `None` for passing checks.
+    pub fix: Option<String>,
+}
+
+impl Finding {
+    fn ok(check: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            check,
+            severity: Severity::Ok,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    fn warning(check: &'static str, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            check,
+            severity: Severity::Warning,
+            message: message.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn error(check: &'static str, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            check,
+            severity: Severity::Error,
+            message: message.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+/// Run every check for the setup seen from `cwd`.
+pub async fn diagnose(sym: &Symposium, cwd: &Path) -> Vec<Finding> {
+    let mut findings = vec![check_path()];
+
+    // The caches are checked before anything resolves the workspace, which
+    // would quietly replace a corrupt dependency cache.
+    let root = crate::workspace_state::find_workspace_root(sym, cwd);
+    if let Some(root) = &root {
+        findings.extend(check_caches(sym, root));
+    }
+
+    findings.extend(check_agents(sym, root.as_deref()));
+
+    let deps = sym.workspace_deps(cwd);
+    let registry =
+        crate::plugins::load_registry_with_workspace(sym, deps.load().map(|w| &**w)).await;
+    if registry.warnings.is_empty() {
+        findings.push(Finding::ok(
+            "registry",
+            "all plugin and skill manifests load",
+        ));
+    }
+    for warning in &registry.warnings {
+        findings.push(Finding::warning(
+            "registry",
+            format!(
+                "{} was skipped: {}",
+                display_path(&warning.path),
+                warning.message
+            ),
+            "fix or remove the manifest",
+        ));
+    }
+
+    if let Some(root) = &root {
+        findings.extend(check_plugin_mcp_servers(sym, &deps).await);
+        findings.extend(check_skill_markers(sym, root));
+    }
+
+    findings.push(check_version(sym));
+    findings
+}
+
+/// The `cargo agents doctor` entry point.
+pub async fn doctor(sym: &Symposium, cwd: &Path) -> Result<()> {
+    let findings = diagnose(sym, cwd).await;
+    let problems = findings
+        .iter()
+        .filter(|f| f.severity != Severity::Ok)
+        .count();
+    for finding in findings {
+        tracing::info!(
+            report = %ReportEvent::DoctorFinding {
+                check: finding.check.to_string(),
+                severity: finding.severity.as_str().to_string(),
+                message: finding.message,
+                fix: finding.fix,
+            },
+        );
+    }
+    tracing::info!(
+        report = %ReportEvent::Info {
+            message: if problems == 0 {
+                "no problems found".to_string()
+            } else {
+                format!("{problems} problem(s) found")
+            },
+        },
+    );
+    Ok(())
+}
+
+fn check_path() -> Finding {
+    match find_executable(BINARY_NAME) {
+        Some(path) => Finding::ok(
+            "path",
+            format!("{BINARY_NAME} is on PATH ({})", display_path(&path)),
+        ),
+        None => Finding::error(
+            "path",
+            format!("{BINARY_NAME} is not on PATH, so agents cannot run its hooks"),
+            "add cargo's bin directory (usually ~/.cargo/bin) to PATH",
+        ),
+    }
+}
+
+/// Check each configured agent's hooks and `symposium` MCP entry where
+/// `sync` registers them, and that none are left behind at the root of the
+/// other hook scope (the home directory or the workspace), where the agent
+/// would run them a second time.
+fn check_agents(sym: &Symposium, workspace_root: Option<&Path>) -> Vec<Finding> {
+    if sym.config.agents.is_empty() {
+        return vec![Finding::error(
+            "agents",
+            "no agents are configured",
+            "run `cargo agents init`",
+        )];
+    }
+    // The other scope's root, and the `uninstall` flag that cleans it.
+    let (hook_root, other_root, other_flag) = match sym.config.hook_scope {
+        HookScope::Global => (Some(sym.home_dir()), workspace_root, "--workspace"),
+        HookScope::Project => (workspace_root, Some(sym.home_dir()), "--global"),
+    };
+    let other_root = other_root.filter(|root| Some(*root) != hook_root);
+
+    let mut findings = Vec::new();
+    if hook_root.is_none() {
+        findings.push(Finding::ok(
+            "hooks",
+            "hooks are registered per project; run doctor inside a workspace to check them",
+        ));
+    }
+    for entry in &sym.config.agents {
+        let agent = match Agent::resolve(&entry.name, &sym.config.agent_profiles) {
+            Ok(agent) => agent,
+            Err(e) => {
+                findings.push(Finding::error(
+                    "agents",
+                    format!("{e:#}"),
+                    "fix the `[[agent]]` entry or its `[[agent-profile]]`",
+                ));
+                continue;
+            }
+        };
+        let name = agent.display_name().to_string();
+
+        if let Some(hook_root) = hook_root {
+            findings.extend(check_registered(sym, &agent, &name, hook_root));
+        }
+        if let Some(other_root) = other_root {
+            findings.extend(check_leftover(sym, &agent, &name, other_root, other_flag));
+        }
+    }
+    findings
+}
+
+/// Hooks and the `symposium` MCP entry must be registered at `hook_root`.
+fn check_registered(sym: &Symposium, agent: &Agent, name: &str, hook_root: &Path) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let probe = Output::probe();
+    findings.push(match agent.register_hooks(hook_root, sym, &probe) {
+        Err(e) => unreadable_config("hooks", name, &e),
+        Ok(()) => match probe.planned_changes().as_slice() {
+            [] => Finding::ok("hooks", format!("{name}: hooks registered")),
+            paths => Finding::error(
+                "hooks",
+                format!("{name}: hooks missing from {}", display_paths(paths)),
+                "run `cargo agents sync`",
+            ),
+        },
+    });
+
+    let probe = Output::probe();
+    let entry = [crate::mcp_serve::registration_entry()];
+    findings.push(
+        match agent.register_global_mcp_servers(hook_root, &entry, &probe) {
+            Err(e) => unreadable_config("mcp", name, &e),
+            Ok(()) => match probe.planned_changes().as_slice() {
+                [] => Finding::ok("mcp", format!("{name}: symposium MCP server registered")),
+                paths => Finding::warning(
+                    "mcp",
+                    format!(
+                        "{name}: symposium MCP server missing or outdated in {}",
+                        display_paths(paths)
+                    ),
+                    "run `cargo agents sync`",
+                ),
+            },
+        },
+    );
+    findings
+}
+
+/// Hooks or a `symposium` MCP entry at the root of the scope `sync` does not
+/// register into — left over from before a `hook-scope` change. `uninstall_flag`
+/// is the `cargo agents uninstall` flag that cleans that scope.
+fn check_leftover(
+    sym: &Symposium,
+    agent: &Agent,
+    name: &str,
+    root: &Path,
+    uninstall_flag: &str,
+) -> Option<Finding> {
+    let probe = Output::probe();
+    agent.unregister_hooks(root, sym, &probe);
+    let _ = agent.unregister_global_mcp_servers(root, &[crate::mcp_serve::SERVER_NAME], &probe);
+    match probe.planned_changes().as_slice() {
+        [] => None,
+        paths => Some(Finding::warning(
+            "hooks",
+            format!(
+                "{name}: symposium is also registered in {}, outside the configured \
+                 hook scope, so its hooks run twice",
+                display_paths(paths),
+            ),
+            format!("run `cargo agents uninstall {uninstall_flag}`, then `cargo agents sync`"),
+        )),
+    }
+}
+
+fn unreadable_config(check: &'static str, agent: &str, error: &anyhow::Error) -> Finding {
+    Finding::error(
+        check,
+        format!("{agent}: cannot read its configuration: {error:#}"),
+        "fix the file's syntax, or move it aside and run `cargo agents sync`",
+    )
+}
+
+fn display_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| display_path(p))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The commands of the plugin MCP servers the meta-server would proxy.
+async fn check_plugin_mcp_servers(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for server in crate::mcp_serve::applicable_servers(sym, deps).await {
+        let sacp::schema::McpServer::Stdio(stdio) = &server else {
+            continue;
+        };
+        let command = stdio.command.to_string_lossy();
+        if find_executable(&command).is_none() {
+            findings.push(Finding::error(
+                "mcp",
+                format!(
+                    "MCP server `{}` runs `{command}`, which was not found",
+                    stdio.name
+                ),
+                "install the command, or fix the plugin's `[[mcp_servers]]` entry",
+            ));
+        }
+    }
+    findings
+}
+
+/// The workspace's dependency and predicate caches must be JSON; anything
+/// else is discarded on every run rather than rebuilt.
+fn check_caches(sym: &Symposium, workspace_root: &Path) -> Vec<Finding> {
+    let files = [
+        crate::pm::disk_cache_file(sym.cache_dir(), workspace_root),
+        crate::predicate_cache::PredicateCache::path_for_workspace(sym.cache_dir(), workspace_root),
+    ];
+    let mut findings = Vec::new();
+    for file in files {
+        let problem = match std::fs::read(&file) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => Some(e.to_string()),
+            Ok(bytes) => serde_json::from_slice::<serde_json::Value>(&bytes)
+                .err()
+                .map(|e| e.to_string()),
+        };
+        if let Some(problem) = problem {
+            findings.push(Finding::warning(
+                "cache",
+                format!("{} is corrupt: {problem}", display_path(&file)),
+                format!(
+                    "delete {}; it is rebuilt on the next sync",
+                    display_path(&file)
+                ),
+            ));
+        }
+    }
+    if findings.is_empty() {
+        findings.push(Finding::ok("cache", "workspace caches are readable"));
+    }
+    findings
+}
+
+/// Skill directories that carry the `.gitignore` sync writes next to its
+/// marker, but not the marker itself: sync takes them for user content and
+/// never updates or removes them again.
+fn check_skill_markers(sym: &Symposium, workspace_root: &Path) -> Vec<Finding> {
+    let mut skill_dirs: Vec<PathBuf> = sym
+        .config
+        .agents
+        .iter()
+        .filter_map(|entry| Agent::resolve(&entry.name, &sym.config.agent_profiles).ok())
+        .map(|agent| crate::sync::skills_parent_dir(&agent, workspace_root))
+        .collect();
+    skill_dirs.sort();
+    skill_dirs.dedup();
+
+    let mut findings = Vec::new();
+    for skills in skill_dirs {
+        let Ok(entries) = std::fs::read_dir(&skills) else {
+            continue;
+        };
+        for dir in entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()) {
+            let managed_gitignore = std::fs::read_to_string(dir.join(".gitignore"))
+                .is_ok_and(|content| content == crate::sync::MANAGED_GITIGNORE);
+            if managed_gitignore && !crate::sync::has_symposium_marker(&dir) {
+                findings.push(Finding::warning(
+                    "skills",
+                    format!(
+                        "{} looks installed by symposium but has no `{}` marker, so sync leaves it alone",
+                        display_path(&dir),
+                        crate::sync::MARKER_FILE
+                    ),
+                    format!(
+                        "delete {} and run `cargo agents sync`",
+                        display_path(&dir)
+                    ),
+                ));
+            }
+        }
+    }
+    if findings.is_empty() {
+        findings.push(Finding::ok(
+            "skills",
+            "installed skills carry their markers",
+        ));
+    }
+    findings
+}
+
+fn check_version(sym: &Symposium) -> Finding {
+    let current = crate::state::CURRENT_VERSION;
+    match crate::self_update::check_upgrade(sym) {
+        Ok(None) => Finding::ok("version", format!("symposium {current} is up to date")),
+        Ok(Some(latest)) => Finding::warning(
+            "version",
+            format!("symposium {latest} is available (running {current})"),
+            "run `cargo agents self-update`",
+        ),
+        Err(e) => Finding::warning(
+            "version",
+            format!("could not check for a newer symposium: {e:#}"),
+            "check your network connection and run doctor again",
+        ),
+    }
+}
+
+/// Resolve `command` the way a shell would: as given when it names a path,
+/// otherwise by searching `PATH`.
+pub(crate) fn find_executable(command: &str) -> Option<PathBuf> {
+    let command = Path::new(command);
+    if command.components().count() > 1 {
+        return is_executable(command).then(|| command.to_path_buf());
+    }
+    let path = std::env::var_os("PATH")?;
+    find_in_dirs(command, std::env::split_paths(&path))
+}
+
+/// The first executable `command` in `dirs`. The platform's executable
+/// extension (`.exe` on Windows) is appended only to a name without one, so
+/// `run-server.sh` and `python3.11` are looked up as written.
+fn find_in_dirs(command: &Path, dirs: impl IntoIterator<Item = PathBuf>) -> Option<PathBuf> {
+    let extension = std::env::consts::EXE_EXTENSION;
+    dirs.into_iter()
+        .map(|dir| {
+            let mut candidate = dir.join(command);
+            if !extension.is_empty() && command.extension().is_none() {
+                candidate.set_extension(extension);
+            }
+            candidate
+        })
+        .find(|candidate| is_executable(candidate))
+}
+
+/// Whether `path` is a file the current user could run: on Unix, one with an
+/// executable bit set.
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+    }
+
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty file the current user may run.
+    fn write_executable(path: &Path) {
+        std::fs::write(path, "").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+    }
+
+    #[test]
+    fn find_executable_takes_paths_as_given() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = tmp.path().join("tool");
+        write_executable(&tool);
+
+        assert_eq!(find_executable(tool.to_str().unwrap()), Some(tool.clone()));
+        let missing = tmp.path().join("missing");
+        assert_eq!(find_executable(missing.to_str().unwrap()), None);
+        assert_eq!(find_executable("symposium-surely-not-on-path"), None);
+    }
+
+    /// A dotted name keeps its extension instead of having it replaced.
+    #[test]
+    fn find_in_dirs_keeps_dotted_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dirs = || [tmp.path().to_path_buf()];
+        for name in ["run-server.sh", "python3.11"] {
+            let tool = tmp.path().join(name);
+            write_executable(&tool);
+            assert_eq!(find_in_dirs(Path::new(name), dirs()), Some(tool), "{name}");
+        }
+        // Nor is a name found under its stripped stem.
+        assert_eq!(find_in_dirs(Path::new("run-server"), dirs()), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn find_in_dirs_skips_files_that_are_not_executable() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("tool"), "").unwrap();
+        assert_eq!(
+            find_in_dirs(Path::new("tool"), [tmp.path().to_path_buf()]),
+            None
+        );
+    }
+
+    #[test]
+    fn skills_without_markers_are_flagged() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut sym = Symposium::from_dir(tmp.path());
+        sym.config.agents = vec![crate::config::AgentEntry {
+            name: "claude".into(),
+        }];
+        let root = tmp.path().join("ws");
+        let skills = root.join(".claude/skills");
+        for name in ["managed", "lost-marker", "user"] {
+            std::fs::create_dir_all(skills.join(name)).unwrap();
+        }
+        crate::sync::mark_generated_skill_directory(&skills.join("managed")).unwrap();
+        std::fs::write(
+            skills.join("lost-marker/.gitignore"),
+            crate::sync::MANAGED_GITIGNORE,
+        )
+        .unwrap();
+
+        let findings = check_skill_markers(&sym, &root);
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("lost-marker"), "{findings:?}");
+    }
+
+    #[test]
+    fn corrupt_caches_are_flagged() {
+        let tmp = tempfile::tempdir().unwrap();
+        let sym = Symposium::from_dir(tmp.path());
+        let root = tmp.path().join("ws");
+        std::fs::create_dir_all(&root).unwrap();
+        assert_eq!(check_caches(&sym, &root)[0].severity, Severity::Ok);
+
+        let file = crate::pm::disk_cache_file(sym.cache_dir(), &root);
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(&file, "{ truncated").unwrap();
+        let findings = check_caches(&sym, &root);
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("workspace-deps.json"));
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
     cli::{Cli, Commands, builtin_audience},
     config::Symposium,
     plugins::{Audience, ParsedPlugin, load_registry_with_workspace},
-    pm::PackageId,
+    predicate::PredicateContext,
     subcommand_dispatch::applicable_subcommands,
 };
 
@@ -96,11 +96,13 @@ pub async fn render_help(sym: &Symposium, cwd: &Path) -> String {
     let dep_ids = crate::pm::workspace_dep_ids(sym, &deps).await;
     let used = workspace
         .as_ref()
-        .map(|ws| sym.config.plugins.used_names_in(&ws.root))
+        .map(|ws| sym.used_names_in(&ws.root))
         .unwrap_or_default();
 
     // Resolve the active plugin set so crate-sourced subcommands appear in help.
-    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids).with_used_names(&used);
+    let mut ctx = PredicateContext::new(&dep_ids)
+        .with_used_names(&used)
+        .with_workspace(workspace.clone());
     let pms = sym.package_managers(&deps);
     let active = crate::plugins::active_plugins(
         sym,
@@ -111,10 +113,10 @@ pub async fn render_help(sym: &Symposium, cwd: &Path) -> String {
     )
     .await;
 
-    render(&active, &dep_ids, &used)
+    render(&active, &mut ctx)
 }
 
-fn render(plugins: &[ParsedPlugin], deps: &[PackageId], used: &[&str]) -> String {
+fn render(plugins: &[ParsedPlugin], ctx: &mut PredicateContext) -> String {
     let mut cmd = Cli::command();
     let full = cmd.render_help().to_string();
 
@@ -127,8 +129,8 @@ fn render(plugins: &[ParsedPlugin], deps: &[PackageId], used: &[&str]) -> String
     let header = &full[..commands_idx];
     let options = &full[options_idx..];
 
-    let humans = collect_section(&cmd, plugins, deps, used, Audience::Humans);
-    let agents = collect_section(&cmd, plugins, deps, used, Audience::Agents);
+    let humans = collect_section(&cmd, plugins, ctx, Audience::Humans);
+    let agents = collect_section(&cmd, plugins, ctx, Audience::Agents);
 
     let col_width = humans
         .iter()
@@ -163,8 +165,7 @@ fn render(plugins: &[ParsedPlugin], deps: &[PackageId], used: &[&str]) -> String
 fn collect_section(
     cmd: &Command,
     plugins: &[ParsedPlugin],
-    deps: &[PackageId],
-    used: &[&str],
+    ctx: &mut PredicateContext,
     target: Audience,
 ) -> Vec<(String, String)> {
     let mut builtins = cmd
@@ -180,7 +181,7 @@ fn collect_section(
 
     builtins.sort();
 
-    let mut plugin_subs = applicable_subcommands(plugins, deps, used)
+    let mut plugin_subs = applicable_subcommands(plugins, ctx)
         .into_iter()
         .filter(|(_, _, subcommand)| subcommand.audience == target)
         .map(|(_, name, subcommand)| (name.to_string(), subcommand.description.clone()))
@@ -201,7 +202,7 @@ mod tests {
 
     use crate::{
         plugins::{Plugin, PluginRegistry, Subcommand},
-        pm::ANY_VERSION,
+        pm::{ANY_VERSION, PackageId},
         predicate::PredicateSet,
     };
 
@@ -231,6 +232,7 @@ mod tests {
                 installations: vec![],
                 custom_predicates: vec![],
                 chained: vec![],
+                package_managers: vec![],
                 requires_use: false,
             },
             workspace_member: false,
@@ -282,13 +284,17 @@ mod tests {
             Usage: cargo agents [OPTIONS] [COMMAND]
 
             Commands for humans:
+            config       Read and edit the user config (get, set, unset, list)
+            doctor       Diagnose hooks, MCP registrations, caches and plugins
             init         Set up user-wide configuration
             plugin       Manage plugins
+            registry     Manage plugin registries (list, add, remove, pin)
             search       Search configured registries for plugins
             self-update  Update symposium to the latest version
             status       Show which plugins are enabled for this workspace, and why
             sync         Synchronize skills with workspace dependencies
-            telemetry    Manage opt-in usage telemetry (status, enable, disable, show)
+            telemetry    Manage opt-in usage telemetry (status, enable, disable, show, summary, export)
+            uninstall    Remove symposium's hooks, MCP registrations and skills
             use          Enable a plugin by name and sync it into the workspace
 
             Commands for agents:
@@ -302,7 +308,7 @@ mod tests {
               -h, --help             Print help
               -V, --version          Print version
         "#]]
-             .assert_eq(&redact(render(&reg.plugins, &ws, &[])));
+             .assert_eq(&redact(render(&reg.plugins, &mut PredicateContext::new(&ws))));
     }
 
     #[test]
@@ -315,7 +321,7 @@ mod tests {
         let reg = registry(vec![plugin_with("example-plugin", "*", subs)]);
         let ws = vec![workspace_crate("example-crate", "1.0.0")];
 
-        let out = render(&reg.plugins, &ws, &[]);
+        let out = render(&reg.plugins, &mut PredicateContext::new(&ws));
         let humans = extract_section(&out, "Commands for humans:");
         assert!(
             humans.contains("example-tool"),
@@ -340,7 +346,7 @@ mod tests {
         let reg = registry(vec![plugin_with("example-plugin", "*", subs)]);
         let ws = vec![workspace_crate("example-crate", "1.0.0")];
 
-        let out = render(&reg.plugins, &ws, &[]);
+        let out = render(&reg.plugins, &mut PredicateContext::new(&ws));
         let agents = extract_section(&out, "Commands for agents:");
         assert!(
             agents.contains("example-tool"),
@@ -369,7 +375,7 @@ mod tests {
         let reg = registry(vec![plugin_with("example-plugin", "*", subs)]);
         let ws = vec![workspace_crate("other-crate-sources", "1.0.0")];
 
-        let out = render(&reg.plugins, &ws, &[]);
+        let out = render(&reg.plugins, &mut PredicateContext::new(&ws));
         assert!(
             !out.contains("example-tool"),
             "example-tool should be filtered when workspace lacks example-crate:\n{out}"
@@ -391,7 +397,7 @@ mod tests {
         let reg = registry(vec![plugin_with("example-plugin", "*", subs)]);
         let ws = vec![workspace_crate("example-crate", "1.0.0")];
 
-        let out = render(&reg.plugins, &ws, &[]);
+        let out = render(&reg.plugins, &mut PredicateContext::new(&ws));
         let agents = extract_section(&out, "Commands for agents:");
         let bar_pos = agents.find("bar-tool").expect("bar-tool present");
         let foo_pos = agents.find("foo-tool").expect("foo-tool present");
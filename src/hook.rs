@@ -1,7 +1,9 @@
 use std::{
+    collections::BTreeSet,
     io::{Read, Write},
-    path::PathBuf,
-    process::{Command, ExitCode, Stdio},
+    path::{Path, PathBuf},
+    process::{ExitCode, Stdio},
+    time::Duration,
 };
 
 use symposium_install::Runnable;
@@ -39,6 +41,9 @@ struct ResolvedHook {
     /// Hook-level `script` override.
     hook_script: Option<String>,
     args: Vec<String>,
+    /// The hook's own `timeout`, if it set one.
+    timeout: Option<u64>,
+    parallel: bool,
 }
 
 impl ResolvedHook {
@@ -67,6 +72,8 @@ impl ResolvedHook {
             hook_executable: hook.executable.clone(),
             hook_script: hook.script.clone(),
             args: hook.args.clone(),
+            timeout: hook.timeout,
+            parallel: hook.parallel,
         })
     }
 }
@@ -187,31 +194,79 @@ async fn build_spawn_spec(sym: &Symposium, hook: &ResolvedHook) -> anyhow::Resul
     })
 }
 
-fn spawn_from_spec(spec: SpawnSpec) -> std::io::Result<std::process::Child> {
-    match spec {
+fn spawn_from_spec(spec: SpawnSpec) -> std::io::Result<tokio::process::Child> {
+    let (mut cmd, env) = match spec {
         SpawnSpec::Script { path, args, env } => {
-            let mut cmd = Command::new("sh");
+            let mut cmd = tokio::process::Command::new("sh");
             cmd.arg(path).args(args);
-            for (k, v) in env {
-                cmd.env(k, v);
-            }
-            cmd.stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
+            (cmd, env)
         }
         SpawnSpec::Exec { path, args, env } => {
-            let mut cmd = Command::new(path);
+            let mut cmd = tokio::process::Command::new(path);
             cmd.args(args);
-            for (k, v) in env {
-                cmd.env(k, v);
-            }
-            cmd.stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
+            (cmd, env)
         }
-    }
+    };
+    cmd.envs(env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // A timed-out (or abandoned) hook is killed when its task drops it.
+        .kill_on_drop(true)
+        .spawn()
+}
+
+/// How a plugin hook process ended.
+enum HookOutcome {
+    Exited(std::process::Output),
+    /// Killed after running past its timeout.
+    TimedOut(Duration),
+    /// Could not be spawned or waited on.
+    Failed(String),
+}
+
+/// Acquire and spawn `hook`, then drive it on a background task that feeds it
+/// `stdin` and collects its output, killing it after `timeout`. The task also
+/// reports how long the hook ran. `None` when the hook's command could not be
+/// prepared.
+async fn start_hook(
+    sym: &Symposium,
+    hook: &ResolvedHook,
+    stdin: String,
+    timeout: Duration,
+) -> Option<tokio::task::JoinHandle<(HookOutcome, Duration)>> {
+    use tokio::io::AsyncWriteExt;
+
+    let spec = match build_spawn_spec(sym, hook).await {
+        Ok(spec) => spec,
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to prepare hook command");
+            return None;
+        }
+    };
+    let mut child = match spawn_from_spec(spec) {
+        Ok(child) => child,
+        Err(e) => {
+            return Some(tokio::spawn(async move {
+                (HookOutcome::Failed(e.to_string()), Duration::ZERO)
+            }));
+        }
+    };
+    Some(tokio::spawn(async move {
+        let started = std::time::Instant::now();
+        let run = async {
+            if let Some(mut pipe) = child.stdin.take() {
+                let _ = pipe.write_all(stdin.as_bytes()).await;
+            }
+            child.wait_with_output().await
+        };
+        let outcome = match tokio::time::timeout(timeout, run).await {
+            Ok(Ok(output)) => HookOutcome::Exited(output),
+            Ok(Err(e)) => HookOutcome::Failed(format!("failed waiting for hook process: {e}")),
+            Err(_) => HookOutcome::TimedOut(timeout),
+        };
+        (outcome, started.elapsed())
+    }))
 }
 
 // Re-export hook schema types for convenience.
@@ -242,9 +297,17 @@ pub async fn execute_hook(
         let deps = sym.workspace_deps(&cwd);
 
         // Auto-sync: install applicable skills into agent dirs (non-fatal).
-        // SessionStart refreshes source caches and syncs unconditionally.
+        // SessionStart refreshes source caches and syncs unconditionally. A
+        // tool call that changed the dependencies is synced by the PostToolUse
+        // handler instead, which needs the cache as it was before the change.
         let session_start = event == HookEvent::SessionStart;
-        run_auto_sync(sym, &deps, session_start).await;
+        let dependency_change = matches!(
+            &sym_input,
+            symposium::InputEvent::PostToolUse(post) if changes_dependencies(post)
+        );
+        if !dependency_change {
+            run_auto_sync(sym, &deps, session_start).await;
+        }
 
         // SessionStart (once per session) also refreshes every hook's already-
         // cached source, so later events dispatch fresh binaries without per-
@@ -253,8 +316,10 @@ pub async fn execute_hook(
             prewarm_hook_sources(sym, &deps).await;
         }
 
+        record_activity(sym, agent, &sym_input, &deps).await;
+
         // Builtin dispatch → symposium output → host agent output as Value
-        let builtin_sym_output = dispatch_builtin(sym, &sym_input, &deps).await;
+        let builtin_sym_output = dispatch_builtin(sym, agent, &sym_input, &deps).await;
         let builtin_agent_output = handler.translate_output(&builtin_sym_output);
         let prior_output = builtin_agent_output.to_hook_output();
 
@@ -390,10 +455,7 @@ async fn run_auto_sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, session_start
     // try again now (sync may have created Cargo.lock).
     let root = workspace_root.or_else(|| crate::workspace_state::find_workspace_root(sym, &cwd));
     if let Some(ref root) = root {
-        let mut state = crate::workspace_state::WorkspaceState::load(sym, root);
-        state.record_sync(root);
-        state.workspace_root = Some(root.clone());
-        state.save(sym, root);
+        crate::workspace_state::record_sync(sym, root);
     }
 }
 
@@ -404,11 +466,18 @@ async fn run_auto_sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, session_start
 /// plugin in — since expansion evaluates edge and plugin predicates against the
 /// crate graph too. Registry plugins reached without any of these dispatch on a
 /// crate-free context (the fast path for `PreToolUse`).
-fn hook_dispatch_needs_deps(sym: &Symposium, registry_plugins: &[ParsedPlugin]) -> bool {
+fn hook_dispatch_needs_deps(
+    sym: &Symposium,
+    registry_plugins: &[ParsedPlugin],
+    workspace_root: Option<&Path>,
+) -> bool {
     registry_plugins
         .iter()
         .any(|p| p.plugin.hooks_need_dep_resolution() || !p.plugin.chained.is_empty())
-        || sym.config.plugins.has_enablement_entries()
+        || match workspace_root {
+            Some(root) => sym.plugins_in(root).has_enablement_entries(),
+            None => sym.config.plugins.has_enablement_entries(),
+        }
 }
 
 /// Refresh the source cache for every hook the workspace could fire this
@@ -428,16 +497,22 @@ async fn prewarm_hook_sources(sym: &Symposium, deps: &Arc<WorkspaceDeps>) {
     // Resolving the workspace runs cargo, so only do it when some hook's
     // gating references a concrete crate, or there is crate-plugin expansion to
     // perform (mirrors dispatch).
-    let dep_ids = if hook_dispatch_needs_deps(sym, &registry.plugins) {
+    let dep_ids = if hook_dispatch_needs_deps(
+        sym,
+        &registry.plugins,
+        workspace.as_ref().map(|ws| ws.root.as_path()),
+    ) {
         crate::pm::workspace_dep_ids(sym, deps).await
     } else {
         Vec::new()
     };
     let used_names = workspace
         .as_ref()
-        .map(|ws| sym.config.plugins.used_names_in(&ws.root))
+        .map(|ws| sym.used_names_in(&ws.root))
         .unwrap_or_default();
-    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids).with_used_names(&used_names);
+    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids)
+        .with_used_names(&used_names)
+        .with_workspace(workspace.clone());
     let pms = sym.package_managers(deps);
     let plugins = crate::plugins::active_plugins(
         sym,
@@ -481,9 +556,69 @@ async fn prewarm_hook_sources(sym: &Symposium, deps: &Arc<WorkspaceDeps>) {
     }
 }
 
+/// Record session starts and tool uses in telemetry. A session start lists
+/// the plugins applicable to the workspace; a tool use keeps only the tool's
+/// name, never its input. Resolving the plugins is skipped when telemetry is
+/// off.
+async fn record_activity(
+    sym: &Symposium,
+    agent: HookAgent,
+    input: &symposium::InputEvent,
+    deps: &Arc<WorkspaceDeps>,
+) {
+    if !sym.config.telemetry.enabled {
+        return;
+    }
+    let session_id = input.session_id().map(str::to_string);
+    let kind = match input {
+        symposium::InputEvent::SessionStart(_) => crate::telemetry::EventKind::SessionStart {
+            session_id,
+            agent: agent.as_str().to_string(),
+            plugins: applicable_plugin_names(sym, deps).await,
+        },
+        symposium::InputEvent::PreToolUse(pre) => crate::telemetry::EventKind::ToolUse {
+            session_id,
+            tool: pre.tool_name.clone(),
+        },
+        _ => return,
+    };
+    crate::telemetry::record_if_enabled(sym, kind);
+}
+
+/// Names of the active plugins whose plugin-level predicates hold for the
+/// workspace, in registry order.
+async fn applicable_plugin_names(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Vec<String> {
+    let workspace = deps.load().cloned();
+    let registry = crate::plugins::load_registry_with_workspace(sym, workspace.as_deref()).await;
+    let dep_ids = crate::pm::workspace_dep_ids(sym, deps).await;
+
+    let used = workspace
+        .as_ref()
+        .map(|ws| sym.used_names_in(&ws.root))
+        .unwrap_or_default();
+    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids)
+        .with_used_names(&used)
+        .with_workspace(workspace.clone());
+    let pms = sym.package_managers(deps);
+    let active = crate::plugins::active_plugins(
+        sym,
+        &registry,
+        &pms,
+        workspace.as_ref().map(|ws| ws.root.as_path()),
+        &mut ctx,
+    )
+    .await;
+    active
+        .iter()
+        .filter(|parsed| parsed.applies(&mut ctx))
+        .map(|parsed| parsed.plugin.name.clone())
+        .collect()
+}
+
 /// Built-in hook logic on canonical symposium types.
 pub async fn dispatch_builtin(
     sym: &Symposium,
+    agent: HookAgent,
     input: &symposium::InputEvent,
     deps: &Arc<WorkspaceDeps>,
 ) -> symposium::OutputEvent {
@@ -491,14 +626,17 @@ pub async fn dispatch_builtin(
         symposium::InputEvent::PreToolUse(_) => {
             symposium::OutputEvent::empty_for(HookEvent::PreToolUse)
         }
-        symposium::InputEvent::PostToolUse(post) => handle_post_tool_use(sym, post).await,
+        symposium::InputEvent::PostToolUse(post) => {
+            handle_post_tool_use(sym, agent, post, deps).await
+        }
         symposium::InputEvent::UserPromptSubmit(prompt) => {
-            handle_user_prompt_submit(sym, prompt).await
+            handle_user_prompt_submit(sym, agent, prompt, deps).await
         }
         symposium::InputEvent::SessionStart(session) => {
             handle_session_start(sym, session, deps).await
         }
-        _ => symposium::OutputEvent::empty_for(HookEvent::PreToolUse),
+        symposium::InputEvent::Stop(stop) => handle_stop(sym, stop).await,
+        other => symposium::OutputEvent::empty_for(other.event()),
     }
 }
 
@@ -535,9 +673,11 @@ async fn discovery_hint(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Option<St
 
     let used = workspace
         .as_ref()
-        .map(|ws| sym.config.plugins.used_names_in(&ws.root))
+        .map(|ws| sym.used_names_in(&ws.root))
         .unwrap_or_default();
-    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids).with_used_names(&used);
+    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids)
+        .with_used_names(&used)
+        .with_workspace(workspace.clone());
     let pms = sym.package_managers(deps);
     let active = crate::plugins::active_plugins(
         sym,
@@ -547,7 +687,7 @@ async fn discovery_hint(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Option<St
         &mut ctx,
     )
     .await;
-    let any_subcommand = !applicable_subcommands(&active, &dep_ids, &used).is_empty();
+    let any_subcommand = !applicable_subcommands(&active, &mut ctx).is_empty();
 
     any_subcommand.then(|| {
         format!(
@@ -598,20 +738,329 @@ fn update_nudge(sym: &Symposium) -> Option<String> {
     ))
 }
 
-/// Handle PostToolUse: no-op for now.
+/// Handle PostToolUse: when the tool changed the workspace's dependencies,
+/// re-resolve them, sync, and tell the agent what the change brought in.
+/// Without this, a crate added mid-session would get no skills until the
+/// next session. Gated by `auto-sync`, like every other hook-driven sync.
+/// A read from an installed skill is recorded in telemetry.
 async fn handle_post_tool_use(
-    _sym: &Symposium,
-    _post: &symposium::PostToolUseInput,
+    sym: &Symposium,
+    agent: HookAgent,
+    post: &symposium::PostToolUseInput,
+    deps: &Arc<WorkspaceDeps>,
 ) -> symposium::OutputEvent {
-    symposium::OutputEvent::empty_for(HookEvent::PostToolUse)
+    if sym.config.telemetry.enabled
+        && let Some(skill) = read_skill(post)
+    {
+        crate::telemetry::record_if_enabled(
+            sym,
+            crate::telemetry::EventKind::SkillRead {
+                session_id: post.session_id.clone(),
+                agent: agent.as_str().to_string(),
+                skill,
+            },
+        );
+    }
+
+    if !sym.config.auto_sync || !changes_dependencies(post) {
+        return symposium::OutputEvent::empty_for(HookEvent::PostToolUse);
+    }
+    match dependency_change_hint(sym, deps).await {
+        Some(hint) => symposium::OutputEvent::with_context(HookEvent::PostToolUse, hint),
+        None => symposium::OutputEvent::empty_for(HookEvent::PostToolUse),
+    }
+}
+
+/// Whether a completed tool call may have changed the workspace's
+/// dependencies: a shell command running `cargo add` or `cargo remove`, or a
+/// write to a `Cargo.toml`. Tool names differ across agents, so this looks at
+/// the tool input — a `command` string or argv array (Codex sends
+/// `["bash", "-lc", "cargo add sqlx"]`), or a `file_path`/`filePath`/`path`
+/// naming a manifest — and only uses the tool name to rule out reads.
+fn changes_dependencies(post: &symposium::PostToolUseInput) -> bool {
+    let input = &post.tool_input;
+    match input.get("command") {
+        Some(serde_json::Value::String(command)) => return runs_cargo_add_or_remove(command),
+        Some(serde_json::Value::Array(argv)) => {
+            let command: Vec<&str> = argv.iter().filter_map(|arg| arg.as_str()).collect();
+            return runs_cargo_add_or_remove(&command.join(" "));
+        }
+        _ => {}
+    }
+
+    let tool = post.tool_name.to_ascii_lowercase();
+    if reads_files(&tool)
+        || ["grep", "glob", "search", "list"]
+            .iter()
+            .any(|t| tool.contains(t))
+    {
+        return false;
+    }
+    tool_paths(input).any(|path| {
+        Path::new(path)
+            .file_name()
+            .is_some_and(|n| n == "Cargo.toml")
+    })
+}
+
+/// Whether a (lowercased) tool name looks like a file read: `Read`,
+/// `read_file`, `fs_read`, `view`, ...
+fn reads_files(tool: &str) -> bool {
+    tool.contains("read") || tool.contains("view")
+}
+
+/// The file paths a tool input names, under the keys agents use for them.
+fn tool_paths(input: &serde_json::Value) -> impl Iterator<Item = &str> {
+    ["file_path", "filePath", "path"]
+        .into_iter()
+        .filter_map(|key| input.get(key)?.as_str())
+}
+
+/// The installed skill a file-read tool call opened, by name. The skill
+/// directory is the nearest ancestor of the read path holding both a
+/// `SKILL.md` and symposium's marker, so user-managed skills don't count.
+/// `None` for other tools and for paths outside managed skills.
+fn read_skill(post: &symposium::PostToolUseInput) -> Option<String> {
+    if !reads_files(&post.tool_name.to_ascii_lowercase()) {
+        return None;
+    }
+    let cwd = post.cwd.as_deref().map(Path::new);
+    tool_paths(&post.tool_input).find_map(|path| {
+        let path = match cwd {
+            Some(cwd) => cwd.join(path),
+            None => PathBuf::from(path),
+        };
+        let dir = path.ancestors().skip(1).find(|dir| {
+            dir.join(crate::sync::MARKER_FILE).is_file() && dir.join("SKILL.md").is_file()
+        })?;
+        let from_frontmatter =
+            std::fs::read_to_string(dir.join("SKILL.md"))
+                .ok()
+                .and_then(|content| {
+                    let (fields, _) = crate::skills::parse_skill_md(&content).ok()?;
+                    fields.get("name").cloned()
+                });
+        from_frontmatter.or_else(|| Some(dir.file_name()?.to_string_lossy().into_owned()))
+    })
+}
+
+/// Whether a shell command line invokes `cargo add`, `cargo remove` or
+/// `cargo rm`, allowing a `+toolchain` between `cargo` and the subcommand.
+/// Shell operators and quotes separate words too, so chained
+/// (`cd crate&&cargo add x`) and quoted (`bash -lc 'cargo add x'`) commands
+/// are seen.
+fn runs_cargo_add_or_remove(command: &str) -> bool {
+    let mut words = command
+        .split(|c: char| {
+            c.is_whitespace() || matches!(c, ';' | '&' | '|' | '(' | ')' | '`' | '\'' | '"')
+        })
+        .filter(|word| !word.is_empty())
+        .peekable();
+    while let Some(word) = words.next() {
+        if word != "cargo" && !word.ends_with("/cargo") {
+            continue;
+        }
+        if words.peek().is_some_and(|w| w.starts_with('+')) {
+            words.next();
+        }
+        if words
+            .peek()
+            .is_some_and(|w| matches!(*w, "add" | "remove" | "rm"))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+/// Re-resolve the workspace after a dependency change, sync it, and describe
+/// what the change newly offers. The last cached resolution is the "before"
+/// picture; with none there is nothing to compare against, so the sync is
+/// left to the next hook's auto-sync and nothing is reported.
+async fn dependency_change_hint(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Option<String> {
+    let before = DependencyOffers::resolve(sym, &deps.previous()?).await;
+
+    let fresh = deps.reloaded();
+    let root = fresh.workspace_root()?.to_path_buf();
+    tracing::debug!("dependencies changed; re-syncing");
+    if let Err(e) = crate::sync::sync(sym, &fresh, symposium_install::UpdateLevel::None).await {
+        tracing::warn!(error = %e, "sync after dependency change failed (continuing)");
+        return None;
+    }
+    crate::workspace_state::record_sync(sym, &root);
+
+    let after = DependencyOffers::resolve(sym, &fresh).await;
+    after.added_since(&before).describe()
+}
+
+/// What a workspace's dependencies bring in: the skills and plugin MCP
+/// servers that apply, and the dependency plugins awaiting consent.
+#[derive(Debug, Default, PartialEq)]
+struct DependencyOffers {
+    skills: BTreeSet<String>,
+    mcp_servers: BTreeSet<String>,
+    pending_plugins: BTreeSet<String>,
+}
+
+impl DependencyOffers {
+    /// Resolve the offers for `deps` over the same active plugin set `sync`
+    /// uses. Empty outside a Rust workspace.
+    async fn resolve(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Self {
+        let skills = crate::skills::workspace_skills(sym, deps)
+            .await
+            .iter()
+            .map(|entry| entry.skill.name().to_string())
+            .collect();
+        let mcp_servers = crate::mcp_serve::applicable_servers(sym, deps)
+            .await
+            .iter()
+            .map(|server| crate::mcp_serve::server_name(server).to_string())
+            .collect();
+        let pending_plugins = crate::discovery::pending_candidates(sym, deps)
+            .await
+            .into_iter()
+            .collect();
+
+        Self {
+            skills,
+            mcp_servers,
+            pending_plugins,
+        }
+    }
+
+    /// The offers in `self` that `before` did not have.
+    fn added_since(&self, before: &Self) -> Self {
+        Self {
+            skills: &self.skills - &before.skills,
+            mcp_servers: &self.mcp_servers - &before.mcp_servers,
+            pending_plugins: &self.pending_plugins - &before.pending_plugins,
+        }
+    }
+
+    /// Context for the agent naming each new offer; `None` when there are none.
+    fn describe(&self) -> Option<String> {
+        let list = |names: &BTreeSet<String>| {
+            names
+                .iter()
+                .map(|n| format!("`{n}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut lines = Vec::new();
+        if !self.skills.is_empty() {
+            lines.push(format!(
+                "- New skills: {}. They are installed; load them before working with the new crates.",
+                list(&self.skills)
+            ));
+        }
+        if !self.mcp_servers.is_empty() {
+            lines.push(format!(
+                "- New MCP servers: {}, available through the `{}` MCP server.",
+                list(&self.mcp_servers),
+                crate::mcp_serve::SERVER_NAME
+            ));
+        }
+        if !self.pending_plugins.is_empty() {
+            lines.push(format!(
+                "- New dependency plugins awaiting the user's consent: {}. \
+                 Tell the user to run `cargo agents sync` (which asks about each one) \
+                 or `cargo agents use <name>`. Do not enable them yourself.",
+                list(&self.pending_plugins)
+            ));
+        }
+
+        if lines.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "The workspace's dependencies changed and symposium re-synced them.\n{}",
+            lines.join("\n")
+        ))
+    }
 }
 
-/// Handle UserPromptSubmit: no-op for now.
+/// Handle UserPromptSubmit: point the agent at installed skills that fit
+/// the prompt (see [`crate::prompt_context`]), within the
+/// `prompt-context-budget`. Telemetry records which skills were pointed at,
+/// never the prompt.
 async fn handle_user_prompt_submit(
-    _sym: &Symposium,
-    _prompt_payload: &symposium::UserPromptSubmitInput,
+    sym: &Symposium,
+    agent: HookAgent,
+    prompt_payload: &symposium::UserPromptSubmitInput,
+    deps: &Arc<WorkspaceDeps>,
 ) -> symposium::OutputEvent {
-    symposium::OutputEvent::empty_for(HookEvent::UserPromptSubmit)
+    let budget = sym.config.prompt_context_budget;
+    let pointers = if budget == 0 {
+        None
+    } else {
+        prompt_pointers(sym, agent, &prompt_payload.prompt, deps).await
+    };
+
+    crate::telemetry::record_if_enabled(
+        sym,
+        crate::telemetry::EventKind::UserPrompt {
+            session_id: prompt_payload.session_id.clone(),
+            skills: pointers
+                .as_ref()
+                .map(|(skills, _)| skills.clone())
+                .unwrap_or_default(),
+        },
+    );
+
+    match pointers {
+        Some((_, context)) => OutputEvent::with_context(HookEvent::UserPromptSubmit, context),
+        None => OutputEvent::empty_for(HookEvent::UserPromptSubmit),
+    }
+}
+
+/// The installed skills matching `prompt`, by name, and the rendered
+/// pointers to them. `None` outside a workspace or when nothing fits.
+///
+/// The hook names only the payload schema, which an `[[agent-profile]]` agent
+/// shares with a built-in one, so skills are looked up in the directories of
+/// every configured agent sending that schema.
+async fn prompt_pointers(
+    sym: &Symposium,
+    agent: HookAgent,
+    prompt: &str,
+    deps: &Arc<WorkspaceDeps>,
+) -> Option<(Vec<String>, String)> {
+    let root = deps.workspace_root()?.to_path_buf();
+    let profiles = &sym.config.agent_profiles;
+    let mut agents: Vec<_> = sym
+        .config
+        .agents
+        .iter()
+        .filter_map(|a| crate::agents::Agent::resolve(&a.name, profiles).ok())
+        .filter(|a| a.hook_schema() == agent)
+        .collect();
+    if agents.is_empty() {
+        agents.push(crate::agents::Agent::resolve(agent.as_str(), profiles).ok()?);
+    }
+    let applicable = crate::skills::workspace_skills(sym, deps).await;
+    let mut installed: Vec<crate::prompt_context::InstalledSkill> = Vec::new();
+    for agent in &agents {
+        for skill in crate::prompt_context::installed_skills(agent, &root, &applicable) {
+            if !installed.iter().any(|s| s.dir == skill.dir) {
+                installed.push(skill);
+            }
+        }
+    }
+    let dep_names: Vec<String> = deps.crates().iter().map(|c| c.name.clone()).collect();
+
+    let matches = crate::prompt_context::matching_skills(prompt, &dep_names, &installed);
+    let (context, count) =
+        crate::prompt_context::render(&matches, sym.config.prompt_context_budget)?;
+    let pointed = matches[..count].iter().map(|s| s.name.clone()).collect();
+    Some((pointed, context))
+}
+
+/// Handle Stop: symposium itself never blocks. Plugin hooks decide whether
+/// the agent may stop (see `StopOutput::block`), and each gate bounds its own
+/// retries from `StopInput::stop_hook_active`.
+async fn handle_stop(_sym: &Symposium, _stop: &symposium::StopInput) -> symposium::OutputEvent {
+    symposium::OutputEvent::empty_for(HookEvent::Stop)
 }
 
 pub enum PluginHookOutput {
@@ -645,16 +1094,22 @@ pub async fn dispatch_plugin_hooks(
     // wildcard or env/shell/path predicate never needs the crate graph), or
     // when there is crate-plugin expansion to perform — that too evaluates
     // predicates against the crate graph.
-    let dep_ids = if hook_dispatch_needs_deps(sym, &registry.plugins) {
+    let dep_ids = if hook_dispatch_needs_deps(
+        sym,
+        &registry.plugins,
+        workspace.as_ref().map(|ws| ws.root.as_path()),
+    ) {
         crate::pm::workspace_dep_ids(sym, deps).await
     } else {
         Vec::new()
     };
     let used_names = workspace
         .as_ref()
-        .map(|ws| sym.config.plugins.used_names_in(&ws.root))
+        .map(|ws| sym.used_names_in(&ws.root))
         .unwrap_or_default();
-    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids).with_used_names(&used_names);
+    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids)
+        .with_used_names(&used_names)
+        .with_workspace(workspace.clone());
     // Dispatch over the active set — registry plugins plus crate-sourced ones —
     // so a crate plugin's hooks fire exactly like a registry plugin's.
     let pms = sym.package_managers(deps);
@@ -670,128 +1125,200 @@ pub async fn dispatch_plugin_hooks(
 
     let mut output = prior_output;
 
-    for hook in hooks {
-        tracing::info!(
-            plugin = %hook.plugin_name,
-            hook = %hook.hook_name,
-            format = ?hook.format,
-            "running plugin hook"
-        );
-
-        // Determine stdin for the plugin based on its declared format.
-        // After format selection, the only two cases are:
-        // - native (matches host agent) → pass through original input
-        // - symposium → deliver canonical format
-        let hook_agent = hook.format.as_agent();
-        let hook_input: &dyn AgentHookInput = if hook_agent == Some(host_agent) {
-            original_input
-        } else {
-            sym_input
-        };
-        let stdin_str = match hook_input.to_string() {
-            Ok(s) => s,
-            Err(e) => {
-                tracing::error!(plugin = %hook.plugin_name, hook = %hook.hook_name, error = %e, "failed to serialize hook input");
-                continue;
+    // Consecutive `parallel` hooks run as one batch; every other hook is a
+    // batch of its own. Outcomes are applied in plugin order either way, so the
+    // merged output does not depend on which hook finishes first.
+    let mut hooks = hooks.into_iter().peekable();
+    while let Some(first) = hooks.next() {
+        let mut batch = vec![first];
+        if batch[0].parallel {
+            while let Some(next) = hooks.next_if(|h| h.parallel) {
+                batch.push(next);
             }
-        };
+        }
 
-        let spawn_res = match build_spawn_spec(sym, &hook).await {
-            Ok(spec) => spawn_from_spec(spec),
-            Err(e) => {
-                tracing::warn!(error = %e, "failed to prepare hook command");
-                continue;
-            }
-        };
+        let mut running = Vec::new();
+        for hook in batch {
+            tracing::info!(
+                plugin = %hook.plugin_name,
+                hook = %hook.hook_name,
+                format = ?hook.format,
+                parallel = hook.parallel,
+                "running plugin hook"
+            );
 
-        match spawn_res {
-            Ok(mut child) => {
-                if let Some(mut stdin) = child.stdin.take() {
-                    let _ = stdin.write_all(stdin_str.as_bytes());
+            // Determine stdin for the plugin based on its declared format.
+            // After format selection, the only two cases are:
+            // - native (matches host agent) → pass through original input
+            // - symposium → deliver canonical format
+            let native = hook.format.as_agent() == Some(host_agent);
+            let hook_input: &dyn AgentHookInput = if native { original_input } else { sym_input };
+            let stdin_str = match hook_input.to_string() {
+                Ok(s) => s,
+                Err(e) => {
+                    tracing::error!(plugin = %hook.plugin_name, hook = %hook.hook_name, error = %e, "failed to serialize hook input");
+                    continue;
                 }
+            };
 
-                let child_out = match child.wait_with_output() {
-                    Ok(o) => o,
-                    Err(e) => {
-                        tracing::warn!(error = %e, "failed waiting for hook process");
-                        continue;
+            let timeout = Duration::from_secs(hook.timeout.unwrap_or(sym.config.hook_timeout_secs));
+            if let Some(handle) = start_hook(sym, &hook, stdin_str, timeout).await {
+                running.push((hook, native, handle));
+            }
+        }
+
+        let mut running = running.into_iter();
+        while let Some((hook, native, handle)) = running.next() {
+            let (outcome, elapsed) = handle
+                .await
+                .unwrap_or_else(|e| (HookOutcome::Failed(e.to_string()), Duration::ZERO));
+            record_hook_dispatch(sym, sym_input, &hook, &outcome, elapsed);
+            match hook_result(&hook, native, host_agent, event, outcome) {
+                Ok(Some(host_json)) => merge(&mut output, host_json),
+                Ok(None) => {}
+                Err(stderr) => {
+                    // A block wins; the rest of the batch is killed.
+                    for (_, _, handle) in running {
+                        handle.abort();
                     }
-                };
+                    return Err(stderr);
+                }
+            }
+        }
+    }
 
-                tracing::trace!(?child_out, "hook finished");
+    Ok(output)
+}
 
-                let exit_code = child_out.status.code();
-                tracing::debug!(
-                    report = %crate::report::ReportEvent::HookDispatched {
-                        plugin: hook.plugin_name.clone(),
-                        hook: hook.hook_name.clone(),
-                        exit_code,
-                        error: None,
-                    },
-                );
-                match exit_code {
-                    None | Some(2) => return Err(child_out.stderr),
-                    Some(0) if child_out.stdout.is_empty() => continue,
-                    Some(0) => {
-                        // Parse output and convert to host agent format.
-                        // Two cases: native (same as host) or symposium.
-                        let host_handler = host_agent.event(event);
-                        let Some(host_h) = host_handler else { continue };
-
-                        let host_json = if hook_agent == Some(host_agent) {
-                            // Native format — parse as host agent output
-                            match host_h.parse_output(&child_out.stdout) {
-                                Ok(o) => o.to_hook_output(),
-                                Err(e) => {
-                                    tracing::warn!(error = %e, "failed to parse hook output");
-                                    continue;
-                                }
-                            }
+/// Record a dispatched hook in telemetry: how long it ran and how it ended.
+fn record_hook_dispatch(
+    sym: &Symposium,
+    input: &symposium::InputEvent,
+    hook: &ResolvedHook,
+    outcome: &HookOutcome,
+    elapsed: Duration,
+) {
+    let (exit_code, timed_out) = match outcome {
+        HookOutcome::Exited(output) => (output.status.code(), false),
+        HookOutcome::TimedOut(_) => (None, true),
+        HookOutcome::Failed(_) => (None, false),
+    };
+    crate::telemetry::record_if_enabled(
+        sym,
+        crate::telemetry::EventKind::HookDispatch {
+            session_id: input.session_id().map(str::to_string),
+            plugin: hook.plugin_name.clone(),
+            hook: hook.hook_name.clone(),
+            event: format!("{:?}", input.event()),
+            duration_ms: elapsed.as_millis().try_into().unwrap_or(u64::MAX),
+            exit_code,
+            timed_out,
+        },
+    );
+}
+
+/// Interpret one hook's outcome: `Ok(Some(..))` is output to merge (already in
+/// the host agent's format), `Ok(None)` contributes nothing, and `Err(stderr)`
+/// blocks the event (exit code 2, or killed by a signal). A hook that failed to
+/// run, timed out, or exited with any other code is reported and skipped.
+fn hook_result(
+    hook: &ResolvedHook,
+    native: bool,
+    host_agent: HookAgent,
+    event: HookEvent,
+    outcome: HookOutcome,
+) -> Result<Option<serde_json::Value>, Vec<u8>> {
+    let child_out = match outcome {
+        HookOutcome::Exited(child_out) => child_out,
+        HookOutcome::TimedOut(timeout) => {
+            let error = format!("timed out after {}s", timeout.as_secs());
+            tracing::debug!(
+                report = %crate::report::ReportEvent::HookDispatched {
+                    plugin: hook.plugin_name.clone(),
+                    hook: hook.hook_name.clone(),
+                    exit_code: None,
+                    error: Some(error.clone()),
+                },
+            );
+            tracing::warn!(
+                plugin = %hook.plugin_name,
+                hook = %hook.hook_name,
+                "plugin hook {error}; killed it (continuing)"
+            );
+            return Ok(None);
+        }
+        HookOutcome::Failed(e) => {
+            tracing::debug!(
+                report = %crate::report::ReportEvent::HookDispatched {
+                    plugin: hook.plugin_name.clone(),
+                    hook: hook.hook_name.clone(),
+                    exit_code: None,
+                    error: Some(e.clone()),
+                },
+            );
+            tracing::warn!(error = %e, "failed to run hook command");
+            return Ok(None);
+        }
+    };
+
+    tracing::trace!(?child_out, "hook finished");
+
+    let exit_code = child_out.status.code();
+    tracing::debug!(
+        report = %crate::report::ReportEvent::HookDispatched {
+            plugin: hook.plugin_name.clone(),
+            hook: hook.hook_name.clone(),
+            exit_code,
+            error: None,
+        },
+    );
+    match exit_code {
+        None | Some(2) => Err(child_out.stderr),
+        Some(0) if child_out.stdout.is_empty() => Ok(None),
+        Some(0) => {
+            // Parse output and convert to host agent format.
+            // Two cases: native (same as host) or symposium.
+            let Some(host_h) = host_agent.event(event) else {
+                return Ok(None);
+            };
+
+            if native {
+                // Native format — parse as host agent output
+                match host_h.parse_output(&child_out.stdout) {
+                    Ok(o) => Ok(Some(o.to_hook_output())),
+                    Err(e) => {
+                        tracing::warn!(error = %e, "failed to parse hook output");
+                        Ok(None)
+                    }
+                }
+            } else {
+                // Symposium format — parse and convert to host agent
+                match serde_json::from_slice::<serde_json::Value>(&child_out.stdout) {
+                    Ok(v) => {
+                        if let Ok(sym_out) =
+                            serde_json::from_value::<symposium::OutputEvent>(v.clone())
+                        {
+                            let host_out = host_h.translate_output(&sym_out);
+                            Ok(Some(host_out.to_hook_output()))
                         } else {
-                            // Symposium format — parse and convert to host agent
-                            match serde_json::from_slice::<serde_json::Value>(&child_out.stdout) {
-                                Ok(v) => {
-                                    if let Ok(sym_out) =
-                                        serde_json::from_value::<symposium::OutputEvent>(v.clone())
-                                    {
-                                        let host_out = host_h.translate_output(&sym_out);
-                                        host_out.to_hook_output()
-                                    } else {
-                                        v
-                                    }
-                                }
-                                Err(e) => {
-                                    tracing::warn!(error = %e, "failed to parse hook output");
-                                    continue;
-                                }
-                            }
-                        };
-
-                        merge(&mut output, host_json);
+                            Ok(Some(v))
+                        }
                     }
-                    Some(code) => {
-                        tracing::warn!(
-                            exit_code = code,
-                            "plugin hook exited with non-zero (continuing)"
-                        );
+                    Err(e) => {
+                        tracing::warn!(error = %e, "failed to parse hook output");
+                        Ok(None)
                     }
                 }
             }
-            Err(e) => {
-                tracing::debug!(
-                    report = %crate::report::ReportEvent::HookDispatched {
-                        plugin: hook.plugin_name.clone(),
-                        hook: hook.hook_name.clone(),
-                        exit_code: None,
-                        error: Some(e.to_string()),
-                    },
-                );
-                tracing::warn!(error = %e, "failed to spawn hook command");
-            }
+        }
+        Some(code) => {
+            tracing::warn!(
+                exit_code = code,
+                "plugin hook exited with non-zero (continuing)"
+            );
+            Ok(None)
         }
     }
-
-    Ok(output)
 }
 
 /// Recursively merge two JSON objects, with `b` taking precedence over `a`.
@@ -1003,8 +1530,8 @@ mod tests {
             env.get("SYMPOSIUM_DIR_setup").map(String::as_str),
             Some("/cache/setup")
         );
-        assert!(env.get("SYMPOSIUM_setup").is_none());
-        assert!(env.get("PATH").is_none());
+        assert!(!env.contains_key("SYMPOSIUM_setup"));
+        assert!(!env.contains_key("PATH"));
     }
 
     #[test]
@@ -1018,9 +1545,9 @@ mod tests {
             }),
         }];
         let env: std::collections::HashMap<_, _> = build_env(&acquired).into_iter().collect();
-        assert!(env.get("SYMPOSIUM_DIR_rg").is_none());
-        assert!(env.get("SYMPOSIUM_rg").is_none());
-        assert!(env.get("PATH").is_none());
+        assert!(!env.contains_key("SYMPOSIUM_DIR_rg"));
+        assert!(!env.contains_key("SYMPOSIUM_rg"));
+        assert!(!env.contains_key("PATH"));
     }
 
     #[tokio::test]
@@ -1034,12 +1561,12 @@ mod tests {
             None,
             None,
         ));
-        let output = dispatch_builtin(&sym, &input, &deps).await;
+        let output = dispatch_builtin(&sym, HookAgent::Claude, &input, &deps).await;
         assert!(output.additional_context().is_none());
     }
 
     #[tokio::test]
-    async fn builtin_post_tool_use_returns_empty_for_now() {
+    async fn builtin_post_tool_use_ignores_unrelated_tools() {
         let tmp = tempfile::tempdir().unwrap();
         let sym = Symposium::from_dir(tmp.path());
         let deps = sym.workspace_deps(tmp.path());
@@ -1050,10 +1577,102 @@ mod tests {
             Some("test-session".to_string()),
             Some("/tmp".to_string()),
         ));
-        let output = dispatch_builtin(&sym, &input, &deps).await;
+        let output = dispatch_builtin(&sym, HookAgent::Claude, &input, &deps).await;
         assert!(output.additional_context().is_none());
     }
 
+    fn post_tool_use(
+        tool_name: &str,
+        tool_input: serde_json::Value,
+    ) -> symposium::PostToolUseInput {
+        symposium::PostToolUseInput::new(
+            tool_name.to_string(),
+            tool_input,
+            serde_json::json!({}),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn cargo_add_and_remove_change_dependencies() {
+        for command in [
+            "cargo add serde --features derive",
+            "cd crates/core && cargo remove tokio",
+            "cargo +nightly rm anyhow",
+            "/usr/local/bin/cargo add rand",
+            "cd crate&&cargo add x",
+            "cargo build;cargo rm x",
+            "bash -lc 'cargo add sqlx'",
+            "(cargo add rand)",
+        ] {
+            let post = post_tool_use("Bash", serde_json::json!({ "command": command }));
+            assert!(changes_dependencies(&post), "{command}");
+        }
+        for argv in [
+            serde_json::json!(["bash", "-lc", "cargo add sqlx"]),
+            serde_json::json!(["cargo", "remove", "tokio"]),
+        ] {
+            let post = post_tool_use("shell", serde_json::json!({ "command": argv }));
+            assert!(changes_dependencies(&post), "{argv}");
+        }
+        let post = post_tool_use(
+            "shell",
+            serde_json::json!({ "command": ["bash", "-lc", "cargo build"] }),
+        );
+        assert!(!changes_dependencies(&post));
+        for command in [
+            "cargo build",
+            "cargo test add",
+            "echo cargo",
+            "git add Cargo.toml",
+        ] {
+            let post = post_tool_use("Bash", serde_json::json!({ "command": command }));
+            assert!(!changes_dependencies(&post), "{command}");
+        }
+    }
+
+    #[test]
+    fn manifest_writes_change_dependencies() {
+        let edit = post_tool_use("Edit", serde_json::json!({"file_path": "/ws/Cargo.toml"}));
+        assert!(changes_dependencies(&edit));
+        let write = post_tool_use(
+            "write_file",
+            serde_json::json!({"path": "crates/a/Cargo.toml"}),
+        );
+        assert!(changes_dependencies(&write));
+        let camel = post_tool_use("edit", serde_json::json!({"filePath": "Cargo.toml"}));
+        assert!(changes_dependencies(&camel));
+
+        let read = post_tool_use("Read", serde_json::json!({"file_path": "/ws/Cargo.toml"}));
+        assert!(!changes_dependencies(&read));
+        let lock = post_tool_use("Edit", serde_json::json!({"file_path": "/ws/Cargo.lock"}));
+        assert!(!changes_dependencies(&lock));
+    }
+
+    #[test]
+    fn dependency_offers_describe_only_new_entries() {
+        let names = |ns: &[&str]| ns.iter().map(|n| n.to_string()).collect::<BTreeSet<_>>();
+        let before = DependencyOffers {
+            skills: names(&["tokio-guidance"]),
+            mcp_servers: names(&[]),
+            pending_plugins: names(&[]),
+        };
+        let after = DependencyOffers {
+            skills: names(&["serde-guidance", "tokio-guidance"]),
+            mcp_servers: names(&["serde-inspector"]),
+            pending_plugins: names(&["serde-plugin"]),
+        };
+
+        assert_eq!(before.added_since(&before).describe(), None);
+        let hint = after.added_since(&before).describe().unwrap();
+        assert!(hint.contains("`serde-guidance`"), "{hint}");
+        assert!(!hint.contains("tokio-guidance"), "{hint}");
+        assert!(hint.contains("`serde-inspector`"), "{hint}");
+        assert!(hint.contains("`serde-plugin`"), "{hint}");
+        assert!(hint.contains("cargo agents use <name>"), "{hint}");
+    }
+
     #[tokio::test]
     async fn builtin_user_prompt_submit_returns_empty_for_now() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1064,10 +1683,30 @@ mod tests {
             Some("test-session".to_string()),
             Some("/tmp".to_string()),
         ));
-        let output = dispatch_builtin(&sym, &input, &deps).await;
+        let output = dispatch_builtin(&sym, HookAgent::Claude, &input, &deps).await;
         assert!(output.additional_context().is_none());
     }
 
+    #[tokio::test]
+    async fn user_prompt_telemetry_omits_prompt_text() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut sym = Symposium::from_dir(tmp.path());
+        sym.config.telemetry.enabled = true;
+        let deps = sym.workspace_deps(tmp.path());
+        let input = symposium::InputEvent::UserPromptSubmit(symposium::UserPromptSubmitInput::new(
+            "secret plans for serde".to_string(),
+            Some("test-session".to_string()),
+            None,
+        ));
+        dispatch_builtin(&sym, HookAgent::Claude, &input, &deps).await;
+
+        let events = crate::telemetry::read_events(sym.config_dir());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind_name(), "user_prompt");
+        let line = serde_json::to_string(&events[0]).unwrap();
+        assert!(!line.contains("secret"), "{line}");
+    }
+
     #[test]
     fn symposium_output_serializes_with_additional_context() {
         let output = symposium::OutputEvent::with_context(
@@ -1112,6 +1751,8 @@ mod tests {
             script: None,
             args: vec![],
             format: HookFormat::Symposium,
+            timeout: None,
+            parallel: false,
             predicates: crate::predicate::PredicateSet {
                 predicates: hook_shell
                     .into_iter()
@@ -1139,6 +1780,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
         crate::plugins::ParsedPlugin {
@@ -8,6 +8,7 @@ use std::{any::Any, fmt::Debug};
 pub mod claude;
 pub mod codex;
 pub mod copilot;
+pub mod cursor;
 pub mod gemini;
 pub mod goose;
 pub mod kiro;
@@ -26,6 +27,9 @@ pub enum HookAgent {
     #[value(name = "copilot")]
     #[serde(rename = "copilot")]
     Copilot,
+    #[value(name = "cursor")]
+    #[serde(rename = "cursor")]
+    Cursor,
     #[value(name = "gemini")]
     #[serde(rename = "gemini")]
     Gemini,
@@ -47,6 +51,7 @@ impl HookAgent {
             HookAgent::Claude => "claude",
             HookAgent::Codex => "codex",
             HookAgent::Copilot => "copilot",
+            HookAgent::Cursor => "cursor",
             HookAgent::Gemini => "gemini",
             HookAgent::Goose => "goose",
             HookAgent::Kiro => "kiro",
@@ -59,6 +64,7 @@ impl HookAgent {
             HookAgent::Claude => claude::ClaudeCode.event(event),
             HookAgent::Codex => codex::Codex.event(event),
             HookAgent::Copilot => copilot::Copilot.event(event),
+            HookAgent::Cursor => cursor::Cursor.event(event),
             HookAgent::Gemini => gemini::Gemini.event(event),
             HookAgent::Goose => goose::Goose.event(event),
             HookAgent::Kiro => kiro::Kiro.event(event),
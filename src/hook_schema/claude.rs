@@ -217,6 +217,40 @@ mod tests {
             Some(serde_json::json!({"command": "safe-cmd"}))
         );
     }
+
+    #[test]
+    fn stop_block_round_trips() {
+        let block = symposium::OutputEvent::Stop(symposium::StopOutput::block("tests fail"));
+        let output = ClaudeStopOutput::from_symposium(&block);
+        assert_eq!(
+            output.to_hook_output(),
+            serde_json::json!({"decision": "block", "reason": "tests fail"})
+        );
+
+        let symposium::OutputEvent::Stop(back) = output.to_symposium() else {
+            panic!("wrong output type")
+        };
+        assert!(back.is_block());
+        assert_eq!(back.additional_context.as_deref(), Some("tests fail"));
+
+        let allow = symposium::OutputEvent::Stop(symposium::StopOutput::default());
+        assert_eq!(
+            ClaudeStopOutput::from_symposium(&allow).to_hook_output(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn stop_input_carries_stop_hook_active() {
+        let input = ClaudeStopInput::parse_input(
+            r#"{"hook_event_name":"Stop","session_id":"s","stop_hook_active":true}"#,
+        )
+        .unwrap();
+        let symposium::InputEvent::Stop(stop) = input.to_symposium() else {
+            panic!("wrong input type")
+        };
+        assert!(stop.stop_hook_active);
+    }
 }
 
 // ── PostToolUse ───────────────────────────────────────────────────────
@@ -554,6 +588,11 @@ pub struct ClaudeStopInput {
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClaudeStopOutput {
+    /// `"block"` keeps Claude working, with `reason` as its next instruction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
     #[serde(rename = "continue", skip_serializing_if = "Option::is_none")]
     pub do_continue: Option<bool>,
     #[serde(rename = "stopReason", skip_serializing_if = "Option::is_none")]
@@ -571,16 +610,24 @@ impl AgentHookInput for ClaudeStopInput {
         Ok(serde_json::from_str(payload)?)
     }
     fn to_symposium(&self) -> symposium::InputEvent {
-        symposium::InputEvent::Stop(symposium::StopInput::new(
-            self.rest
-                .get("session_id")
-                .and_then(|v| v.as_str())
-                .map(String::from),
-            self.rest
-                .get("cwd")
-                .and_then(|v| v.as_str())
-                .map(String::from),
-        ))
+        symposium::InputEvent::Stop(
+            symposium::StopInput::new(
+                self.rest
+                    .get("session_id")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                self.rest
+                    .get("cwd")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+            )
+            .with_stop_hook_active(
+                self.rest
+                    .get("stop_hook_active")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false),
+            ),
+        )
     }
     fn from_symposium(event: &symposium::InputEvent) -> Self {
         let symposium::InputEvent::Stop(p) = event else {
@@ -593,6 +640,10 @@ impl AgentHookInput for ClaudeStopInput {
         if let Some(c) = &p.cwd {
             rest.insert("cwd".into(), serde_json::Value::String(c.clone()));
         }
+        rest.insert(
+            "stop_hook_active".into(),
+            serde_json::Value::Bool(p.stop_hook_active),
+        );
         Self {
             hook_event_name: "Stop".into(),
             rest,
@@ -613,12 +664,25 @@ impl AgentHookOutput for ClaudeStopOutput {
         }
         Ok(serde_json::from_slice(output)?)
     }
-    fn from_symposium(_event: &symposium::OutputEvent) -> Self {
-        // Stop hooks don't inject additionalContext into the agent.
-        Self::default()
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        // Stop hooks don't inject additionalContext into the agent; only a
+        // block carries text, as the reason to keep going.
+        match event {
+            symposium::OutputEvent::Stop(o) if o.is_block() => Self {
+                decision: Some("block".into()),
+                reason: o.additional_context.clone(),
+                ..Default::default()
+            },
+            _ => Self::default(),
+        }
     }
     fn to_symposium(&self) -> symposium::OutputEvent {
-        symposium::OutputEvent::Stop(symposium::StopOutput::default())
+        match self.decision.as_deref() {
+            Some("block") => symposium::OutputEvent::Stop(symposium::StopOutput::block(
+                self.reason.clone().unwrap_or_default(),
+            )),
+            _ => symposium::OutputEvent::Stop(symposium::StopOutput::default()),
+        }
     }
     fn to_hook_output(&self) -> serde_json::Value {
         serde_json::to_value(self).unwrap()
@@ -14,6 +14,7 @@ impl Agent for Codex {
                 Some(erase_agent_hook_event(CodexUserPromptSubmitEvent))
             }
             super::HookEvent::SessionStart => Some(erase_agent_hook_event(CodexSessionStartEvent)),
+            super::HookEvent::Stop => Some(erase_agent_hook_event(CodexStopEvent)),
             _ => None,
         }
     }
@@ -49,6 +50,7 @@ codex_event!(
     CodexSessionStartInput,
     CodexSessionStartOutput
 );
+codex_event!(CodexStopEvent, CodexStopInput, CodexStopOutput);
 
 fn codex_hook_output_from_symposium(
     event_name: &str,
@@ -456,3 +458,92 @@ impl AgentHookOutput for CodexSessionStartOutput {
         self
     }
 }
+
+// ── Stop ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexStopInput {
+    pub hook_event_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    #[serde(default)]
+    pub stop_hook_active: bool,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CodexStopOutput {
+    /// `"block"` makes Codex continue, with `reason` as its next prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for CodexStopInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::Stop(
+            symposium::StopInput::new(self.session_id.clone(), self.cwd.clone())
+                .with_stop_hook_active(self.stop_hook_active),
+        )
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::Stop(p) = event else {
+            panic!("wrong event")
+        };
+        Self {
+            hook_event_name: "Stop".into(),
+            session_id: p.session_id.clone(),
+            cwd: p.cwd.clone(),
+            stop_hook_active: p.stop_hook_active,
+            rest: Default::default(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for CodexStopOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        match event {
+            symposium::OutputEvent::Stop(o) if o.is_block() => Self {
+                decision: Some("block".into()),
+                reason: o.additional_context.clone(),
+                rest: Default::default(),
+            },
+            _ => Self::default(),
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        match self.decision.as_deref() {
+            Some("block") => symposium::OutputEvent::Stop(symposium::StopOutput::block(
+                self.reason.clone().unwrap_or_default(),
+            )),
+            _ => symposium::OutputEvent::Stop(symposium::StopOutput::default()),
+        }
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
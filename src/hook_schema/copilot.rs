@@ -16,6 +16,7 @@ impl Agent for Copilot {
             super::HookEvent::SessionStart => {
                 Some(erase_agent_hook_event(CopilotSessionStartEvent))
             }
+            super::HookEvent::Stop => Some(erase_agent_hook_event(CopilotStopEvent)),
             _ => None,
         }
     }
@@ -51,6 +52,7 @@ copilot_event!(
     CopilotSessionStartInput,
     CopilotSessionStartOutput
 );
+copilot_event!(CopilotStopEvent, CopilotStopInput, CopilotStopOutput);
 
 // Copilot output is flat (additionalContext at top level, no hookSpecificOutput).
 
@@ -363,3 +365,86 @@ copilot_output_impl!(
     SessionStart,
     SessionStartOutput {}
 );
+
+// ── Stop ──────────────────────────────────────────────────────────────
+//
+// Fired by VS Code only, which takes Claude Code's `decision`/`reason` output.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopilotStopInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CopilotStopOutput {
+    /// `"block"` keeps Copilot working, with `reason` as its next instruction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for CopilotStopInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::Stop(symposium::StopInput::new(None, self.cwd.clone()))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::Stop(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            timestamp: None,
+            cwd: p.cwd.clone(),
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for CopilotStopOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        match event {
+            symposium::OutputEvent::Stop(o) if o.is_block() => Self {
+                decision: Some("block".into()),
+                reason: o.additional_context.clone(),
+                rest: serde_json::Map::new(),
+            },
+            _ => Self::default(),
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        match self.decision.as_deref() {
+            Some("block") => symposium::OutputEvent::Stop(symposium::StopOutput::block(
+                self.reason.clone().unwrap_or_default(),
+            )),
+            _ => symposium::OutputEvent::Stop(symposium::StopOutput::default()),
+        }
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
@@ -0,0 +1,668 @@
+//! Cursor hook payloads.
+//!
+//! Cursor's hooks are per-action rather than per-tool: a shell command, an
+//! MCP call and a file read each fire their own event. `hooks.json` routes
+//! each Cursor event to the symposium event it corresponds to, and the
+//! payload's `hook_event_name` tells the variants apart:
+//!
+//! | Cursor event | Symposium event | `tool_name` |
+//! |--------------|-----------------|-------------|
+//! | `beforeShellExecution` | `PreToolUse` | `Bash` |
+//! | `beforeMCPExecution` | `PreToolUse` | the MCP tool name |
+//! | `beforeReadFile` | `PreToolUse` | `Read` |
+//! | `afterShellExecution` | `PostToolUse` | `Bash` |
+//! | `afterMCPExecution` | `PostToolUse` | the MCP tool name |
+//! | `afterFileEdit` | `PostToolUse` | `Edit` |
+//! | `beforeSubmitPrompt` | `UserPromptSubmit` | — |
+//! | `stop` | `Stop` | — |
+//!
+//! Built-in tools use Claude Code's names so that plugin matchers written for
+//! `Bash`/`Read`/`Edit` apply to Cursor too. Cursor has no session-start hook.
+//!
+//! Cursor hooks cannot inject context: a pre-tool-use hook answers with a
+//! `permission` plus messages for the user and the agent, and the other
+//! hooks' outputs carry no context — except that a `stop` hook's
+//! `followup_message` is submitted as the next prompt, which is how a Stop
+//! block keeps Cursor working. Symposium only ever *denies* — an allowed
+//! call leaves `permission` unset so Cursor's own approval rules still apply.
+
+use serde::{Deserialize, Serialize};
+
+use crate::hook_schema::{
+    Agent, AgentHookEvent, AgentHookInput, AgentHookOutput, erase_agent_hook_event, symposium,
+};
+
+pub struct Cursor;
+impl Agent for Cursor {
+    fn event(&self, event: super::HookEvent) -> Option<Box<dyn super::ErasedAgentHookEvent>> {
+        match event {
+            super::HookEvent::PreToolUse => Some(erase_agent_hook_event(CursorPreToolUseEvent)),
+            super::HookEvent::PostToolUse => Some(erase_agent_hook_event(CursorPostToolUseEvent)),
+            super::HookEvent::UserPromptSubmit => {
+                Some(erase_agent_hook_event(CursorUserPromptSubmitEvent))
+            }
+            super::HookEvent::Stop => Some(erase_agent_hook_event(CursorStopEvent)),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! cursor_event {
+    ($event:ident, $input:ident, $output:ident) => {
+        pub struct $event;
+        impl AgentHookEvent for $event {
+            type Input = $input;
+            type Output = $output;
+        }
+    };
+}
+
+cursor_event!(
+    CursorPreToolUseEvent,
+    CursorPreToolUseInput,
+    CursorPreToolUseOutput
+);
+cursor_event!(
+    CursorPostToolUseEvent,
+    CursorPostToolUseInput,
+    CursorPostToolUseOutput
+);
+cursor_event!(
+    CursorUserPromptSubmitEvent,
+    CursorUserPromptSubmitInput,
+    CursorUserPromptSubmitOutput
+);
+cursor_event!(CursorStopEvent, CursorStopInput, CursorStopOutput);
+
+/// Cursor sends MCP `tool_input`/`result_json` as JSON-encoded strings.
+fn decode_json_string(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => serde_json::from_str(s).unwrap_or_else(|_| value.clone()),
+        other => other.clone(),
+    }
+}
+
+/// Cursor reports the open workspace folders rather than a working
+/// directory; the first one stands in for `cwd` when none is given.
+fn first_root(roots: &[String]) -> Option<String> {
+    roots.first().cloned()
+}
+
+fn input_str<'a>(input: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+    input.get(key).and_then(|v| v.as_str())
+}
+
+// ── PreToolUse ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CursorPreToolUseInput {
+    pub hook_event_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub workspace_roots: Vec<String>,
+    /// `beforeShellExecution`: the command line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// `beforeMCPExecution`: the tool and its (JSON-encoded) arguments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_input: Option<serde_json::Value>,
+    /// `beforeReadFile`: the file about to be read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CursorPreToolUseOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<String>,
+    #[serde(rename = "userMessage", skip_serializing_if = "Option::is_none")]
+    pub user_message: Option<String>,
+    #[serde(rename = "agentMessage", skip_serializing_if = "Option::is_none")]
+    pub agent_message: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for CursorPreToolUseInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        let (tool_name, tool_input) = match self.hook_event_name.as_str() {
+            "beforeShellExecution" => (
+                "Bash".to_string(),
+                serde_json::json!({ "command": self.command }),
+            ),
+            "beforeReadFile" => (
+                "Read".to_string(),
+                serde_json::json!({ "file_path": self.file_path }),
+            ),
+            _ => (
+                self.tool_name.clone().unwrap_or_default(),
+                self.tool_input
+                    .as_ref()
+                    .map(decode_json_string)
+                    .unwrap_or_default(),
+            ),
+        };
+        symposium::InputEvent::PreToolUse(symposium::PreToolUseInput::new(
+            tool_name,
+            tool_input,
+            self.conversation_id.clone(),
+            self.cwd
+                .clone()
+                .or_else(|| first_root(&self.workspace_roots)),
+        ))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::PreToolUse(p) = event else {
+            panic!("wrong event type")
+        };
+        let mut input = Self {
+            hook_event_name: String::new(),
+            conversation_id: p.session_id.clone(),
+            workspace_roots: p.cwd.iter().cloned().collect(),
+            command: None,
+            cwd: None,
+            tool_name: None,
+            tool_input: None,
+            file_path: None,
+            rest: serde_json::Map::new(),
+        };
+        match p.tool_name.as_str() {
+            "Bash" => {
+                input.hook_event_name = "beforeShellExecution".into();
+                input.command = input_str(&p.tool_input, "command").map(String::from);
+                input.cwd = p.cwd.clone();
+            }
+            "Read" => {
+                input.hook_event_name = "beforeReadFile".into();
+                input.file_path = input_str(&p.tool_input, "file_path").map(String::from);
+            }
+            _ => {
+                input.hook_event_name = "beforeMCPExecution".into();
+                input.tool_name = Some(p.tool_name.clone());
+                input.tool_input = Some(serde_json::Value::String(p.tool_input.to_string()));
+            }
+        }
+        input
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for CursorPreToolUseOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        let symposium::OutputEvent::PreToolUse(o) = event else {
+            return Self::default();
+        };
+        // A deny's context is the reason, shown to both the user and the
+        // agent; otherwise the context can only reach the agent's message.
+        if o.decision == symposium_sdk::hook::Decision::Deny {
+            return Self {
+                permission: Some("deny".into()),
+                user_message: o.additional_context.clone(),
+                agent_message: o.additional_context.clone(),
+                ..Self::default()
+            };
+        }
+        Self {
+            agent_message: o.additional_context.clone(),
+            ..Self::default()
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        let decision = match self.permission.as_deref() {
+            Some("deny") => symposium_sdk::hook::Decision::Deny,
+            _ => symposium_sdk::hook::Decision::Allow,
+        };
+        symposium::OutputEvent::PreToolUse(symposium::PreToolUseOutput::new(
+            decision,
+            self.agent_message
+                .clone()
+                .or_else(|| self.user_message.clone()),
+            None,
+        ))
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+// ── PostToolUse ───────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CursorPostToolUseInput {
+    pub hook_event_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub workspace_roots: Vec<String>,
+    /// `afterShellExecution`: the command line and its output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// `afterMCPExecution`: the tool, its arguments and its result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_input: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_json: Option<serde_json::Value>,
+    /// `afterFileEdit`: the edited file and the edits made to it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edits: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Cursor ignores the output of its after-action hooks.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CursorPostToolUseOutput {
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for CursorPostToolUseInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        let (tool_name, tool_input, tool_response) = match self.hook_event_name.as_str() {
+            "afterShellExecution" => (
+                "Bash".to_string(),
+                serde_json::json!({ "command": self.command }),
+                serde_json::json!({ "output": self.output }),
+            ),
+            "afterFileEdit" => (
+                "Edit".to_string(),
+                serde_json::json!({ "file_path": self.file_path, "edits": self.edits }),
+                serde_json::Value::Null,
+            ),
+            _ => (
+                self.tool_name.clone().unwrap_or_default(),
+                self.tool_input
+                    .as_ref()
+                    .map(decode_json_string)
+                    .unwrap_or_default(),
+                self.result_json
+                    .as_ref()
+                    .map(decode_json_string)
+                    .unwrap_or_default(),
+            ),
+        };
+        symposium::InputEvent::PostToolUse(symposium::PostToolUseInput::new(
+            tool_name,
+            tool_input,
+            tool_response,
+            self.conversation_id.clone(),
+            first_root(&self.workspace_roots),
+        ))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::PostToolUse(p) = event else {
+            panic!("wrong event type")
+        };
+        let mut input = Self {
+            hook_event_name: String::new(),
+            conversation_id: p.session_id.clone(),
+            workspace_roots: p.cwd.iter().cloned().collect(),
+            command: None,
+            output: None,
+            tool_name: None,
+            tool_input: None,
+            result_json: None,
+            file_path: None,
+            edits: None,
+            rest: serde_json::Map::new(),
+        };
+        match p.tool_name.as_str() {
+            "Bash" => {
+                input.hook_event_name = "afterShellExecution".into();
+                input.command = input_str(&p.tool_input, "command").map(String::from);
+                input.output = input_str(&p.tool_response, "output").map(String::from);
+            }
+            "Edit" | "Write" => {
+                input.hook_event_name = "afterFileEdit".into();
+                input.file_path = input_str(&p.tool_input, "file_path").map(String::from);
+                input.edits = Some(
+                    p.tool_input
+                        .get("edits")
+                        .cloned()
+                        .unwrap_or_else(|| serde_json::json!([])),
+                );
+            }
+            _ => {
+                input.hook_event_name = "afterMCPExecution".into();
+                input.tool_name = Some(p.tool_name.clone());
+                input.tool_input = Some(serde_json::Value::String(p.tool_input.to_string()));
+                input.result_json = Some(serde_json::Value::String(p.tool_response.to_string()));
+            }
+        }
+        input
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for CursorPostToolUseOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(_event: &symposium::OutputEvent) -> Self {
+        Self::default()
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        symposium::OutputEvent::PostToolUse(symposium::PostToolUseOutput::default())
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+// ── UserPromptSubmit ──────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CursorUserPromptSubmitInput {
+    pub hook_event_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub workspace_roots: Vec<String>,
+    #[serde(default)]
+    pub prompt: String,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CursorUserPromptSubmitOutput {
+    #[serde(rename = "continue", skip_serializing_if = "Option::is_none")]
+    pub do_continue: Option<bool>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for CursorUserPromptSubmitInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::UserPromptSubmit(symposium::UserPromptSubmitInput::new(
+            self.prompt.clone(),
+            self.conversation_id.clone(),
+            first_root(&self.workspace_roots),
+        ))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::UserPromptSubmit(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            hook_event_name: "beforeSubmitPrompt".into(),
+            conversation_id: p.session_id.clone(),
+            workspace_roots: p.cwd.iter().cloned().collect(),
+            prompt: p.prompt.clone(),
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for CursorUserPromptSubmitOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(_event: &symposium::OutputEvent) -> Self {
+        // `beforeSubmitPrompt` can only let the prompt through or stop it;
+        // symposium never stops a prompt.
+        Self {
+            do_continue: Some(true),
+            ..Self::default()
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        symposium::OutputEvent::UserPromptSubmit(symposium::UserPromptSubmitOutput::default())
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+// ── Stop ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CursorStopInput {
+    pub hook_event_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub workspace_roots: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// How many times a stop hook's follow-up has already resumed the
+    /// conversation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_count: Option<u32>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CursorStopOutput {
+    /// Submitted as the next user message, which keeps Cursor working.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followup_message: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for CursorStopInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::Stop(
+            symposium::StopInput::new(
+                self.conversation_id.clone(),
+                first_root(&self.workspace_roots),
+            )
+            .with_stop_hook_active(self.loop_count.is_some_and(|n| n > 0)),
+        )
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::Stop(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            hook_event_name: "stop".into(),
+            conversation_id: p.session_id.clone(),
+            workspace_roots: p.cwd.iter().cloned().collect(),
+            status: None,
+            loop_count: p.stop_hook_active.then_some(1),
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for CursorStopOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        // A block's reason becomes the follow-up that resumes the agent.
+        match event {
+            symposium::OutputEvent::Stop(o) if o.is_block() => Self {
+                followup_message: o.additional_context.clone(),
+                ..Default::default()
+            },
+            _ => Self::default(),
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        match &self.followup_message {
+            Some(message) => {
+                symposium::OutputEvent::Stop(symposium::StopOutput::block(message.clone()))
+            }
+            None => symposium::OutputEvent::Stop(symposium::StopOutput::default()),
+        }
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shell_execution_maps_to_bash() {
+        let input = CursorPreToolUseInput::parse_input(
+            r#"{"hook_event_name":"beforeShellExecution","conversation_id":"c1","generation_id":"g1","workspace_roots":["/work"],"command":"cargo test","cwd":"/work/crate"}"#,
+        )
+        .unwrap();
+        let symposium::InputEvent::PreToolUse(p) = input.to_symposium() else {
+            panic!("expected PreToolUse");
+        };
+        assert_eq!(p.tool_name, "Bash");
+        assert_eq!(p.tool_input, serde_json::json!({"command": "cargo test"}));
+        assert_eq!(p.session_id.as_deref(), Some("c1"));
+        assert_eq!(p.cwd.as_deref(), Some("/work/crate"));
+    }
+
+    #[test]
+    fn mcp_execution_decodes_tool_input() {
+        let input = CursorPreToolUseInput::parse_input(
+            r#"{"hook_event_name":"beforeMCPExecution","workspace_roots":["/work"],"tool_name":"fetch","tool_input":"{\"url\":\"https://example.com\"}","command":"npx fetch-server"}"#,
+        )
+        .unwrap();
+        let symposium::InputEvent::PreToolUse(p) = input.to_symposium() else {
+            panic!("expected PreToolUse");
+        };
+        assert_eq!(p.tool_name, "fetch");
+        assert_eq!(
+            p.tool_input,
+            serde_json::json!({"url": "https://example.com"})
+        );
+        assert_eq!(p.cwd.as_deref(), Some("/work"));
+    }
+
+    #[test]
+    fn deny_sets_permission_and_messages() {
+        let out = CursorPreToolUseOutput::from_symposium(&symposium::OutputEvent::PreToolUse(
+            symposium::PreToolUseOutput::deny("no force pushes"),
+        ));
+        assert_eq!(
+            out.to_hook_output(),
+            serde_json::json!({
+                "permission": "deny",
+                "userMessage": "no force pushes",
+                "agentMessage": "no force pushes",
+            })
+        );
+    }
+
+    #[test]
+    fn allow_leaves_permission_to_cursor() {
+        let out = CursorPreToolUseOutput::from_symposium(&symposium::OutputEvent::PreToolUse(
+            symposium::PreToolUseOutput::context("prefer cargo nextest"),
+        ));
+        assert_eq!(
+            out.to_hook_output(),
+            serde_json::json!({"agentMessage": "prefer cargo nextest"})
+        );
+    }
+
+    #[test]
+    fn file_edit_maps_to_edit() {
+        let input = CursorPostToolUseInput::parse_input(
+            r#"{"hook_event_name":"afterFileEdit","workspace_roots":["/work"],"file_path":"/work/Cargo.toml","edits":[{"old_string":"a","new_string":"b"}]}"#,
+        )
+        .unwrap();
+        let symposium::InputEvent::PostToolUse(p) = input.to_symposium() else {
+            panic!("expected PostToolUse");
+        };
+        assert_eq!(p.tool_name, "Edit");
+        assert_eq!(p.tool_input["file_path"], "/work/Cargo.toml");
+    }
+
+    #[test]
+    fn stop_block_becomes_a_followup() {
+        let block = symposium::OutputEvent::Stop(symposium::StopOutput::block("tests fail"));
+        let output = CursorStopOutput::from_symposium(&block);
+        assert_eq!(
+            output.to_hook_output(),
+            serde_json::json!({"followup_message": "tests fail"})
+        );
+        let symposium::OutputEvent::Stop(back) = output.to_symposium() else {
+            panic!("expected Stop");
+        };
+        assert!(back.is_block());
+
+        let input = CursorStopInput::parse_input(
+            r#"{"hook_event_name":"stop","workspace_roots":["/work"],"status":"completed","loop_count":1}"#,
+        )
+        .unwrap();
+        let symposium::InputEvent::Stop(stop) = input.to_symposium() else {
+            panic!("expected Stop");
+        };
+        assert!(stop.stop_hook_active);
+    }
+}
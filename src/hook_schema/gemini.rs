@@ -14,6 +14,7 @@ impl Agent for Gemini {
                 Some(erase_agent_hook_event(GeminiUserPromptSubmitEvent))
             }
             super::HookEvent::SessionStart => Some(erase_agent_hook_event(GeminiSessionStartEvent)),
+            super::HookEvent::Stop => Some(erase_agent_hook_event(GeminiStopEvent)),
             _ => None,
         }
     }
@@ -49,6 +50,7 @@ gemini_event!(
     GeminiSessionStartInput,
     GeminiSessionStartOutput
 );
+gemini_event!(GeminiStopEvent, GeminiStopInput, GeminiStopOutput);
 
 fn gemini_hook_output_from_symposium(
     event_name: &str,
@@ -477,3 +479,93 @@ impl AgentHookOutput for GeminiSessionStartOutput {
         self
     }
 }
+
+// ── Stop (AfterAgent) ─────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeminiStopInput {
+    pub hook_event_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    #[serde(default)]
+    pub stop_hook_active: bool,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeminiStopOutput {
+    /// `"deny"` rejects the agent's final response and retries the turn,
+    /// with `reason` as the new prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for GeminiStopInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::Stop(
+            symposium::StopInput::new(self.session_id.clone(), self.cwd.clone())
+                .with_stop_hook_active(self.stop_hook_active),
+        )
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::Stop(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            hook_event_name: "AfterAgent".into(),
+            session_id: p.session_id.clone(),
+            cwd: p.cwd.clone(),
+            stop_hook_active: p.stop_hook_active,
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for GeminiStopOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        match event {
+            symposium::OutputEvent::Stop(o) if o.is_block() => Self {
+                decision: Some("deny".into()),
+                reason: o.additional_context.clone(),
+                rest: serde_json::Map::new(),
+            },
+            _ => Self::default(),
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        match self.decision.as_deref() {
+            Some("deny" | "block") => symposium::OutputEvent::Stop(symposium::StopOutput::block(
+                self.reason.clone().unwrap_or_default(),
+            )),
+            _ => symposium::OutputEvent::Stop(symposium::StopOutput::default()),
+        }
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
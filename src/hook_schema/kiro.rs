@@ -14,6 +14,7 @@ impl Agent for Kiro {
                 Some(erase_agent_hook_event(KiroUserPromptSubmitEvent))
             }
             super::HookEvent::SessionStart => Some(erase_agent_hook_event(KiroSessionStartEvent)),
+            super::HookEvent::Stop => Some(erase_agent_hook_event(KiroStopEvent)),
             _ => None,
         }
     }
@@ -57,6 +58,7 @@ kiro_event!(
     KiroSessionStartInput,
     KiroSessionStartOutput
 );
+kiro_event!(KiroStopEvent, KiroStopInput, KiroStopOutput);
 
 // Kiro output: plain text stdout → additionalContext
 macro_rules! kiro_output_impl {
@@ -365,3 +367,59 @@ impl AgentHookInput for KiroSessionStartInput {
 }
 
 kiro_output_impl!(KiroSessionStartOutput, SessionStart, SessionStartOutput {});
+
+// ── Stop ──────────────────────────────────────────────────────────────
+//
+// Kiro has no way for a stop hook to keep the agent working. A block's
+// reason is still printed, so the user sees why the turn should not have
+// ended.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KiroStopInput {
+    pub hook_event_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KiroStopOutput {
+    #[serde(rename = "additionalContext", skip_serializing_if = "Option::is_none")]
+    pub additional_context: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for KiroStopInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::Stop(symposium::StopInput::new(
+            self.session_id.clone(),
+            self.cwd.clone(),
+        ))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::Stop(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            hook_event_name: "stop".into(),
+            cwd: p.cwd.clone(),
+            session_id: p.session_id.clone(),
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+kiro_output_impl!(KiroStopOutput, Stop, StopOutput {});
@@ -1,8 +1,464 @@
-use crate::hook_schema::{Agent, ErasedAgentHookEvent, HookEvent};
+//! OpenCode hook payloads.
+//!
+//! OpenCode has no shell-command hooks; its extensibility is JS/TS plugins.
+//! Sync installs a small managed plugin (see `agents::OPENCODE_PLUGIN`) that
+//! forwards plugin hook calls to `cargo-agents hook opencode <event>`, so the
+//! payloads here are the bridge's wire format. They reuse OpenCode's own field
+//! names (`tool`, `sessionID`, `callID`, `args`) plus the plugin's `directory`.
+//!
+//! Outputs are flat objects the bridge maps back onto OpenCode's semantics:
+//! `decision: "deny"` throws (blocking the tool with `reason`), `args` replaces
+//! the tool arguments, and `additionalContext` is appended to the tool result
+//! (or, on session start, sent to the session as a no-reply message).
+
+use serde::{Deserialize, Serialize};
+
+use crate::hook_schema::{
+    Agent, AgentHookEvent, AgentHookInput, AgentHookOutput, erase_agent_hook_event, symposium,
+};
 
 pub struct OpenCode;
 impl Agent for OpenCode {
-    fn event(&self, _event: HookEvent) -> Option<Box<dyn ErasedAgentHookEvent>> {
-        None // OpenCode uses JS/TS plugins, not shell hooks
+    fn event(&self, event: super::HookEvent) -> Option<Box<dyn super::ErasedAgentHookEvent>> {
+        match event {
+            super::HookEvent::PreToolUse => Some(erase_agent_hook_event(OpenCodePreToolUseEvent)),
+            super::HookEvent::PostToolUse => Some(erase_agent_hook_event(OpenCodePostToolUseEvent)),
+            super::HookEvent::SessionStart => {
+                Some(erase_agent_hook_event(OpenCodeSessionStartEvent))
+            }
+            super::HookEvent::Stop => Some(erase_agent_hook_event(OpenCodeStopEvent)),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! opencode_event {
+    ($event:ident, $input:ident, $output:ident) => {
+        pub struct $event;
+        impl AgentHookEvent for $event {
+            type Input = $input;
+            type Output = $output;
+        }
+    };
+}
+
+opencode_event!(
+    OpenCodePreToolUseEvent,
+    OpenCodePreToolUseInput,
+    OpenCodePreToolUseOutput
+);
+opencode_event!(
+    OpenCodePostToolUseEvent,
+    OpenCodePostToolUseInput,
+    OpenCodePostToolUseOutput
+);
+opencode_event!(
+    OpenCodeSessionStartEvent,
+    OpenCodeSessionStartInput,
+    OpenCodeSessionStartOutput
+);
+opencode_event!(OpenCodeStopEvent, OpenCodeStopInput, OpenCodeStopOutput);
+
+// ── PreToolUse (tool.execute.before) ──────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenCodePreToolUseInput {
+    pub hook: String,
+    pub tool: String,
+    #[serde(default)]
+    pub args: serde_json::Value,
+    #[serde(rename = "sessionID", default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(rename = "callID", default, skip_serializing_if = "Option::is_none")]
+    pub call_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenCodePreToolUseOutput {
+    /// `"deny"` makes the bridge throw, blocking the tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<String>,
+    /// The error message thrown on deny.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Replacement tool arguments, written over `output.args`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<serde_json::Value>,
+    #[serde(rename = "additionalContext", skip_serializing_if = "Option::is_none")]
+    pub additional_context: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for OpenCodePreToolUseInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::PreToolUse(symposium::PreToolUseInput::new(
+            self.tool.clone(),
+            self.args.clone(),
+            self.session_id.clone(),
+            self.directory.clone(),
+        ))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::PreToolUse(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            hook: "tool.execute.before".into(),
+            tool: p.tool_name.clone(),
+            args: p.tool_input.clone(),
+            session_id: p.session_id.clone(),
+            call_id: None,
+            directory: p.cwd.clone(),
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for OpenCodePreToolUseOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        let symposium::OutputEvent::PreToolUse(o) = event else {
+            return Self::default();
+        };
+        // A deny's context is the reason the tool was blocked.
+        if o.decision == symposium_sdk::hook::Decision::Deny {
+            return Self {
+                decision: Some("deny".into()),
+                reason: o.additional_context.clone(),
+                ..Self::default()
+            };
+        }
+        Self {
+            args: o.updated_input.clone(),
+            additional_context: o.additional_context.clone(),
+            ..Self::default()
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        if self.decision.as_deref() == Some("deny") {
+            return symposium::OutputEvent::PreToolUse(symposium::PreToolUseOutput::new(
+                symposium_sdk::hook::Decision::Deny,
+                self.reason.clone(),
+                None,
+            ));
+        }
+        symposium::OutputEvent::PreToolUse(symposium::PreToolUseOutput::new(
+            symposium_sdk::hook::Decision::Allow,
+            self.additional_context.clone(),
+            self.args.clone(),
+        ))
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+// ── PostToolUse (tool.execute.after) ──────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenCodePostToolUseInput {
+    pub hook: String,
+    pub tool: String,
+    #[serde(default)]
+    pub args: serde_json::Value,
+    /// The tool's `{ title, output, metadata }` result.
+    #[serde(default)]
+    pub result: serde_json::Value,
+    #[serde(rename = "sessionID", default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(rename = "callID", default, skip_serializing_if = "Option::is_none")]
+    pub call_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenCodePostToolUseOutput {
+    #[serde(rename = "additionalContext", skip_serializing_if = "Option::is_none")]
+    pub additional_context: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for OpenCodePostToolUseInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::PostToolUse(symposium::PostToolUseInput::new(
+            self.tool.clone(),
+            self.args.clone(),
+            self.result.clone(),
+            self.session_id.clone(),
+            self.directory.clone(),
+        ))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::PostToolUse(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            hook: "tool.execute.after".into(),
+            tool: p.tool_name.clone(),
+            args: p.tool_input.clone(),
+            result: p.tool_response.clone(),
+            session_id: p.session_id.clone(),
+            call_id: None,
+            directory: p.cwd.clone(),
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for OpenCodePostToolUseOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        Self {
+            additional_context: event.additional_context().map(String::from),
+            ..Self::default()
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        symposium::OutputEvent::PostToolUse(symposium::PostToolUseOutput::new(
+            self.additional_context.clone(),
+        ))
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+// ── SessionStart (session.created) ────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenCodeSessionStartInput {
+    pub hook: String,
+    #[serde(rename = "sessionID", default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenCodeSessionStartOutput {
+    #[serde(rename = "additionalContext", skip_serializing_if = "Option::is_none")]
+    pub additional_context: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for OpenCodeSessionStartInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::SessionStart(symposium::SessionStartInput::new(
+            self.session_id.clone(),
+            self.directory.clone(),
+        ))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::SessionStart(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            hook: "session.created".into(),
+            session_id: p.session_id.clone(),
+            directory: p.cwd.clone(),
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for OpenCodeSessionStartOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(event: &symposium::OutputEvent) -> Self {
+        Self {
+            additional_context: event.additional_context().map(String::from),
+            ..Self::default()
+        }
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        symposium::OutputEvent::SessionStart(symposium::SessionStartOutput::new(
+            self.additional_context.clone(),
+        ))
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+// ── Stop (stop) ───────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenCodeStopInput {
+    pub hook: String,
+    #[serde(rename = "sessionID", default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenCodeStopOutput {
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AgentHookInput for OpenCodeStopInput {
+    fn parse_input(payload: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(payload)?)
+    }
+    fn to_symposium(&self) -> symposium::InputEvent {
+        symposium::InputEvent::Stop(symposium::StopInput::new(
+            self.session_id.clone(),
+            self.directory.clone(),
+        ))
+    }
+    fn from_symposium(event: &symposium::InputEvent) -> Self {
+        let symposium::InputEvent::Stop(p) = event else {
+            panic!("wrong event type")
+        };
+        Self {
+            hook: "stop".into(),
+            session_id: p.session_id.clone(),
+            directory: p.cwd.clone(),
+            rest: serde_json::Map::new(),
+        }
+    }
+    fn to_string(&self) -> anyhow::Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+impl AgentHookOutput for OpenCodeStopOutput {
+    fn parse_output(output: &[u8]) -> anyhow::Result<Self> {
+        if output.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(output)?)
+    }
+    fn from_symposium(_event: &symposium::OutputEvent) -> Self {
+        // Stop hooks don't inject additionalContext into the agent.
+        Self::default()
+    }
+    fn to_symposium(&self) -> symposium::OutputEvent {
+        symposium::OutputEvent::Stop(symposium::StopOutput::default())
+    }
+    fn to_hook_output(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pre_tool_use_input_maps_bridge_fields() {
+        let input = OpenCodePreToolUseInput::parse_input(
+            r#"{"hook":"tool.execute.before","tool":"bash","sessionID":"s1","callID":"c1","args":{"command":"ls"},"directory":"/work"}"#,
+        )
+        .unwrap();
+        let symposium::InputEvent::PreToolUse(p) = input.to_symposium() else {
+            panic!("expected PreToolUse");
+        };
+        assert_eq!(p.tool_name, "bash");
+        assert_eq!(p.tool_input, serde_json::json!({"command": "ls"}));
+        assert_eq!(p.session_id.as_deref(), Some("s1"));
+        assert_eq!(p.cwd.as_deref(), Some("/work"));
+    }
+
+    #[test]
+    fn pre_tool_use_deny_becomes_decision_and_reason() {
+        let out = OpenCodePreToolUseOutput::from_symposium(&symposium::OutputEvent::PreToolUse(
+            symposium::PreToolUseOutput::deny("no force pushes"),
+        ));
+        assert_eq!(
+            out.to_hook_output(),
+            serde_json::json!({"decision": "deny", "reason": "no force pushes"})
+        );
+    }
+
+    #[test]
+    fn pre_tool_use_updated_input_becomes_args() {
+        let out = OpenCodePreToolUseOutput::from_symposium(&symposium::OutputEvent::PreToolUse(
+            symposium::PreToolUseOutput::with_updated_input(
+                serde_json::json!({"command": "safe-cmd"}),
+            ),
+        ));
+        assert_eq!(
+            out.to_hook_output(),
+            serde_json::json!({"args": {"command": "safe-cmd"}})
+        );
+        let symposium::OutputEvent::PreToolUse(back) = out.to_symposium() else {
+            panic!("expected PreToolUse");
+        };
+        assert_eq!(
+            back.updated_input,
+            Some(serde_json::json!({"command": "safe-cmd"}))
+        );
     }
 }
@@ -4,7 +4,7 @@ use anyhow::{Context, Result};
 use dialoguer::MultiSelect;
 
 use crate::agents::Agent;
-use crate::config::{AgentEntry, Symposium};
+use crate::config::{AgentEntry, AgentProfile, Symposium};
 use crate::output::{Output, display_path};
 
 /// Options that can be provided on the command line to skip interactive prompts.
@@ -27,35 +27,38 @@ fn interactive(out: &Output) -> bool {
 /// 1. Explicit `--add-agent` / `--remove-agent` flags (applied to existing set)
 /// 2. Interactive multi-select (if `should_prompt`), pre-selecting existing agents
 /// 3. Default to first agent (Claude) in non-interactive mode
+///
+/// Names may refer to built-in agents or to `[[agent-profile]]` entries.
 fn resolve_agents(
     opts: &InitOpts,
     existing: &[AgentEntry],
+    profiles: &[AgentProfile],
     should_prompt: bool,
 ) -> Result<Vec<Agent>> {
     if !opts.agents.is_empty() || !opts.remove_agents.is_empty() {
         let mut names: Vec<String> = existing.iter().map(|e| e.name.clone()).collect();
         for name in &opts.agents {
-            Agent::from_config_name(name)?;
+            Agent::resolve(name, profiles)?;
             if !names.contains(name) {
                 names.push(name.clone());
             }
         }
         for name in &opts.remove_agents {
-            Agent::from_config_name(name)?;
+            Agent::resolve(name, profiles)?;
             names.retain(|n| n != name);
         }
-        return names.iter().map(|n| Agent::from_config_name(n)).collect();
+        return names.iter().map(|n| Agent::resolve(n, profiles)).collect();
     }
     if should_prompt {
-        return prompt_for_agents(existing);
+        return prompt_for_agents(existing, profiles);
     }
     if !existing.is_empty() {
         return existing
             .iter()
-            .map(|e| Agent::from_config_name(&e.name))
+            .map(|e| Agent::resolve(&e.name, profiles))
             .collect();
     }
-    Ok(vec![Agent::all()[0]])
+    Ok(vec![Agent::Claude])
 }
 
 /// Run user-wide initialization.
@@ -71,7 +74,12 @@ pub async fn init(sym: &mut Symposium, out: &Output, opts: &InitOpts) -> Result<
     let should_prompt = !cli_driven && interactive(out);
 
     // Resolve each setting: CLI flag > interactive prompt > keep existing.
-    let agents = resolve_agents(opts, &sym.config.agents, should_prompt)?;
+    let agents = resolve_agents(
+        opts,
+        &sym.config.agents,
+        &sym.config.agent_profiles,
+        should_prompt,
+    )?;
 
     sym.config.agents = agents
         .iter()
@@ -101,7 +109,8 @@ pub async fn init(sym: &mut Symposium, out: &Output, opts: &InitOpts) -> Result<
     );
 
     // Persist and apply.
-    sym.save_config().context("failed to write user config")?;
+    sym.save_config_keys(&["agent", "hook-scope", "auto-update", "telemetry.enabled"])
+        .context("failed to write user config")?;
 
     let config_path = sym.config_dir().join("config.toml");
 
@@ -196,8 +205,8 @@ fn prompt_for_telemetry(current: bool) -> Result<bool> {
         .interact()?)
 }
 
-fn prompt_for_agents(existing: &[AgentEntry]) -> Result<Vec<Agent>> {
-    let agents = Agent::all();
+fn prompt_for_agents(existing: &[AgentEntry], profiles: &[AgentProfile]) -> Result<Vec<Agent>> {
+    let agents = Agent::known(profiles);
     let items: Vec<&str> = agents.iter().map(|a| a.display_name()).collect();
 
     let defaults: Vec<bool> = agents
@@ -211,5 +220,5 @@ fn prompt_for_agents(existing: &[AgentEntry]) -> Result<Vec<Agent>> {
         .defaults(&defaults)
         .interact()?;
 
-    Ok(selections.into_iter().map(|i| agents[i]).collect())
+    Ok(selections.into_iter().map(|i| agents[i].clone()).collect())
 }
@@ -10,7 +10,7 @@ use anyhow::{Result, bail};
 
 use crate::config::Symposium;
 use crate::plugins::Installation;
-use symposium_install::{Runnable, acquire_source, make_executable};
+use symposium_install::{InstallContext, Runnable, acquire_source, make_executable};
 
 /// Run a list of post-install shell commands sequentially. Stops at the first
 /// failure.
@@ -80,6 +80,9 @@ pub enum AcquiredRunnable {
 /// `plugin_dir` is the directory containing the plugin's manifest;
 /// relative `executable` / `script` paths on no-source installations
 /// resolve against it.
+///
+/// A source the [system policy](crate::policy) blocks is an error, before
+/// anything is fetched.
 pub async fn acquire_installation(
     sym: &Symposium,
     installation: &Installation,
@@ -87,19 +90,117 @@ pub async fn acquire_installation(
     override_script: Option<&str>,
     update: symposium_install::UpdateLevel,
 ) -> anyhow::Result<AcquiredInstallation> {
+    sym.policy().check_installation(installation)?;
+    acquire_installation_in(
+        &sym.install_context(),
+        installation,
+        override_executable,
+        override_script,
+        update,
+    )
+    .await
+}
+
+/// [`acquire_installation`] against an explicit install context, for holders
+/// that keep an [`InstallContext`] rather than a whole `Symposium` (an
+/// [`ExternalPm`](crate::pm::ExternalPm)).
+pub async fn acquire_installation_in(
+    ctx: &InstallContext,
+    installation: &Installation,
+    override_executable: Option<&str>,
+    override_script: Option<&str>,
+    update: symposium_install::UpdateLevel,
+) -> anyhow::Result<AcquiredInstallation> {
+    let exec_choice = installation.executable.as_deref().or(override_executable);
+    let script_choice = installation.script.as_deref().or(override_script);
+
+    let acquired = match &installation.source {
+        Some(source) => Some(acquire_source(ctx, source, exec_choice, update).await?),
+        None => None,
+    };
+
+    run_install_commands(&installation.install_commands).await?;
+
+    let runnable = resolve_acquired_runnable(acquired.as_ref(), exec_choice, script_choice);
+    if let Some(AcquiredRunnable::ResolvedExec { path }) = &runnable {
+        make_executable(path).ok();
+    }
+
+    Ok(AcquiredInstallation {
+        name: installation.name.clone(),
+        base: acquired.as_ref().and_then(|a| a.base.clone()),
+        runnable,
+    })
+}
+
+/// An installation as it already is on disk, resolved without fetching a
+/// source or running `install_commands` — what a dry run may use. `None`
+/// when [`acquire_installation`] would first have to install something: a
+/// source not in the cache, a global cargo binary not on `$PATH`, or
+/// `install_commands` whose runnable doesn't exist yet.
+pub async fn installed_installation(
+    sym: &Symposium,
+    installation: &Installation,
+    override_executable: Option<&str>,
+    override_script: Option<&str>,
+) -> Option<AcquiredInstallation> {
+    sym.policy().check_installation(installation).ok()?;
     let exec_choice = installation.executable.as_deref().or(override_executable);
     let script_choice = installation.script.as_deref().or(override_script);
+    let ctx = sym.install_context();
 
     let acquired = match &installation.source {
+        Some(symposium_install::Source::Cargo(c)) if c.global => {
+            let name = exec_choice?;
+            crate::doctor_command::find_executable(name)?;
+            return Some(AcquiredInstallation {
+                name: installation.name.clone(),
+                base: None,
+                runnable: Some(AcquiredRunnable::GlobalExec {
+                    path: PathBuf::from(name),
+                }),
+            });
+        }
         Some(source) => {
-            Some(acquire_source(&sym.install_context(), source, exec_choice, update).await?)
+            if !symposium_install::source_is_cached(&ctx, source, exec_choice) {
+                return None;
+            }
+            let update = symposium_install::UpdateLevel::None;
+            Some(
+                acquire_source(&ctx, source, exec_choice, update)
+                    .await
+                    .ok()?,
+            )
         }
         None => None,
     };
 
-    run_install_commands(&installation.install_commands).await?;
+    let runnable = resolve_acquired_runnable(acquired.as_ref(), exec_choice, script_choice)?;
+    if acquired.is_none() && !installation.install_commands.is_empty() {
+        let (AcquiredRunnable::ResolvedExec { path } | AcquiredRunnable::ResolvedScript { path }) =
+            &runnable
+        else {
+            return None;
+        };
+        if !path.is_absolute() || !path.exists() {
+            return None;
+        }
+    }
+    Some(AcquiredInstallation {
+        name: installation.name.clone(),
+        base: acquired.as_ref().and_then(|a| a.base.clone()),
+        runnable: Some(runnable),
+    })
+}
 
-    let runnable = match (&acquired, exec_choice, script_choice) {
+/// What an installation runs, given its acquired source (if any) and the
+/// `executable` / `script` it (or the hook) names.
+fn resolve_acquired_runnable(
+    acquired: Option<&symposium_install::AcquiredSource>,
+    exec_choice: Option<&str>,
+    script_choice: Option<&str>,
+) -> Option<AcquiredRunnable> {
+    match (acquired, exec_choice, script_choice) {
         // Unmanaged source (global cargo): bare name, $PATH lookup at spawn.
         // Validation guarantees `exec_choice` is set for cargo + global.
         (Some(a), _, _) if a.base.is_none() => {
@@ -130,17 +231,7 @@ pub async fn acquire_installation(
         }),
         (None, None, None) => None,
         (_, Some(_), Some(_)) => unreachable!("validation forbids both executable and script"),
-    };
-
-    if let Some(AcquiredRunnable::ResolvedExec { path }) = &runnable {
-        make_executable(path).ok();
     }
-
-    Ok(AcquiredInstallation {
-        name: installation.name.clone(),
-        base: acquired.as_ref().and_then(|a| a.base.clone()),
-        runnable,
-    })
 }
 
 /// Refresh an installation's already-acquired source in place (a freshness
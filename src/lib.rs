@@ -1,16 +1,22 @@
 pub mod agents;
 pub mod cli;
 pub mod config;
+pub mod config_command;
 pub mod crate_command;
 pub mod dirs;
 pub mod discovery;
+pub mod doctor_command;
 pub mod help_render;
 pub mod hook;
 pub mod hook_schema;
 pub(crate) mod installation;
+pub mod lockfile;
+pub mod mcp_serve;
 pub mod output;
 pub mod plugins;
 pub mod pm;
+pub mod policy;
+pub mod registry_command;
 pub mod report;
 pub mod search_command;
 pub mod self_update;
@@ -18,7 +24,9 @@ pub mod state;
 pub mod status_command;
 pub mod subcommand_dispatch;
 pub mod telemetry;
+pub mod uninstall_command;
 pub mod use_command;
+pub mod workspace_config;
 pub mod workspace_state;
 
 pub(crate) mod crate_metadata;
@@ -28,6 +36,7 @@ pub mod sync;
 pub(crate) mod crate_sources;
 pub(crate) mod predicate;
 pub(crate) mod predicate_cache;
+pub(crate) mod prompt_context;
 pub(crate) mod skills;
 
 pub use symposium_install::UpdateLevel;
@@ -0,0 +1,356 @@
+//! The workspace lockfile, `symposium.lock`.
+//!
+//! Records what a sync resolved, so teammates (and CI) can tell whether they
+//! install the same agent guidance: for each active plugin, where it came
+//! from — the registry commit for a git registry, the version for a crate
+//! plugin — and a content hash of every skill it contributed.
+//!
+//! Skills are hashed by content rather than by their `skill_origin_hash`:
+//! the origin hash is derived from the `SKILL.md`'s path on *this* machine,
+//! so it differs between checkouts that install identical bytes.
+//!
+//! `cargo agents sync --lock` creates the file; once it exists, every sync
+//! keeps it up to date. `cargo agents sync --locked` resolves as usual but
+//! refuses to install anything when the result differs from the file.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::ParsedPlugin;
+use crate::pm::{ANY_VERSION, PmRegistry, RegistrySource};
+use crate::skills::SkillWithGroupContext;
+
+/// File name of the lockfile, at the workspace root.
+pub const LOCKFILE_NAME: &str = "symposium.lock";
+
+/// Current lockfile format version.
+const LOCKFILE_VERSION: u32 = 1;
+
+const HEADER: &str = "# This file is generated by `cargo agents sync`.\n\
+                      # It is not intended for manual editing.\n";
+
+/// How a sync treats `symposium.lock`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LockMode {
+    /// Rewrite the lockfile if the workspace already has one.
+    #[default]
+    Update,
+    /// Write the lockfile, creating it if needed (`sync --lock`).
+    Write,
+    /// Fail, installing nothing, if the resolution differs from the
+    /// lockfile or there is none (`sync --locked`).
+    Locked,
+}
+
+/// The parsed contents of `symposium.lock`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Lockfile {
+    pub version: u32,
+    #[serde(default, rename = "plugin", skip_serializing_if = "Vec::is_empty")]
+    pub plugins: Vec<LockedPlugin>,
+}
+
+/// One active plugin, keyed by `(source, name)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LockedPlugin {
+    pub name: String,
+    /// The package manager the plugin came through: a registry name,
+    /// `cargo` for a crate plugin, or `local` for a workspace plugin.
+    pub source: String,
+    /// The resolved package version, for package managers that have one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The commit the plugin's git registry was fetched at.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(default, rename = "skill", skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<LockedSkill>,
+}
+
+/// A skill a plugin contributed, with a SHA-256 over its directory contents.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LockedSkill {
+    pub name: String,
+    pub hash: String,
+}
+
+impl Lockfile {
+    /// Build the lockfile for one resolution: the `active` plugin set and the
+    /// `applicable` skills collected from it. Git registry commits are read
+    /// from the cache meta of the registries in `pms`.
+    pub(crate) fn resolve(
+        active: &[ParsedPlugin],
+        applicable: &[SkillWithGroupContext],
+        pms: &PmRegistry,
+    ) -> Result<Self> {
+        let commits = registry_commits(pms);
+
+        let mut skills: BTreeMap<&crate::pm::PackageId, BTreeSet<LockedSkill>> = BTreeMap::new();
+        for entry in applicable {
+            let dir = entry
+                .skill
+                .path
+                .parent()
+                .context("skill path has no parent directory")?;
+            skills
+                .entry(&entry.plugin)
+                .or_default()
+                .insert(LockedSkill {
+                    name: entry.skill.name().to_string(),
+                    hash: content_hash(dir)?,
+                });
+        }
+
+        let mut plugins: Vec<LockedPlugin> = active
+            .iter()
+            .map(|parsed| {
+                let id = &parsed.canonical;
+                LockedPlugin {
+                    name: parsed.plugin.name.clone(),
+                    source: id.pm.clone(),
+                    version: (id.version != ANY_VERSION).then(|| id.version.clone()),
+                    commit: commits.get(&id.pm).cloned(),
+                    skills: skills
+                        .get(id)
+                        .map(|s| s.iter().cloned().collect())
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+        plugins.sort();
+        plugins.dedup();
+
+        Ok(Self {
+            version: LOCKFILE_VERSION,
+            plugins,
+        })
+    }
+
+    /// The lockfile path for a workspace.
+    pub fn path(workspace_root: &Path) -> PathBuf {
+        workspace_root.join(LOCKFILE_NAME)
+    }
+
+    /// Read the lockfile at `path`. `Ok(None)` when there is none.
+    pub fn read(path: &Path) -> Result<Option<Self>> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("read {}", path.display())),
+        };
+        let lockfile: Self =
+            toml::from_str(&contents).with_context(|| format!("parse {}", path.display()))?;
+        if lockfile.version != LOCKFILE_VERSION {
+            bail!(
+                "{} has format version {}, but this symposium writes version {LOCKFILE_VERSION}",
+                path.display(),
+                lockfile.version
+            );
+        }
+        Ok(Some(lockfile))
+    }
+
+    /// Write the lockfile to `path`, leaving it untouched when the contents
+    /// would not change. Returns whether it was written.
+    pub fn write(&self, path: &Path) -> Result<bool> {
+        let contents = format!("{HEADER}\n{}", toml::to_string(self)?);
+        if std::fs::read_to_string(path).is_ok_and(|old| old == contents) {
+            return Ok(false);
+        }
+        std::fs::write(path, contents).with_context(|| format!("write {}", path.display()))?;
+        Ok(true)
+    }
+
+    /// Human-readable differences between `self` (the lockfile) and
+    /// `resolved` (what this sync would install), one line per plugin.
+    /// Empty when they agree.
+    pub fn diff(&self, resolved: &Lockfile) -> Vec<String> {
+        let key = |p: &LockedPlugin| (p.source.clone(), p.name.clone());
+        let old: BTreeMap<_, _> = self.plugins.iter().map(|p| (key(p), p)).collect();
+        let new: BTreeMap<_, _> = resolved.plugins.iter().map(|p| (key(p), p)).collect();
+
+        let mut lines = Vec::new();
+        for ((source, name), plugin) in &new {
+            match old.get(&(source.clone(), name.clone())) {
+                None => lines.push(format!("plugin `{name}` ({source}) is not locked")),
+                Some(locked) if locked != plugin => {
+                    lines.push(format!(
+                        "plugin `{name}` ({source}) changed: {}",
+                        plugin_changes(locked, plugin).join(", ")
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        for (source, name) in old.keys() {
+            if !new.contains_key(&(source.clone(), name.clone())) {
+                lines.push(format!("plugin `{name}` ({source}) is no longer active"));
+            }
+        }
+        lines
+    }
+}
+
+/// What differs between two entries for the same plugin.
+fn plugin_changes(old: &LockedPlugin, new: &LockedPlugin) -> Vec<String> {
+    let show = |v: &Option<String>| v.clone().unwrap_or_else(|| "none".into());
+    let mut changes = Vec::new();
+    if old.version != new.version {
+        changes.push(format!(
+            "version {} -> {}",
+            show(&old.version),
+            show(&new.version)
+        ));
+    }
+    if old.commit != new.commit {
+        changes.push(format!(
+            "commit {} -> {}",
+            show(&old.commit),
+            show(&new.commit)
+        ));
+    }
+    let old_skills: BTreeSet<_> = old.skills.iter().collect();
+    let new_skills: BTreeSet<_> = new.skills.iter().collect();
+    let old_names: BTreeSet<_> = old.skills.iter().map(|s| &s.name).collect();
+    let new_names: BTreeSet<_> = new.skills.iter().map(|s| &s.name).collect();
+    for name in new_names.difference(&old_names) {
+        changes.push(format!("skill `{name}` added"));
+    }
+    for name in old_names.difference(&new_names) {
+        changes.push(format!("skill `{name}` removed"));
+    }
+    let changed: BTreeSet<_> = new_skills
+        .difference(&old_skills)
+        .map(|s| &s.name)
+        .filter(|name| old_names.contains(name))
+        .collect();
+    for name in changed {
+        changes.push(format!("skill `{name}` content changed"));
+    }
+    changes
+}
+
+/// The commit each git registry in `pms` is currently cached at, by name.
+fn registry_commits(pms: &PmRegistry) -> BTreeMap<String, String> {
+    pms.instances()
+        .filter_map(|inst| {
+            let RegistrySource::Git { .. } = inst.pm.registry_source()? else {
+                return None;
+            };
+            let dir = inst.pm.content_dir()?;
+            let parent = dir.parent()?;
+            let meta = symposium_install::git::GitCacheManager::from_cache_dir(parent)
+                .read_meta_for(dir)?;
+            Some((inst.name.clone(), meta.commit_sha))
+        })
+        .collect()
+}
+
+/// SHA-256 over a skill directory's files (relative path and contents, in
+/// path order), ignoring the metadata sync writes into installed copies.
+fn content_hash(dir: &Path) -> Result<String> {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    for (path, bytes) in crate::sync::collect_dir_contents(dir)? {
+        let path = path.to_string_lossy().replace('\\', "/");
+        hasher.update((path.len() as u64).to_le_bytes());
+        hasher.update(path.as_bytes());
+        hasher.update((bytes.len() as u64).to_le_bytes());
+        hasher.update(&bytes);
+    }
+    let digest = hasher.finalize();
+    let mut out = String::from("sha256:");
+    for byte in digest {
+        use std::fmt::Write;
+        write!(out, "{byte:02x}").unwrap();
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin(name: &str, version: Option<&str>, skills: &[(&str, &str)]) -> LockedPlugin {
+        LockedPlugin {
+            name: name.into(),
+            source: "cargo".into(),
+            version: version.map(Into::into),
+            commit: None,
+            skills: skills
+                .iter()
+                .map(|(name, hash)| LockedSkill {
+                    name: (*name).into(),
+                    hash: (*hash).into(),
+                })
+                .collect(),
+        }
+    }
+
+    fn lockfile(plugins: Vec<LockedPlugin>) -> Lockfile {
+        Lockfile {
+            version: LOCKFILE_VERSION,
+            plugins,
+        }
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join(LOCKFILE_NAME);
+        let lock = lockfile(vec![plugin(
+            "serde",
+            Some("1.0.3"),
+            &[("basics", "sha256:aa")],
+        )]);
+        assert!(lock.write(&path).unwrap());
+        assert!(
+            !lock.write(&path).unwrap(),
+            "unchanged contents are not rewritten"
+        );
+        assert_eq!(Lockfile::read(&path).unwrap(), Some(lock));
+        assert_eq!(Lockfile::read(&tmp.path().join("missing")).unwrap(), None);
+    }
+
+    #[test]
+    fn diff_names_each_change() {
+        let locked = lockfile(vec![
+            plugin("serde", Some("1.0.3"), &[("basics", "sha256:aa")]),
+            plugin("gone", None, &[]),
+        ]);
+        let resolved = lockfile(vec![
+            plugin(
+                "serde",
+                Some("1.0.4"),
+                &[("basics", "sha256:bb"), ("derive", "sha256:cc")],
+            ),
+            plugin("tokio", None, &[]),
+        ]);
+        expect_test::expect![[r#"
+            [
+                "plugin `serde` (cargo) changed: version 1.0.3 -> 1.0.4, skill `derive` added, skill `basics` content changed",
+                "plugin `tokio` (cargo) is not locked",
+                "plugin `gone` (cargo) is no longer active",
+            ]
+        "#]]
+        .assert_debug_eq(&locked.diff(&resolved));
+        assert!(resolved.diff(&resolved).is_empty());
+    }
+
+    #[test]
+    fn content_hash_ignores_sync_metadata() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("SKILL.md"), "body").unwrap();
+        let before = content_hash(tmp.path()).unwrap();
+        std::fs::write(tmp.path().join(crate::sync::MARKER_FILE), "").unwrap();
+        assert_eq!(content_hash(tmp.path()).unwrap(), before);
+        std::fs::write(tmp.path().join("SKILL.md"), "changed").unwrap();
+        assert_ne!(content_hash(tmp.path()).unwrap(), before);
+    }
+}
@@ -0,0 +1,182 @@
+//! The meta-server's process table: one entry per backing plugin MCP server.
+//!
+//! Backing servers start lazily — on the first `list_tools` that asks for
+//! their declarations, or the first `execute` call that reaches one of their
+//! tools. An entry is *cold* until then; concurrent callers queue on the
+//! entry's lock while it starts; a server whose connection has closed is
+//! *dead* and is restarted on the next call. Child processes are killed when
+//! the table is dropped (the meta-server exits or re-resolves).
+
+use std::collections::BTreeMap;
+use std::process::Stdio;
+use std::sync::Arc;
+
+use anyhow::{Context, Result, bail};
+use rmcp::ServiceExt;
+use rmcp::model::{CallToolRequestParams, CallToolResult, Tool};
+use rmcp::service::{RoleClient, RunningService};
+use sacp::schema::McpServer;
+use tokio::sync::Mutex;
+
+/// A running backing server: the MCP client session, the child process it
+/// talks to, and the tool list fetched at startup.
+struct Running {
+    client: RunningService<RoleClient, ()>,
+    tools: Vec<Tool>,
+    _child: tokio::process::Child,
+}
+
+/// One backing server and its lifecycle state (`None` while cold).
+struct Backend {
+    server: McpServer,
+    running: Mutex<Option<Running>>,
+}
+
+/// The backing servers for one resolution of the workspace, keyed by the
+/// name each plugin declared.
+#[derive(Default)]
+pub struct Backends {
+    servers: BTreeMap<String, Arc<Backend>>,
+}
+
+impl Backends {
+    /// Build a table of cold entries. On a name collision the first server
+    /// wins and a warning is reported.
+    pub fn new(servers: Vec<McpServer>) -> Self {
+        let mut table = BTreeMap::new();
+        for server in servers {
+            let name = super::server_name(&server).to_string();
+            if table.contains_key(&name) {
+                tracing::info!(
+                    report = %crate::report::ReportEvent::Warning {
+                        message: format!(
+                            "MCP server `{name}` is declared by more than one plugin; using the first"
+                        ),
+                    },
+                );
+                continue;
+            }
+            table.insert(
+                name,
+                Arc::new(Backend {
+                    server,
+                    running: Mutex::new(None),
+                }),
+            );
+        }
+        Self { servers: table }
+    }
+
+    /// The server names, sorted.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.servers.keys().map(String::as_str)
+    }
+
+    /// The tools a server offers, starting it if needed.
+    pub async fn tools(&self, name: &str) -> Result<Vec<Tool>> {
+        let backend = self.get(name)?;
+        let mut running = backend.running.lock().await;
+        Ok(backend.ensure_running(&mut running).await?.tools.clone())
+    }
+
+    /// Call a tool on a server, starting it if needed.
+    pub async fn call(
+        &self,
+        name: &str,
+        tool: &str,
+        arguments: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<CallToolResult> {
+        let backend = self.get(name)?;
+        let mut running = backend.running.lock().await;
+        let session = backend.ensure_running(&mut running).await?;
+        // Scripts name tools by their declared name or by the identifier
+        // `list_tools` rendered for it (`migrate-status` → `migrate_status`).
+        let tool = session
+            .tools
+            .iter()
+            .find(|t| t.name == tool)
+            .or_else(|| {
+                session
+                    .tools
+                    .iter()
+                    .find(|t| super::typescript::js_identifier(&t.name) == tool)
+            })
+            .map(|t| t.name.to_string())
+            .unwrap_or_else(|| tool.to_string());
+        let peer = session.client.peer().clone();
+        // Release the lock so concurrent calls to the same server are not
+        // serialized behind this one.
+        drop(running);
+
+        let params = CallToolRequestParams::new(tool.clone()).with_arguments(arguments);
+        peer.call_tool(params)
+            .await
+            .with_context(|| format!("calling `{tool}` on MCP server `{name}`"))
+    }
+
+    fn get(&self, name: &str) -> Result<&Arc<Backend>> {
+        self.servers
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("no MCP server named `{name}`"))
+    }
+}
+
+impl Backend {
+    /// The running session, (re)starting the server when it is cold or dead.
+    async fn ensure_running<'a>(&self, slot: &'a mut Option<Running>) -> Result<&'a Running> {
+        if slot.as_ref().is_some_and(|r| r.client.is_closed()) {
+            tracing::debug!(
+                server = super::server_name(&self.server),
+                "backing MCP server exited, restarting"
+            );
+            *slot = None;
+        }
+        if slot.is_none() {
+            *slot = Some(self.start().await?);
+        }
+        Ok(slot.as_ref().unwrap())
+    }
+
+    async fn start(&self) -> Result<Running> {
+        let McpServer::Stdio(stdio) = &self.server else {
+            bail!(
+                "MCP server `{}` uses a transport the meta-server cannot proxy (only stdio is supported)",
+                super::server_name(&self.server)
+            );
+        };
+
+        tracing::debug!(server = %stdio.name, command = %stdio.command.display(), "starting backing MCP server");
+        let mut child = tokio::process::Command::new(&stdio.command)
+            .args(&stdio.args)
+            .envs(stdio.env.iter().map(|e| (&e.name, &e.value)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| {
+                format!(
+                    "failed to start MCP server `{}` ({})",
+                    stdio.name,
+                    stdio.command.display()
+                )
+            })?;
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let stdin = child.stdin.take().expect("stdin is piped");
+
+        let client = ()
+            .serve((stdout, stdin))
+            .await
+            .with_context(|| format!("MCP handshake with `{}` failed", stdio.name))?;
+        let tools = client
+            .list_all_tools()
+            .await
+            .with_context(|| format!("listing tools of MCP server `{}`", stdio.name))?;
+
+        Ok(Running {
+            client,
+            tools,
+            _child: child,
+        })
+    }
+}
@@ -0,0 +1,426 @@
+//! `cargo agents mcp-serve`: the MCP meta-server (see the [MCP meta-server
+//! RFD](../../md/rfds/mcp-meta-server/README.md)).
+//!
+//! `sync` registers this server as the `symposium` MCP entry in every agent's
+//! config; plugin stdio `[[mcp_servers]]` are never written there. Instead
+//! the meta-server resolves the workspace the same way `sync` does, collects
+//! the applicable plugin servers, and proxies them behind two tools:
+//!
+//! - `list_tools` — TypeScript declarations for the backing servers' tools.
+//!   Its description carries the capability index (the server names).
+//! - `execute` — run a JavaScript program with each backing server exposed
+//!   as a namespace of async functions.
+//!
+//! The meta-server speaks stdio to its backing servers; an HTTP or SSE plugin
+//! server is registered with the agent directly, as before (see
+//! [`is_proxied`]).
+//!
+//! Backing servers start lazily ([`backend`]). When auto-sync is enabled the
+//! meta-server re-resolves on `list_tools` if `Cargo.lock` changed since the
+//! last resolution.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use rmcp::model::{
+    CallToolRequestParams, CallToolResult, Content, ListToolsResult, PaginatedRequestParams,
+    ServerCapabilities, ServerInfo, Tool,
+};
+use rmcp::service::RequestContext;
+use rmcp::{ErrorData, RoleServer, ServerHandler, ServiceExt};
+use sacp::schema::{McpServer, McpServerStdio};
+use serde::Deserialize;
+use tokio::sync::RwLock;
+
+use crate::config::Symposium;
+use crate::plugins;
+use crate::pm::WorkspaceDeps;
+
+mod backend;
+mod script;
+mod typescript;
+
+use backend::Backends;
+
+/// The name of the meta-server's entry in agent MCP configs.
+pub const SERVER_NAME: &str = "symposium";
+
+/// Budget for the capability index in the `list_tools` description.
+const INDEX_MAX_CHARS: usize = 2000;
+
+/// The single MCP entry `sync` registers for every agent.
+pub fn registration_entry() -> McpServer {
+    McpServer::Stdio(
+        McpServerStdio::new(SERVER_NAME, "cargo-agents").args(vec!["mcp-serve".into()]),
+    )
+}
+
+/// Whether the meta-server proxies `server`. It can only start stdio
+/// servers; `sync` registers the others with each agent directly.
+pub(crate) fn is_proxied(server: &McpServer) -> bool {
+    matches!(server, McpServer::Stdio(_))
+}
+
+/// The names of the proxied (stdio) MCP servers these plugins declare —
+/// entries earlier versions registered with agents directly, before the
+/// meta-server.
+pub(crate) fn legacy_entry_names<'a>(
+    plugins: impl IntoIterator<Item = &'a plugins::ParsedPlugin>,
+) -> Vec<String> {
+    let mut names: Vec<String> = plugins
+        .into_iter()
+        .flat_map(|p| &p.plugin.mcp_servers)
+        .filter(|s| is_proxied(&s.server))
+        .map(|s| server_name(&s.server).to_string())
+        .filter(|name| name != SERVER_NAME)
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// The configured name of an MCP server entry.
+pub(crate) fn server_name(server: &McpServer) -> &str {
+    match server {
+        McpServer::Stdio(s) => &s.name,
+        McpServer::Http(s) => &s.name,
+        McpServer::Sse(s) => &s.name,
+        _ => panic!("unsupported McpServer variant"),
+    }
+}
+
+/// The plugin MCP servers applicable to a workspace: the `[[mcp_servers]]` of
+/// every plugin in the active set whose predicates hold, in plugin order.
+/// This is the set the meta-server proxies — the same plugin resolution
+/// `sync` uses for skills. Empty outside a Rust workspace.
+pub async fn applicable_servers(sym: &Symposium, deps: &Arc<WorkspaceDeps>) -> Vec<McpServer> {
+    let Some(loaded) = deps.load() else {
+        tracing::debug!("not in a Rust workspace; no plugin MCP servers apply");
+        return Vec::new();
+    };
+    let project_root = loaded.root.clone();
+    let registry = plugins::load_registry_with_workspace(sym, Some(loaded)).await;
+    let custom_entries = crate::sync::resolve_custom_predicate_entries(
+        sym,
+        &registry,
+        symposium_install::UpdateLevel::None,
+    )
+    .await;
+
+    let dep_ids = crate::pm::workspace_dep_ids(sym, deps).await;
+    let used_names = sym.used_names_in(&project_root);
+    let predicate_cache_path =
+        crate::predicate_cache::PredicateCache::path_for_workspace(sym.cache_dir(), &project_root);
+    let mut ctx =
+        crate::predicate::PredicateContext::with_custom_predicates(&dep_ids, custom_entries)
+            .with_used_names(&used_names)
+            .with_workspace(Some(loaded.clone()))
+            .with_disk_cache(&predicate_cache_path);
+
+    let pms = sym.package_managers(deps);
+    let active = plugins::active_plugins(sym, &registry, &pms, Some(&project_root), &mut ctx).await;
+
+    let mut servers = Vec::new();
+    for p in &active {
+        if p.applies(&mut ctx) {
+            servers.extend(p.plugin.applicable_mcp_servers(&mut ctx));
+        }
+    }
+    if let Err(e) = ctx.persist_disk_cache(&predicate_cache_path) {
+        tracing::warn!(
+            path = %predicate_cache_path.display(),
+            error = %e,
+            "failed to persist predicate cache"
+        );
+    }
+    servers
+}
+
+/// Run the meta-server on stdin/stdout until the client disconnects.
+pub async fn run(sym: Symposium, cwd: &Path) -> ExitCode {
+    match serve(sym, cwd, rmcp::transport::stdio()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            tracing::warn!(error = %e, "mcp-serve failed");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Serve the meta-server for the workspace at `cwd` over `transport`, until
+/// the client disconnects.
+pub async fn serve<T, E, A>(sym: Symposium, cwd: &Path, transport: T) -> Result<()>
+where
+    T: rmcp::transport::IntoTransport<RoleServer, E, A>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let server = MetaServer::new(sym, cwd).await;
+    let running = server.serve(transport).await?;
+    running.waiting().await?;
+    Ok(())
+}
+
+/// One resolution of the workspace: the backing servers and the
+/// `Cargo.lock` mtime they were resolved against.
+struct Resolved {
+    backends: Arc<Backends>,
+    lock_mtime: Option<u64>,
+}
+
+/// The meta-server's MCP handler.
+pub struct MetaServer {
+    sym: Symposium,
+    cwd: PathBuf,
+    resolved: RwLock<Resolved>,
+}
+
+#[derive(Deserialize)]
+struct ListToolsArgs {
+    #[serde(default)]
+    servers: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct ExecuteArgs {
+    script: String,
+}
+
+impl MetaServer {
+    /// Resolve the workspace at `cwd` and build a server with every backing
+    /// server cold.
+    pub async fn new(sym: Symposium, cwd: &Path) -> Self {
+        let resolved = resolve(&sym, cwd).await;
+        Self {
+            sym,
+            cwd: cwd.to_path_buf(),
+            resolved: RwLock::new(resolved),
+        }
+    }
+
+    /// The `list_tools` / `execute` tool definitions, with the capability
+    /// index built from the current backing servers.
+    async fn tools(&self) -> Vec<Tool> {
+        let backends = self.backends().await;
+        let names: Vec<&str> = backends.names().collect();
+        vec![
+            Tool::new(
+                "list_tools",
+                list_tools_description(&names),
+                schema(serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "servers": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Which servers to show (default: all)"
+                        }
+                    }
+                })),
+            ),
+            Tool::new(
+                "execute",
+                "Run a JavaScript program with the servers from list_tools as globals.\n\
+                 Each server is a namespace of async functions: `await server.tool({...})`.\n\
+                 The result is the value of the last expression, or the value passed to `return`.",
+                schema(serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "script": {
+                            "type": "string",
+                            "description": "JavaScript program to run"
+                        }
+                    },
+                    "required": ["script"]
+                })),
+            ),
+        ]
+    }
+
+    async fn backends(&self) -> Arc<Backends> {
+        self.resolved.read().await.backends.clone()
+    }
+
+    /// Re-resolve the workspace if `Cargo.lock` changed since the last
+    /// resolution. Gated by `auto-sync`, like hook-driven syncs: with it off
+    /// the index stays as resolved at startup.
+    async fn refresh_if_stale(&self) {
+        if !self.sym.config.auto_sync {
+            return;
+        }
+        let current = lock_mtime(&self.sym, &self.cwd);
+        if self.resolved.read().await.lock_mtime == current {
+            return;
+        }
+        tracing::debug!("Cargo.lock changed; re-resolving MCP servers");
+        let resolved = resolve(&self.sym, &self.cwd).await;
+        *self.resolved.write().await = resolved;
+    }
+
+    async fn list_tools_call(&self, args: ListToolsArgs) -> CallToolResult {
+        self.refresh_if_stale().await;
+        let backends = self.backends().await;
+
+        let requested: Vec<String> = match args.servers {
+            Some(servers) => servers,
+            None => backends.names().map(str::to_string).collect(),
+        };
+        if requested.is_empty() {
+            return CallToolResult::success(vec![Content::text(
+                "// No MCP servers apply to this workspace.",
+            )]);
+        }
+
+        let mut declarations = Vec::new();
+        for name in &requested {
+            match backends.tools(name).await {
+                Ok(tools) => declarations.push(typescript::render_namespace(name, &tools)),
+                Err(e) => declarations.push(format!("// {name}: unavailable ({e:#})\n")),
+            }
+        }
+        CallToolResult::success(vec![Content::text(declarations.join("\n"))])
+    }
+
+    async fn execute_call(&self, args: ExecuteArgs) -> CallToolResult {
+        self.refresh_if_stale().await;
+        let timeout = Duration::from_secs(self.sym.config.mcp_execute_timeout_secs);
+        match script::execute(self.backends().await, &args.script, timeout).await {
+            Ok(value) => CallToolResult::success(vec![Content::text(
+                serde_json::to_string_pretty(&value).unwrap(),
+            )]),
+            Err(message) => CallToolResult::error(vec![Content::text(message)]),
+        }
+    }
+}
+
+impl ServerHandler for MetaServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo::new(ServerCapabilities::builder().enable_tools().build()).with_instructions(
+            "Tools from the workspace's Symposium plugins. Call list_tools for TypeScript \
+             declarations, then execute a JavaScript program that calls them.",
+        )
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult::with_all_items(self.tools().await))
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let arguments = serde_json::Value::Object(request.arguments.unwrap_or_default());
+        match request.name.as_ref() {
+            "list_tools" => {
+                let args = parse_args(arguments)?;
+                Ok(self.list_tools_call(args).await)
+            }
+            "execute" => {
+                let args = parse_args(arguments)?;
+                Ok(self.execute_call(args).await)
+            }
+            other => Err(ErrorData::invalid_params(
+                format!("unknown tool `{other}`"),
+                None,
+            )),
+        }
+    }
+}
+
+fn parse_args<T: serde::de::DeserializeOwned>(value: serde_json::Value) -> Result<T, ErrorData> {
+    serde_json::from_value(value).map_err(|e| ErrorData::invalid_params(e.to_string(), None))
+}
+
+fn schema(value: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+    match value {
+        serde_json::Value::Object(map) => map,
+        _ => unreachable!("tool schemas are objects"),
+    }
+}
+
+async fn resolve(sym: &Symposium, cwd: &Path) -> Resolved {
+    let deps = sym.workspace_deps(cwd);
+    let mut servers = applicable_servers(sym, &deps).await;
+    servers.retain(is_proxied);
+    tracing::debug!(count = servers.len(), "resolved backing MCP servers");
+    Resolved {
+        backends: Arc::new(Backends::new(servers)),
+        lock_mtime: lock_mtime(sym, cwd),
+    }
+}
+
+/// The mtime of the workspace's `Cargo.lock`, located without resolving
+/// dependencies.
+fn lock_mtime(sym: &Symposium, cwd: &Path) -> Option<u64> {
+    let root = crate::workspace_state::find_workspace_root(sym, cwd)?;
+    crate::pm::file_mtime(&root.join("Cargo.lock"))
+}
+
+/// The `list_tools` description: what the tool does plus the capability
+/// index, truncated to [`INDEX_MAX_CHARS`].
+fn list_tools_description(names: &[&str]) -> String {
+    let mut description = String::from("List available tools as TypeScript declarations.\n\n");
+    if names.is_empty() {
+        description.push_str("No servers are available for this workspace.");
+        return description;
+    }
+
+    let mut index = String::from("Available servers: ");
+    let mut shown = 0;
+    for name in names {
+        let separator = if shown == 0 { "" } else { ", " };
+        if index.len() + separator.len() + name.len() > INDEX_MAX_CHARS {
+            break;
+        }
+        index.push_str(separator);
+        index.push_str(name);
+        shown += 1;
+    }
+    if shown < names.len() {
+        index.push_str(&format!(", … ({} more)", names.len() - shown));
+    }
+    description.push_str(&index);
+    description.push_str("\nCall list_tools for full TypeScript declarations.");
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn description_lists_servers() {
+        expect_test::expect![[r#"
+            List available tools as TypeScript declarations.
+
+            Available servers: sqlx, sea_orm
+            Call list_tools for full TypeScript declarations."#]]
+        .assert_eq(&list_tools_description(&["sqlx", "sea_orm"]));
+    }
+
+    #[test]
+    fn description_truncates_long_index() {
+        let names: Vec<String> = (0..500).map(|i| format!("server-{i}")).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let description = list_tools_description(&names);
+        assert!(description.len() < INDEX_MAX_CHARS + 200);
+        assert!(description.contains("more)"), "{description}");
+    }
+
+    #[test]
+    fn registration_entry_runs_mcp_serve() {
+        let McpServer::Stdio(entry) = registration_entry() else {
+            panic!("expected a stdio entry");
+        };
+        assert_eq!(entry.name, "symposium");
+        assert_eq!(entry.command, PathBuf::from("cargo-agents"));
+        assert_eq!(entry.args, vec!["mcp-serve".to_string()]);
+    }
+}
@@ -0,0 +1,244 @@
+//! The `execute` tool's JavaScript sandbox.
+//!
+//! Each agent-submitted script runs in a fresh QuickJS runtime with bounded
+//! memory and a wall-clock deadline. The only capabilities on the global
+//! scope are the backing servers' namespaces: `server.tool(params)` is an
+//! async function that dispatches a `tools/call` to the backing server and
+//! resolves to its result. There is no filesystem, network, or module
+//! loader.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rquickjs::prelude::Async;
+use rquickjs::{AsyncContext, AsyncRuntime, CatchResultExt, Function, Value, async_with};
+
+use super::backend::Backends;
+use super::typescript::js_identifier;
+
+/// Heap limit for one script run.
+const MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Stack limit for one script run.
+const MAX_STACK_SIZE: usize = 1024 * 1024;
+
+/// Installs one namespace object per backing server. Tool lookups go
+/// through a `Proxy`, so scripts can call any tool without the server being
+/// started up front; `then` is excluded so a namespace is never mistaken
+/// for a thenable.
+const PRELUDE: &str = r#"
+(function (call, servers) {
+  for (const [ident, server] of servers) {
+    globalThis[ident] = new Proxy({}, {
+      get(_target, tool) {
+        if (typeof tool !== "string" || tool === "then") return undefined;
+        return async (params) => {
+          const reply = JSON.parse(await call(server, tool, JSON.stringify(params ?? {})));
+          if ("error" in reply) throw new Error(reply.error);
+          return reply.value;
+        };
+      },
+    });
+  }
+})
+"#;
+
+/// Run `script` against `backends` and return its result as JSON.
+///
+/// The script may use top-level `await`; its value is the last expression
+/// statement, or — for scripts that use `return` — the returned value.
+/// Uncaught exceptions, including errors reported by a backing server, are
+/// returned as `Err` with the exception message.
+pub async fn execute(
+    backends: Arc<Backends>,
+    script: &str,
+    timeout: Duration,
+) -> Result<serde_json::Value, String> {
+    // The QuickJS runtime is not `Send`, so the script runs on a blocking
+    // thread of its own; backing-server calls it makes still run on the
+    // shared tokio runtime.
+    let script = script.to_string();
+    let handle = tokio::runtime::Handle::current();
+    tokio::task::spawn_blocking(move || handle.block_on(run(backends, script, timeout)))
+        .await
+        .map_err(|e| format!("script runner failed: {e}"))?
+}
+
+async fn run(
+    backends: Arc<Backends>,
+    script: String,
+    timeout: Duration,
+) -> Result<serde_json::Value, String> {
+    let runtime = AsyncRuntime::new().map_err(|e| e.to_string())?;
+    runtime.set_memory_limit(MEMORY_LIMIT).await;
+    runtime.set_max_stack_size(MAX_STACK_SIZE).await;
+    // The interrupt handler stops CPU-bound loops; the tokio timeout below
+    // stops scripts that are waiting on a slow backing server.
+    let deadline = Instant::now() + timeout;
+    runtime
+        .set_interrupt_handler(Some(Box::new(move || Instant::now() > deadline)))
+        .await;
+    let context = AsyncContext::full(&runtime)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let servers: Vec<(String, String)> = backends
+        .names()
+        .map(|name| (js_identifier(name), name.to_string()))
+        .collect();
+    let servers = serde_json::to_string(&servers).unwrap();
+
+    let evaluation = async_with!(context => |ctx| {
+        let call = Function::new(
+            ctx.clone(),
+            Async(move |server: String, tool: String, params: String| {
+                let backends = backends.clone();
+                async move { dispatch(&backends, &server, &tool, &params).await }
+            }),
+        )
+        .map_err(|e| e.to_string())?;
+
+        let install: Function = ctx.eval(PRELUDE).map_err(|e| e.to_string())?;
+        let servers: Value = ctx.json_parse(servers).map_err(|e| e.to_string())?;
+        install
+            .call::<_, ()>((call, servers))
+            .catch(&ctx)
+            .map_err(|e| e.to_string())?;
+
+        // Top-level code first; a script using `return` is a syntax error at
+        // top level, so fall back to running it as an async function body.
+        let promise = match ctx.eval_promise(script.clone()) {
+            Ok(promise) => promise,
+            Err(_) => {
+                let _ = ctx.catch();
+                ctx.eval_promise(format!("await (async () => {{\n{script}\n}})()"))
+                    .catch(&ctx)
+                    .map_err(|e| e.to_string())?
+            }
+        };
+        let completion: rquickjs::Object = promise
+            .into_future()
+            .await
+            .catch(&ctx)
+            .map_err(|e| e.to_string())?;
+        let value: Value = completion.get("value").map_err(|e| e.to_string())?;
+        let json = ctx
+            .json_stringify(value)
+            .map_err(|e| e.to_string())?
+            .map(|s| s.to_string())
+            .transpose()
+            .map_err(|e| e.to_string())?;
+        Ok::<_, String>(json.unwrap_or_else(|| "null".into()))
+    });
+
+    let result = match tokio::time::timeout(timeout, evaluation).await {
+        Ok(result) => result,
+        Err(_) => Err(timeout_message(timeout)),
+    };
+    let result = if Instant::now() > deadline {
+        result.map_err(|_| timeout_message(timeout))
+    } else {
+        result
+    };
+    result.and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()))
+}
+
+fn timeout_message(timeout: Duration) -> String {
+    format!("script exceeded the {}s timeout", timeout.as_secs())
+}
+
+/// Dispatch one namespace call and encode the outcome as the JSON envelope
+/// the prelude expects: `{"value": ...}` or `{"error": "..."}`.
+async fn dispatch(backends: &Backends, server: &str, tool: &str, params: &str) -> String {
+    let envelope = match call(backends, server, tool, params).await {
+        Ok(value) => serde_json::json!({ "value": value }),
+        Err(message) => serde_json::json!({ "error": message }),
+    };
+    envelope.to_string()
+}
+
+async fn call(
+    backends: &Backends,
+    server: &str,
+    tool: &str,
+    params: &str,
+) -> Result<serde_json::Value, String> {
+    let arguments = match serde_json::from_str(params) {
+        Ok(serde_json::Value::Object(map)) => map,
+        Ok(_) => return Err(format!("{server}.{tool}: parameters must be an object")),
+        Err(e) => return Err(e.to_string()),
+    };
+    let result = backends
+        .call(server, tool, arguments)
+        .await
+        .map_err(|e| format!("{e:#}"))?;
+    let value = tool_result_value(&result);
+    if result.is_error == Some(true) {
+        let message = match value {
+            serde_json::Value::String(s) => s,
+            other => other.to_string(),
+        };
+        return Err(format!("{server}.{tool}: {message}"));
+    }
+    Ok(value)
+}
+
+/// The JavaScript-facing value of a tool result: its structured content when
+/// present, otherwise its text content — parsed as JSON when it is JSON.
+fn tool_result_value(result: &rmcp::model::CallToolResult) -> serde_json::Value {
+    if let Some(structured) = &result.structured_content {
+        return structured.clone();
+    }
+    let text: Vec<&str> = result
+        .content
+        .iter()
+        .filter_map(|c| c.as_text().map(|t| t.text.as_str()))
+        .collect();
+    let text = text.join("\n");
+    serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run_script(script: &str) -> Result<serde_json::Value, String> {
+        execute(
+            Arc::new(Backends::default()),
+            script,
+            Duration::from_secs(5),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn last_expression_is_the_result() {
+        assert_eq!(run_script("const x = 20; x + 1").await.unwrap(), 21);
+    }
+
+    #[tokio::test]
+    async fn explicit_return_is_the_result() {
+        let value = run_script("const xs = [1, 2, 3];\nreturn xs.map(x => x * 2);")
+            .await
+            .unwrap();
+        assert_eq!(value, serde_json::json!([2, 4, 6]));
+    }
+
+    #[tokio::test]
+    async fn uncaught_exception_is_an_error() {
+        let err = run_script("throw new Error('boom')").await.unwrap_err();
+        assert!(err.contains("boom"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn runaway_loop_hits_the_timeout() {
+        let err = execute(
+            Arc::new(Backends::default()),
+            "while (true) {}",
+            Duration::from_millis(200),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.contains("timeout"), "{err}");
+    }
+}
@@ -0,0 +1,259 @@
+//! JSON Schema → TypeScript declaration rendering for `list_tools`.
+//!
+//! MCP tool input schemas are JSON Schema objects. The mapping is the
+//! mechanical one from the meta-server RFD: primitive types map directly,
+//! `array`/`object` recurse, `enum` becomes a union of literals, properties
+//! not listed in `required` become optional, and anything else is `any`.
+//! Return types are always `any` — MCP does not type tool outputs.
+
+use serde_json::{Map, Value};
+
+/// A JavaScript identifier for an MCP server or tool name. Characters that
+/// cannot appear in an identifier become `_`, and a leading digit is
+/// prefixed with `_` (`my-server` → `my_server`, `2fa` → `_2fa`).
+pub fn js_identifier(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// Render one server's tools as a `declare namespace` block.
+pub fn render_namespace(server: &str, tools: &[rmcp::model::Tool]) -> String {
+    let mut out = format!("declare namespace {} {{\n", js_identifier(server));
+    for tool in tools {
+        if let Some(description) = tool.description.as_deref() {
+            push_jsdoc(&mut out, "  ", description);
+        }
+        let params = if has_properties(&tool.input_schema) {
+            format!("params: {}", object_type(&tool.input_schema, 1))
+        } else {
+            String::new()
+        };
+        out.push_str(&format!(
+            "  function {}({params}): Promise<any>;\n",
+            js_identifier(&tool.name)
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// The TypeScript type for a JSON Schema value, at nesting depth `depth`
+/// (used to indent object literal members).
+pub fn schema_type(schema: &Value, depth: usize) -> String {
+    let Some(obj) = schema.as_object() else {
+        return "any".into();
+    };
+
+    if let Some(values) = obj.get("enum").and_then(Value::as_array) {
+        let literals: Vec<String> = values
+            .iter()
+            .filter_map(|v| match v {
+                Value::String(_) | Value::Number(_) | Value::Bool(_) | Value::Null => {
+                    Some(v.to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        return if literals.is_empty() {
+            "any".into()
+        } else {
+            literals.join(" | ")
+        };
+    }
+
+    match obj.get("type").and_then(Value::as_str) {
+        Some("string") => "string".into(),
+        Some("number") | Some("integer") => "number".into(),
+        Some("boolean") => "boolean".into(),
+        Some("null") => "null".into(),
+        Some("array") => {
+            let item = obj
+                .get("items")
+                .map(|items| schema_type(items, depth))
+                .unwrap_or_else(|| "any".into());
+            if item.contains(' ') {
+                format!("({item})[]")
+            } else {
+                format!("{item}[]")
+            }
+        }
+        Some("object") if obj.contains_key("properties") => object_type(obj, depth),
+        _ => "any".into(),
+    }
+}
+
+fn has_properties(schema: &Map<String, Value>) -> bool {
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .is_some_and(|p| !p.is_empty())
+}
+
+/// An object literal type from a schema's `properties` / `required`.
+fn object_type(schema: &Map<String, Value>, depth: usize) -> String {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return "{}".into();
+    };
+    if properties.is_empty() {
+        return "{}".into();
+    }
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let indent = "  ".repeat(depth + 1);
+    let mut out = String::from("{\n");
+    for (name, prop) in properties {
+        if let Some(description) = prop.get("description").and_then(Value::as_str) {
+            push_jsdoc(&mut out, &indent, description);
+        }
+        let optional = if required.contains(&name.as_str()) {
+            ""
+        } else {
+            "?"
+        };
+        out.push_str(&format!(
+            "{indent}{}{optional}: {};\n",
+            property_key(name),
+            schema_type(prop, depth + 1)
+        ));
+    }
+    out.push_str(&"  ".repeat(depth));
+    out.push('}');
+    out
+}
+
+/// A property key, quoted when it is not a plain identifier.
+fn property_key(name: &str) -> String {
+    if js_identifier(name) == name {
+        name.to_string()
+    } else {
+        serde_json::to_string(name).unwrap()
+    }
+}
+
+fn push_jsdoc(out: &mut String, indent: &str, description: &str) {
+    let description = description.trim().replace("*/", "*\\/");
+    let lines: Vec<&str> = description.lines().collect();
+    if lines.len() <= 1 {
+        out.push_str(&format!("{indent}/** {description} */\n"));
+    } else {
+        out.push_str(&format!("{indent}/**\n"));
+        for line in lines {
+            out.push_str(&format!("{indent} * {line}\n").replace(" \n", "\n"));
+        }
+        out.push_str(&format!("{indent} */\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tool(name: &str, description: &str, schema: Value) -> rmcp::model::Tool {
+        let Value::Object(schema) = schema else {
+            panic!("schema must be an object")
+        };
+        rmcp::model::Tool::new(name.to_string(), description.to_string(), schema)
+    }
+
+    #[test]
+    fn primitive_types() {
+        assert_eq!(schema_type(&json!({"type": "string"}), 0), "string");
+        assert_eq!(schema_type(&json!({"type": "integer"}), 0), "number");
+        assert_eq!(schema_type(&json!({"type": "number"}), 0), "number");
+        assert_eq!(schema_type(&json!({"type": "boolean"}), 0), "boolean");
+        assert_eq!(schema_type(&json!({"oneOf": []}), 0), "any");
+        assert_eq!(schema_type(&json!(true), 0), "any");
+    }
+
+    #[test]
+    fn arrays_and_enums() {
+        assert_eq!(
+            schema_type(&json!({"type": "array", "items": {"type": "string"}}), 0),
+            "string[]"
+        );
+        assert_eq!(schema_type(&json!({"type": "array"}), 0), "any[]");
+        assert_eq!(schema_type(&json!({"enum": ["a", "b"]}), 0), r#""a" | "b""#);
+        assert_eq!(
+            schema_type(&json!({"type": "array", "items": {"enum": ["x", "y"]}}), 0),
+            r#"("x" | "y")[]"#
+        );
+    }
+
+    #[test]
+    fn object_marks_optional_properties() {
+        let ty = schema_type(
+            &json!({
+                "type": "object",
+                "properties": {
+                    "sql": {"type": "string", "description": "The query"},
+                    "params": {"type": "array"}
+                },
+                "required": ["sql"]
+            }),
+            0,
+        );
+        expect_test::expect![[r#"
+            {
+              params?: any[];
+              /** The query */
+              sql: string;
+            }"#]]
+        .assert_eq(&ty);
+    }
+
+    #[test]
+    fn namespace_renders_jsdoc_and_params() {
+        let tools = vec![
+            tool(
+                "query",
+                "Execute a SQL query and return rows",
+                json!({
+                    "type": "object",
+                    "properties": {"sql": {"type": "string"}},
+                    "required": ["sql"]
+                }),
+            ),
+            tool(
+                "migrate-status",
+                "Show migrations",
+                json!({"type": "object"}),
+            ),
+        ];
+        expect_test::expect![[r#"
+            declare namespace sea_orm {
+              /** Execute a SQL query and return rows */
+              function query(params: {
+                sql: string;
+              }): Promise<any>;
+              /** Show migrations */
+              function migrate_status(): Promise<any>;
+            }
+        "#]]
+        .assert_eq(&render_namespace("sea-orm", &tools));
+    }
+
+    #[test]
+    fn identifiers_are_sanitized() {
+        assert_eq!(js_identifier("always-server"), "always_server");
+        assert_eq!(js_identifier("2fa"), "_2fa");
+        assert_eq!(js_identifier("ok_name"), "ok_name");
+        assert_eq!(property_key("content-type"), r#""content-type""#);
+    }
+}
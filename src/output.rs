@@ -3,7 +3,8 @@
 //! All user-visible status messages go through `Output`, which can be
 //! silenced with `--quiet` or when running from a hook.
 
-use std::path::Path;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 /// Controls whether user-facing status messages are printed.
@@ -11,6 +12,13 @@ use std::sync::{Arc, Mutex};
 pub struct Output {
     quiet: bool,
     capture: Option<Arc<Mutex<Vec<String>>>>,
+    /// Set for `sync --dry-run`: agent config writes are reported as planned
+    /// instead of performed. Holds the paths already reported, so a file
+    /// touched by several registration steps is planned once.
+    dry_run: Option<Arc<Mutex<BTreeSet<PathBuf>>>>,
+    /// Set for [`probe`](Self::probe): planned changes are collected but not
+    /// reported.
+    probe: bool,
 }
 
 impl Output {
@@ -18,6 +26,8 @@ impl Output {
         Self {
             quiet: false,
             capture: None,
+            dry_run: None,
+            probe: false,
         }
     }
 
@@ -25,6 +35,8 @@ impl Output {
         Self {
             quiet: true,
             capture: None,
+            dry_run: None,
+            probe: false,
         }
     }
 
@@ -34,6 +46,8 @@ impl Output {
         Self {
             quiet: false,
             capture: Some(Arc::new(Mutex::new(Vec::new()))),
+            dry_run: None,
+            probe: false,
         }
     }
 
@@ -47,10 +61,51 @@ impl Output {
             .clone()
     }
 
+    /// This output, for a dry run: code that writes agent configs checks
+    /// [`is_dry_run`](Self::is_dry_run) and reports the write instead.
+    pub fn with_dry_run(mut self) -> Self {
+        self.dry_run = Some(Arc::default());
+        self
+    }
+
+    /// A silent dry run, for asking whether agent config registration would
+    /// change anything: writes are neither performed nor reported, only
+    /// collected in [`planned_changes`](Self::planned_changes).
+    pub fn probe() -> Self {
+        Self {
+            probe: true,
+            ..Self::quiet().with_dry_run()
+        }
+    }
+
     pub fn is_quiet(&self) -> bool {
         self.quiet
     }
 
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.is_some()
+    }
+
+    pub fn is_probe(&self) -> bool {
+        self.probe
+    }
+
+    /// The paths a dry run has planned to change so far.
+    pub fn planned_changes(&self) -> Vec<PathBuf> {
+        self.dry_run
+            .as_ref()
+            .map(|planned| planned.lock().unwrap().iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Record that a dry run plans to change `path`. Returns `false` if the
+    /// change was already planned (or this is not a dry run).
+    pub fn plan_change(&self, path: &Path) -> bool {
+        self.dry_run
+            .as_ref()
+            .is_some_and(|planned| planned.lock().unwrap().insert(path.to_path_buf()))
+    }
+
     /// May we block on stdin to ask the user a question?
     ///
     /// Only a non-quiet, non-capturing output attached to a terminal on both
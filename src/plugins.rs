@@ -227,9 +227,15 @@ struct RawChainedPlugin {
     depends_on: Option<crate::predicate::DependsOnList>,
     #[serde(default)]
     predicates: crate::predicate::PredicateSet,
-    source: RawChainedSource,
+    /// Split by [`RawChainedPlugin::validate`]: the known kinds
+    /// ([`CHAINED_SOURCE_KINDS`]) parse strictly as a [`RawChainedSource`];
+    /// any other key names a plugin-provided package manager.
+    source: toml::Table,
 }
 
+/// The `[[plugins]] source.*` kinds symposium itself knows.
+const CHAINED_SOURCE_KINDS: &[&str] = &[crate::pm::CARGO_PM, "git", "path"];
+
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 struct RawChainedSource {
@@ -246,6 +252,14 @@ struct RawChainedSource {
     path: Option<toml::Value>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawChainedPmTable {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 enum RawChainedCargo {
@@ -265,13 +279,50 @@ struct RawChainedCargoTable {
 
 impl RawChainedPlugin {
     fn validate(self) -> Result<ChainedPlugin> {
-        if self.source.git.is_some() || self.source.path.is_some() {
+        if self.source.len() > 1 {
+            bail!("[[plugins]] entry names more than one source");
+        }
+        // `source.<pm>` for a plugin-provided package manager
+        // (`[[package-manager]]`): a package name, or a `{ name, version }`
+        // table. Whether `<pm>` exists is only known once the package-manager
+        // set is built, so active-plugin resolution rejects an unknown one.
+        let (known, other): (toml::Table, toml::Table) = self
+            .source
+            .into_iter()
+            .partition(|(kind, _)| CHAINED_SOURCE_KINDS.contains(&kind.as_str()));
+        let source: RawChainedSource = toml::Value::Table(known).try_into()?;
+        if source.git.is_some() || source.path.is_some() {
             bail!(
-                "[[plugins]] currently supports only `source.cargo`; \
+                "[[plugins]] supports `source.cargo` or a plugin-provided `source.<pm>`; \
                  git and path chained plugins are not yet implemented"
             );
         }
-        let Some(cargo) = self.source.cargo else {
+        let predicates = crate::predicate::PredicateSet::merged(self.depends_on, self.predicates);
+        if let Some((pm, value)) = other.into_iter().next() {
+            let (name, version) = match value {
+                toml::Value::String(name) => (name, None),
+                toml::Value::Table(_) => {
+                    let t: RawChainedPmTable = value.try_into().with_context(|| {
+                        format!("[[plugins]] source.{pm} takes a package name or a `{{ name = \"...\", version = \"...\" }}` table")
+                    })?;
+                    (t.name, t.version.filter(|v| !v.is_empty()))
+                }
+                _ => bail!(
+                    "[[plugins]] source.{pm} takes a package name or a \
+                     `{{ name = \"...\", version = \"...\" }}` table"
+                ),
+            };
+            if name.is_empty() {
+                bail!("[[plugins]] source.{pm} names an empty package");
+            }
+            return Ok(ChainedPlugin {
+                predicates,
+                pm,
+                name,
+                version,
+            });
+        }
+        let Some(cargo) = source.cargo else {
             bail!(
                 "[[plugins]] entry needs `source.cargo = \"<crate><version req>\"` \
                  or `source.cargo = {{ name = \"...\", version = \"...\" }}`"
@@ -315,7 +366,8 @@ impl RawChainedPlugin {
             }
         };
         Ok(ChainedPlugin {
-            predicates: crate::predicate::PredicateSet::merged(self.depends_on, self.predicates),
+            predicates,
+            pm: crate::pm::CARGO_PM.to_string(),
             name,
             version,
         })
@@ -475,6 +527,11 @@ pub struct Plugin {
     /// too. Expanded during skill resolution by `skills::expand_chained_plugins`.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub chained: Vec<ChainedPlugin>,
+    /// Package-manager types vended by this plugin (`[[package-manager]]`).
+    /// Like custom predicates, these register whether or not the plugin is
+    /// active, since the dependency set its gate reads comes from them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub package_managers: Vec<PluginPackageManager>,
     /// A registry plugin whose manifest references no dependency anywhere
     /// has nothing to infer a gate from, so it is *dormant*: installed and
     /// known, but never active until the user enables it by name (a
@@ -490,22 +547,66 @@ pub struct Plugin {
 }
 
 /// A validated `[[plugins]]` chained reference: whenever the owning plugin is
-/// active and `predicates` hold, the referenced plugin is loaded too. Only
-/// `source.cargo` (a crate carrying plugin content) is representable today.
+/// active and `predicates` hold, the referenced plugin is loaded too. The
+/// source is `source.cargo` (a crate carrying plugin content) or
+/// `source.<pm>` for a package manager a plugin provides
+/// (`[[package-manager]]`).
 #[derive(Debug, Clone, Serialize)]
 pub struct ChainedPlugin {
     /// Predicates gating this edge, on top of the owning plugin's own gate.
     #[serde(skip_serializing_if = "crate::predicate::PredicateSet::is_empty")]
     pub predicates: crate::predicate::PredicateSet,
-    /// Crate carrying the chained plugin content (`source.cargo`).
+    /// The package manager resolving the reference — the `source.<pm>` key
+    /// (`cargo` for `source.cargo`).
+    pub pm: String,
+    /// Package carrying the chained plugin content.
     pub name: String,
-    /// Version requirement, if given. Recorded but not yet enforced — the crate
-    /// always resolves against the workspace (pin / path override), whether or
-    /// not this is set.
+    /// Version requirement, if given. For cargo it is enforced during
+    /// expansion: the crate resolves against the workspace (pin / path
+    /// override) when it is a dependency, otherwise to a matching version,
+    /// and an edge whose crate resolves to a version outside the requirement
+    /// is skipped (see [`ChainedVersionCheck`]). For other package managers it
+    /// is passed through as the id's version, for the PM to interpret.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
 
+impl ChainedPlugin {
+    /// The package id expansion resolves this reference through.
+    pub fn package_id(&self) -> crate::pm::PackageId {
+        if self.pm == crate::pm::CARGO_PM {
+            crate::pm::CargoPm::id_for(&self.name, None)
+        } else {
+            crate::pm::PackageId::new(
+                &self.pm,
+                &self.name,
+                self.version.as_deref().unwrap_or(crate::pm::ANY_VERSION),
+            )
+        }
+    }
+
+    /// The cargo version requirement this edge enforces, if any. Validation
+    /// already checked that it parses.
+    fn cargo_requirement(&self) -> Option<semver::VersionReq> {
+        if self.pm != crate::pm::CARGO_PM {
+            return None;
+        }
+        semver::VersionReq::parse(self.version.as_deref()?).ok()
+    }
+}
+
+/// A validated `[[package-manager]]` entry: a package-manager type this
+/// plugin provides, run out of process as an
+/// [`ExternalPm`](crate::pm::ExternalPm).
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginPackageManager {
+    /// The PM's name — the `pm` component of the ids it owns, and the key
+    /// `[[plugins]] source.<name>` references use.
+    pub name: String,
+    /// Name of the installation whose binary implements the PM protocol.
+    pub command: String,
+}
+
 impl Plugin {
     /// Check if this plugin's activation predicates hold in `ctx`. A dormant
     /// plugin ([`requires_use`](Self::requires_use)) applies only when an
@@ -603,6 +704,14 @@ pub struct Hook {
     /// (hook `args`, installation `args`) is non-empty.
     pub args: Vec<String>,
     pub format: HookFormat,
+    /// Seconds the hook may run before it is killed. `None` uses the
+    /// config's `hook-timeout-secs`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// The hook only reads its input and reports context, so it may run
+    /// concurrently with adjacent parallel hooks.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub parallel: bool,
     /// Activation predicates that must all hold for this hook to dispatch.
     /// Evaluated at dispatch time, ANDed with the plugin's predicates.
     #[serde(
@@ -690,9 +799,22 @@ fn validate_hook(
         script: hook_script,
         args: hook_args,
         format,
+        timeout,
+        parallel,
         predicates,
     } = raw;
 
+    if timeout == Some(0) {
+        bail!("hook `{hook_name}`: `timeout` must be at least one second");
+    }
+    // Hooks on the other events can block or rewrite the tool input, and
+    // concurrent ones would race to do so.
+    if parallel && !matches!(event, HookEvent::PostToolUse | HookEvent::SessionStart) {
+        bail!(
+            "hook `{hook_name}`: `parallel` is only allowed on `PostToolUse` and `SessionStart` hooks"
+        );
+    }
+
     let command = resolve_or_promote(
         raw_command,
         installations,
@@ -780,6 +902,8 @@ fn validate_hook(
         script: hook_script,
         args: final_args,
         format,
+        timeout,
+        parallel,
         predicates,
     })
 }
@@ -875,7 +999,8 @@ pub struct PluginInfo {
 /// acquisition can look up the `Installation` later.
 #[derive(Debug, Clone)]
 pub struct ResolvedCustomPredicate {
-    /// Index into `PluginRegistry.plugins` for the owning plugin.
+    /// Index of the owning plugin in the list the registry was built from
+    /// (`PluginRegistry.plugins` for the registry's own).
     pub plugin_index: usize,
     /// The command installation name on the owning plugin.
     pub command: String,
@@ -955,6 +1080,15 @@ struct RawCustomPredicate {
     args: Vec<String>,
 }
 
+/// A `[[package-manager]]` entry in the raw TOML manifest.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawPackageManager {
+    name: String,
+    /// Named installation or inline installation table.
+    command: RawInstallationRef,
+}
+
 /// `[defaults]` section: opt-outs for the default content added to
 /// workspace plugin manifests (and, later, crate-embedded plugins).
 #[derive(Debug, Deserialize)]
@@ -1036,6 +1170,9 @@ struct RawPluginManifest {
     /// Chained plugin references — `[[plugins]]`.
     #[serde(default)]
     plugins: Vec<RawChainedPlugin>,
+    /// Package-manager types — `[[package-manager]]`.
+    #[serde(default, rename = "package-manager")]
+    package_manager: Vec<RawPackageManager>,
 }
 
 impl RawPluginManifest {
@@ -1052,6 +1189,7 @@ impl RawPluginManifest {
         self.mcp_servers.extend(over.mcp_servers);
         self.predicate.extend(over.predicate);
         self.plugins.extend(over.plugins);
+        self.package_manager.extend(over.package_manager);
         self.subcommand.extend(over.subcommand);
         self.depends_on.0.extend(over.depends_on.0);
         self.predicates
@@ -1133,6 +1271,10 @@ struct RawHook {
     #[serde(default)]
     format: HookFormat,
     #[serde(default)]
+    timeout: Option<u64>,
+    #[serde(default)]
+    parallel: bool,
+    #[serde(default)]
     predicates: crate::predicate::PredicateSet,
 }
 
@@ -1147,8 +1289,31 @@ pub async fn ensure_registries(sym: &Symposium, update: UpdateLevel) {
     // is a no-op).
     let force = matches!(update, UpdateLevel::Fetch);
     for inst in sym.registry_instances() {
-        if let Err(e) = inst.pm.refresh(update, force).await {
-            tracing::warn!(registry = %inst.name, error = %e, "failed to refresh registry");
+        match inst.pm.refresh(update, force).await {
+            Ok(true) => sym.forget_package_manager_declarations(),
+            Ok(false) => {}
+            Err(e) => {
+                tracing::warn!(registry = %inst.name, error = %e, "failed to refresh registry");
+            }
+        }
+    }
+}
+
+/// Fetch/update the registries a workspace recommends and the user has
+/// trusted, under the same rules as [`ensure_registries`].
+pub async fn ensure_workspace_registries(
+    sym: &Symposium,
+    workspace_root: &Path,
+    update: UpdateLevel,
+) {
+    let force = matches!(update, UpdateLevel::Fetch);
+    for inst in sym.workspace_registry_instances(workspace_root) {
+        match inst.pm.refresh(update, force).await {
+            Ok(true) => sym.forget_package_manager_declarations(),
+            Ok(false) => {}
+            Err(e) => {
+                tracing::warn!(registry = %inst.name, error = %e, "failed to refresh registry");
+            }
         }
     }
 }
@@ -1172,6 +1337,7 @@ pub async fn sync_registries(sym: &Symposium, provider: Option<&str>) -> Result<
         };
         match inst.pm.refresh(UpdateLevel::Fetch, force).await {
             Ok(true) => {
+                sym.forget_package_manager_declarations();
                 tracing::info!(registry = %inst.name, "synced");
                 synced.push(inst.name.clone());
             }
@@ -1263,6 +1429,44 @@ pub(crate) fn load_entry(
     }
 }
 
+/// What the plugins in one registry source say about package managers.
+#[derive(Debug, Default)]
+pub(crate) struct PackageManagerDeclarations {
+    /// Each `[[package-manager]]`, paired with the plugin that declares it
+    /// and the installation its `command` names.
+    pub declared: Vec<(String, PluginPackageManager, Installation)>,
+    /// The package managers the plugins' `[[plugins]] source.<pm>`
+    /// references name.
+    pub named: std::collections::BTreeSet<String>,
+}
+
+/// Scan the plugins in the registry source at `root` for
+/// [`PackageManagerDeclarations`]. Entries that fail to load are skipped
+/// here; registry loading is what reports them.
+pub(crate) fn package_manager_declarations(
+    root: &Path,
+    source_name: &str,
+) -> PackageManagerDeclarations {
+    let mut out = PackageManagerDeclarations::default();
+    let Ok(entries) = crate::pm::layout::enumerate(root) else {
+        return out;
+    };
+    for entry in entries {
+        let Some(Ok(parsed)) = load_entry(root, &entry.subpath, source_name) else {
+            continue;
+        };
+        for pm in &parsed.plugin.package_managers {
+            if let Some(installation) = parsed.plugin.get_installation(&pm.command) {
+                out.declared
+                    .push((parsed.plugin.name.clone(), pm.clone(), installation.clone()));
+            }
+        }
+        out.named
+            .extend(parsed.plugin.chained.iter().map(|c| c.pm.clone()));
+    }
+    out
+}
+
 /// Resolve each `source.path` skill group to an absolute directory and a
 /// display label, given the plugin's own base directory (what the relative
 /// path is joined onto) and the attribution root the label is shown relative
@@ -1335,6 +1539,7 @@ fn load_standalone_skill_plugin(
         subcommands: std::collections::BTreeMap::new(),
         custom_predicates: Vec::new(),
         chained: Vec::new(),
+        package_managers: Vec::new(),
         requires_use,
     };
     let base = skill_md.parent().unwrap_or(source_dir);
@@ -1387,12 +1592,29 @@ async fn load_registry_impl(
         plugins.extend(inst.pm.active_plugins(&[]).await);
     }
 
+    let mut pm_names: std::collections::BTreeSet<String> =
+        pms.instances().map(|i| i.pm.name().to_string()).collect();
     if let Some(ws) = workspace {
+        for inst in sym.workspace_registry_instances(&ws.root) {
+            // The detached set has no workspace registries, nor the package
+            // managers their plugins declare.
+            pm_names.insert(inst.pm.name().to_string());
+            if let Some(dir) = inst.pm.content_dir() {
+                pm_names.extend(
+                    sym.package_manager_declarations(dir, &inst.name)
+                        .declared
+                        .iter()
+                        .map(|(_, pm, _)| pm.name.clone()),
+                );
+            }
+            plugins.extend(inst.pm.active_plugins(&[]).await);
+        }
         let (ws_plugins, ws_warnings) =
             workspace_plugins(&ws.root, &ws.members, sym.config.agents_syncing);
         plugins.extend(ws_plugins);
         warnings.extend(ws_warnings);
     }
+    let plugins = reject_unknown_chained_sources(plugins, |pm| pm_names.contains(pm));
 
     tracing::debug!(plugins = plugins.len(), "plugin registry loaded");
 
@@ -1426,7 +1648,166 @@ pub async fn active_plugins(
     workspace_root: Option<&Path>,
     ctx: &mut crate::predicate::PredicateContext<'_>,
 ) -> Vec<ParsedPlugin> {
+    resolve_active_plugins(sym, registry, pms, workspace_root, ctx)
+        .await
+        .plugins
+}
+
+/// The outcome of checking a version-constrained `[[plugins]] source.cargo`
+/// edge against the version its crate resolved to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChainedVersionCheck {
+    /// The plugin declaring the edge.
+    pub from: String,
+    /// The referenced crate.
+    pub name: String,
+    /// The edge's version requirement.
+    pub requirement: String,
+    /// The version the crate resolved to.
+    pub resolved: String,
+    /// Whether `resolved` satisfies `requirement`. An unsatisfied edge is
+    /// skipped: its plugin does not load through it.
+    pub satisfied: bool,
+}
+
+/// [`active_plugins`] plus the version checks made on the way, for callers
+/// that report on chained edges (`cargo agents status`).
+pub struct ActivePlugins {
+    pub plugins: Vec<ParsedPlugin>,
+    pub version_checks: Vec<ChainedVersionCheck>,
+}
+
+/// A package id waiting in the fixed-point worklist, with the chained edge
+/// that enqueued it when that edge carries a cargo version requirement.
+struct PendingLoad {
+    id: crate::pm::PackageId,
+    requirement: Option<(String, semver::VersionReq)>,
+}
+
+/// [`active_plugins`], also returning the [`ChainedVersionCheck`]s.
+///
+/// Plugins loaded through `pms` may define `[[predicate]]`s of their own, and
+/// registering one can change which gates hold — and so which plugins load.
+/// Resolution therefore runs to a fixed point: resolve the active set, register
+/// the custom predicates the loaded plugins define (through the same
+/// collision rules as [`PluginRegistry::custom_predicates`], except that a
+/// trust root's definition outranks a loaded crate's), and resolve again
+/// until no new predicate registers. Each predicate is registered at most once,
+/// so the loop terminates; afterwards, a predicate whose defining plugin its
+/// own evaluation stopped from loading is reported as a cycle, and names gates
+/// evaluated without any definition are reported as unresolved. A
+/// [cache-only](crate::predicate::PredicateContext::set_cache_only) context
+/// registers only the predicates already installed, and reports the rest as
+/// not installed.
+pub async fn resolve_active_plugins(
+    sym: &Symposium,
+    registry: &PluginRegistry,
+    pms: &crate::pm::PmRegistry,
+    workspace_root: Option<&Path>,
+    ctx: &mut crate::predicate::PredicateContext<'_>,
+) -> ActivePlugins {
+    ctx.set_shell_allowed(sym.policy().allow_shell_predicates);
+    // Loads are memoized across rounds; only gates change between them.
+    let mut loads = std::collections::HashMap::new();
+    // Predicates registered here, by name, with the plugin that defines each.
+    let mut registered: std::collections::BTreeMap<String, ParsedPlugin> =
+        std::collections::BTreeMap::new();
+    let mut attempted = std::collections::HashSet::new();
+    // Predicates left uninstalled because the context is cache-only.
+    let mut not_installed = std::collections::BTreeSet::new();
+
+    loop {
+        let round = resolve_round(sym, registry, pms, workspace_root, ctx, &mut loads).await;
+        let unresolved = ctx.take_unresolved_custom_predicates();
+
+        // Definitions come from the trust roots plus every plugin loaded this
+        // round, active or not — as for registry plugins, a definition
+        // registers whether or not its plugin applies.
+        let mut collisions = Vec::new();
+        let definers = predicate_definers(registry, &round.loaded, &mut collisions);
+        let definitions = build_custom_predicate_registry(&definers, &mut collisions);
+
+        let mut progressed = false;
+        for (name, definition) in definitions.iter() {
+            // A loaded plugin's predicates register eagerly, since its own
+            // skills and hooks may name them. A trust root's registered here
+            // only when a gate needed one the caller did not supply.
+            let from_loaded = definition.plugin_index >= registry.plugins.len();
+            if !(from_loaded || unresolved.contains(name))
+                || ctx.has_custom_predicate(name)
+                || !attempted.insert(name.clone())
+            {
+                continue;
+            }
+            let definer = &definers[definition.plugin_index];
+            let entry = if ctx.is_cache_only() {
+                crate::sync::installed_custom_predicate_entry(
+                    sym,
+                    &definer.plugin,
+                    name,
+                    definition,
+                )
+                .await
+            } else {
+                crate::sync::resolve_custom_predicate_entry(
+                    sym,
+                    &definer.plugin,
+                    name,
+                    definition,
+                    UpdateLevel::None,
+                )
+                .await
+            };
+            match entry {
+                Some(entry) => {
+                    ctx.register_custom_predicate(name.clone(), entry);
+                    registered.insert(name.clone(), definer.clone());
+                    progressed = true;
+                }
+                None if ctx.is_cache_only() => {
+                    not_installed.insert(name.clone());
+                }
+                None => {}
+            }
+        }
+        if progressed {
+            continue;
+        }
+
+        report_custom_predicate_outcome(
+            registry,
+            &round,
+            &registered,
+            &unresolved,
+            &not_installed,
+            collisions,
+        );
+        for check in round.active.version_checks.iter().filter(|c| !c.satisfied) {
+            warn_chained_version(check);
+        }
+        return round.active;
+    }
+}
+
+/// One pass of active-plugin resolution under the custom predicates
+/// registered so far: the active set, plus every plugin loaded through `pms`
+/// on the way (active or not) — the candidates for predicate registration.
+struct ResolutionRound {
+    active: ActivePlugins,
+    loaded: Vec<ParsedPlugin>,
+}
+
+async fn resolve_round(
+    sym: &Symposium,
+    registry: &PluginRegistry,
+    pms: &crate::pm::PmRegistry,
+    workspace_root: Option<&Path>,
+    ctx: &mut crate::predicate::PredicateContext<'_>,
+    loads: &mut std::collections::HashMap<crate::pm::PackageId, Vec<ParsedPlugin>>,
+) -> ResolutionRound {
     let mut active = Vec::new();
+    let mut loaded = Vec::new();
+    let mut version_checks = Vec::new();
     // Crate identities already loaded through the set, keyed on `(pm, name)` so
     // a crate reached through two chains — or a chain and dependency enablement —
     // loads once (its hooks don't double-fire, its subcommands aren't a false
@@ -1434,11 +1815,11 @@ pub async fn active_plugins(
     // distinct.
     let mut visited = std::collections::HashSet::new();
     // Package ids still to resolve through the set.
-    let mut worklist: Vec<crate::pm::PackageId> = Vec::new();
+    let mut worklist: Vec<PendingLoad> = Vec::new();
 
     // Seed with the trust-root plugins (registry + workspace), gated.
     for parsed in &registry.plugins {
-        record_active(parsed.clone(), ctx, &mut active, &mut worklist);
+        record_active(sym, parsed.clone(), ctx, &mut active, &mut worklist);
     }
 
     // Enabled crates: consented dependencies and `use`d crates that aren't
@@ -1452,22 +1833,136 @@ pub async fn active_plugins(
             .collect();
         for name in crate::discovery::enabled_dependencies(sym, ctx.deps, root) {
             if !registry_names.contains(&crate::crate_sources::normalize_crate_name(&name)) {
-                worklist.push(crate::pm::CargoPm::id_for(&name, None));
+                worklist.push(PendingLoad {
+                    id: crate::pm::CargoPm::id_for(&name, None),
+                    requirement: None,
+                });
             }
         }
     }
 
     // Fixed-point: resolve each id through the set, record any new plugin, and
-    // enqueue its own chained references.
-    while let Some(id) = worklist.pop() {
-        for plugin in pms.load_plugin(&id).await {
+    // enqueue its own chained references. A version-constrained edge is checked
+    // before dedup, so a skipped edge doesn't shadow another edge to the same
+    // crate that the resolved version does satisfy.
+    while let Some(pending) = worklist.pop() {
+        if !loads.contains_key(&pending.id) {
+            let plugins = pms.load_plugin(&pending.id).await;
+            loads.insert(pending.id.clone(), plugins);
+        }
+        for plugin in loads[&pending.id].clone() {
+            if let Some((from, req)) = &pending.requirement {
+                let check = check_chained_version(from, req, &plugin.canonical);
+                let satisfied = check.satisfied;
+                version_checks.push(check);
+                if !satisfied {
+                    continue;
+                }
+            }
             if visited.insert(plugin_key(&plugin.canonical)) {
-                record_active(plugin, ctx, &mut active, &mut worklist);
+                loaded.push(plugin.clone());
+                record_active(sym, plugin, ctx, &mut active, &mut worklist);
             }
         }
     }
 
-    active
+    ResolutionRound {
+        active: ActivePlugins {
+            plugins: active,
+            version_checks,
+        },
+        loaded,
+    }
+}
+
+/// Report how custom predicate registration ended: collisions among the
+/// loaded plugins' definitions (the trust roots' own are already in
+/// [`PluginRegistry::warnings`]), predicates whose evaluation stopped their
+/// defining plugin from loading, and names no definition ever resolved.
+fn report_custom_predicate_outcome(
+    registry: &PluginRegistry,
+    round: &ResolutionRound,
+    registered: &std::collections::BTreeMap<String, ParsedPlugin>,
+    unresolved: &std::collections::BTreeSet<String>,
+    not_installed: &std::collections::BTreeSet<String>,
+    collisions: Vec<LoadWarning>,
+) {
+    let warn = |message: String| {
+        tracing::info!(report = %crate::report::ReportEvent::Warning { message });
+    };
+
+    for collision in collisions {
+        if !registry
+            .warnings
+            .iter()
+            .any(|w| w.message == collision.message)
+        {
+            warn(collision.message);
+        }
+    }
+
+    for (name, definer) in registered {
+        let still_loaded = round
+            .loaded
+            .iter()
+            .any(|p| p.canonical == definer.canonical);
+        if !still_loaded {
+            warn(format!(
+                "custom predicate `{name}` (from `{}`) stops the plugin that defines it \
+                 from loading; this cycle is left as first resolved",
+                definer.plugin.name
+            ));
+        }
+    }
+
+    for name in not_installed {
+        tracing::info!(
+            report = %crate::report::ReportEvent::Info {
+                message: format!(
+                    "custom predicate `{name}` is not installed during a dry run; \
+                     it evaluates to false"
+                ),
+            },
+        );
+    }
+
+    for name in unresolved.difference(not_installed) {
+        warn(format!(
+            "custom predicate `{name}` is not defined by any loaded plugin \
+             (or its installation failed); it evaluates to false"
+        ));
+    }
+}
+
+/// Check a loaded crate's resolved version against the requirement of the
+/// edge that reached it. A version that is not semver never satisfies a
+/// requirement (as for `depends-on` atoms).
+fn check_chained_version(
+    from: &str,
+    req: &semver::VersionReq,
+    resolved: &crate::pm::PackageId,
+) -> ChainedVersionCheck {
+    ChainedVersionCheck {
+        from: from.to_string(),
+        name: resolved.name.clone(),
+        requirement: req.to_string(),
+        resolved: resolved.version.clone(),
+        satisfied: semver::Version::parse(&resolved.version).is_ok_and(|v| req.matches(&v)),
+    }
+}
+
+/// Warn that an edge was skipped because its crate resolved outside the
+/// edge's version requirement.
+fn warn_chained_version(check: &ChainedVersionCheck) {
+    tracing::info!(
+        report = %crate::report::ReportEvent::Warning {
+            message: format!(
+                "plugin `{}` requires {} {}, but it resolved to {}; \
+                 skipping the chained plugin",
+                check.from, check.name, check.requirement, check.resolved
+            ),
+        },
+    );
 }
 
 /// The dedup key for a loaded crate plugin: its ecosystem plus normalized name,
@@ -1481,15 +1976,27 @@ fn plugin_key(id: &crate::pm::PackageId) -> String {
     )
 }
 
-/// Gate `plugin` and, if it passes, record it into `active` and enqueue its
+/// Gate `plugin` — the system policy first, then its own predicates — and, if
+/// it passes, record it into `active` and enqueue its
 /// `[[plugins]]` chained references (evaluated against this plugin's provenance)
 /// onto `worklist`.
 fn record_active(
+    sym: &Symposium,
     plugin: ParsedPlugin,
     ctx: &mut crate::predicate::PredicateContext<'_>,
     active: &mut Vec<ParsedPlugin>,
-    worklist: &mut Vec<crate::pm::PackageId>,
+    worklist: &mut Vec<PendingLoad>,
 ) {
+    if !sym.policy().permits_plugin(&plugin.plugin.name) {
+        tracing::debug!(
+            report = %crate::report::ReportEvent::PluginConsidered {
+                plugin: plugin.plugin.name.clone(),
+                matched: false,
+                reason: Some("denied by system policy".into()),
+            },
+        );
+        return;
+    }
     if !plugin.applies(ctx) {
         tracing::debug!(
             report = %crate::report::ReportEvent::PluginConsidered {
@@ -1507,29 +2014,64 @@ fn record_active(
             reason: None,
         },
     );
-    warn_undispatched_crate_features(&plugin);
     for edge in &plugin.plugin.chained {
         ctx.set_workspace_member(plugin.workspace_member);
-        if edge.predicates.evaluate(ctx) {
-            worklist.push(crate::pm::CargoPm::id_for(&edge.name, None));
+        if !edge.predicates.evaluate(ctx) {
+            continue;
         }
+        let Some(req) = edge.cargo_requirement() else {
+            worklist.push(PendingLoad {
+                id: edge.package_id(),
+                requirement: None,
+            });
+            continue;
+        };
+        // A dependency resolves against the workspace pin, which the check
+        // then accepts or rejects; any other crate is fetched at a version
+        // matching the requirement.
+        let normalized = crate::crate_sources::normalize_crate_name(&edge.name);
+        let is_dependency = ctx.deps.iter().any(|d| {
+            d.pm == crate::pm::CARGO_PM
+                && crate::crate_sources::normalize_crate_name(&d.name) == normalized
+        });
+        let id = if is_dependency {
+            edge.package_id()
+        } else {
+            crate::pm::CargoPm::id_for(&edge.name, edge.version.as_deref())
+        };
+        worklist.push(PendingLoad {
+            id,
+            requirement: Some((plugin.plugin.name.clone(), req)),
+        });
     }
     active.push(plugin);
 }
 
-/// Warn when a crate-embedded plugin declares custom predicates. Its skills,
-/// hooks, MCP servers, and subcommands now dispatch through the active-plugin
-/// set, but custom predicate *definitions* are still resolved only from
-/// configured registries, so a crate that vends its own predicate cannot yet
-/// have it evaluated.
-fn warn_undispatched_crate_features(parsed: &ParsedPlugin) {
-    if !parsed.plugin.custom_predicates.is_empty() {
-        tracing::warn!(
-            plugin = %parsed.plugin.name,
-            "crate-embedded plugin declares custom predicates, which are not yet \
-             registered (its skills, hooks, MCP servers, and subcommands are dispatched)"
-        );
-    }
+/// Drop, with a warning, every plugin with a `[[plugins]] source.<pm>`
+/// naming a package manager `is_known` rejects. Which plugin-provided package
+/// managers exist is only known once the package-manager set is built, so this
+/// is where a misspelled source kind (`source.crago`) becomes a load error.
+pub(crate) fn reject_unknown_chained_sources(
+    plugins: Vec<ParsedPlugin>,
+    is_known: impl Fn(&str) -> bool,
+) -> Vec<ParsedPlugin> {
+    plugins
+        .into_iter()
+        .filter(|parsed| {
+            let Some(edge) = parsed.plugin.chained.iter().find(|c| !is_known(&c.pm)) else {
+                return true;
+            };
+            tracing::info!(
+                report = %crate::report::ReportEvent::Warning {
+                    message: format!(
+                        "skipping {}: [[plugins]] source.{} names no known package manager",
+                        parsed.plugin.name, edge.pm
+                    ),
+                },
+            );
+            false
+        })
+        .collect()
 }
 
 /// Display name workspace plugins are attributed to. Parenthesized so it
@@ -2031,6 +2573,15 @@ fn validate_manifest(
         )?);
     }
 
+    let mut package_managers = Vec::with_capacity(manifest.package_manager.len());
+    for raw in manifest.package_manager {
+        package_managers.push(validate_package_manager(
+            raw,
+            &mut installations,
+            &mut names,
+        )?);
+    }
+
     reject_crates_field(&manifest.crates)?;
     let predicates =
         crate::predicate::PredicateSet::merged(Some(manifest.depends_on), manifest.predicates);
@@ -2086,6 +2637,7 @@ fn validate_manifest(
         subcommands,
         custom_predicates,
         chained,
+        package_managers,
         requires_use,
     })
 }
@@ -2096,6 +2648,7 @@ const RESERVED_SUBCOMMAND_NAMES: &[&str] = &[
     "init",
     "sync",
     "hook",
+    "mcp-serve",
     "plugin",
     "crate-info",
     "self-update",
@@ -2185,6 +2738,86 @@ fn validate_custom_predicate(
     })
 }
 
+/// Package-manager names a plugin cannot claim: the built-in ecosystem
+/// transport and the reserved `[[plugins]] source.*` keys.
+const RESERVED_PACKAGE_MANAGER_NAMES: &[&str] = &[crate::pm::CARGO_PM, "git", "path"];
+
+/// Validate a `[[package-manager]]` entry, promoting inline `command` if needed.
+fn validate_package_manager(
+    raw: RawPackageManager,
+    installations: &mut Vec<Installation>,
+    names: &mut std::collections::BTreeSet<String>,
+) -> Result<PluginPackageManager> {
+    if raw.name.is_empty()
+        || !raw
+            .name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    {
+        bail!(
+            "package manager name `{}` must be non-empty lowercase letters, digits, `-`, or `_`",
+            raw.name
+        );
+    }
+    if RESERVED_PACKAGE_MANAGER_NAMES.contains(&raw.name.as_str()) {
+        bail!("package manager name `{}` is reserved", raw.name);
+    }
+
+    let command = resolve_or_promote(
+        raw.command,
+        installations,
+        names,
+        &mut || format!("__pm_{}", raw.name),
+        &format!("package manager `{}`", raw.name),
+    )?;
+
+    Ok(PluginPackageManager {
+        name: raw.name,
+        command,
+    })
+}
+
+/// The plugins whose `[[predicate]]`s a resolution round registers: the
+/// trust roots, then the `loaded` crate plugins. A trust root's definition
+/// outranks a loaded crate's, so a crate can't replace (or, by colliding,
+/// disable) a predicate the registry defines; its own is dropped with a
+/// warning.
+fn predicate_definers(
+    registry: &PluginRegistry,
+    loaded: &[ParsedPlugin],
+    warnings: &mut Vec<LoadWarning>,
+) -> Vec<ParsedPlugin> {
+    let mut registry_definers = std::collections::HashMap::new();
+    for parsed in &registry.plugins {
+        for cp in &parsed.plugin.custom_predicates {
+            registry_definers
+                .entry(cp.name.as_str())
+                .or_insert(parsed.plugin.name.as_str());
+        }
+    }
+
+    let mut definers = registry.plugins.clone();
+    for parsed in loaded {
+        let mut parsed = parsed.clone();
+        parsed.plugin.custom_predicates.retain(|cp| {
+            let Some(definer) = registry_definers.get(cp.name.as_str()) else {
+                return true;
+            };
+            warnings.push(LoadWarning {
+                path: PathBuf::from(&parsed.plugin.name),
+                message: format!(
+                    "custom predicate `{}` defined by `{}` is ignored: registry plugin `{}` \
+                     already defines it",
+                    cp.name, parsed.plugin.name, definer
+                ),
+            });
+            false
+        });
+        definers.push(parsed);
+    }
+    definers
+}
+
 /// Collect custom predicates from all plugins, detecting collisions.
 fn build_custom_predicate_registry(
     plugins: &[ParsedPlugin],
@@ -2311,7 +2944,74 @@ mod tests {
             "#,
         )
         .unwrap_err();
-        assert!(err.to_string().contains("only `source.cargo`"), "{err}");
+        assert!(err.to_string().contains("not yet implemented"), "{err}");
+    }
+
+    #[test]
+    fn package_manager_declaration_and_chained_source() {
+        let plugin = from_str(
+            r#"
+            name = "artifactory"
+            depends-on = ["*"]
+
+            [[package-manager]]
+            name = "internal"
+            command = { executable = "/opt/internal-pm" }
+
+            [[plugins]]
+            source.internal = "widgets"
+
+            [[plugins]]
+            source.internal = { name = "gadgets", version = "3.1" }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(plugin.package_managers.len(), 1);
+        let pm = &plugin.package_managers[0];
+        assert_eq!(pm.name, "internal");
+        let installation = plugin.get_installation(&pm.command).unwrap();
+        assert_eq!(installation.executable.as_deref(), Some("/opt/internal-pm"));
+
+        assert_eq!(
+            plugin.chained[0].package_id(),
+            crate::pm::PackageId::new("internal", "widgets", "*")
+        );
+        assert_eq!(
+            plugin.chained[1].package_id(),
+            crate::pm::PackageId::new("internal", "gadgets", "3.1")
+        );
+    }
+
+    #[test]
+    fn package_manager_names_are_validated() {
+        for (name, expected) in [("cargo", "reserved"), ("Bad Name", "lowercase")] {
+            let err = from_str(&format!(
+                r#"
+                name = "p"
+                depends-on = ["*"]
+                [[package-manager]]
+                name = "{name}"
+                command = {{ executable = "/opt/pm" }}
+                "#
+            ))
+            .unwrap_err();
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn chained_plugin_rejects_two_sources() {
+        let err = from_str(
+            r#"
+            name = "recs"
+            depends-on = ["serde"]
+            [[plugins]]
+            source.cargo = "widget"
+            source.internal = "widget"
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("more than one source"), "{err}");
     }
 
     #[test]
@@ -3181,6 +3881,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
         assert!(plugin_wildcard.applies(&mut ctx(&workspace_crates)));
@@ -3196,6 +3897,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
         assert!(plugin_serde.applies(&mut ctx(&workspace_crates)));
@@ -3211,6 +3913,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
         assert!(!plugin_other.applies(&mut ctx(&workspace_crates)));
@@ -3226,6 +3929,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
         assert!(!plugin_version.applies(&mut ctx(&workspace_crates)));
@@ -3423,6 +4127,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
         let mut parsed = ParsedPlugin {
@@ -3813,6 +4518,39 @@ mod tests {
         );
     }
 
+    /// `timeout = 0` and `parallel` on an event whose hooks can block are
+    /// rejected.
+    #[test]
+    fn hook_timeout_and_parallel_are_validated() {
+        let manifest = |event: &str, extra: &str| {
+            format!(
+                indoc! {r#"
+                    name = "p"
+                    depends-on = ["*"]
+
+                    [[hooks]]
+                    name = "h"
+                    event = "{}"
+                    command = {{ executable = "/bin/true" }}
+                    {}
+                "#},
+                event, extra
+            )
+        };
+        let err = from_str(&manifest("PostToolUse", "timeout = 0")).unwrap_err();
+        assert!(
+            err.to_string().contains("at least one second"),
+            "got: {err}"
+        );
+        for event in ["PreToolUse", "UserPromptSubmit", "Stop"] {
+            let err = from_str(&manifest(event, "parallel = true")).unwrap_err();
+            assert!(err.to_string().contains("`parallel`"), "{event}: {err}");
+        }
+        for event in ["PostToolUse", "SessionStart"] {
+            from_str(&manifest(event, "parallel = true")).unwrap();
+        }
+    }
+
     /// A bare-installation + hook-level `script` is valid: the installation
     /// only contributes `install_commands`, the hook supplies the runnable.
     #[test]
@@ -4703,7 +5441,7 @@ mod tests {
             command = "tool"
             bogus = 42
         "#};
-        let err = from_str(toml).err().expect("expected error");
+        let err = from_str(toml).expect_err("expected error");
         let msg = format!("{err:#}");
         assert!(msg.contains("bogus") || msg.contains("unknown"), "{msg}");
     }
@@ -4723,7 +5461,7 @@ mod tests {
             description = "Try to shadow init"
             command = "tool"
         "#};
-        let err = from_str(toml).err().expect("expected error");
+        let err = from_str(toml).expect_err("expected error");
         let msg = format!("{err:#}");
         assert!(msg.contains("shadows") && msg.contains("init"), "{msg}");
     }
@@ -4743,7 +5481,7 @@ mod tests {
             description = "dotted name"
             command = "tool"
         "#};
-        let err = from_str(toml).err().expect("expected error");
+        let err = from_str(toml).expect_err("expected error");
         let msg = format!("{err:#}");
         assert!(msg.contains("invalid characters"), "{msg}");
     }
@@ -4766,7 +5504,7 @@ mod tests {
             command = "tool"
             "#
         );
-        let err = from_str(&toml).err().expect("expected error");
+        let err = from_str(&toml).expect_err("expected error");
         let msg = format!("{err:#}");
         assert!(msg.contains("1024"), "{msg}");
     }
@@ -4781,7 +5519,7 @@ mod tests {
             description = "..."
             command = "missing"
         "#};
-        let err = from_str(toml).err().expect("expected error");
+        let err = from_str(toml).expect_err("expected error");
         let msg = format!("{err:#}");
         assert!(msg.contains("unknown installation"), "{msg}");
     }
@@ -4874,6 +5612,7 @@ mod tests {
                     args: vec![],
                 }],
                 chained: vec![],
+                package_managers: vec![],
                 requires_use: false,
             },
             workspace_member: false,
@@ -19,7 +19,8 @@ use crate::plugins::ParsedPlugin;
 
 pub mod workspace;
 pub use workspace::{
-    LoadedWorkspace, WorkspaceCrate, WorkspaceDeps, file_mtime, workspace_dir_name,
+    DepKind, LoadedWorkspace, WorkspaceCrate, WorkspaceDeps, disk_cache_file, file_mtime,
+    workspace_dir_name,
 };
 
 use super::{ANY_VERSION, CARGO_PM, FetchedPackage, PackageId, PackageManager, PluginInfo};
@@ -195,6 +196,9 @@ impl PackageManager for CargoPm {
     /// whatever it embeds — any fetchable crate yields at least the default
     /// `skills/` plugin. Fetched cache-only.
     async fn load_plugin(&self, id: &PackageId) -> Vec<ParsedPlugin> {
+        if id.pm != CARGO_PM {
+            return Vec::new();
+        }
         let fetched = match self.fetch(id, UpdateLevel::None).await {
             Ok(f) => f,
             Err(e) => {
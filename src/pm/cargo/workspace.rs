@@ -4,7 +4,7 @@
 //! workspace. Plugin binaries obtain one via
 //! [`SymposiumDirs::workspace_deps`](crate::dirs::SymposiumDirs::workspace_deps).
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
 use std::time::SystemTime;
@@ -13,7 +13,20 @@ use std::{fmt::Write as _, fs};
 use cargo_metadata::{CargoOpt, MetadataCommand};
 use serde::{Deserialize, Serialize};
 
-/// A crate in the workspace's direct dependency graph.
+/// How a workspace member depends on a crate, from `cargo metadata`'s
+/// `dep_kinds`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DepKind {
+    /// A `[dependencies]` entry.
+    Normal,
+    /// A `[dev-dependencies]` entry.
+    Dev,
+    /// A `[build-dependencies]` entry.
+    Build,
+}
+
+/// A crate in the workspace's resolved dependency graph.
 #[non_exhaustive]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceCrate {
@@ -31,6 +44,14 @@ pub struct WorkspaceCrate {
     /// source can be inspected or fetched without a fresh cargo probe.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_dir: Option<PathBuf>,
+    /// How workspace members depend on this crate directly. Empty for a crate
+    /// that is only reached through other dependencies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<DepKind>,
+    /// The crate's enabled features when the workspace members build with
+    /// their default features, as resolved by `cargo metadata`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<String>,
 }
 
 impl WorkspaceCrate {
@@ -44,6 +65,8 @@ impl WorkspaceCrate {
             version,
             source_dir: path.clone(),
             path,
+            kinds: Vec::new(),
+            features: Vec::new(),
         }
     }
 
@@ -52,6 +75,18 @@ impl WorkspaceCrate {
         self.source_dir = source_dir;
         self
     }
+
+    /// Set how workspace members depend on this crate directly.
+    pub fn with_kinds(mut self, kinds: Vec<DepKind>) -> Self {
+        self.kinds = kinds;
+        self
+    }
+
+    /// Set the crate's enabled features.
+    pub fn with_features(mut self, features: Vec<String>) -> Self {
+        self.features = features;
+        self
+    }
 }
 
 /// The resolved workspace: root path + dependency list + member directories.
@@ -62,11 +97,18 @@ pub struct LoadedWorkspace {
     pub root: PathBuf,
     /// Direct dependencies of all workspace members.
     pub crates: Vec<WorkspaceCrate>,
+    /// Every other crate in the resolved graph: reached only through other
+    /// dependencies. One entry per resolved version.
+    #[serde(default)]
+    pub transitive: Vec<WorkspaceCrate>,
     /// Manifest directories of the workspace's member packages. Backs
     /// workspace-plugin discovery (a member directory may define a plugin).
     pub members: Vec<PathBuf>,
 }
 
+/// File name of the on-disk cache, inside the per-workspace cache directory.
+const DISK_CACHE_FILE: &str = "workspace-deps.json";
+
 /// On-disk cache format. Adding a field is a compatible cache bump: an old
 /// cache file fails to deserialize, reads as a miss, and is rebuilt.
 #[derive(Serialize, Deserialize)]
@@ -74,9 +116,21 @@ struct DiskCache {
     lock_mtime: u64,
     root: PathBuf,
     crates: Vec<WorkspaceCrate>,
+    transitive: Vec<WorkspaceCrate>,
     members: Vec<PathBuf>,
 }
 
+impl DiskCache {
+    fn into_loaded(self) -> LoadedWorkspace {
+        LoadedWorkspace {
+            root: self.root,
+            crates: self.crates,
+            transitive: self.transitive,
+            members: self.members,
+        }
+    }
+}
+
 /// Lazy, cached workspace dependency resolver.
 ///
 /// The first `load()` checks the disk cache (keyed on `Cargo.lock` mtime); on
@@ -90,6 +144,8 @@ pub struct WorkspaceDeps {
     cwd: PathBuf,
     dirs: crate::dirs::SymposiumDirs,
     cached: OnceLock<Option<Arc<LoadedWorkspace>>>,
+    /// Skip the disk cache and always run `cargo metadata` (see [`Self::reloaded`]).
+    bypass_disk_cache: bool,
 }
 
 impl WorkspaceDeps {
@@ -98,9 +154,38 @@ impl WorkspaceDeps {
             cwd: cwd.into(),
             dirs: dirs.clone(),
             cached: OnceLock::new(),
+            bypass_disk_cache: false,
         }
     }
 
+    /// A fresh resolver for the same directory that ignores the disk cache.
+    /// The cache is keyed on `Cargo.lock`, which a `Cargo.toml` edit does not
+    /// touch until cargo next resolves, so a manifest change needs this to be
+    /// seen. The new resolution is written through to disk as usual.
+    pub fn reloaded(&self) -> Arc<Self> {
+        Arc::new(Self {
+            cwd: self.cwd.clone(),
+            dirs: self.dirs.clone(),
+            cached: OnceLock::new(),
+            bypass_disk_cache: true,
+        })
+    }
+
+    /// The workspace as last resolved, read from the disk cache without
+    /// checking it against `Cargo.lock` — what symposium last saw before the
+    /// dependencies changed. `None` when nothing has been cached yet.
+    pub fn previous(&self) -> Option<Arc<Self>> {
+        let loaded = read_disk_cache(&self.workspace_cache_dir()?)?;
+        let cached = OnceLock::new();
+        let _ = cached.set(Some(Arc::new(loaded.into_loaded())));
+        Some(Arc::new(Self {
+            cwd: self.cwd.clone(),
+            dirs: self.dirs.clone(),
+            cached,
+            bypass_disk_cache: false,
+        }))
+    }
+
     /// A pre-resolved resolver for tests: skips `cargo metadata` and returns
     /// exactly `crates` (rooted at `root`, no members).
     #[cfg(test)]
@@ -110,12 +195,14 @@ impl WorkspaceDeps {
         let _ = cached.set(Some(Arc::new(LoadedWorkspace {
             root: root.clone(),
             crates,
+            transitive: Vec::new(),
             members: Vec::new(),
         })));
         Arc::new(Self {
             cwd: root,
             dirs: crate::dirs::SymposiumDirs::new(PathBuf::new(), PathBuf::new(), None),
             cached,
+            bypass_disk_cache: false,
         })
     }
 
@@ -130,6 +217,7 @@ impl WorkspaceDeps {
             cwd: PathBuf::new(),
             dirs: crate::dirs::SymposiumDirs::new(PathBuf::new(), PathBuf::new(), None),
             cached,
+            bypass_disk_cache: false,
         }
     }
 
@@ -161,7 +249,8 @@ impl WorkspaceDeps {
     fn resolve(&self) -> Option<Arc<LoadedWorkspace>> {
         let cache_dir = self.workspace_cache_dir();
 
-        if let Some(dir) = &cache_dir
+        if !self.bypass_disk_cache
+            && let Some(dir) = &cache_dir
             && let Some(loaded) = try_disk_cache(dir)
         {
             return Some(Arc::new(loaded));
@@ -191,9 +280,7 @@ impl WorkspaceDeps {
 }
 
 fn try_disk_cache(ws_cache_dir: &Path) -> Option<LoadedWorkspace> {
-    let cache_file = ws_cache_dir.join("workspace-deps.json");
-    let contents = fs::read_to_string(&cache_file).ok()?;
-    let cached: DiskCache = serde_json::from_str(&contents).ok()?;
+    let cached = read_disk_cache(ws_cache_dir)?;
 
     // Validate: Cargo.lock mtime must match.
     let lock_path = cached.root.join("Cargo.lock");
@@ -202,11 +289,22 @@ fn try_disk_cache(ws_cache_dir: &Path) -> Option<LoadedWorkspace> {
         return None;
     }
 
-    Some(LoadedWorkspace {
-        root: cached.root,
-        crates: cached.crates,
-        members: cached.members,
-    })
+    Some(cached.into_loaded())
+}
+
+/// The workspace-deps cache file for `workspace_root` under `cache_dir`.
+pub fn disk_cache_file(cache_dir: &Path, workspace_root: &Path) -> PathBuf {
+    let canonical = fs::canonicalize(workspace_root).unwrap_or_else(|_| workspace_root.into());
+    cache_dir
+        .join("workspaces")
+        .join(workspace_dir_name(&canonical))
+        .join(DISK_CACHE_FILE)
+}
+
+fn read_disk_cache(ws_cache_dir: &Path) -> Option<DiskCache> {
+    let cache_file = ws_cache_dir.join(DISK_CACHE_FILE);
+    let contents = fs::read_to_string(&cache_file).ok()?;
+    serde_json::from_str(&contents).ok()
 }
 
 fn write_disk_cache(ws_cache_dir: &Path, loaded: &LoadedWorkspace) {
@@ -219,12 +317,13 @@ fn write_disk_cache(ws_cache_dir: &Path, loaded: &LoadedWorkspace) {
         lock_mtime: mtime,
         root: loaded.root.clone(),
         crates: loaded.crates.clone(),
+        transitive: loaded.transitive.clone(),
         members: loaded.members.clone(),
     };
 
     let _ = fs::create_dir_all(ws_cache_dir);
     let _ = fs::write(
-        ws_cache_dir.join("workspace-deps.json"),
+        ws_cache_dir.join(DISK_CACHE_FILE),
         serde_json::to_string_pretty(&disk).unwrap_or_default(),
     );
 }
@@ -283,30 +382,71 @@ pub fn file_mtime(path: &Path) -> Option<u64> {
     )
 }
 
-/// Run `cargo metadata` and extract workspace root + direct deps.
+/// Run `cargo metadata` and extract workspace root, direct deps (with their
+/// dependency kinds and features), and the rest of the resolved graph.
+///
+/// The graph comes from an `--all-features` resolve, so a dependency behind
+/// any member feature counts. Features come from a second, default-features
+/// resolve: a member feature nobody turns on must not make a `feature(..)`
+/// predicate match.
 fn load_workspace(cwd: &Path, cargo_path: Option<&Path>) -> Option<LoadedWorkspace> {
-    let mut cmd = MetadataCommand::new();
-    cmd.features(CargoOpt::AllFeatures).current_dir(cwd);
-    if let Some(path) = cargo_path {
-        cmd.cargo_path(path);
-    }
-    let metadata = cmd.exec().ok()?;
+    let metadata_command = |features: Option<CargoOpt>| {
+        let mut cmd = MetadataCommand::new();
+        if let Some(features) = features {
+            cmd.features(features);
+        }
+        cmd.current_dir(cwd);
+        if let Some(path) = cargo_path {
+            cmd.cargo_path(path);
+        }
+        cmd
+    };
+    let metadata = metadata_command(Some(CargoOpt::AllFeatures)).exec().ok()?;
+    let default_features: HashMap<cargo_metadata::PackageId, Vec<String>> = match metadata_command(
+        None,
+    )
+    .exec()
+    {
+        Ok(defaults) => defaults
+            .resolve
+            .map(|r| r.nodes.into_iter().map(|n| (n.id, n.features)).collect())
+            .unwrap_or_default(),
+        Err(e) => {
+            tracing::debug!(error = %e, "default-features resolve failed; no features recorded");
+            HashMap::new()
+        }
+    };
 
     let root = metadata.workspace_root.clone().into_std_path_buf();
 
     let resolve = metadata.resolve.as_ref()?;
 
     let ws_members: HashSet<_> = metadata.workspace_members.iter().collect();
-    let mut direct_dep_ids: HashSet<&cargo_metadata::PackageId> = HashSet::new();
+    let nodes: HashMap<_, _> = resolve.nodes.iter().map(|n| (&n.id, n)).collect();
 
+    // Direct deps of the members, with every kind they are depended on as.
+    let mut direct_kinds: HashMap<&cargo_metadata::PackageId, BTreeSet<DepKind>> = HashMap::new();
     for node in &resolve.nodes {
         if ws_members.contains(&node.id) {
             for dep in &node.deps {
-                direct_dep_ids.insert(&dep.pkg);
+                let kinds = direct_kinds.entry(&dep.pkg).or_default();
+                kinds.extend(dep.dep_kinds.iter().filter_map(|k| dep_kind(&k.kind)));
             }
         }
     }
 
+    // Everything reachable from the members.
+    let mut reachable: HashSet<&cargo_metadata::PackageId> = HashSet::new();
+    let mut queue: Vec<_> = direct_kinds.keys().copied().collect();
+    while let Some(id) = queue.pop() {
+        if !reachable.insert(id) {
+            continue;
+        }
+        if let Some(node) = nodes.get(id) {
+            queue.extend(node.deps.iter().map(|d| &d.pkg));
+        }
+    }
+
     let path_overrides: HashMap<String, PathBuf> = metadata
         .packages
         .iter()
@@ -318,24 +458,33 @@ fn load_workspace(cwd: &Path, cargo_path: Option<&Path>) -> Option<LoadedWorkspa
         })
         .collect();
 
-    let mut crates: Vec<_> = metadata
-        .packages
-        .iter()
-        .filter(|p| direct_dep_ids.contains(&p.id) && !ws_members.contains(&p.id))
-        .filter_map(|p| {
-            semver::Version::parse(&p.version.to_string())
-                .ok()
-                .map(|v| WorkspaceCrate {
-                    path: path_overrides.get(&p.name).cloned(),
-                    source_dir: p.manifest_path.parent().map(|dir| dir.into()),
-                    name: p.name.to_string(),
-                    version: v,
-                })
-        })
-        .collect();
+    let mut crates = Vec::new();
+    let mut transitive = Vec::new();
+    for p in &metadata.packages {
+        if !reachable.contains(&p.id) || ws_members.contains(&p.id) {
+            continue;
+        }
+        let Ok(version) = semver::Version::parse(&p.version.to_string()) else {
+            continue;
+        };
+        let krate = WorkspaceCrate {
+            path: path_overrides.get(&p.name).cloned(),
+            source_dir: p.manifest_path.parent().map(|dir| dir.into()),
+            name: p.name.to_string(),
+            version,
+            kinds: Vec::new(),
+            features: default_features.get(&p.id).cloned().unwrap_or_default(),
+        };
+        match direct_kinds.get(&p.id) {
+            Some(kinds) => crates.push(krate.with_kinds(kinds.iter().copied().collect())),
+            None => transitive.push(krate),
+        }
+    }
 
     crates.sort_by(|a, b| a.name.cmp(&b.name));
     crates.dedup_by(|a, b| a.name == b.name);
+    transitive.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+    transitive.dedup_by(|a, b| a.name == b.name && a.version == b.version);
 
     let mut members: Vec<PathBuf> = metadata
         .packages
@@ -349,6 +498,16 @@ fn load_workspace(cwd: &Path, cargo_path: Option<&Path>) -> Option<LoadedWorkspa
     Some(LoadedWorkspace {
         root,
         crates,
+        transitive,
         members,
     })
 }
+
+fn dep_kind(kind: &cargo_metadata::DependencyKind) -> Option<DepKind> {
+    match kind {
+        cargo_metadata::DependencyKind::Normal => Some(DepKind::Normal),
+        cargo_metadata::DependencyKind::Development => Some(DepKind::Dev),
+        cargo_metadata::DependencyKind::Build => Some(DepKind::Build),
+        _ => None,
+    }
+}
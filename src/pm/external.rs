@@ -0,0 +1,577 @@
+//! Out-of-process package managers: a [`PackageManager`] whose operations are
+//! answered by an external binary over JSON-RPC.
+//!
+//! The binary is an ordinary [`Installation`] — acquired like a hook command
+//! (cargo, github, a script on disk) the first time the PM is asked anything,
+//! then spawned once and kept for the instance's lifetime. It speaks
+//! JSON-RPC 2.0 over stdio, one message per line. The first request is
+//! `initialize`; after that each [`PackageManager`] operation is one method
+//! of the same name:
+//!
+//! | Method | Params | Result |
+//! |--------|--------|--------|
+//! | `initialize` | `{ protocol_version, name, cwd }` | `{ protocol_version }` |
+//! | `active_plugins` | `{ deps: [id] }` | `[{ id, path }]` |
+//! | `load_plugin` | `{ id }` | `[{ id, path }]` |
+//! | `list_deps` | `{}` | `[id]` |
+//! | `search` | `{ query }` | `[{ id, description? }]` |
+//! | `fetch` | `{ id, update }` | `{ id, root }` |
+//! | `refresh` | `{ update, force }` | `bool` |
+//!
+//! An `id` is a [`PackageId`] object (`{ pm, name, version }`); `update` is
+//! `"none"`, `"check"`, or `"fetch"`. A plugin is returned as the directory
+//! holding it (a `SYMPOSIUM.toml` or a bare `SKILL.md`), which symposium
+//! parses itself, so the manifest schema stays on this side of the protocol.
+//! Failures are JSON-RPC error responses; the binary's stderr is forwarded to
+//! the debug log. A request left unanswered for `pm-timeout-secs` is an
+//! error too, and the binary is killed (the next request respawns it).
+
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use symposium_install::{Runnable, UpdateLevel};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::process::{Child, ChildStdin, ChildStdout};
+use tokio::sync::Mutex;
+
+use super::{FetchedPackage, PackageId, PackageManager, PluginInfo};
+use crate::plugins::{Installation, ParsedPlugin};
+
+/// The protocol revision this side speaks, exchanged in `initialize`.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// A package manager implemented by an external binary.
+pub struct ExternalPm {
+    name: String,
+    ctx: symposium_install::InstallContext,
+    installation: Installation,
+    /// Working directory for the binary, also sent in `initialize` — the PM
+    /// resolves its own workspace from it.
+    cwd: PathBuf,
+    /// How long one request may go unanswered.
+    timeout: Duration,
+    /// The running binary; `None` until the first request, and again after
+    /// it exits (the next request respawns it).
+    conn: Mutex<Option<Connection>>,
+}
+
+struct Connection {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: Lines<BufReader<ChildStdout>>,
+    next_id: u64,
+}
+
+/// A plugin as the binary reports it: its identity and the directory it
+/// lives in.
+#[derive(Deserialize)]
+struct WirePlugin {
+    id: PackageId,
+    path: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct WireInfo {
+    id: PackageId,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct WireFetched {
+    id: PackageId,
+    root: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct WireInitialized {
+    protocol_version: u32,
+}
+
+#[derive(Deserialize)]
+struct WireError {
+    message: String,
+}
+
+impl ExternalPm {
+    /// An instance named `name` driving the binary `installation` resolves
+    /// to, run from `cwd`, giving up on a request after `timeout`. Nothing is
+    /// acquired or spawned until first use.
+    pub fn new(
+        name: impl Into<String>,
+        ctx: symposium_install::InstallContext,
+        installation: Installation,
+        cwd: impl Into<PathBuf>,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            ctx,
+            installation,
+            cwd: cwd.into(),
+            timeout,
+            conn: Mutex::new(None),
+        }
+    }
+
+    /// Send one request and decode its result, spawning the binary first if
+    /// it is not running.
+    async fn request<R: DeserializeOwned>(&self, method: &str, params: Value) -> Result<R> {
+        let mut conn = self.conn.lock().await;
+        if conn.is_none() {
+            *conn = Some(self.spawn().await?);
+        }
+        let result = conn
+            .as_mut()
+            .unwrap()
+            .call_within(self.timeout, method, params)
+            .await;
+        if result.is_err() && conn.as_mut().is_some_and(|c| c.exited()) {
+            *conn = None;
+        }
+        let value = result.with_context(|| format!("package manager `{}`", self.name))?;
+        serde_json::from_value(value).with_context(|| {
+            format!(
+                "package manager `{}` returned a malformed `{method}` result",
+                self.name
+            )
+        })
+    }
+
+    async fn spawn(&self) -> Result<Connection> {
+        let label = format!("package manager `{}`", self.name);
+        let acquired = crate::installation::acquire_installation_in(
+            &self.ctx,
+            &self.installation,
+            None,
+            None,
+            UpdateLevel::None,
+        )
+        .await
+        .with_context(|| format!("installing {label}"))?;
+        let runnable = crate::installation::resolve_runnable(acquired, &label)?;
+
+        let mut cmd = match &runnable {
+            Runnable::Exec(path) => tokio::process::Command::new(path),
+            Runnable::Script(path) => {
+                let mut cmd = tokio::process::Command::new("sh");
+                cmd.arg(path);
+                cmd
+            }
+        };
+        cmd.args(&self.installation.args);
+        if self.cwd.is_dir() {
+            cmd.current_dir(&self.cwd);
+        }
+        tracing::debug!(pm = %self.name, runnable = ?runnable, "spawning package manager");
+        let mut child = cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("failed to start {label}"))?;
+
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped")).lines();
+        let stderr = child.stderr.take().expect("stderr is piped");
+        let pm = self.name.clone();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                tracing::debug!(pm = %pm, "{line}");
+            }
+        });
+
+        let mut conn = Connection {
+            child,
+            stdin,
+            stdout,
+            next_id: 0,
+        };
+        let initialized: WireInitialized = serde_json::from_value(
+            conn.call_within(
+                self.timeout,
+                "initialize",
+                json!({
+                    "protocol_version": PROTOCOL_VERSION,
+                    "name": self.name,
+                    "cwd": self.cwd,
+                }),
+            )
+            .await
+            .with_context(|| format!("initializing {label}"))?,
+        )
+        .with_context(|| format!("{label} returned a malformed `initialize` result"))?;
+        if initialized.protocol_version != PROTOCOL_VERSION {
+            bail!(
+                "{label} speaks protocol version {}, expected {PROTOCOL_VERSION}",
+                initialized.protocol_version
+            );
+        }
+        Ok(conn)
+    }
+
+    /// Load the plugins the binary reported, stamping each with the id it
+    /// was reported under. Entries that fail to load are skipped with a
+    /// warning.
+    fn load_reported(&self, reported: Vec<WirePlugin>) -> Vec<ParsedPlugin> {
+        let mut out = Vec::new();
+        for WirePlugin { id, path } in reported {
+            match crate::plugins::load_entry(&path, std::path::Path::new(""), &self.name) {
+                Some(Ok(mut p)) => {
+                    p.canonical = id;
+                    out.push(p);
+                }
+                Some(Err(e)) => tracing::info!(
+                    report = %crate::report::ReportEvent::Warning {
+                        message: format!(
+                            "skipping {}: {e:#}",
+                            crate::output::display_path(&path)
+                        ),
+                    },
+                ),
+                None => tracing::warn!(
+                    pm = %self.name,
+                    id = %id,
+                    path = %path.display(),
+                    "reported plugin directory has no SYMPOSIUM.toml or SKILL.md"
+                ),
+            }
+        }
+        out
+    }
+}
+
+impl Connection {
+    /// Whether the binary has exited, so the connection cannot be reused.
+    fn exited(&mut self) -> bool {
+        !matches!(self.child.try_wait(), Ok(None))
+    }
+
+    /// [`call`](Self::call), killing the binary if it has not answered
+    /// within `timeout` — a stuck PM must not hang sync or a hook.
+    async fn call_within(
+        &mut self,
+        timeout: Duration,
+        method: &str,
+        params: Value,
+    ) -> Result<Value> {
+        match tokio::time::timeout(timeout, self.call(method, params)).await {
+            Ok(result) => result,
+            Err(_) => {
+                let _ = self.child.kill().await;
+                bail!(
+                    "did not answer `{method}` within {}s",
+                    timeout.as_secs_f64()
+                );
+            }
+        }
+    }
+
+    /// Write one request and read lines until its response arrives. Lines
+    /// that are not JSON-RPC responses (notifications, stray output) are
+    /// skipped.
+    async fn call(&mut self, method: &str, params: Value) -> Result<Value> {
+        self.next_id += 1;
+        let id = self.next_id;
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        let mut line = serde_json::to_string(&request)?;
+        line.push('\n');
+        self.stdin
+            .write_all(line.as_bytes())
+            .await
+            .context("writing request")?;
+        self.stdin.flush().await.context("writing request")?;
+
+        loop {
+            let Some(line) = self.stdout.next_line().await.context("reading response")? else {
+                bail!("exited before answering `{method}`");
+            };
+            let Ok(response) = serde_json::from_str::<Value>(&line) else {
+                tracing::debug!(line = %line, "ignoring non-JSON output from package manager");
+                continue;
+            };
+            if response.get("id").and_then(Value::as_u64) != Some(id) {
+                continue;
+            }
+            if let Some(error) = response.get("error") {
+                let message = serde_json::from_value::<WireError>(error.clone())
+                    .map(|e| e.message)
+                    .unwrap_or_else(|_| error.to_string());
+                bail!("`{method}` failed: {message}");
+            }
+            return Ok(response.get("result").cloned().unwrap_or(Value::Null));
+        }
+    }
+}
+
+fn update_level_str(update: UpdateLevel) -> &'static str {
+    match update {
+        UpdateLevel::None => "none",
+        UpdateLevel::Check => "check",
+        _ => "fetch",
+    }
+}
+
+#[async_trait::async_trait]
+impl PackageManager for ExternalPm {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn active_plugins(&self, deps: &[PackageId]) -> Vec<ParsedPlugin> {
+        match self
+            .request::<Vec<WirePlugin>>("active_plugins", json!({ "deps": deps }))
+            .await
+        {
+            Ok(reported) => self.load_reported(reported),
+            Err(e) => {
+                tracing::warn!(pm = %self.name, error = %format!("{e:#}"), "cannot list active plugins");
+                Vec::new()
+            }
+        }
+    }
+
+    /// The plugin(s) one of this PM's own ids maps to. Ids of other PMs are
+    /// not forwarded, so resolving them never spawns the binary.
+    async fn load_plugin(&self, id: &PackageId) -> Vec<ParsedPlugin> {
+        if id.pm != self.name {
+            return Vec::new();
+        }
+        match self
+            .request::<Vec<WirePlugin>>("load_plugin", json!({ "id": id }))
+            .await
+        {
+            // The reference naming the package is its gate, as for a chained
+            // crate, so a manifest without a dependency gate is not dormant.
+            Ok(reported) => self
+                .load_reported(reported)
+                .into_iter()
+                .map(|mut p| {
+                    p.plugin.requires_use = false;
+                    p
+                })
+                .collect(),
+            Err(e) => {
+                tracing::warn!(pm = %self.name, id = %id, error = %format!("{e:#}"), "failed to load plugin");
+                Vec::new()
+            }
+        }
+    }
+
+    async fn list_deps(&self) -> Result<Vec<PackageId>> {
+        self.request("list_deps", json!({})).await
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<PluginInfo>> {
+        let infos: Vec<WireInfo> = self.request("search", json!({ "query": query })).await?;
+        Ok(infos
+            .into_iter()
+            .map(|i| PluginInfo {
+                id: i.id,
+                description: i.description,
+            })
+            .collect())
+    }
+
+    async fn fetch(&self, id: &PackageId, update: UpdateLevel) -> Result<FetchedPackage> {
+        let fetched: WireFetched = self
+            .request(
+                "fetch",
+                json!({ "id": id, "update": update_level_str(update) }),
+            )
+            .await?;
+        Ok(FetchedPackage {
+            id: fetched.id,
+            root: fetched.root,
+        })
+    }
+
+    async fn refresh(&self, update: UpdateLevel, force: bool) -> Result<bool> {
+        self.request(
+            "refresh",
+            json!({ "update": update_level_str(update), "force": force }),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A shell PM that answers every method from canned replies. `$PLUGIN`
+    /// is the directory `load_plugin` reports.
+    const MOCK_PM: &str = r#"
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":\([0-9][0-9]*\).*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*) result='{"protocol_version":1}' ;;
+    *'"method":"list_deps"'*) result='[{"pm":"internal","name":"widgets","version":"2.1.0"}]' ;;
+    *'"method":"search"'*) result='[{"id":{"pm":"internal","name":"widgets","version":"*"},"description":"Widget helpers"}]' ;;
+    *'"method":"load_plugin"'*) result="[{\"id\":{\"pm\":\"internal\",\"name\":\"widgets\",\"version\":\"2.1.0\"},\"path\":\"$PLUGIN\"}]" ;;
+    *'"method":"fetch"'*) result="{\"id\":{\"pm\":\"internal\",\"name\":\"widgets\",\"version\":\"2.1.0\"},\"root\":\"$PLUGIN\"}" ;;
+    *'"method":"refresh"'*'"force":true'*) result='true' ;;
+    *'"method":"refresh"'*) result='false' ;;
+    *)
+      echo "unhandled: $line" >&2
+      printf '{"jsonrpc":"2.0","id":%s,"error":{"code":-32601,"message":"method not found"}}\n' "$id"
+      continue
+      ;;
+  esac
+  echo "not a response"
+  printf '{"jsonrpc":"2.0","id":%s,"result":%s}\n' "$id" "$result"
+done
+"#;
+
+    fn mock_pm(tmp: &std::path::Path) -> ExternalPm {
+        let plugin = tmp.join("widgets");
+        std::fs::create_dir_all(&plugin).unwrap();
+        std::fs::write(
+            plugin.join("SYMPOSIUM.toml"),
+            "name = \"widgets\"\ndepends-on = [\"*\"]\n",
+        )
+        .unwrap();
+        let script = tmp.join("pm.sh");
+        std::fs::write(&script, format!("PLUGIN='{}'\n{MOCK_PM}", plugin.display())).unwrap();
+
+        let installation = Installation {
+            name: "internal-pm".into(),
+            requirements: Vec::new(),
+            install_commands: Vec::new(),
+            source: None,
+            executable: None,
+            script: Some(script.display().to_string()),
+            args: Vec::new(),
+        };
+        let ctx = symposium_install::InstallContext::new(tmp.join("cache"));
+        ExternalPm::new("internal", ctx, installation, tmp, Duration::from_secs(30))
+    }
+
+    #[tokio::test]
+    async fn operations_round_trip_over_json_rpc() {
+        let tmp = tempfile::tempdir().unwrap();
+        let pm = mock_pm(tmp.path());
+
+        let deps = pm.list_deps().await.unwrap();
+        assert_eq!(deps, vec![PackageId::new("internal", "widgets", "2.1.0")]);
+
+        let hits = pm.search("wid").await.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].description.as_deref(), Some("Widget helpers"));
+
+        let loaded = pm
+            .load_plugin(&PackageId::any_version("internal", "widgets"))
+            .await;
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].plugin.name, "widgets");
+        assert_eq!(
+            loaded[0].canonical,
+            PackageId::new("internal", "widgets", "2.1.0")
+        );
+
+        let fetched = pm
+            .fetch(
+                &PackageId::any_version("internal", "widgets"),
+                UpdateLevel::None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(fetched.id.version, "2.1.0");
+        assert_eq!(fetched.root, tmp.path().join("widgets"));
+
+        assert!(!pm.refresh(UpdateLevel::Check, false).await.unwrap());
+        assert!(pm.refresh(UpdateLevel::Check, true).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn error_responses_surface_as_errors() {
+        let tmp = tempfile::tempdir().unwrap();
+        let pm = mock_pm(tmp.path());
+
+        // `active_plugins` is best-effort: the error is logged, not returned.
+        assert!(pm.active_plugins(&[]).await.is_empty());
+        // The connection survives an error response.
+        assert_eq!(pm.list_deps().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn unanswered_requests_time_out_and_kill_the_binary() {
+        let tmp = tempfile::tempdir().unwrap();
+        // Answers `initialize`, then reads every request without replying.
+        let script = tmp.path().join("pm.sh");
+        std::fs::write(
+            &script,
+            r#"
+read -r line
+printf '{"jsonrpc":"2.0","id":1,"result":{"protocol_version":1}}\n'
+while IFS= read -r line; do :; done
+"#,
+        )
+        .unwrap();
+        let installation = Installation {
+            name: "silent-pm".into(),
+            requirements: Vec::new(),
+            install_commands: Vec::new(),
+            source: None,
+            executable: None,
+            script: Some(script.display().to_string()),
+            args: Vec::new(),
+        };
+        let ctx = symposium_install::InstallContext::new(tmp.path().join("cache"));
+        let pm = ExternalPm::new(
+            "internal",
+            ctx,
+            installation,
+            tmp.path(),
+            Duration::from_millis(200),
+        );
+
+        let err = pm.list_deps().await.unwrap_err();
+        assert!(
+            format!("{err:#}").contains("did not answer `list_deps`"),
+            "{err:#}"
+        );
+        // The stuck binary was killed; the next request starts a fresh one.
+        assert!(pm.conn.lock().await.is_none());
+        assert!(pm.search("x").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn missing_binary_is_an_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let installation = Installation {
+            name: "missing".into(),
+            requirements: Vec::new(),
+            install_commands: Vec::new(),
+            source: None,
+            executable: Some(tmp.path().join("nope").display().to_string()),
+            script: None,
+            args: Vec::new(),
+        };
+        let ctx = symposium_install::InstallContext::new(tmp.path().join("cache"));
+        let pm = ExternalPm::new(
+            "internal",
+            ctx,
+            installation,
+            tmp.path(),
+            Duration::from_secs(30),
+        );
+        let err = pm.list_deps().await.unwrap_err();
+        assert!(
+            format!("{err:#}").contains("failed to start package manager `internal`"),
+            "{err:#}"
+        );
+    }
+}
@@ -97,6 +97,10 @@ impl PackageManager for GitPm {
             url: self.git_url.clone(),
         })
     }
+
+    fn content_dir(&self) -> Option<&std::path::Path> {
+        self.inner.content_dir()
+    }
 }
 
 #[cfg(test)]
@@ -20,8 +20,10 @@
 //! to. Registry instances resolve their own ids, so those ids are never routed
 //! through the ecosystem transports.
 //!
-//! In-process for now — when PMs move out of process, [`PmRegistry`] becomes
-//! the seam that spawns and talks to them.
+//! The built-in instances run in-process. An [`ExternalPm`] is the
+//! out-of-process form: the same operations, answered by a separate binary
+//! over JSON-RPC (see [`external`] for the protocol), so an ecosystem or an
+//! internal registry can ship its own PM without changes to symposium.
 
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -32,12 +34,15 @@ use symposium_install::UpdateLevel;
 use crate::plugins::ParsedPlugin;
 
 mod cargo;
+pub mod external;
 mod git;
 pub mod layout;
 mod path;
 pub use cargo::{
-    CargoPm, LoadedWorkspace, WorkspaceCrate, WorkspaceDeps, file_mtime, workspace_dir_name,
+    CargoPm, DepKind, LoadedWorkspace, WorkspaceCrate, WorkspaceDeps, disk_cache_file, file_mtime,
+    workspace_dir_name,
 };
+pub use external::ExternalPm;
 pub use git::GitPm;
 pub use path::PathPm;
 
@@ -54,7 +59,9 @@ pub const ANY_VERSION: &str = "*";
 /// `version` may still be a *requirement* (a semver range, or
 /// [`ANY_VERSION`]); [`PackageManager::fetch`] canonicalizes it — the id on
 /// a [`FetchedPackage`] always names the exact resolved version.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 pub struct PackageId {
     pub pm: String,
     pub name: String,
@@ -173,6 +180,58 @@ pub trait PackageManager {
     fn registry_source(&self) -> Option<RegistrySource> {
         None
     }
+
+    /// The local directory a registry instance reads its entries from, once
+    /// its content is on disk. Lets [`Symposium::package_managers`] scan
+    /// registry plugins for `[[package-manager]]` declarations without going
+    /// through the async read path. `None` for PMs that are not a registry.
+    ///
+    /// [`Symposium::package_managers`]: crate::config::Symposium::package_managers
+    fn content_dir(&self) -> Option<&std::path::Path> {
+        None
+    }
+}
+
+/// A shared instance is a PM too, so one long-lived instance (an
+/// [`ExternalPm`] and the process behind it) can appear in every
+/// [`PmRegistry`] built for a workspace.
+#[async_trait::async_trait]
+impl<T: PackageManager + Send + Sync + ?Sized> PackageManager for Arc<T> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    async fn active_plugins(&self, deps: &[PackageId]) -> Vec<ParsedPlugin> {
+        (**self).active_plugins(deps).await
+    }
+
+    async fn load_plugin(&self, id: &PackageId) -> Vec<ParsedPlugin> {
+        (**self).load_plugin(id).await
+    }
+
+    async fn list_deps(&self) -> Result<Vec<PackageId>> {
+        (**self).list_deps().await
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<PluginInfo>> {
+        (**self).search(query).await
+    }
+
+    async fn fetch(&self, id: &PackageId, update: UpdateLevel) -> Result<FetchedPackage> {
+        (**self).fetch(id, update).await
+    }
+
+    async fn refresh(&self, update: UpdateLevel, force: bool) -> Result<bool> {
+        (**self).refresh(update, force).await
+    }
+
+    fn registry_source(&self) -> Option<RegistrySource> {
+        (**self).registry_source()
+    }
+
+    fn content_dir(&self) -> Option<&std::path::Path> {
+        (**self).content_dir()
+    }
 }
 
 /// Where a registry instance's content comes from — the git-vs-path
@@ -197,6 +256,32 @@ pub struct PmInstance {
     pub pm: Box<dyn PackageManager + Send + Sync>,
 }
 
+impl PmInstance {
+    /// An instance driving the external PM binary that `installation`
+    /// resolves to (see [`ExternalPm`]). The binary is acquired and spawned
+    /// on first use.
+    pub fn external(
+        sym: &crate::config::Symposium,
+        name: impl Into<String>,
+        installation: crate::plugins::Installation,
+        cwd: impl Into<PathBuf>,
+        trusted: bool,
+    ) -> Self {
+        let name = name.into();
+        Self {
+            pm: Box::new(ExternalPm::new(
+                name.clone(),
+                sym.install_context(),
+                installation,
+                cwd,
+                std::time::Duration::from_secs(sym.config.pm_timeout_secs),
+            )),
+            name,
+            trusted,
+        }
+    }
+}
+
 /// The active set of package-manager instances — one flat collection, the cargo
 /// transport alongside one instance per configured registry. Ids are dispatched
 /// by their `pm` component ([`PackageId::pm`]) to the instance that owns them.
@@ -230,22 +315,44 @@ impl PmRegistry {
 
     /// Union of `list_deps` across the instances — the workspace's full
     /// dependency set for discovery and `depends-on` predicate evaluation.
+    /// The cargo transport's failure fails the union; any other instance that
+    /// fails (an external PM that won't start) is skipped with a warning, so
+    /// one broken plugin-provided PM doesn't empty the dependency set.
     pub async fn list_deps(&self) -> Result<Vec<PackageId>> {
         let mut deps = Vec::new();
         for inst in &self.instances {
-            deps.extend(inst.pm.list_deps().await?);
+            match inst.pm.list_deps().await {
+                Ok(ids) => deps.extend(ids),
+                Err(e) if inst.name != CARGO_PM => {
+                    tracing::warn!(instance = %inst.name, error = %format!("{e:#}"), "cannot list dependencies, skipping");
+                }
+                Err(e) => return Err(e),
+            }
         }
         Ok(deps)
     }
 
     /// Load the plugin(s) an id maps to, asking every instance. Any instance may
-    /// contribute a plugin relevant to the id, so this can return several.
+    /// contribute a plugin relevant to the id, so this can return several. An
+    /// id naming no instance (a `source.<pm>` reference to a package manager
+    /// no plugin provides) is reported and loads nothing, and a loaded plugin
+    /// whose own `source.<pm>` names no instance is dropped with a warning.
     pub async fn load_plugin(&self, id: &PackageId) -> Vec<ParsedPlugin> {
+        if !self.instances.iter().any(|inst| inst.pm.name() == id.pm) {
+            tracing::info!(
+                report = %crate::report::ReportEvent::Warning {
+                    message: format!("no package manager named `{}` to load `{}`", id.pm, id.name),
+                },
+            );
+            return Vec::new();
+        }
         let mut out = Vec::new();
         for inst in &self.instances {
             out.extend(inst.pm.load_plugin(id).await);
         }
-        out
+        crate::plugins::reject_unknown_chained_sources(out, |pm| {
+            self.instances.iter().any(|inst| inst.pm.name() == pm)
+        })
     }
 
     /// Search every instance for packages matching `query`, tagged with the
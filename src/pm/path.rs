@@ -121,6 +121,10 @@ impl PackageManager for PathPm {
             dir: self.dir.clone(),
         })
     }
+
+    fn content_dir(&self) -> Option<&Path> {
+        Some(&self.dir)
+    }
 }
 
 #[cfg(test)]
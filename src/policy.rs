@@ -0,0 +1,405 @@
+//! System-wide configuration: the policy an administrator sets for every user
+//! on the machine.
+//!
+//! Read from `$SYMPOSIUM_SYSTEM_CONFIG`, or else `/etc/symposium/config.toml`:
+//!
+//! ```toml
+//! [[registry]]
+//! name = "acme"
+//! git = "https://github.com/acme/agent-plugins"
+//!
+//! [policy]
+//! allow-auto-enable-all = false
+//! deny-plugins = ["unvetted-plugin"]
+//! allow-sources = ["crates.io/", "https://github.com/acme/"]
+//! allow-shell-predicates = false
+//! allow-cargo-git = false
+//! ```
+//!
+//! Registries listed here are *required*: they load for every user, whatever
+//! the user's `[defaults]` say, and a user registry of the same name is
+//! ignored. The `[policy]` rules outrank every user and workspace decision:
+//! [`discovery`](crate::discovery) classifies a denied plugin as
+//! [`Denied`](crate::discovery::Enablement::Denied),
+//! [`active_plugins`](crate::plugins::active_plugins) never activates one,
+//! and [`acquire_installation`](crate::installation::acquire_installation)
+//! refuses a blocked source.
+//!
+//! A system config that exists but can't be read or fails to parse denies
+//! every plugin, so a typo or a permissions mistake cannot silently lift the
+//! policy.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use serde::Deserialize;
+use symposium_install::Source;
+
+use crate::config::RegistryConfig;
+use crate::crate_sources::normalize_crate_name;
+use crate::plugins::Installation;
+
+/// The environment variable naming the system config file.
+pub const SYSTEM_CONFIG_ENV: &str = "SYMPOSIUM_SYSTEM_CONFIG";
+
+/// Where the system config is read from when the variable is unset.
+pub const DEFAULT_SYSTEM_CONFIG: &str = "/etc/symposium/config.toml";
+
+/// The system config file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SystemConfig {
+    /// Registries every user loads. Relative `path` values resolve against
+    /// the system config's directory.
+    #[serde(default, rename = "registry")]
+    pub registries: Vec<RegistryConfig>,
+
+    #[serde(default)]
+    pub policy: Policy,
+
+    /// The file this was read from; `None` when there is none.
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+/// The `[policy]` table. Every default permits what symposium permits without
+/// a system config.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Policy {
+    /// Whether `[plugins] auto-enable = ["*"]` is honored.
+    #[serde(default = "default_true")]
+    pub allow_auto_enable_all: bool,
+
+    /// When set, the only plugin names that may run.
+    #[serde(default)]
+    pub allow_plugins: Option<Vec<String>>,
+
+    /// Plugin names that may never run.
+    #[serde(default)]
+    pub deny_plugins: Vec<String>,
+
+    /// When set, the only installation sources that may be acquired, as
+    /// prefixes of the source (see [`source_string`]) that match on whole
+    /// path segments (see [`source_segments`]).
+    #[serde(default)]
+    pub allow_sources: Option<Vec<String>>,
+
+    /// Installation sources that may never be acquired, as prefixes matched
+    /// like `allow_sources`.
+    #[serde(default)]
+    pub deny_sources: Vec<String>,
+
+    /// Whether `shell(...)` predicates run. When they may not, they evaluate
+    /// false.
+    #[serde(default = "default_true")]
+    pub allow_shell_predicates: bool,
+
+    /// Whether cargo installations from git (`source.cargo.git`) may be
+    /// acquired.
+    #[serde(default = "default_true")]
+    pub allow_cargo_git: bool,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            allow_auto_enable_all: true,
+            allow_plugins: None,
+            deny_plugins: Vec::new(),
+            allow_sources: None,
+            deny_sources: Vec::new(),
+            allow_shell_predicates: true,
+            allow_cargo_git: true,
+        }
+    }
+}
+
+impl Policy {
+    /// The policy for an unreadable system config: no plugin may run.
+    fn deny_all() -> Self {
+        Self {
+            allow_plugins: Some(Vec::new()),
+            ..Self::default()
+        }
+    }
+
+    /// May the plugin `name` run? Names match hyphen/underscore-insensitively,
+    /// like `[plugins]` entries; `deny-plugins` outranks `allow-plugins`.
+    pub fn permits_plugin(&self, name: &str) -> bool {
+        let norm = normalize_crate_name(name);
+        let listed = |names: &[String]| names.iter().any(|n| normalize_crate_name(n) == norm);
+        if listed(&self.deny_plugins) {
+            return false;
+        }
+        self.allow_plugins.as_deref().is_none_or(listed)
+    }
+
+    /// Refuse an installation whose source the policy blocks.
+    pub fn check_installation(&self, installation: &Installation) -> Result<()> {
+        let Some(source) = &installation.source else {
+            return Ok(());
+        };
+        if let Source::Cargo(cargo) = source
+            && cargo.git.is_some()
+            && !self.allow_cargo_git
+        {
+            bail!(
+                "installation `{}` is blocked by system policy: cargo installations from git are not allowed",
+                installation.name
+            );
+        }
+        let described = source_string(source);
+        let segments = source_segments(&described);
+        let matches = |prefixes: &[String]| {
+            !described.is_empty()
+                && prefixes
+                    .iter()
+                    .any(|p| segments.starts_with(&source_segments(p)))
+        };
+        if matches(&self.deny_sources)
+            || self
+                .allow_sources
+                .as_deref()
+                .is_some_and(|allowed| !matches(allowed))
+        {
+            bail!(
+                "installation `{}` is blocked by system policy: source `{described}` is not allowed",
+                installation.name
+            );
+        }
+        Ok(())
+    }
+}
+
+/// How `allow-sources` / `deny-sources` see a source: its git or GitHub URL,
+/// or `crates.io/<crate>` for a crate from crates.io. A source kind this
+/// build doesn't know matches no prefix.
+pub fn source_string(source: &Source) -> String {
+    match source {
+        Source::Cargo(cargo) => match &cargo.git {
+            Some(url) => url.clone(),
+            None => format!("crates.io/{}", cargo.crate_name),
+        },
+        Source::Github(github) => github.url.clone(),
+        _ => String::new(),
+    }
+}
+
+/// A source or source prefix split into the segments policy matching
+/// compares, so that spelling variants of one URL match alike: the scheme,
+/// any `user@`, a trailing `/` and a `.git` suffix are dropped, an scp-style
+/// `host:path` is read as `host/path`, and the host is lowercased (the whole
+/// URL on `github.com`, whose owners and repositories are case-insensitive).
+/// The first segment is the host, or `crates.io` for a crate.
+fn source_segments(source: &str) -> Vec<String> {
+    let rest = match source.split_once("://") {
+        Some((_, rest)) => rest.to_string(),
+        None => match source.split_once(':') {
+            Some((host, path)) if !host.contains('/') => format!("{host}/{path}"),
+            _ => source.to_string(),
+        },
+    };
+    let (authority, path) = rest.split_once('/').unwrap_or((&rest, ""));
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host)
+        .to_lowercase();
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let path = if host == "github.com" {
+        path.to_lowercase()
+    } else {
+        path.to_string()
+    };
+    std::iter::once(host)
+        .chain(path.split('/').map(str::to_string))
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// Read the system config: `$SYMPOSIUM_SYSTEM_CONFIG` if set, else
+/// [`DEFAULT_SYSTEM_CONFIG`]. A missing file is the default (no policy).
+pub fn load_system_config() -> SystemConfig {
+    let path = std::env::var_os(SYSTEM_CONFIG_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_SYSTEM_CONFIG));
+    load_system_config_from(&path)
+}
+
+/// Read the system config at `path`. Only a missing file means no policy;
+/// one that exists but can't be read denies every plugin, like a parse error.
+pub fn load_system_config_from(path: &Path) -> SystemConfig {
+    // Can't use tracing here — this runs before logging is initialized.
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return SystemConfig::default(),
+        Err(e) => {
+            eprintln!(
+                "warning: failed to read {}: {e}; denying every plugin until it is fixed",
+                path.display()
+            );
+            return SystemConfig {
+                policy: Policy::deny_all(),
+                ..SystemConfig::default()
+            };
+        }
+    };
+    let mut config = match toml::from_str::<SystemConfig>(&contents) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!(
+                "warning: failed to parse {}: {e}; denying every plugin until it is fixed",
+                path.display()
+            );
+            SystemConfig {
+                policy: Policy::deny_all(),
+                ..SystemConfig::default()
+            }
+        }
+    };
+    let base = path.parent().unwrap_or(Path::new("/"));
+    for registry in &mut config.registries {
+        if let Some(rel) = &registry.path {
+            registry.path = Some(base.join(rel).display().to_string());
+        }
+    }
+    config.path = Some(path.to_path_buf());
+    config
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use symposium_install::CargoSource;
+
+    fn installation(source: Source) -> Installation {
+        Installation {
+            name: "tool".into(),
+            requirements: Vec::new(),
+            install_commands: Vec::new(),
+            source: Some(source),
+            executable: Some("tool".into()),
+            script: None,
+            args: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn plugin_lists() {
+        let policy = Policy {
+            allow_plugins: Some(vec!["widget-lib".into(), "noisy".into()]),
+            deny_plugins: vec!["noisy".into()],
+            ..Policy::default()
+        };
+        assert!(policy.permits_plugin("widget_lib"));
+        assert!(!policy.permits_plugin("noisy"));
+        assert!(!policy.permits_plugin("other"));
+        assert!(Policy::default().permits_plugin("other"));
+    }
+
+    #[test]
+    fn installation_sources() {
+        let policy = Policy {
+            allow_sources: Some(vec!["crates.io/".into()]),
+            deny_sources: vec!["crates.io/evil".into()],
+            allow_cargo_git: false,
+            ..Policy::default()
+        };
+        let crates_io = |name: &str| installation(Source::Cargo(CargoSource::new(name)));
+        assert!(policy.check_installation(&crates_io("ripgrep")).is_ok());
+        assert!(policy.check_installation(&crates_io("evil")).is_err());
+        // Prefixes match whole segments: `crates.io/evil` is not `evil-tool`.
+        assert!(policy.check_installation(&crates_io("evil-tool")).is_ok());
+
+        let mut git = CargoSource::new("tool");
+        git.git = Some("https://example.com/tool".into());
+        let err = policy
+            .check_installation(&installation(Source::Cargo(git)))
+            .unwrap_err();
+        assert!(err.to_string().contains("from git"), "{err}");
+    }
+
+    #[test]
+    fn source_prefixes_match_normalized_segments() {
+        let policy = Policy {
+            deny_sources: vec!["https://github.com/evil/".into(), "example.com/acme".into()],
+            ..Policy::default()
+        };
+        let git = |url: &str| {
+            let mut cargo = CargoSource::new("tool");
+            cargo.git = Some(url.into());
+            policy.check_installation(&installation(Source::Cargo(cargo)))
+        };
+        for url in [
+            "https://github.com/evil/tool",
+            "https://GitHub.com/evil/tool",
+            "http://github.com/Evil/tool.git",
+            "ssh://git@github.com/evil/tool.git",
+            "git@github.com:evil/tool.git",
+            "https://github.com/evil",
+            "https://example.com/acme/tool.git",
+        ] {
+            assert!(git(url).is_err(), "{url} should be denied");
+        }
+        for url in [
+            "https://github.com/evil-twin/tool",
+            "https://github.com/good/evil",
+            "https://example.com/acme-evil/tool",
+            "https://example.com/ACME/tool",
+        ] {
+            assert!(git(url).is_ok(), "{url} should be allowed");
+        }
+    }
+
+    #[test]
+    fn loads_and_fails_closed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(
+            &path,
+            indoc! {r#"
+                [[registry]]
+                name = "acme"
+                path = "plugins"
+
+                [policy]
+                allow-auto-enable-all = false
+            "#},
+        )
+        .unwrap();
+        let config = load_system_config_from(&path);
+        assert!(!config.policy.allow_auto_enable_all);
+        assert_eq!(
+            config.registries[0].path.as_deref(),
+            Some(tmp.path().join("plugins").display().to_string().as_str())
+        );
+
+        std::fs::write(&path, "[policy]\nunknown = 1\n").unwrap();
+        assert!(
+            !load_system_config_from(&path)
+                .policy
+                .permits_plugin("anything")
+        );
+
+        assert_eq!(
+            load_system_config_from(&tmp.path().join("missing.toml")),
+            SystemConfig::default()
+        );
+
+        // A config that exists but can't be read (here, a directory) fails
+        // closed too.
+        let unreadable = tmp.path().join("dir.toml");
+        std::fs::create_dir(&unreadable).unwrap();
+        assert!(
+            !load_system_config_from(&unreadable)
+                .policy
+                .permits_plugin("anything")
+        );
+    }
+}
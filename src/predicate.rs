@@ -11,6 +11,12 @@
 //! - The `predicates` field uses **function-call** syntax:
 //!   - `depends-on(<atom>)` — a workspace dependency is present (and its version
 //!     satisfies the optional requirement); `depends-on(*)` matches any workspace.
+//!   - `depends-on(<atom>, transitive)` — the crate is anywhere in the resolved
+//!     cargo graph, not only a direct dependency.
+//!   - `dev-depends-on(<atom>)` / `build-depends-on(<atom>)` — a workspace member
+//!     lists the crate under `[dev-dependencies]` / `[build-dependencies]`.
+//!   - `feature(<crate>/<feature>)` — the crate is in the resolved graph with
+//!     that feature enabled.
 //!   - `shell(<command>)` — `sh -c <command>` exits 0.
 //!   - `path_exists(<arg>)` — `<arg>` exists on disk, falling back to a `$PATH`
 //!     lookup for bare names.
@@ -30,7 +36,7 @@ use std::sync::Arc;
 use anyhow::{Context, Result, bail};
 use symposium_sdk::predicate::CustomPredicateEvent;
 
-use crate::pm::PackageId;
+use crate::pm::{DepKind, LoadedWorkspace, PackageId, WorkspaceCrate};
 use crate::predicate_cache::{
     CacheEntry, CacheTtl, Fingerprints, PredicateCache, WatchSet, cache_key, now_ms,
 };
@@ -40,6 +46,9 @@ use crate::predicate_cache::{
 /// can never squat on it.
 pub const BUILTIN_PREDICATE_NAMES: &[&str] = &[
     "depends-on",
+    "dev-depends-on",
+    "build-depends-on",
+    "feature",
     "crate",
     "shell",
     "path_exists",
@@ -52,13 +61,18 @@ pub const BUILTIN_PREDICATE_NAMES: &[&str] = &[
 
 /// The evaluation environment a predicate is checked against.
 ///
-/// The workspace dependency list is passed explicitly; the OS environment
+/// The workspace dependency list is passed explicitly, and the resolved cargo
+/// graph (dependency kinds, features, transitive crates) is attached with
+/// [`with_workspace`](Self::with_workspace); the OS environment
 /// (`shell`, `path_exists`, `env`) is read ambiently at evaluation time. Custom
 /// (plugin-defined) predicates are resolved entries whose results are cached
 /// for the lifetime of the context.
 #[derive(Debug)]
 pub struct PredicateContext<'a> {
     pub deps: &'a [PackageId],
+    /// The resolved cargo workspace, for the predicates that look past the
+    /// direct dependency ids. Without it they only see `deps`.
+    workspace: Option<Arc<LoadedWorkspace>>,
     /// Whether the plugin currently being evaluated is defined by a member
     /// of the active workspace. This is *provenance*, not a workspace fact:
     /// the loader stamps it per plugin (via `ParsedPlugin::applies`) before
@@ -69,8 +83,19 @@ pub struct PredicateContext<'a> {
     /// ([`Plugin::requires_use`](crate::plugins::Plugin::requires_use)) is
     /// dormant unless it is named here.
     used_names: std::collections::HashSet<String>,
+    /// Whether `shell(...)` may run. The system policy can forbid it, in
+    /// which case every `shell` predicate evaluates false.
+    shell_allowed: bool,
+    /// Whether resolution may only use what is already on disk: custom
+    /// predicate installations and git skill sources are neither fetched nor
+    /// installed. Set by a dry-run sync.
+    cache_only: bool,
     custom_entries: std::collections::HashMap<String, ResolvedPredicateEntry>,
     custom_cache: std::collections::HashMap<(String, String), CustomPredicateResult>,
+    /// Custom predicate names evaluated while no definition was registered.
+    /// Active-plugin resolution drains this to decide whether another round
+    /// could register them, and reports what is left.
+    unresolved_custom: std::collections::BTreeSet<String>,
     /// The current (possibly mutated) view of the on-disk cache.
     disk_cache: Option<Arc<PredicateCache>>,
     /// The load-time snapshot. `Arc::ptr_eq(&disk_cache, &disk_cache_original)`
@@ -83,10 +108,14 @@ impl<'a> PredicateContext<'a> {
     pub fn new(deps: &'a [PackageId]) -> Self {
         Self {
             deps,
+            workspace: None,
             workspace_member: false,
             used_names: std::collections::HashSet::new(),
+            shell_allowed: true,
+            cache_only: false,
             custom_entries: std::collections::HashMap::new(),
             custom_cache: std::collections::HashMap::new(),
+            unresolved_custom: std::collections::BTreeSet::new(),
             disk_cache: None,
             disk_cache_original: None,
         }
@@ -102,6 +131,22 @@ impl<'a> PredicateContext<'a> {
         }
     }
 
+    /// Attach the resolved cargo workspace, so `depends-on(.., transitive)`,
+    /// `dev-depends-on`, `build-depends-on`, and `feature` can see the full
+    /// graph.
+    pub fn with_workspace(mut self, workspace: Option<Arc<LoadedWorkspace>>) -> Self {
+        self.workspace = workspace;
+        self
+    }
+
+    /// Every crate in the attached workspace graph: direct dependencies first,
+    /// then transitive ones. Empty without a workspace.
+    fn graph_crates(&self) -> impl Iterator<Item = &WorkspaceCrate> {
+        self.workspace
+            .iter()
+            .flat_map(|ws| ws.crates.iter().chain(&ws.transitive))
+    }
+
     /// Record the plugin names the applicable `[plugins] use` entries enable.
     /// Matching is hyphen/underscore-insensitive, like every other name
     /// comparison against user-typed config.
@@ -152,36 +197,76 @@ impl<'a> PredicateContext<'a> {
         self.workspace_member = workspace_member;
     }
 
+    /// Allow or forbid `shell(...)` predicates, per the system
+    /// [policy](crate::policy::Policy::allow_shell_predicates).
+    pub fn set_shell_allowed(&mut self, allowed: bool) {
+        self.shell_allowed = allowed;
+    }
+
+    /// Restrict resolution to what is already on disk (see the `cache_only`
+    /// field).
+    pub fn set_cache_only(&mut self, cache_only: bool) {
+        self.cache_only = cache_only;
+    }
+
+    /// May resolution only use what is already on disk?
+    pub fn is_cache_only(&self) -> bool {
+        self.cache_only
+    }
+
+    /// Is a custom predicate named `name` registered in this context?
+    pub fn has_custom_predicate(&self, name: &str) -> bool {
+        self.custom_entries.contains_key(name)
+    }
+
+    /// Register (or replace) a custom predicate definition, dropping any
+    /// in-memory results computed for that name.
+    pub fn register_custom_predicate(&mut self, name: String, entry: ResolvedPredicateEntry) {
+        self.custom_cache.retain(|(n, _), _| *n != name);
+        self.unresolved_custom.remove(&name);
+        self.custom_entries.insert(name, entry);
+    }
+
+    /// Drain the names of custom predicates evaluated while unregistered.
+    pub fn take_unresolved_custom_predicates(&mut self) -> std::collections::BTreeSet<String> {
+        std::mem::take(&mut self.unresolved_custom)
+    }
+
     /// Evaluate a custom predicate by name and argument, returning the cached
-    /// result if already computed. Consults the in-memory cache first, then
+    /// result if already computed. An unregistered name evaluates to false
+    /// without caching, and is recorded as unresolved. Consults the in-memory cache first, then
     /// the on-disk cache (when present). On miss, spawns the predicate and
     /// updates both caches according to the emitted watch events.
     fn evaluate_custom(&mut self, name: &str, arg: &str) -> bool {
+        if !self.custom_entries.contains_key(name) {
+            tracing::debug!(predicate = name, "custom predicate not registered");
+            self.unresolved_custom.insert(name.to_string());
+            return false;
+        }
+
         let mem_key = (name.to_string(), arg.to_string());
         if let Some(result) = self.custom_cache.get(&mem_key) {
             return result.passed;
         }
 
         let disk_key = cache_key(name, arg);
-        if let Some(cache) = &self.disk_cache {
-            if let Some(entry) = cache.get(&disk_key) {
-                if !entry.is_time_expired(now_ms())
-                    && Fingerprints::capture(&watch_set_from_entry(entry)) == entry.fingerprints
-                {
-                    // Disk hit. Populate the in-memory cache with a result
-                    // that has no events; the events belong to the run that
-                    // originally produced this entry.
-                    let passed = entry.result;
-                    self.custom_cache.insert(
-                        mem_key,
-                        CustomPredicateResult {
-                            passed,
-                            events: Vec::new(),
-                        },
-                    );
-                    return passed;
-                }
-            }
+        if let Some(cache) = &self.disk_cache
+            && let Some(entry) = cache.get(&disk_key)
+            && !entry.is_time_expired(now_ms())
+            && Fingerprints::capture(&watch_set_from_entry(entry)) == entry.fingerprints
+        {
+            // Disk hit. Populate the in-memory cache with a result
+            // that has no events; the events belong to the run that
+            // originally produced this entry.
+            let passed = entry.result;
+            self.custom_cache.insert(
+                mem_key,
+                CustomPredicateResult {
+                    passed,
+                    events: Vec::new(),
+                },
+            );
+            return passed;
         }
 
         let result = run_custom_predicate(&self.custom_entries, name, arg);
@@ -220,6 +305,12 @@ pub enum Predicate {
     DependsOn(String, Option<semver::VersionReq>),
     /// `depends-on(*)` / bare `*` — matches any workspace (even with zero deps).
     DependsOnWildcard,
+    /// `depends-on(<name>, transitive)`, `dev-depends-on(<name>)`,
+    /// `build-depends-on(<name>)` — a dependency matches within `scope`.
+    DependsOnScoped(String, Option<semver::VersionReq>, DepScope),
+    /// `feature(<crate>/<feature>)` — the crate is in the resolved graph with
+    /// the feature enabled.
+    Feature(String, String),
     /// `shell(<command>)` — passes when `sh -c <command>` exits 0.
     Shell(String),
     /// `path_exists(<arg>)` — passes when `<arg>` exists (disk, then `$PATH`).
@@ -254,6 +345,28 @@ impl Predicate {
                 .iter()
                 .any(|dep| dep_matches(dep, name, version_req.as_ref())),
             Predicate::DependsOnWildcard => true,
+            Predicate::DependsOnScoped(name, version_req, scope) => {
+                let req = version_req.as_ref();
+                let kind = match scope {
+                    DepScope::Transitive => {
+                        return ctx.deps.iter().any(|dep| dep_matches(dep, name, req))
+                            || ctx.graph_crates().any(|c| crate_matches(c, name, req));
+                    }
+                    DepScope::Dev => DepKind::Dev,
+                    DepScope::Build => DepKind::Build,
+                };
+                ctx.workspace
+                    .iter()
+                    .flat_map(|ws| &ws.crates)
+                    .any(|c| c.kinds.contains(&kind) && crate_matches(c, name, req))
+            }
+            Predicate::Feature(krate, feature) => ctx
+                .graph_crates()
+                .any(|c| c.name == *krate && c.features.contains(feature)),
+            Predicate::Shell(cmd) if !ctx.shell_allowed => {
+                tracing::debug!(command = %cmd, "shell predicate blocked by system policy");
+                false
+            }
             Predicate::Shell(cmd) => run_shell(cmd),
             Predicate::PathExists(arg) => path_exists(arg),
             Predicate::Env(name, expected) => env_matches(name, expected.as_deref()),
@@ -269,7 +382,9 @@ impl Predicate {
     /// anywhere (including inside combinators and negations).
     pub fn references_dep(&self, name: &str) -> bool {
         match self {
-            Predicate::DependsOn(n, _) => n == name,
+            Predicate::DependsOn(n, _)
+            | Predicate::DependsOnScoped(n, ..)
+            | Predicate::Feature(n, _) => n == name,
             Predicate::Not(p) => p.references_dep(name),
             Predicate::Any(v) | Predicate::All(v) => v.iter().any(|p| p.references_dep(name)),
             Predicate::Custom { .. } => false,
@@ -281,7 +396,10 @@ impl Predicate {
     /// `depends-on(*)`).
     pub fn mentions_dep(&self) -> bool {
         match self {
-            Predicate::DependsOn(..) | Predicate::DependsOnWildcard => true,
+            Predicate::DependsOn(..)
+            | Predicate::DependsOnWildcard
+            | Predicate::DependsOnScoped(..)
+            | Predicate::Feature(..) => true,
             Predicate::Not(p) => p.mentions_dep(),
             Predicate::Any(v) | Predicate::All(v) => v.iter().any(Predicate::mentions_dep),
             Predicate::Custom { .. } => false,
@@ -294,7 +412,9 @@ impl Predicate {
     /// Non-allocating — used on the hook hot path.
     pub fn has_concrete_dep(&self) -> bool {
         match self {
-            Predicate::DependsOn(..) => true,
+            Predicate::DependsOn(..) | Predicate::DependsOnScoped(..) | Predicate::Feature(..) => {
+                true
+            }
             Predicate::Not(p) => p.has_concrete_dep(),
             Predicate::Any(v) | Predicate::All(v) => v.iter().any(Predicate::has_concrete_dep),
             Predicate::Custom { .. } => false,
@@ -309,7 +429,9 @@ impl Predicate {
     /// predicates are a no-op — their names are dynamic.
     pub fn collect_dep_names(&self, out: &mut std::collections::BTreeSet<String>) {
         match self {
-            Predicate::DependsOn(name, _) => {
+            Predicate::DependsOn(name, _)
+            | Predicate::DependsOnScoped(name, ..)
+            | Predicate::Feature(name, _) => {
                 out.insert(name.clone());
             }
             Predicate::Not(p) => p.collect_dep_names(out),
@@ -324,6 +446,17 @@ impl Predicate {
     }
 }
 
+/// Where a [`Predicate::DependsOnScoped`] looks for its dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepScope {
+    /// Anywhere in the resolved graph (`depends-on(<atom>, transitive)`).
+    Transitive,
+    /// A direct `[dev-dependencies]` entry of a member (`dev-depends-on`).
+    Dev,
+    /// A direct `[build-dependencies]` entry of a member (`build-depends-on`).
+    Build,
+}
+
 /// A list of predicates, ANDed together.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct PredicateSet {
@@ -401,6 +534,11 @@ fn dep_matches(dep: &PackageId, name: &str, req: Option<&semver::VersionReq>) ->
         && req.is_none_or(|req| semver::Version::parse(&dep.version).is_ok_and(|v| req.matches(&v)))
 }
 
+/// [`dep_matches`] for a crate of the resolved cargo graph.
+fn crate_matches(krate: &WorkspaceCrate, name: &str, req: Option<&semver::VersionReq>) -> bool {
+    krate.name == name && req.is_none_or(|req| req.matches(&krate.version))
+}
+
 // --- the `depends-on` field: a list of dependency atoms, OR-combined ---
 
 /// The parsed `depends-on = [...]` field — a list of crate atoms. Lowers to a
@@ -503,7 +641,10 @@ fn parse(input: &str) -> Result<Predicate> {
     let arg = trimmed[open + 1..trimmed.len() - 1].trim();
 
     match name {
-        "depends-on" => parse_dep_atom(arg),
+        "depends-on" => parse_depends_on(arg),
+        "dev-depends-on" => parse_scoped_dep(arg, DepScope::Dev),
+        "build-depends-on" => parse_scoped_dep(arg, DepScope::Build),
+        "feature" => parse_feature(arg),
         "crate" => bail!("`crate({arg})` is no longer supported; use `depends-on({arg})` instead"),
         "shell" => Ok(Predicate::Shell(arg.to_string())),
         "path_exists" => Ok(Predicate::PathExists(arg.to_string())),
@@ -550,6 +691,42 @@ pub fn parse_comma_separated(input: &str) -> Result<Vec<Predicate>> {
         .collect()
 }
 
+/// `depends-on(<atom>)` or `depends-on(<atom>, transitive)`.
+fn parse_depends_on(arg: &str) -> Result<Predicate> {
+    let parts = split_top_level(arg);
+    match parts.as_slice() {
+        [atom] => parse_dep_atom(atom),
+        [atom, modifier] if modifier.trim() == "transitive" => {
+            parse_scoped_dep(atom, DepScope::Transitive)
+        }
+        [_, modifier] => bail!(
+            "unknown `depends-on` modifier {:?} (expected `transitive`)",
+            modifier.trim()
+        ),
+        _ => bail!("`depends-on(...)` takes a dependency and an optional `transitive`"),
+    }
+}
+
+/// A dependency atom restricted to `scope`. `*` has no scoped form.
+fn parse_scoped_dep(atom: &str, scope: DepScope) -> Result<Predicate> {
+    match parse_dep_atom(atom)? {
+        Predicate::DependsOn(name, req) => Ok(Predicate::DependsOnScoped(name, req, scope)),
+        _ => bail!("`*` cannot be combined with a dependency kind or `transitive`"),
+    }
+}
+
+/// `feature(<crate>/<feature>)`.
+fn parse_feature(arg: &str) -> Result<Predicate> {
+    let Some((krate, feature)) = arg.split_once('/') else {
+        bail!("`feature(...)` expects `<crate>/<feature>`, got {arg:?}");
+    };
+    let (krate, feature) = (krate.trim(), feature.trim());
+    if krate.is_empty() || feature.is_empty() {
+        bail!("`feature(...)` expects `<crate>/<feature>`, got {arg:?}");
+    }
+    Ok(Predicate::Feature(krate.to_string(), feature.to_string()))
+}
+
 fn parse_env(arg: &str) -> Result<Predicate> {
     match arg.split_once('=') {
         Some((name, value)) => {
@@ -784,6 +961,15 @@ impl std::fmt::Display for Predicate {
             Predicate::DependsOn(name, None) => write!(f, "depends-on({name})"),
             Predicate::DependsOn(name, Some(req)) => write!(f, "depends-on({name}{req})"),
             Predicate::DependsOnWildcard => write!(f, "depends-on(*)"),
+            Predicate::DependsOnScoped(name, req, scope) => {
+                let req = req.as_ref().map(|r| r.to_string()).unwrap_or_default();
+                match scope {
+                    DepScope::Transitive => write!(f, "depends-on({name}{req}, transitive)"),
+                    DepScope::Dev => write!(f, "dev-depends-on({name}{req})"),
+                    DepScope::Build => write!(f, "build-depends-on({name}{req})"),
+                }
+            }
+            Predicate::Feature(krate, feature) => write!(f, "feature({krate}/{feature})"),
             Predicate::Shell(cmd) => write!(f, "shell({cmd})"),
             Predicate::PathExists(arg) => write!(f, "path_exists({arg})"),
             Predicate::Env(name, None) => write!(f, "env({name})"),
@@ -1071,6 +1257,109 @@ mod tests {
         assert!(parse("depends-on(*)").unwrap().evaluate(&mut ctx(&[])));
     }
 
+    /// A workspace that depends on `axum` (normal) and `proptest` (dev);
+    /// `tokio` comes in only through `axum`.
+    fn graph() -> Arc<LoadedWorkspace> {
+        let krate = |name: &str, version: &str| {
+            WorkspaceCrate::new(name.into(), semver::Version::parse(version).unwrap(), None)
+        };
+        Arc::new(LoadedWorkspace {
+            root: "/ws".into(),
+            crates: vec![
+                krate("axum", "0.7.5").with_kinds(vec![DepKind::Normal]),
+                krate("proptest", "1.4.0").with_kinds(vec![DepKind::Dev]),
+            ],
+            transitive: vec![
+                krate("tokio", "1.38.0")
+                    .with_features(vec!["macros".into(), "rt-multi-thread".into()]),
+            ],
+            members: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn parse_scoped_dependency_forms() {
+        assert_eq!(
+            parse("depends-on(tokio, transitive)").unwrap(),
+            Predicate::DependsOnScoped("tokio".into(), None, DepScope::Transitive)
+        );
+        assert_eq!(
+            parse("dev-depends-on(proptest)").unwrap(),
+            Predicate::DependsOnScoped("proptest".into(), None, DepScope::Dev)
+        );
+        assert_eq!(
+            parse("feature(tokio/rt-multi-thread)").unwrap(),
+            Predicate::Feature("tokio".into(), "rt-multi-thread".into())
+        );
+        assert!(parse("depends-on(tokio, direct)").is_err());
+        assert!(parse("depends-on(*, transitive)").is_err());
+        assert!(parse("dev-depends-on(*)").is_err());
+        assert!(parse("feature(tokio)").is_err());
+        assert!(parse("feature(/rt)").is_err());
+        assert!(validate_custom_predicate_name("feature").is_err());
+    }
+
+    #[test]
+    fn evaluate_transitive_dependency() {
+        let w = ws(&[("axum", "0.7.5"), ("proptest", "1.4.0")]);
+        let eval = |s: &str| {
+            parse(s)
+                .unwrap()
+                .evaluate(&mut ctx(&w).with_workspace(Some(graph())))
+        };
+        // Plain `depends-on` stays direct-only.
+        assert!(!eval("depends-on(tokio)"));
+        assert!(eval("depends-on(tokio, transitive)"));
+        assert!(eval("depends-on(tokio>=1.30, transitive)"));
+        assert!(!eval("depends-on(tokio<1, transitive)"));
+        // Direct dependencies are part of the transitive set.
+        assert!(eval("depends-on(axum, transitive)"));
+        assert!(!eval("depends-on(hyper, transitive)"));
+        // Without the graph, only the direct ids are visible.
+        assert!(
+            !parse("depends-on(tokio, transitive)")
+                .unwrap()
+                .evaluate(&mut ctx(&w))
+        );
+        assert!(
+            parse("depends-on(axum, transitive)")
+                .unwrap()
+                .evaluate(&mut ctx(&w))
+        );
+    }
+
+    #[test]
+    fn evaluate_dependency_kinds_and_features() {
+        let w = ws(&[("axum", "0.7.5"), ("proptest", "1.4.0")]);
+        let eval = |s: &str| {
+            parse(s)
+                .unwrap()
+                .evaluate(&mut ctx(&w).with_workspace(Some(graph())))
+        };
+        assert!(eval("dev-depends-on(proptest)"));
+        assert!(eval("dev-depends-on(proptest>=1)"));
+        assert!(!eval("dev-depends-on(axum)"));
+        assert!(!eval("build-depends-on(proptest)"));
+        // A transitive crate is nobody's dev-dependency.
+        assert!(!eval("dev-depends-on(tokio)"));
+
+        assert!(eval("feature(tokio/rt-multi-thread)"));
+        assert!(!eval("feature(tokio/io-uring)"));
+        assert!(!eval("feature(axum/rt-multi-thread)"));
+        assert!(eval("all(depends-on(axum), feature(tokio/macros))"));
+    }
+
+    #[test]
+    fn scoped_predicates_reference_their_crate() {
+        let p = PredicateSet::parse("feature(tokio/rt), dev-depends-on(proptest)").unwrap();
+        assert!(p.references_dep("tokio"));
+        assert!(p.references_dep("proptest"));
+        assert!(p.has_concrete_dep());
+        let mut names = std::collections::BTreeSet::new();
+        p.collect_dep_names(&mut names);
+        assert_eq!(names.into_iter().collect::<Vec<_>>(), ["proptest", "tokio"]);
+    }
+
     #[test]
     fn evaluate_combinators() {
         let w = ws(&[("serde", "1.0.0")]);
@@ -1091,6 +1380,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shell_blocked_by_policy() {
+        let w = ws(&[]);
+        let mut ctx = ctx(&w);
+        assert!(parse("shell(true)").unwrap().evaluate(&mut ctx));
+        ctx.set_shell_allowed(false);
+        assert!(!parse("shell(true)").unwrap().evaluate(&mut ctx));
+    }
+
     #[test]
     fn path_exists_empty_is_false() {
         // `path_exists()` must not resolve to a `$PATH` dir via `dir.join("")`.
@@ -1147,6 +1445,11 @@ mod tests {
             "not(depends-on(serde))",
             "any(depends-on(a), path_exists(b))",
             "all(depends-on(a), not(env(CI)))",
+            "depends-on(tokio, transitive)",
+            "depends-on(tokio>=1.0, transitive)",
+            "dev-depends-on(proptest)",
+            "build-depends-on(cc^1)",
+            "feature(tokio/rt-multi-thread)",
         ] {
             let p = parse(input).unwrap();
             assert_eq!(p.to_string(), input, "display drift: {input}");
@@ -1344,6 +1647,13 @@ mod tests {
             arg: "x".into(),
         };
         assert!(!pred.evaluate(&mut ctx));
+        assert_eq!(
+            ctx.take_unresolved_custom_predicates()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["nonexistent".to_string()]
+        );
+        assert!(ctx.take_unresolved_custom_predicates().is_empty());
     }
 
     #[test]
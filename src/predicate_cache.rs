@@ -52,10 +52,11 @@ pub struct WatchSet {
 
 /// How long the predicate result may be cached, independent of file / env
 /// invalidation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum CacheTtl {
     /// No `WatchTime` events were emitted; the result never becomes stale by
     /// time alone.
+    #[default]
     Forever,
     /// The shortest `WatchTime(N>0)` reported by the predicate.
     For(Duration),
@@ -63,12 +64,6 @@ pub enum CacheTtl {
     Never,
 }
 
-impl Default for CacheTtl {
-    fn default() -> Self {
-        Self::Forever
-    }
-}
-
 impl WatchSet {
     /// Union every event from a single predicate execution into one set.
     pub fn from_events(events: &[CustomPredicateEvent]) -> Self {
@@ -0,0 +1,206 @@
+//! Crate-aware context for `UserPromptSubmit`.
+//!
+//! A prompt is matched against the workspace's dependency names and the names
+//! and descriptions of the skills applicable to the workspace. Each skill that
+//! fits is pointed at by its installed location — agents seldom open a skill
+//! just because it is listed, but they do follow a path handed to them. The
+//! pointers stay within the `prompt-context-budget`, and the prompt itself is
+//! only ever matched, never stored.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use crate::agents::Agent;
+use crate::skills::SkillWithGroupContext;
+
+/// An applicable skill and where sync installed it for the agent.
+#[derive(Debug, Clone)]
+pub(crate) struct InstalledSkill {
+    pub name: String,
+    pub description: String,
+    /// The installed skill directory, relative to the workspace root.
+    pub dir: PathBuf,
+}
+
+/// Pair each applicable skill with its installed directory for `agent`,
+/// following the naming `sync` uses: the plain `<name>/` slot, or
+/// `<name>-<origin-hash>/` when that slot was taken. A workspace skill that
+/// already sits in the agent's directory counts as installed in place. Skills
+/// with no installed copy are left out.
+pub(crate) fn installed_skills(
+    agent: &Agent,
+    project_root: &Path,
+    applicable: &[SkillWithGroupContext],
+) -> Vec<InstalledSkill> {
+    let mut installed = Vec::new();
+    for entry in applicable {
+        let name = entry.skill.name();
+        let plain = agent.project_skill_dir(project_root, name);
+        let hashed =
+            agent.project_skill_dir(project_root, &format!("{name}-{}", entry.origin_hash));
+        let in_place = || match (entry.skill.path.parent(), plain.canonicalize()) {
+            (Some(source), Ok(plain)) => source.canonicalize().is_ok_and(|s| s == plain),
+            _ => false,
+        };
+
+        let dir = if crate::sync::has_symposium_marker(&hashed) {
+            hashed
+        } else if crate::sync::has_symposium_marker(&plain) || in_place() {
+            plain
+        } else {
+            continue;
+        };
+        let dir = dir
+            .strip_prefix(project_root)
+            .map(Path::to_path_buf)
+            .unwrap_or(dir);
+        if installed.iter().any(|s: &InstalledSkill| s.dir == dir) {
+            continue;
+        }
+        installed.push(InstalledSkill {
+            name: name.to_string(),
+            description: entry
+                .skill
+                .frontmatter
+                .get("description")
+                .cloned()
+                .unwrap_or_default(),
+            dir,
+        });
+    }
+    installed
+}
+
+/// The skills relevant to `prompt`: those it names outright, then those whose
+/// name or description mentions a workspace dependency the prompt names.
+pub(crate) fn matching_skills<'a>(
+    prompt: &str,
+    dep_names: &[String],
+    skills: &'a [InstalledSkill],
+) -> Vec<&'a InstalledSkill> {
+    let prompt_words = words(prompt);
+    let mentioned_deps: BTreeSet<String> = dep_names
+        .iter()
+        .map(|name| fold(name))
+        .filter(|name| prompt_words.contains(name))
+        .collect();
+
+    let (named, rest): (Vec<_>, Vec<_>) = skills
+        .iter()
+        .partition(|skill| prompt_words.contains(&fold(&skill.name)));
+    let via_deps = rest.into_iter().filter(|skill| {
+        let skill_words = words_and_parts(&format!("{} {}", skill.name, skill.description));
+        mentioned_deps.iter().any(|dep| skill_words.contains(dep))
+    });
+    named.into_iter().chain(via_deps).collect()
+}
+
+/// One pointer line per skill, in order, stopping before the total would
+/// exceed `budget` characters; returns the context and how many skills it
+/// points at. `None` when nothing fits.
+pub(crate) fn render(skills: &[&InstalledSkill], budget: usize) -> Option<(String, usize)> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut used = 0;
+    for skill in skills {
+        let line = format!(
+            "The `{}` skill is available at {}.",
+            skill.name,
+            skill.dir.display()
+        );
+        let cost = line.chars().count() + usize::from(!lines.is_empty());
+        if used + cost > budget {
+            break;
+        }
+        used += cost;
+        lines.push(line);
+    }
+    (!lines.is_empty()).then(|| (lines.join("\n"), lines.len()))
+}
+
+/// Crate names treat `-` and `_` alike; fold both to `_` and lowercase.
+fn fold(word: &str) -> String {
+    word.to_lowercase().replace('-', "_")
+}
+
+/// The folded words of `text`: runs of alphanumerics, `-` and `_`.
+fn words(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+        .filter(|w| !w.is_empty())
+        .map(fold)
+        .collect()
+}
+
+/// [`words`] plus each word's `_`-separated parts, so the dependency `serde`
+/// matches a skill named `serde-guidance`.
+fn words_and_parts(text: &str) -> BTreeSet<String> {
+    let mut all = words(text);
+    let parts: Vec<String> = all
+        .iter()
+        .flat_map(|w| w.split('_').filter(|p| !p.is_empty()).map(str::to_string))
+        .collect();
+    all.extend(parts);
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skill(name: &str, description: &str) -> InstalledSkill {
+        InstalledSkill {
+            name: name.to_string(),
+            description: description.to_string(),
+            dir: PathBuf::from(".claude/skills").join(name),
+        }
+    }
+
+    fn names(matches: &[&InstalledSkill]) -> Vec<String> {
+        matches.iter().map(|s| s.name.clone()).collect()
+    }
+
+    #[test]
+    fn matches_named_skills_then_skills_for_named_dependencies() {
+        let skills = [
+            skill("serde-guidance", "Tips for serde derive macros"),
+            skill("tokio-guidance", "Async runtime advice"),
+            skill("axum", "Routing with axum"),
+        ];
+        let deps = ["serde".to_string(), "tokio".to_string(), "axum".to_string()];
+
+        let matches = matching_skills("Add a Serde derive to the axum handler", &deps, &skills);
+        assert_eq!(names(&matches), ["axum", "serde-guidance"]);
+
+        let matches = matching_skills("use the tokio_guidance skill", &deps, &skills);
+        assert_eq!(names(&matches), ["tokio-guidance"]);
+
+        assert!(matching_skills("fix the typo in README", &deps, &skills).is_empty());
+    }
+
+    #[test]
+    fn dependencies_outside_the_workspace_do_not_match() {
+        let skills = [skill("serde-guidance", "Tips for serde")];
+        assert!(matching_skills("add serde", &[], &skills).is_empty());
+    }
+
+    #[test]
+    fn render_stays_within_budget() {
+        let skills = [skill("axum", ""), skill("serde-guidance", "")];
+        let refs: Vec<&InstalledSkill> = skills.iter().collect();
+
+        let (all, count) = render(&refs, 1000).unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            all,
+            "The `axum` skill is available at .claude/skills/axum.\n\
+             The `serde-guidance` skill is available at .claude/skills/serde-guidance."
+        );
+
+        let (first, count) = render(&refs, 60).unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(
+            first,
+            "The `axum` skill is available at .claude/skills/axum."
+        );
+        assert_eq!(render(&refs, 10), None);
+    }
+}
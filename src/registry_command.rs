@@ -0,0 +1,362 @@
+//! `cargo agents registry` — manage the `[[registry]]` entries and the
+//! built-in registries' `[defaults]` toggles.
+//!
+//! Every change goes through [`Symposium::edit_config`], so the rest of the
+//! user's `config.toml` — formatting and comments included — is left as it
+//! was. A new or re-pinned source is fetched once and checked with
+//! [`validate_source_dir`](crate::plugins::validate_source_dir) before it is
+//! recorded.
+//!
+//! The built-in registries (`symposium-recommendations`, `user-plugins`) are
+//! toggled rather than edited: `remove` turns one off in `[defaults]`, and
+//! `add` with no source turns it back on. Registries the system config
+//! requires can't be changed from here.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use symposium_install::UpdateLevel;
+use symposium_install::git::{GitSource, parse_git_url};
+
+use crate::config::{RegistryConfig, Symposium, builtin_registries};
+use crate::plugins::ValidationResult;
+use crate::report::ReportEvent;
+
+/// What to add with `cargo agents registry add`.
+#[derive(Debug, Clone, Default)]
+pub struct AddOptions {
+    pub git: Option<String>,
+    pub path: Option<String>,
+    pub no_auto_update: bool,
+}
+
+/// `cargo agents registry list`: every registry, including the built-in ones
+/// that are turned off.
+pub fn list(sym: &Symposium) {
+    let emit = |cfg: &RegistryConfig, origin: &str, enabled: bool| {
+        tracing::info!(
+            report = %ReportEvent::RegistryListed {
+                name: cfg.name.clone(),
+                source: source_of(cfg),
+                origin: origin.to_string(),
+                enabled,
+                auto_update: cfg.auto_update,
+            },
+        );
+    };
+    for cfg in &sym.system.registries {
+        emit(cfg, "system", true);
+    }
+    for cfg in builtin_registries() {
+        if !is_required(sym, &cfg.name) {
+            emit(&cfg, "builtin", sym.config.defaults.is_enabled(&cfg.name));
+        }
+    }
+    for cfg in &sym.config.registries {
+        // A user registry named like a required one is ignored.
+        emit(cfg, "configured", !is_required(sym, &cfg.name));
+    }
+}
+
+/// `cargo agents registry add`.
+pub async fn add(sym: &mut Symposium, cwd: &Path, name: &str, options: &AddOptions) -> Result<()> {
+    if is_required(sym, name) {
+        bail!("`{name}` is required by the system config");
+    }
+    if is_builtin(name) {
+        if options.git.is_some() || options.path.is_some() {
+            bail!("`{name}` is a built-in registry; choose another name");
+        }
+        return set_builtin(sym, name, true);
+    }
+    if sym.config.registries.iter().any(|r| r.name == name) {
+        bail!("a registry named `{name}` already exists");
+    }
+
+    let cfg = RegistryConfig {
+        name: name.to_string(),
+        git: options.git.clone(),
+        // Stored absolute: a relative path in the config would resolve against
+        // the config directory, not where the command ran.
+        path: options
+            .path
+            .as_ref()
+            .map(|path| cwd.join(path).display().to_string()),
+        auto_update: !options.no_auto_update,
+    };
+    match (&cfg.git, &cfg.path) {
+        (Some(url), None) => {
+            parse_git_url(url).with_context(|| format!("invalid git URL `{url}`"))?;
+        }
+        (None, Some(_)) => {}
+        _ => bail!("pass exactly one of `--git` and `--path`"),
+    }
+    validate(sym, &cfg).await?;
+
+    sym.edit_config(|doc| {
+        let registries = doc
+            .entry("registry")
+            .or_insert_with(|| toml_edit::ArrayOfTables::new().into())
+            .as_array_of_tables_mut()
+            .context("`registry` in the config is not an array of tables")?;
+        let mut table = toml_edit::Table::new();
+        table["name"] = toml_edit::value(&cfg.name);
+        if let Some(url) = &cfg.git {
+            table["git"] = toml_edit::value(url);
+        }
+        if let Some(path) = &cfg.path {
+            table["path"] = toml_edit::value(path);
+        }
+        if !cfg.auto_update {
+            table["auto-update"] = toml_edit::value(false);
+        }
+        registries.push(table);
+        Ok(())
+    })?;
+    report(format!("added registry `{name}` ({})", source_of(&cfg)));
+    Ok(())
+}
+
+/// `cargo agents registry remove`.
+pub fn remove(sym: &mut Symposium, name: &str) -> Result<()> {
+    if is_required(sym, name) {
+        bail!("`{name}` is required by the system config");
+    }
+    if is_builtin(name) {
+        return set_builtin(sym, name, false);
+    }
+    if !sym.config.registries.iter().any(|r| r.name == name) {
+        bail!("no registry named `{name}` (see `cargo agents registry list`)");
+    }
+    sym.edit_config(|doc| {
+        let item = doc
+            .get_mut("registry")
+            .context("registry entry not found in the config file")?;
+        // `[[registry]]` tables, or the inline `registry = [{ name = … }]`.
+        let (removed, now_empty) = if let Some(registries) = item.as_array_of_tables_mut() {
+            let before = registries.len();
+            registries.retain(|table| table.get("name").and_then(|n| n.as_str()) != Some(name));
+            (registries.len() < before, registries.is_empty())
+        } else if let Some(registries) = item.as_array_mut() {
+            let before = registries.len();
+            registries.retain(|value| {
+                value
+                    .as_inline_table()
+                    .is_none_or(|table| table.get("name").and_then(|n| n.as_str()) != Some(name))
+            });
+            (registries.len() < before, registries.is_empty())
+        } else {
+            bail!("`registry` in the config is not an array of tables");
+        };
+        if !removed {
+            bail!("registry entry not found in the config file");
+        }
+        if now_empty {
+            doc.remove("registry");
+        }
+        Ok(())
+    })?;
+    report(format!("removed registry `{name}`"));
+    Ok(())
+}
+
+/// `cargo agents registry pin`: pin a configured git registry to `git_ref`,
+/// or with `None` go back to the default branch.
+pub async fn pin(sym: &mut Symposium, name: &str, git_ref: Option<&str>) -> Result<()> {
+    let Some(current) = sym.config.registries.iter().find(|r| r.name == name) else {
+        if is_builtin(name) || is_required(sym, name) {
+            bail!("`{name}` can't be pinned; add your own registry for the same source instead");
+        }
+        bail!("no registry named `{name}` (see `cargo agents registry list`)");
+    };
+    let Some(url) = &current.git else {
+        bail!("`{name}` is a path registry; only git registries can be pinned");
+    };
+    let pinned = RegistryConfig {
+        git: Some(with_ref(url, git_ref.unwrap_or(""))?),
+        ..current.clone()
+    };
+    validate(sym, &pinned).await?;
+
+    let new_url = pinned.git.clone().unwrap_or_default();
+    sym.edit_config(|doc| {
+        let table = doc
+            .get_mut("registry")
+            .and_then(|item| item.as_array_of_tables_mut())
+            .and_then(|registries| {
+                registries
+                    .iter_mut()
+                    .find(|table| table.get("name").and_then(|n| n.as_str()) == Some(name))
+            })
+            .context("registry entry not found in the config file")?;
+        table["git"] = toml_edit::value(&new_url);
+        Ok(())
+    })?;
+    report(match git_ref {
+        Some(git_ref) => format!("pinned registry `{name}` to `{git_ref}`"),
+        None => format!("unpinned registry `{name}`"),
+    });
+    Ok(())
+}
+
+fn is_builtin(name: &str) -> bool {
+    builtin_registries().iter().any(|r| r.name == name)
+}
+
+fn is_required(sym: &Symposium, name: &str) -> bool {
+    sym.system.registries.iter().any(|r| r.name == name)
+}
+
+/// Turn a built-in registry on or off. Its `[defaults]` key is its name.
+fn set_builtin(sym: &mut Symposium, name: &str, enabled: bool) -> Result<()> {
+    sym.edit_config(|doc| {
+        let defaults = doc
+            .entry("defaults")
+            .or_insert_with(|| toml_edit::Table::new().into())
+            .as_table_mut()
+            .context("`defaults` in the config is not a table")?;
+        if enabled {
+            // On is the default, so drop the key rather than spell it out.
+            defaults.remove(name);
+            if defaults.is_empty() {
+                doc.remove("defaults");
+            }
+        } else {
+            defaults[name] = toml_edit::value(false);
+        }
+        Ok(())
+    })?;
+    report(format!(
+        "{} built-in registry `{name}`",
+        if enabled { "enabled" } else { "disabled" }
+    ));
+    Ok(())
+}
+
+/// Fetch `cfg` once and check what it holds, failing on the first invalid
+/// plugin or skill — or when it holds none at all.
+async fn validate(sym: &Symposium, cfg: &RegistryConfig) -> Result<()> {
+    let inst = sym
+        .registry_instance(cfg.clone())
+        .with_context(|| format!("`{}` has an unusable source", cfg.name))?;
+    inst.pm
+        .refresh(UpdateLevel::Fetch, true)
+        .await
+        .with_context(|| format!("failed to fetch `{}`", source_of(cfg)))?;
+    sym.forget_package_manager_declarations();
+    let dir = inst
+        .pm
+        .content_dir()
+        .context("registry has no content directory")?;
+    let results = crate::plugins::validate_source_dir(dir)
+        .with_context(|| format!("failed to read {}", dir.display()))?;
+    if results.is_empty() {
+        bail!("no plugins or skills found in `{}`", source_of(cfg));
+    }
+    if let Some(error) = results.iter().find_map(first_error) {
+        bail!("`{}` is not a valid registry: {error}", source_of(cfg));
+    }
+    Ok(())
+}
+
+fn first_error(result: &ValidationResult) -> Option<String> {
+    match &result.result {
+        Err(e) => Some(format!("{}: {e:#}", result.id)),
+        Ok(()) => result.children.iter().find_map(first_error),
+    }
+}
+
+/// `url` with its ref replaced by `git_ref` (empty for the default branch),
+/// keeping any subpath. GitHub URLs pin with `/tree/<ref>`, any other with a
+/// `#<ref>` fragment. A `/tree/` URL reads its ref as one path segment, so a
+/// ref containing `/` is rejected there rather than misread as a subpath.
+fn with_ref(url: &str, git_ref: &str) -> Result<String> {
+    let source = parse_git_url(url).with_context(|| format!("invalid git URL `{url}`"))?;
+    let subpath = source.subpath().to_string();
+    Ok(match source {
+        GitSource::GitHub { owner, repo, .. } => {
+            if git_ref.contains('/') {
+                bail!(
+                    "a GitHub URL can't pin `{git_ref}`: a ref containing `/` would be read as a subpath; pin a tag or commit instead"
+                );
+            }
+            let mut pinned = format!("https://github.com/{owner}/{repo}");
+            if !git_ref.is_empty() {
+                pinned.push_str(&format!("/tree/{git_ref}"));
+            } else if !subpath.is_empty() {
+                bail!(
+                    "a GitHub URL can only name a subpath with a ref; pin `{url}` to a branch instead"
+                );
+            }
+            if !subpath.is_empty() {
+                pinned.push_str(&format!("/{subpath}"));
+            }
+            pinned
+        }
+        GitSource::Generic { url, .. } => match (git_ref.is_empty(), subpath.is_empty()) {
+            (true, true) => url,
+            (_, true) => format!("{url}#{git_ref}"),
+            _ => format!("{url}#{git_ref}:{subpath}"),
+        },
+        _ => bail!("can't pin `{url}`"),
+    })
+}
+
+/// The source as shown to the user: the git URL or the path.
+fn source_of(cfg: &RegistryConfig) -> String {
+    cfg.git
+        .clone()
+        .or_else(|| cfg.path.clone())
+        .unwrap_or_default()
+}
+
+fn report(message: String) {
+    tracing::info!(report = %ReportEvent::Info { message });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_ref_rewrites_each_url_form() {
+        let cases = [
+            (
+                "https://github.com/a/b",
+                "v1",
+                "https://github.com/a/b/tree/v1",
+            ),
+            (
+                "https://github.com/a/b/tree/main/plugins",
+                "v1",
+                "https://github.com/a/b/tree/v1/plugins",
+            ),
+            (
+                "https://github.com/a/b/tree/main",
+                "",
+                "https://github.com/a/b",
+            ),
+            ("ssh://host/repo.git", "v1", "ssh://host/repo.git#v1"),
+            (
+                "ssh://host/repo.git#main:sub",
+                "v1",
+                "ssh://host/repo.git#v1:sub",
+            ),
+            ("ssh://host/repo.git#main", "", "ssh://host/repo.git"),
+        ];
+        for (url, git_ref, expected) in cases {
+            assert_eq!(
+                with_ref(url, git_ref).unwrap(),
+                expected,
+                "{url} @ {git_ref}"
+            );
+        }
+        assert!(with_ref("https://github.com/a/b/tree/main/plugins", "").is_err());
+        // `/tree/release/1.0` would read back as ref `release`, subpath `1.0`.
+        assert!(with_ref("https://github.com/a/b", "release/1.0").is_err());
+        assert_eq!(
+            with_ref("ssh://host/repo.git", "release/1.0").unwrap(),
+            "ssh://host/repo.git#release/1.0"
+        );
+    }
+}
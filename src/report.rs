@@ -82,6 +82,16 @@ pub enum ReportEvent {
     /// An MCP server was registered for an agent.
     McpServerRegistered { agent: String, server: String },
 
+    /// A change `sync --dry-run` would make, left unmade.
+    SyncPlanned {
+        /// `install`, `update`, `remove`, or `write`.
+        action: String,
+        /// The skill directory or file the change applies to.
+        path: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        detail: Option<String>,
+    },
+
     /// Informational message.
     Info { message: String },
 
@@ -150,10 +160,27 @@ pub enum ReportEvent {
         /// Why the entry is in the state it is: its enablement root, or the
         /// reason it will not load.
         root: String,
-        /// `active`, `dormant`, `candidate`, or `declined`.
+        /// `active`, `dormant`, `candidate`, `declined`, `skipped`, or
+        /// `denied`.
         state: String,
     },
 
+    /// One result of `cargo agents doctor`.
+    DoctorFinding {
+        /// The check that produced it: `path`, `agents`, `hooks`, `mcp`,
+        /// `registry`, `cache`, `skills`, or `version`.
+        check: String,
+        /// `ok`, `warning`, or `error`.
+        severity: String,
+        message: String,
+        /// The suggested fix, for anything but `ok`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fix: Option<String>,
+    },
+
+    /// `cargo agents telemetry summary` output.
+    TelemetrySummary(crate::telemetry::Summary),
+
     /// A provider was listed with its plugins.
     ProviderListed {
         name: String,
@@ -164,6 +191,21 @@ pub enum ReportEvent {
         path: Option<String>,
         plugins: Vec<String>,
     },
+
+    /// One registry in `cargo agents registry list`.
+    RegistryListed {
+        name: String,
+        /// The git URL or path.
+        source: String,
+        /// `system` (required by the system config), `builtin`, or
+        /// `configured`.
+        origin: String,
+        enabled: bool,
+        auto_update: bool,
+    },
+
+    /// One key in `cargo agents config get` or `list`.
+    ConfigValue { key: String, value: toml::Value },
 }
 
 impl std::fmt::Display for ReportEvent {
@@ -240,6 +282,17 @@ impl ReportEvent {
             Self::McpServerRegistered { agent, server } => {
                 format!("✅ registered MCP server {server} for {agent}")
             }
+            Self::SyncPlanned {
+                action,
+                path,
+                detail,
+            } => {
+                let detail = detail
+                    .as_deref()
+                    .map(|d| format!(" ({d})"))
+                    .unwrap_or_default();
+                format!("📝 would {action} {path}{detail}")
+            }
             Self::Info { message } => {
                 format!("ℹ️  {message}")
             }
@@ -339,6 +392,8 @@ impl ReportEvent {
                     "active" => "✅",
                     "dormant" => "💤",
                     "candidate" => "❓",
+                    "skipped" => "⚠️",
+                    "denied" => "⛔",
                     _ => "➖",
                 };
                 let version = version
@@ -348,6 +403,25 @@ impl ReportEvent {
                 format!("{marker} {name}{version} — {root}")
             }
 
+            Self::DoctorFinding {
+                check,
+                severity,
+                message,
+                fix,
+            } => {
+                let marker = match severity.as_str() {
+                    "ok" => "✅",
+                    "warning" => "⚠️ ",
+                    _ => "❌",
+                };
+                match fix {
+                    Some(fix) => format!("{marker} {check}: {message}\n     fix: {fix}"),
+                    None => format!("{marker} {check}: {message}"),
+                }
+            }
+
+            Self::TelemetrySummary(summary) => summary.render().trim_end().to_string(),
+
             Self::ProviderListed {
                 name,
                 source_type,
@@ -366,6 +440,26 @@ impl ReportEvent {
                 }
                 lines
             }
+
+            Self::RegistryListed {
+                name,
+                source,
+                origin,
+                enabled,
+                auto_update,
+            } => {
+                let marker = if *enabled { "✅" } else { "➖" };
+                let mut notes = vec![origin.clone()];
+                if !enabled {
+                    notes.push("disabled".to_string());
+                }
+                if !auto_update {
+                    notes.push("no auto-update".to_string());
+                }
+                format!("{marker} {name} — {source} ({})", notes.join(", "))
+            }
+
+            Self::ConfigValue { key, value } => format!("{key} = {value}"),
         }
     }
 }
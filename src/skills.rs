@@ -99,6 +99,8 @@ pub struct SkillWithGroupContext {
     /// The hash of where the skill was discovered. Drives install-path disambiguation
     /// and dedup at sync time.
     pub origin_hash: String,
+    /// The canonical id of the plugin that contributed the skill.
+    pub plugin: crate::pm::PackageId,
 }
 
 /// Resolve all applicable skills from the registry.
@@ -130,10 +132,11 @@ pub async fn skills_applicable_to(
 ) -> Vec<SkillWithGroupContext> {
     let for_crates = crate::pm::workspace_dep_ids(sym, deps).await;
     let used_names = workspace_root
-        .map(|root| sym.config.plugins.used_names_in(root))
+        .map(|root| sym.used_names_in(root))
         .unwrap_or_default();
     let mut ctx = PredicateContext::with_custom_predicates(&for_crates, custom_predicate_entries)
-        .with_used_names(&used_names);
+        .with_used_names(&used_names)
+        .with_workspace(deps.load().cloned());
 
     let pms = sym.package_managers(deps);
     let active =
@@ -141,6 +144,34 @@ pub async fn skills_applicable_to(
     collect_skills(sym, &active, &mut ctx, update).await
 }
 
+/// The skills applicable to a workspace, over the same active plugin set
+/// `sync` installs from — for hooks that report on skills without syncing.
+/// Serves cached sources (`UpdateLevel::None`). Empty outside a Rust
+/// workspace.
+pub(crate) async fn workspace_skills(
+    sym: &Symposium,
+    deps: &std::sync::Arc<crate::pm::WorkspaceDeps>,
+) -> Vec<SkillWithGroupContext> {
+    let Some(loaded) = deps.load() else {
+        return Vec::new();
+    };
+    let update = UpdateLevel::None;
+    let registry = crate::plugins::load_registry_with_workspace(sym, Some(loaded)).await;
+    let custom_entries =
+        crate::sync::resolve_custom_predicate_entries(sym, &registry, update).await;
+
+    let dep_ids = crate::pm::workspace_dep_ids(sym, deps).await;
+    let used_names = sym.used_names_in(&loaded.root);
+    let mut ctx = PredicateContext::with_custom_predicates(&dep_ids, custom_entries)
+        .with_used_names(&used_names)
+        .with_workspace(Some(loaded.clone()));
+
+    let pms = sym.package_managers(deps);
+    let active =
+        crate::plugins::active_plugins(sym, &registry, &pms, Some(&loaded.root), &mut ctx).await;
+    collect_skills(sym, &active, &mut ctx, update).await
+}
+
 /// Extract the applicable skills from an already-resolved active plugin set.
 ///
 /// Provenance is re-stamped per plugin so a `source.path` group's
@@ -159,13 +190,7 @@ pub(crate) async fn collect_skills(
         for group in &parsed.plugin.skills {
             let skills = load_skills_for_group(sym, parsed, group, ctx, update).await;
             for (skill, origin_hash) in skills {
-                collect_skill_applicable_to(
-                    skill,
-                    origin_hash,
-                    &parsed.plugin.name,
-                    ctx,
-                    &mut results,
-                );
+                collect_skill_applicable_to(skill, origin_hash, parsed, ctx, &mut results);
             }
         }
     }
@@ -215,7 +240,7 @@ async fn load_skills_for_group(
         return Vec::new();
     }
 
-    let resolved = resolve_group_dirs(sym, parsed, group, update).await;
+    let resolved = resolve_group_dirs(sym, parsed, group, update, ctx.is_cache_only()).await;
     let skills = collect_skills_from_dirs(resolved, group);
 
     tracing::debug!(
@@ -249,12 +274,14 @@ struct ResolvedSkillDir {
 /// land on a directory to walk; the only difference is whether the base is local
 /// (`Path`) or fetched (`Git` via a git cache). Skill identity is not decided
 /// here — every discovered skill's origin is the hash of its on-disk `SKILL.md`
-/// path (see the module-level note above `skill_origin_hash`).
+/// path (see the module-level note above `skill_origin_hash`). With
+/// `cache_only`, a `Git` source resolves only if it is already cached.
 async fn resolve_group_dirs(
     sym: &Symposium,
     parsed: &ParsedPlugin,
     group: &SkillGroup,
     update: UpdateLevel,
+    cache_only: bool,
 ) -> Vec<ResolvedSkillDir> {
     let plugin = &parsed.plugin;
 
@@ -269,9 +296,12 @@ async fn resolve_group_dirs(
             }]
         }
         PluginSource::Git(url) => {
-            let Some((cache_dir, source, _commit_sha)) =
+            let fetched = if cache_only {
+                cached_git_skill_source(sym, url)
+            } else {
                 fetch_git_skill_source(sym, url, update).await
-            else {
+            };
+            let Some((cache_dir, source, _commit_sha)) = fetched else {
                 return Vec::new();
             };
             vec![ResolvedSkillDir {
@@ -345,6 +375,26 @@ async fn fetch_git_skill_source(
     Some((cache_dir, source, meta.commit_sha))
 }
 
+/// [`fetch_git_skill_source`] without touching the network or the cache:
+/// `None` unless a previous fetch already cached the source.
+fn cached_git_skill_source(
+    sym: &Symposium,
+    git_url: &str,
+) -> Option<(PathBuf, symposium_install::git::GitSource, String)> {
+    let cache_mgr = symposium_install::git::GitCacheManager::new(&sym.install_context(), "plugins");
+    let source = symposium_install::git::parse_git_url(git_url).ok()?;
+    let cache_dir = cache_mgr.cache_path_for_url(git_url)?;
+    let Some(meta) = cache_mgr.read_meta_for(&cache_dir) else {
+        tracing::info!(
+            report = %crate::report::ReportEvent::Info {
+                message: format!("skill source `{git_url}` is not fetched during a dry run"),
+            },
+        );
+        return None;
+    };
+    Some((cache_dir, source, meta.commit_sha))
+}
+
 /// Discover all skills found in a given directory.
 ///
 /// Recursively searches for `SKILL.md` files, then prunes nested candidates
@@ -552,10 +602,11 @@ fn load_skill(
 fn collect_skill_applicable_to(
     skill: Skill,
     origin_hash: String,
-    plugin_name: &str,
+    parsed: &ParsedPlugin,
     ctx: &mut PredicateContext,
     results: &mut Vec<SkillWithGroupContext>,
 ) {
+    let plugin_name = &parsed.plugin.name;
     if !skill.predicates.evaluate(ctx) {
         tracing::debug!(
             report = %crate::report::ReportEvent::SkillConsidered {
@@ -576,7 +627,11 @@ fn collect_skill_applicable_to(
             reason: None,
         },
     );
-    results.push(SkillWithGroupContext { skill, origin_hash });
+    results.push(SkillWithGroupContext {
+        skill,
+        origin_hash,
+        plugin: parsed.canonical.clone(),
+    });
 }
 
 /// Raw frontmatter fields extracted from a SKILL.md file.
@@ -590,10 +645,9 @@ struct RawFrontmatter {
     predicates: Option<String>,
 }
 
-/// Parse SKILL.md content: extract the `---`-fenced frontmatter. The body
-/// after the frontmatter is not retained — skills install by copying the
-/// file, not by re-emitting parsed content.
-fn parse_frontmatter(content: &str) -> Result<RawFrontmatter> {
+/// Split SKILL.md content at its `---` fences into the frontmatter text and
+/// the body that follows the closing fence.
+fn split_frontmatter(content: &str) -> Result<(&str, &str)> {
     let trimmed = content.trim_start();
     if !trimmed.starts_with("---") {
         bail!("SKILL.md must start with --- frontmatter fence");
@@ -608,7 +662,23 @@ fn parse_frontmatter(content: &str) -> Result<RawFrontmatter> {
         .find("\n---")
         .context("no closing --- fence in frontmatter")?;
 
-    let frontmatter_text = &after_first_fence[..end];
+    let body = &after_first_fence[end + "\n---".len()..];
+    let body = body.split_once('\n').map_or("", |(_, rest)| rest);
+    Ok((&after_first_fence[..end], body))
+}
+
+/// Parse SKILL.md content into its frontmatter fields and body, for agents
+/// that re-emit a skill in their own format (Cursor's `.mdc` rules).
+pub(crate) fn parse_skill_md(content: &str) -> Result<(BTreeMap<String, String>, &str)> {
+    let (_, body) = split_frontmatter(content)?;
+    Ok((parse_frontmatter(content)?.fields, body))
+}
+
+/// Parse SKILL.md content: extract the `---`-fenced frontmatter. The body
+/// after the frontmatter is not retained — skills install by copying the
+/// file, not by re-emitting parsed content.
+fn parse_frontmatter(content: &str) -> Result<RawFrontmatter> {
+    let (frontmatter_text, _) = split_frontmatter(content)?;
 
     let yaml: serde_yaml_ng::Value =
         serde_yaml_ng::from_str(frontmatter_text).context("frontmatter is not valid YAML")?;
@@ -1088,6 +1158,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
 
@@ -1147,6 +1218,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
 
@@ -1224,6 +1296,7 @@ mod tests {
             subcommands: BTreeMap::new(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
 
@@ -1306,6 +1379,7 @@ mod tests {
             subcommands: Default::default(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
 
@@ -1389,6 +1463,7 @@ mod tests {
             subcommands: Default::default(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
 
@@ -1518,6 +1593,7 @@ mod tests {
             subcommands: Default::default(),
             custom_predicates: vec![],
             chained: vec![],
+            package_managers: vec![],
             requires_use: false,
         };
         let registry = PluginRegistry {
@@ -25,6 +25,15 @@ pub struct State {
     /// Last time we checked crates.io for a newer version.
     #[serde(default, rename = "last-update-check")]
     pub last_update_check: Option<DateTime<Utc>>,
+
+    /// Hook roots whose agent configs no longer hold the per-plugin MCP
+    /// entries written before the meta-server.
+    #[serde(
+        default,
+        rename = "mcp-migrated-roots",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub mcp_migrated_roots: Vec<PathBuf>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -32,6 +41,8 @@ struct RawState {
     version: String,
     #[serde(default, rename = "last-update-check")]
     last_update_check: Option<DateTime<Utc>>,
+    #[serde(default, rename = "mcp-migrated-roots")]
+    mcp_migrated_roots: Vec<PathBuf>,
 }
 
 impl RawState {
@@ -39,6 +50,7 @@ impl RawState {
         State {
             version: self.version,
             last_update_check: self.last_update_check,
+            mcp_migrated_roots: self.mcp_migrated_roots,
         }
     }
 }
@@ -48,6 +60,7 @@ impl Default for State {
         Self {
             version: CURRENT_VERSION.to_string(),
             last_update_check: None,
+            mcp_migrated_roots: Vec::new(),
         }
     }
 }
@@ -114,6 +127,21 @@ pub fn record_update_check(config_dir: &Path) {
     save(config_dir, &state);
 }
 
+/// Whether the agent configs under `hook_root` may still hold the per-plugin
+/// MCP entries that releases before the meta-server registered.
+pub fn needs_mcp_migration(config_dir: &Path, hook_root: &Path) -> bool {
+    load(config_dir).is_none_or(|state| !state.mcp_migrated_roots.iter().any(|r| r == hook_root))
+}
+
+/// Record that the legacy MCP entries under `hook_root` were removed.
+pub fn record_mcp_migration(config_dir: &Path, hook_root: &Path) {
+    let mut state = load(config_dir).unwrap_or_default();
+    if !state.mcp_migrated_roots.iter().any(|r| r == hook_root) {
+        state.mcp_migrated_roots.push(hook_root.to_path_buf());
+        save(config_dir, &state);
+    }
+}
+
 fn state_path(config_dir: &Path) -> PathBuf {
     config_dir.join(STATE_FILE)
 }
@@ -146,6 +174,7 @@ mod tests {
         let old = State {
             version: "0.1.0".to_string(),
             last_update_check: None,
+            mcp_migrated_roots: Vec::new(),
         };
         fs::write(
             tmp.path().join(STATE_FILE),
@@ -159,6 +188,16 @@ mod tests {
         assert_eq!(state.version, CURRENT_VERSION);
     }
 
+    #[test]
+    fn mcp_migration_is_recorded_per_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (home, project) = (Path::new("/home/u"), Path::new("/work/p"));
+        assert!(needs_mcp_migration(tmp.path(), home));
+        record_mcp_migration(tmp.path(), home);
+        assert!(!needs_mcp_migration(tmp.path(), home));
+        assert!(needs_mcp_migration(tmp.path(), project));
+    }
+
     #[test]
     fn should_check_when_no_state() {
         let tmp = tempfile::tempdir().unwrap();
@@ -182,8 +221,10 @@ mod tests {
     #[test]
     fn should_check_when_interval_elapsed() {
         let tmp = tempfile::tempdir().unwrap();
-        let mut state = State::default();
-        state.last_update_check = Some(Utc::now() - chrono::Duration::hours(25));
+        let state = State {
+            last_update_check: Some(Utc::now() - chrono::Duration::hours(25)),
+            ..Default::default()
+        };
         save(tmp.path(), &state);
         assert!(should_check_for_update(tmp.path()));
     }
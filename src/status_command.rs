@@ -5,7 +5,7 @@
 //! per plugin, each naming its enablement root — so it answers "why is
 //! serde-skills here?" with "enabled via serde".
 //!
-//! Four states, matching the axis:
+//! Six states, matching the axis:
 //!
 //! - **active** — enabled and its predicates hold for this workspace. The
 //!   root names the trust root: workspace membership, a configured registry,
@@ -18,6 +18,15 @@
 //!   asks about.
 //! - **declined** — recorded in `[plugins] disable`, the record of pruned
 //!   nodes and declined discoveries.
+//! - **skipped** — reached through a `[[plugins]] source.cargo` edge whose
+//!   version requirement the resolved crate does not meet.
+//! - **denied** — forbidden by the [system policy](crate::policy), whatever
+//!   the user or workspace decided.
+//!
+//! A decision that comes from the workspace's team config
+//! (`[workspace.metadata.symposium]`) rather than the user's own is marked
+//! "(workspace config)"; a registry the system config requires is marked
+//! "(system config)".
 
 use std::path::Path;
 
@@ -28,6 +37,7 @@ use std::sync::Arc;
 use crate::config::Symposium;
 use crate::discovery::{DiscoveredPlugin, Enablement};
 use crate::report::ReportEvent;
+use crate::workspace_config::ConfigLayer;
 
 /// What enablement decided about one plugin.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -40,6 +50,10 @@ pub enum StatusState {
     Candidate,
     /// Declined, via `[plugins] disable`.
     Declined,
+    /// A chained edge's version requirement is not met.
+    Skipped,
+    /// Forbidden by the system policy.
+    Denied,
 }
 
 impl StatusState {
@@ -50,6 +64,8 @@ impl StatusState {
             Self::Dormant => "dormant",
             Self::Candidate => "candidate",
             Self::Declined => "declined",
+            Self::Skipped => "skipped",
+            Self::Denied => "denied",
         }
     }
 }
@@ -84,17 +100,52 @@ pub async fn workspace_status(
     // plugins and whether the predicates hold.
     let registry = crate::plugins::load_registry_with_workspace(sym, Some(&ws)).await;
     let dep_ids = crate::pm::workspace_dep_ids(sym, deps).await;
-    let used_names = sym.config.plugins.used_names_in(&ws.root);
-    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids).with_used_names(&used_names);
+    let used_names = sym.used_names_in(&ws.root);
+    let workspace_registries: Vec<String> = sym
+        .workspace_registry_instances(&ws.root)
+        .into_iter()
+        .map(|inst| inst.name)
+        .collect();
+    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids)
+        .with_used_names(&used_names)
+        .with_workspace(Some(ws.clone()));
+    // One resolution backs both the registry plugins' state and the chained
+    // edges reported below.
+    let pms = sym.package_managers(deps);
+    let resolved =
+        crate::plugins::resolve_active_plugins(sym, &registry, &pms, Some(&ws.root), &mut ctx)
+            .await;
     for parsed in &registry.plugins {
+        if !sym.policy().permits_plugin(&parsed.plugin.name) {
+            entries.push(StatusEntry {
+                name: parsed.plugin.name.clone(),
+                version: None,
+                root: "denied by system policy".to_string(),
+                state: StatusState::Denied,
+            });
+            continue;
+        }
         let root = if parsed.workspace_member {
             "workspace member".to_string()
         } else if parsed.plugin.requires_use && ctx.is_used(&parsed.plugin.name) {
-            "`[plugins] use`".to_string()
+            let layer = sym.plugin_layer(&parsed.plugin.name, &ws.root);
+            format!("`[plugins] use`{}", layer_suffix(layer))
+        } else if sym
+            .system
+            .registries
+            .iter()
+            .any(|r| r.name == parsed.canonical.pm)
+        {
+            format!("registry `{}` (system config)", parsed.canonical.pm)
+        } else if workspace_registries.contains(&parsed.canonical.pm) {
+            format!("registry `{}` (workspace config)", parsed.canonical.pm)
         } else {
             format!("registry `{}`", parsed.canonical.pm)
         };
-        let active = parsed.applies(&mut ctx);
+        let active = resolved
+            .plugins
+            .iter()
+            .any(|p| p.canonical == parsed.canonical);
         entries.push(StatusEntry {
             name: parsed.plugin.name.clone(),
             version: None,
@@ -121,6 +172,7 @@ pub async fn workspace_status(
         .chain(&discovery.auto_enabled)
         .chain(&discovery.candidates)
         .chain(&discovery.declined)
+        .chain(&discovery.denied)
     {
         if found.enablement == Enablement::Declined {
             declined_names.push(found.name().to_string());
@@ -145,6 +197,7 @@ pub async fn workspace_status(
                 .chain(&discovery.auto_enabled)
                 .chain(&discovery.candidates)
                 .chain(&discovery.declined)
+                .chain(&discovery.denied)
                 .map(|d| normalize(d.name())),
         )
         .collect();
@@ -152,24 +205,58 @@ pub async fn workspace_status(
         if shown.contains(&normalize(&name)) {
             continue;
         }
+        let layer = sym.plugin_layer(&name, &ws.root);
         entries.push(StatusEntry {
             name,
             version: None,
-            root: "`[plugins] use` (not a dependency)".to_string(),
+            root: format!("`[plugins] use`{} (not a dependency)", layer_suffix(layer)),
             state: StatusState::Active,
         });
     }
 
+    // Version-constrained chained edges: the crate each resolved to, and
+    // whether the edge loaded it or was skipped.
+    let mut seen_checks = std::collections::HashSet::new();
+    for check in resolved.version_checks {
+        if !seen_checks.insert(check.clone()) {
+            continue;
+        }
+        let (state, root) = if check.satisfied {
+            (
+                StatusState::Active,
+                format!(
+                    "chained from `{}` (requires {})",
+                    check.from, check.requirement
+                ),
+            )
+        } else {
+            (
+                StatusState::Skipped,
+                format!(
+                    "skipped: `{}` requires {} {}",
+                    check.from, check.name, check.requirement
+                ),
+            )
+        };
+        entries.push(StatusEntry {
+            name: check.name,
+            version: Some(check.resolved),
+            root,
+            state,
+        });
+    }
+
     // Names declined without ever being discovered (a `disable` entry for a
     // dependency whose source isn't on disk, or one added by hand).
-    for name in &sym.config.plugins.disable {
+    for name in &sym.plugins_in(&ws.root).disable {
         if declined_names.iter().any(|n| n == name) {
             continue;
         }
+        let layer = sym.plugin_layer(name, &ws.root);
         entries.push(StatusEntry {
             name: name.clone(),
             version: None,
-            root: "declined (`[plugins] disable`)".to_string(),
+            root: format!("declined (`[plugins] disable`{})", layer_suffix(layer)),
             state: StatusState::Declined,
         });
     }
@@ -179,13 +266,18 @@ pub async fn workspace_status(
 
 /// Render one discovered dependency plugin as a status line.
 fn entry_for(found: &DiscoveredPlugin) -> StatusEntry {
+    let layer = layer_suffix(found.layer);
     let (state, root) = match found.enablement {
-        Enablement::Used => (StatusState::Active, "`[plugins] use`".to_string()),
-        Enablement::AutoEnabled => (StatusState::Active, "`[plugins] auto-enable`".to_string()),
+        Enablement::Used => (StatusState::Active, format!("`[plugins] use`{layer}")),
+        Enablement::AutoEnabled => (
+            StatusState::Active,
+            format!("`[plugins] auto-enable`{layer}"),
+        ),
         Enablement::Declined => (
             StatusState::Declined,
-            "declined (`[plugins] disable`)".to_string(),
+            format!("declined (`[plugins] disable`{layer})"),
         ),
+        Enablement::Denied => (StatusState::Denied, "denied by system policy".to_string()),
         Enablement::Candidate => (
             StatusState::Candidate,
             format!(
@@ -203,8 +295,24 @@ fn entry_for(found: &DiscoveredPlugin) -> StatusEntry {
     }
 }
 
+/// Attribution for a decision: nothing for the user's own config, a note for
+/// the workspace's team config.
+fn layer_suffix(layer: ConfigLayer) -> &'static str {
+    match layer {
+        ConfigLayer::User => "",
+        ConfigLayer::Workspace => " (workspace config)",
+    }
+}
+
 /// The `cargo agents status` entry point.
 pub async fn status(sym: &Symposium, cwd: &Path) -> Result<()> {
+    if let Some(path) = &sym.system.path {
+        tracing::info!(
+            report = %ReportEvent::Info {
+                message: format!("system policy from {} applies", path.display()),
+            },
+        );
+    }
     let deps = sym.workspace_deps(cwd);
     let entries = workspace_status(sym, &deps).await?;
     if entries.is_empty() {
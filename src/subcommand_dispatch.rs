@@ -12,16 +12,17 @@ use crate::{
     config::Symposium,
     installation::{acquire_installation, resolve_runnable},
     plugins::{self, ParsedPlugin, Plugin, Subcommand},
-    pm::PackageId,
+    predicate::PredicateContext,
 };
 use anyhow::{Context, Result, bail};
 use symposium_install::{Runnable, UpdateLevel};
 use tokio::process::Command;
 
 /// Collect every plugin subcommand whose plugin-level and subcommand-level predicates
-/// apply to `deps`. `used` names the plugins the applicable `[plugins] use`
-/// entries enable, which is what wakes a dormant plugin. Shared between
-/// dispatch (name lookup) and help rendering (audience grouping).
+/// hold in `ctx` — the context the caller resolved the active set with, so the
+/// same dependency graph and `[plugins] use` names (which wake a dormant plugin)
+/// apply. Shared between dispatch (name lookup) and help rendering (audience
+/// grouping).
 ///
 /// `plugins` is the resolved active set from
 /// [`plugins::active_plugins`](crate::plugins::active_plugins) — registry plugins
@@ -29,18 +30,16 @@ use tokio::process::Command;
 /// exactly like a registry plugin's.
 pub fn applicable_subcommands<'a>(
     plugins: &'a [ParsedPlugin],
-    deps: &[PackageId],
-    used: &[&str],
+    ctx: &mut PredicateContext,
 ) -> Vec<(&'a Plugin, &'a str, &'a Subcommand)> {
-    let mut ctx = crate::predicate::PredicateContext::new(deps).with_used_names(used);
     let mut results = Vec::new();
     for parsed in plugins {
         let plugin = &parsed.plugin;
-        if !parsed.applies(&mut ctx) {
+        if !parsed.applies(ctx) {
             continue;
         }
         for (name, subcommand) in &plugin.subcommands {
-            if subcommand.predicates.evaluate(&mut ctx) {
+            if subcommand.predicates.evaluate(ctx) {
                 results.push((plugin, name.as_str(), subcommand));
             }
         }
@@ -57,10 +56,9 @@ pub fn applicable_subcommands<'a>(
 pub fn find_subcommand<'a>(
     plugins: &'a [ParsedPlugin],
     name: &str,
-    deps: &[PackageId],
-    used: &[&str],
+    ctx: &mut PredicateContext,
 ) -> Result<Option<(&'a Plugin, &'a Subcommand)>> {
-    let matches: Vec<_> = applicable_subcommands(plugins, deps, used)
+    let matches: Vec<_> = applicable_subcommands(plugins, ctx)
         .into_iter()
         .filter(|(_, n, _)| *n == name)
         .map(|(plugin, _, subcmd)| (plugin, subcmd))
@@ -108,11 +106,13 @@ pub async fn dispatch_external(
     let dep_ids = crate::pm::workspace_dep_ids(sym, &deps).await;
     let used = workspace
         .as_ref()
-        .map(|ws| sym.config.plugins.used_names_in(&ws.root))
+        .map(|ws| sym.used_names_in(&ws.root))
         .unwrap_or_default();
 
     // Resolve the active plugin set so crate-sourced subcommands are dispatchable.
-    let mut ctx = crate::predicate::PredicateContext::new(&dep_ids).with_used_names(&used);
+    let mut ctx = PredicateContext::new(&dep_ids)
+        .with_used_names(&used)
+        .with_workspace(workspace.clone());
     let pms = sym.package_managers(&deps);
     let active = crate::plugins::active_plugins(
         sym,
@@ -123,7 +123,7 @@ pub async fn dispatch_external(
     )
     .await;
 
-    let (plugin, subcommand) = find_subcommand(&active, name, &dep_ids, &used)?
+    let (plugin, subcommand) = find_subcommand(&active, name, &mut ctx)?
         .with_context(|| format!("no plugin defines subcommand `{name}`"))?;
 
     let installation = plugin
@@ -183,7 +183,7 @@ fn exit_byte_from(status: ExitStatus) -> u8 {
 mod tests {
     use super::*;
     use crate::plugins::PluginRegistry;
-    use crate::pm::ANY_VERSION;
+    use crate::pm::{ANY_VERSION, PackageId};
     use crate::{plugins::Audience, predicate::PredicateSet};
     use std::collections::BTreeMap;
 
@@ -212,6 +212,7 @@ mod tests {
                 subcommands,
                 custom_predicates: vec![],
                 chained: vec![],
+                package_managers: vec![],
                 requires_use: false,
             },
             workspace_member: false,
@@ -243,7 +244,7 @@ mod tests {
 
         let ws = [ws_crate("skill-tree", "1.0.0")];
 
-        let (plugin, sub) = find_subcommand(&reg.plugins, "greet", &ws, &[])
+        let (plugin, sub) = find_subcommand(&reg.plugins, "greet", &mut PredicateContext::new(&ws))
             .unwrap()
             .unwrap();
         assert_eq!(plugin.name, "example-plugin");
@@ -258,7 +259,7 @@ mod tests {
         let ws = [ws_crate("skill-tree", "1.0.0")];
 
         assert!(
-            find_subcommand(&reg.plugins, "nope", &ws, &[])
+            find_subcommand(&reg.plugins, "nope", &mut PredicateContext::new(&ws))
                 .unwrap()
                 .is_none()
         );
@@ -272,7 +273,7 @@ mod tests {
         let ws = [ws_crate("skill-tree", "1.0.0")];
 
         assert!(
-            find_subcommand(&reg.plugins, "greet", &ws, &[])
+            find_subcommand(&reg.plugins, "greet", &mut PredicateContext::new(&ws))
                 .unwrap()
                 .is_none()
         );
@@ -292,7 +293,7 @@ mod tests {
         ]);
         let ws = [ws_crate("skill-tree", "1.0.0")];
 
-        let err = find_subcommand(&reg.plugins, "greet", &ws, &[])
+        let err = find_subcommand(&reg.plugins, "greet", &mut PredicateContext::new(&ws))
             .unwrap_err()
             .to_string();
 
@@ -9,11 +9,12 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime};
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use symposium_install::UpdateLevel;
 
 use crate::agents::Agent;
 use crate::config::Symposium;
+use crate::lockfile::{LOCKFILE_NAME, LockMode, Lockfile};
 use crate::output::{Output, display_path};
 use crate::plugins;
 use crate::pm::WorkspaceDeps;
@@ -27,6 +28,9 @@ use std::sync::Arc;
 /// user-managed skill directories (which lack the marker) untouched.
 pub(crate) const MARKER_FILE: &str = ".symposium";
 
+/// Contents of the `.gitignore` written next to the marker.
+pub(crate) const MANAGED_GITIGNORE: &str = "*\n";
+
 /// Create `path` and any missing ancestors up to `boundary`.
 ///
 /// `boundary` is the workspace root — we never walk above it.
@@ -47,7 +51,7 @@ pub(crate) fn create_managed_dir_all(path: &Path, boundary: &Path) -> Result<()>
 
 /// Skills parent directory for an agent (e.g. `.claude/skills/` or
 /// `.agents/skills/`), derived from `Agent::project_skill_dir`.
-fn skills_parent_dir(agent: Agent, project_root: &Path) -> PathBuf {
+pub(crate) fn skills_parent_dir(agent: &Agent, project_root: &Path) -> PathBuf {
     agent
         .project_skill_dir(project_root, "_")
         .parent()
@@ -62,10 +66,10 @@ fn skills_parent_dir(agent: Agent, project_root: &Path) -> PathBuf {
 /// Idempotent — overwrites any pre-existing marker or `.gitignore` in
 /// `dir`. Callers use this both for freshly-installed plugin skills and
 /// for skills propagated by the agents-syncing feature.
-fn mark_generated_skill_directory(dir: &Path) -> Result<()> {
+pub(crate) fn mark_generated_skill_directory(dir: &Path) -> Result<()> {
     fs::write(dir.join(MARKER_FILE), "")
         .with_context(|| format!("write marker in {}", dir.display()))?;
-    fs::write(dir.join(".gitignore"), "*\n")
+    fs::write(dir.join(".gitignore"), MANAGED_GITIGNORE)
         .with_context(|| format!("write .gitignore in {}", dir.display()))?;
     Ok(())
 }
@@ -73,7 +77,7 @@ fn mark_generated_skill_directory(dir: &Path) -> Result<()> {
 /// Does `dir` contain the `.symposium` marker, i.e. is it a symposium-managed
 /// skill directory? Returns `false` for user-authored skills and for any
 /// directory symposium did not create.
-fn has_symposium_marker(dir: &Path) -> bool {
+pub(crate) fn has_symposium_marker(dir: &Path) -> bool {
     dir.join(MARKER_FILE).exists()
 }
 
@@ -100,7 +104,7 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
 /// Collect all regular files in `dir` recursively, returning paths relative
 /// to `dir` paired with their contents. Skips the `.symposium` marker and
 /// `.gitignore` since those are managed metadata, not skill content.
-fn collect_dir_contents(dir: &Path) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+pub(crate) fn collect_dir_contents(dir: &Path) -> Result<Vec<(PathBuf, Vec<u8>)>> {
     let mut result = Vec::new();
     collect_dir_contents_inner(dir, dir, &mut result)?;
     result.sort_by(|a, b| a.0.cmp(&b.0));
@@ -137,10 +141,12 @@ fn collect_dir_contents_inner(
 }
 
 /// Returns true if the source directory's content differs from the
-/// destination's content (ignoring managed metadata files).
-fn dir_contents_differ(source_dir: &Path, dest_dir: &Path) -> Result<bool> {
+/// destination's content (ignoring managed metadata files and the files
+/// `agent` derives from the skill).
+fn dir_contents_differ(source_dir: &Path, dest_dir: &Path, agent: &Agent) -> Result<bool> {
     let src = collect_dir_contents(source_dir)?;
-    let dst = collect_dir_contents(dest_dir)?;
+    let mut dst = collect_dir_contents(dest_dir)?;
+    dst.retain(|(rel, _)| !agent.is_derived_skill_file(dest_dir, rel));
     Ok(src != dst)
 }
 
@@ -161,6 +167,7 @@ fn sync_skill_dir(
     source_dir: &Path,
     dest_dir: &Path,
     project_root: &Path,
+    agent: &Agent,
     debounce: Duration,
 ) -> Result<bool> {
     if dest_dir == source_dir {
@@ -188,7 +195,7 @@ fn sync_skill_dir(
     }
 
     // Compare content (excluding managed metadata).
-    if !dir_contents_differ(source_dir, dest_dir)? {
+    if !dir_contents_differ(source_dir, dest_dir, agent)? {
         // Content is identical — just touch the marker to reset debounce.
         touch_marker(&marker_path)?;
         return Ok(false);
@@ -202,6 +209,36 @@ fn sync_skill_dir(
     Ok(true)
 }
 
+/// What [`sync_skill_dir`] would do to `dest_dir`, ignoring the debounce:
+/// `Some("install")` or `Some("update")`, or `None` when it is up to date.
+fn planned_skill_change(
+    source_dir: &Path,
+    dest_dir: &Path,
+    agent: &Agent,
+) -> Result<Option<&'static str>> {
+    if dest_dir == source_dir {
+        return Ok(None);
+    }
+    if !dest_dir.exists() {
+        return Ok(Some("install"));
+    }
+    if dir_contents_differ(source_dir, dest_dir, agent)? {
+        return Ok(Some("update"));
+    }
+    Ok(None)
+}
+
+/// Report a change a dry run would make.
+fn plan(action: &str, path: &Path, detail: Option<String>) {
+    tracing::info!(
+        report = %crate::report::ReportEvent::SyncPlanned {
+            action: action.to_string(),
+            path: display_path(path),
+            detail,
+        },
+    );
+}
+
 /// Update the marker file's mtime to now without changing content.
 fn touch_marker(marker_path: &Path) -> Result<()> {
     fs::write(marker_path, "")
@@ -211,69 +248,155 @@ fn touch_marker(marker_path: &Path) -> Result<()> {
 
 /// Resolve custom predicate installations from the registry into entries
 /// suitable for [`PredicateContext::with_custom_predicates`].
-async fn resolve_custom_predicate_entries(
+pub(crate) async fn resolve_custom_predicate_entries(
     sym: &Symposium,
     registry: &plugins::PluginRegistry,
     update: UpdateLevel,
 ) -> std::collections::HashMap<String, crate::predicate::ResolvedPredicateEntry> {
-    use crate::predicate::ResolvedPredicateEntry;
-
     let mut entries = std::collections::HashMap::new();
 
     for (name, resolved) in registry.custom_predicates.iter() {
         let plugin = &registry.plugins[resolved.plugin_index];
-        let Some(install) = plugin.plugin.get_installation(&resolved.command) else {
-            tracing::warn!(
-                predicate = name,
-                command = &resolved.command,
-                "custom predicate references unknown installation"
-            );
-            continue;
-        };
+        if let Some(entry) =
+            resolve_custom_predicate_entry(sym, &plugin.plugin, name, resolved, update).await
+        {
+            entries.insert(name.clone(), entry);
+        }
+    }
 
-        let acquired =
-            match crate::installation::acquire_installation(sym, install, None, None, update).await
-            {
-                Ok(a) => a,
-                Err(e) => {
-                    tracing::warn!(
-                        predicate = name,
-                        error = %e,
-                        "failed to acquire custom predicate installation"
-                    );
-                    continue;
-                }
-            };
+    entries
+}
 
-        let runnable =
-            match crate::installation::resolve_runnable(acquired, &format!("predicate `{name}`")) {
-                Ok(r) => r,
-                Err(e) => {
-                    tracing::warn!(
-                        predicate = name,
-                        error = %e,
-                        "failed to resolve custom predicate runnable"
-                    );
-                    continue;
-                }
-            };
+/// [`resolve_custom_predicate_entries`] for a dry run: only the predicates
+/// already installed, none installed here.
+async fn installed_custom_predicate_entries(
+    sym: &Symposium,
+    registry: &plugins::PluginRegistry,
+) -> std::collections::HashMap<String, crate::predicate::ResolvedPredicateEntry> {
+    let mut entries = std::collections::HashMap::new();
+    for (name, resolved) in registry.custom_predicates.iter() {
+        let plugin = &registry.plugins[resolved.plugin_index];
+        if let Some(entry) =
+            installed_custom_predicate_entry(sym, &plugin.plugin, name, resolved).await
+        {
+            entries.insert(name.clone(), entry);
+        }
+    }
+    entries
+}
 
-        entries.insert(
-            name.clone(),
-            ResolvedPredicateEntry {
-                runnable,
-                args: resolved.args.clone(),
-            },
+/// Acquire one custom predicate's installation (looked up on its defining
+/// `plugin`) and resolve it to a runnable entry. `None` when the
+/// installation is unknown or cannot be acquired (logged).
+pub(crate) async fn resolve_custom_predicate_entry(
+    sym: &Symposium,
+    plugin: &plugins::Plugin,
+    name: &str,
+    resolved: &plugins::ResolvedCustomPredicate,
+    update: UpdateLevel,
+) -> Option<crate::predicate::ResolvedPredicateEntry> {
+    let install = custom_predicate_installation(plugin, name, resolved)?;
+    let acquired =
+        match crate::installation::acquire_installation(sym, install, None, None, update).await {
+            Ok(a) => a,
+            Err(e) => {
+                tracing::warn!(
+                    predicate = name,
+                    error = %e,
+                    "failed to acquire custom predicate installation"
+                );
+                return None;
+            }
+        };
+    custom_predicate_entry(acquired, name, resolved)
+}
+
+/// [`resolve_custom_predicate_entry`] for a dry run: only an installation
+/// already on disk resolves
+/// ([`installed_installation`](crate::installation::installed_installation)),
+/// and nothing is fetched or installed. `None` when it isn't installed yet.
+pub(crate) async fn installed_custom_predicate_entry(
+    sym: &Symposium,
+    plugin: &plugins::Plugin,
+    name: &str,
+    resolved: &plugins::ResolvedCustomPredicate,
+) -> Option<crate::predicate::ResolvedPredicateEntry> {
+    let install = custom_predicate_installation(plugin, name, resolved)?;
+    let acquired = crate::installation::installed_installation(sym, install, None, None).await?;
+    custom_predicate_entry(acquired, name, resolved)
+}
+
+/// The installation a custom predicate's `command` names on `plugin`.
+fn custom_predicate_installation<'a>(
+    plugin: &'a plugins::Plugin,
+    name: &str,
+    resolved: &plugins::ResolvedCustomPredicate,
+) -> Option<&'a plugins::Installation> {
+    let install = plugin.get_installation(&resolved.command);
+    if install.is_none() {
+        tracing::warn!(
+            predicate = name,
+            command = &resolved.command,
+            "custom predicate references unknown installation"
         );
     }
+    install
+}
 
-    entries
+fn custom_predicate_entry(
+    acquired: crate::installation::AcquiredInstallation,
+    name: &str,
+    resolved: &plugins::ResolvedCustomPredicate,
+) -> Option<crate::predicate::ResolvedPredicateEntry> {
+    let runnable =
+        match crate::installation::resolve_runnable(acquired, &format!("predicate `{name}`")) {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::warn!(
+                    predicate = name,
+                    error = %e,
+                    "failed to resolve custom predicate runnable"
+                );
+                return None;
+            }
+        };
+
+    Some(crate::predicate::ResolvedPredicateEntry {
+        runnable,
+        args: resolved.args.clone(),
+    })
+}
+
+/// Options for a user-invoked [`sync_with`]. The defaults are what every
+/// other sync (hook-triggered auto-sync, `cargo agents use`) runs with.
+#[derive(Debug, Clone, Default)]
+pub struct SyncOptions {
+    /// How to treat the workspace's `symposium.lock`.
+    pub lock: LockMode,
+    /// Resolve as usual, but report each install, removal, and config write
+    /// as a [`SyncPlanned`](crate::report::ReportEvent::SyncPlanned) event
+    /// instead of performing it.
+    pub dry_run: bool,
 }
 
 /// Run the full sync: discover applicable skills, install into agent dirs,
 /// clean up stale installations.
 pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLevel) -> Result<()> {
-    let out = &Output::quiet();
+    sync_with(sym, deps, update, &SyncOptions::default()).await
+}
+
+/// [`sync`] with explicit [`SyncOptions`].
+pub async fn sync_with(
+    sym: &Symposium,
+    deps: &Arc<WorkspaceDeps>,
+    update: UpdateLevel,
+    options: &SyncOptions,
+) -> Result<()> {
+    let out = &if options.dry_run {
+        Output::quiet().with_dry_run()
+    } else {
+        Output::quiet()
+    };
     let loaded = deps
         .load()
         .ok_or_else(|| anyhow::anyhow!("not in a Rust workspace"))?;
@@ -283,6 +406,16 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
     let debounce = Duration::from_secs(sym.config.sync_debounce_secs);
     tracing::debug!(root = %project_root.display(), "resolved workspace root");
 
+    // Registries the workspace recommends load only once the user trusts
+    // them; point at any still awaiting a decision.
+    crate::workspace_config::report_pending_registries(sym, &project_root);
+    // A dry run plans from what is already on disk: no registry refresh, and
+    // (through the cache-only predicate context below) no custom predicate
+    // installs or skill source fetches.
+    if !options.dry_run {
+        plugins::ensure_workspace_registries(sym, &project_root, update).await;
+    }
+
     // Load plugin registry (registry sources + workspace plugins)
     let registry = plugins::load_registry_with_workspace(sym, Some(&loaded)).await;
 
@@ -300,21 +433,28 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
         },
     );
 
-    // Resolve custom predicate installations.
-    let custom_entries = resolve_custom_predicate_entries(sym, &registry, update).await;
+    // Resolve custom predicate installations. A dry run installs none, but
+    // still evaluates the ones already installed.
+    let custom_entries = if options.dry_run {
+        installed_custom_predicate_entries(sym, &registry).await
+    } else {
+        resolve_custom_predicate_entries(sym, &registry, update).await
+    };
 
     // Resolve the workspace once and build the predicate context shared by
     // skill resolution and MCP-server filtering. Attach the on-disk cache so
     // custom predicate results survive across sync runs; results are persisted
     // at the end of this evaluation pass.
     let dep_ids = crate::pm::workspace_dep_ids(sym, deps).await;
-    let used_names = sym.config.plugins.used_names_in(&project_root);
+    let used_names = sym.used_names_in(&project_root);
     let predicate_cache_path =
         crate::predicate_cache::PredicateCache::path_for_workspace(sym.cache_dir(), &project_root);
     let mut ctx =
         crate::predicate::PredicateContext::with_custom_predicates(&dep_ids, custom_entries)
             .with_used_names(&used_names)
+            .with_workspace(Some(loaded.clone()))
             .with_disk_cache(&predicate_cache_path);
+    ctx.set_cache_only(options.dry_run);
 
     // The active plugin set: registry plugins plus the crate-sourced plugins
     // reached through `[[plugins]]` chained references and dependency
@@ -346,14 +486,28 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
         }
     }
 
-    // Collect MCP servers from the same active plugin set.
-    let mut mcp_servers: Vec<sacp::schema::McpServer> = Vec::new();
+    // Stdio plugin MCP servers are proxied by the meta-server, so agents see
+    // the `symposium` entry plus the HTTP/SSE servers it can't proxy. Those
+    // belong to this workspace, so they are registered in its own agent
+    // configs whatever the hook scope.
+    let meta_server = [crate::mcp_serve::registration_entry()];
+    let mut direct_mcp_servers = Vec::new();
     for p in &active {
         if p.applies(&mut ctx) {
-            mcp_servers.extend(p.plugin.applicable_mcp_servers(&mut ctx));
+            direct_mcp_servers.extend(
+                p.plugin
+                    .applicable_mcp_servers(&mut ctx)
+                    .into_iter()
+                    .filter(|s| !crate::mcp_serve::is_proxied(s)),
+            );
         }
     }
-    if let Err(e) = ctx.persist_disk_cache(&predicate_cache_path) {
+    let legacy_mcp_names =
+        crate::mcp_serve::legacy_entry_names(registry.plugins.iter().chain(&active));
+
+    if !options.dry_run
+        && let Err(e) = ctx.persist_disk_cache(&predicate_cache_path)
+    {
         tracing::warn!(
             path = %predicate_cache_path.display(),
             error = %e,
@@ -361,15 +515,19 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
         );
     }
 
-    let server_names: Vec<&str> = mcp_servers
-        .iter()
-        .map(|s| match s {
-            sacp::schema::McpServer::Stdio(s) => s.name.as_str(),
-            sacp::schema::McpServer::Http(s) => s.name.as_str(),
-            sacp::schema::McpServer::Sse(s) => s.name.as_str(),
-            _ => panic!("unsupported McpServer variant"),
-        })
-        .collect();
+    // Settle the lockfile before installing anything, so `--locked` never
+    // leaves a partially synced workspace behind.
+    match apply_lock_mode(&project_root, &active, &applicable, &pms, options) {
+        Ok(()) => {}
+        Err(e) if options.lock == LockMode::Update && !options.dry_run => {
+            tracing::info!(
+                report = %crate::report::ReportEvent::Warning {
+                    message: format!("failed to update {LOCKFILE_NAME}: {e:#}"),
+                },
+            );
+        }
+        Err(e) => return Err(e),
+    }
 
     // Sync each configured agent
     let agent_names: Vec<String> = sym.config.agents.iter().map(|a| a.name.clone()).collect();
@@ -393,22 +551,52 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
     // Track every skill directory we (re)install during this sync. Anything
     // we find later that has the marker file but isn't in this set is stale.
     let mut installed_dirs: BTreeSet<PathBuf> = BTreeSet::new();
+    let mut installed_count = 0;
+    let mut removed_count = 0;
 
-    for agent_name in &agent_names {
-        let agent = Agent::from_config_name(agent_name)?;
+    let hook_root = match sym.config.hook_scope {
+        crate::config::HookScope::Global => sym.home_dir().to_path_buf(),
+        crate::config::HookScope::Project => project_root.clone(),
+    };
+    let agents = agent_names
+        .iter()
+        .map(|name| Agent::resolve(name, &sym.config.agent_profiles))
+        .collect::<Result<Vec<_>>>()?;
+    if !options.dry_run {
+        migrate_legacy_mcp_entries(sym, &agents, &hook_root, &legacy_mcp_names, out);
+    }
 
-        let hook_root = match sym.config.hook_scope {
-            crate::config::HookScope::Global => sym.home_dir().to_path_buf(),
-            crate::config::HookScope::Project => project_root.clone(),
-        };
+    // Direct servers an earlier sync registered here that no longer apply.
+    let direct_mcp_names: Vec<String> = direct_mcp_servers
+        .iter()
+        .map(|s| crate::mcp_serve::server_name(s).to_string())
+        .collect();
+    let previous_mcp_names =
+        crate::workspace_state::WorkspaceState::load(sym, &project_root).mcp_servers;
+    let stale_mcp_names: Vec<&str> = previous_mcp_names
+        .iter()
+        .filter(|name| !direct_mcp_names.contains(name))
+        .map(String::as_str)
+        .collect();
 
+    for (agent_name, agent) in agent_names.iter().zip(&agents) {
         // Register hooks and MCP servers
         agent
             .register_hooks(&hook_root, sym, out)
             .context("failed to register hooks")?;
         agent
-            .register_global_mcp_servers(&hook_root, &mcp_servers, out)
+            .register_global_mcp_servers(&hook_root, &meta_server, out)
             .context("failed to register MCP servers")?;
+        if !direct_mcp_servers.is_empty() {
+            agent
+                .register_global_mcp_servers(&project_root, &direct_mcp_servers, out)
+                .context("failed to register MCP servers")?;
+        }
+        if !stale_mcp_names.is_empty() {
+            agent
+                .unregister_global_mcp_servers(&project_root, &stale_mcp_names, out)
+                .context("failed to remove stale MCP servers")?;
+        }
 
         for (skill_name, origin_hash, skill_source) in &to_install {
             // `skill_source` is the path to the SKILL.md file; the skill
@@ -465,9 +653,33 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
                 continue;
             }
 
-            match sync_skill_dir(source_dir, &dest_dir, &project_root, debounce) {
+            if options.dry_run {
+                installed_dirs.insert(dest_dir.clone());
+                match planned_skill_change(source_dir, &dest_dir, agent) {
+                    Ok(Some(action)) => plan(
+                        action,
+                        &dest_dir,
+                        Some(format!("skill {skill_name} for {agent_name}")),
+                    ),
+                    Ok(None) => {}
+                    Err(e) => tracing::info!(
+                        report = %crate::report::ReportEvent::Warning {
+                            message: format!("failed to compare skill {dir_name}: {e}"),
+                        },
+                    ),
+                }
+                continue;
+            }
+
+            let synced = sync_skill_dir(source_dir, &dest_dir, &project_root, agent, debounce)
+                .and_then(|changed| {
+                    agent.derive_skill_files(&dest_dir)?;
+                    Ok(changed)
+                });
+            match synced {
                 Ok(true) => {
                     installed_dirs.insert(dest_dir.clone());
+                    installed_count += 1;
                     tracing::info!(
                         report = %crate::report::ReportEvent::SkillInstalled {
                             skill: dir_name.clone(),
@@ -496,8 +708,8 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
     // all known agents, so we also clean up after agents removed from config)
     // and remove subdirs containing the marker that we didn't just install.
     let mut scanned: BTreeSet<PathBuf> = BTreeSet::new();
-    for &agent in Agent::all() {
-        let parent = skills_parent_dir(agent, &project_root);
+    for agent in Agent::known(&sym.config.agent_profiles) {
+        let parent = skills_parent_dir(&agent, &project_root);
         if !scanned.insert(parent.clone()) {
             continue;
         }
@@ -512,8 +724,13 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
             if !has_symposium_marker(&path) {
                 continue;
             }
+            if options.dry_run {
+                plan("remove", &path, Some("stale skill".into()));
+                continue;
+            }
             match fs::remove_dir_all(&path) {
                 Ok(()) => {
+                    removed_count += 1;
                     tracing::info!(
                         report = %crate::report::ReportEvent::SkillRemoved {
                             path: display_path(&path),
@@ -532,12 +749,28 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
     }
 
     // Unregister hooks/MCP for agents no longer configured
-    for &agent in Agent::all() {
+    let workspace_mcp_names: Vec<&str> = direct_mcp_names
+        .iter()
+        .map(String::as_str)
+        .chain(stale_mcp_names.iter().copied())
+        .collect();
+    for agent in Agent::known(&sym.config.agent_profiles) {
         if !agent_names.contains(&agent.config_name().to_string()) {
             agent.unregister_hooks(sym.home_dir(), sym, out);
-            let _ = agent.unregister_global_mcp_servers(sym.home_dir(), &server_names, out);
+            let _ = agent.unregister_global_mcp_servers(
+                sym.home_dir(),
+                &[crate::mcp_serve::SERVER_NAME],
+                out,
+            );
+            if !workspace_mcp_names.is_empty() {
+                let _ =
+                    agent.unregister_global_mcp_servers(&project_root, &workspace_mcp_names, out);
+            }
         }
     }
+    if !options.dry_run {
+        crate::workspace_state::record_mcp_servers(sym, &project_root, direct_mcp_names);
+    }
 
     if to_install.is_empty() {
         tracing::info!(
@@ -547,6 +780,72 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
         );
     }
 
+    if options.dry_run {
+        tracing::info!(
+            report = %crate::report::ReportEvent::Info {
+                message: "dry run: nothing was changed".into(),
+            },
+        );
+    } else {
+        crate::telemetry::record_if_enabled(
+            sym,
+            crate::telemetry::EventKind::Sync {
+                installed: installed_count,
+                removed: removed_count,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+/// Write or check `symposium.lock` for this resolution, per the lock mode.
+/// In [`LockMode::Update`], a workspace without a lockfile is left alone; in
+/// a dry run, a write is reported rather than made.
+fn apply_lock_mode(
+    project_root: &Path,
+    active: &[plugins::ParsedPlugin],
+    applicable: &[skills::SkillWithGroupContext],
+    pms: &crate::pm::PmRegistry,
+    options: &SyncOptions,
+) -> Result<()> {
+    let mode = options.lock;
+    let path = Lockfile::path(project_root);
+    if mode == LockMode::Update && !path.exists() {
+        return Ok(());
+    }
+    let resolved = Lockfile::resolve(active, applicable, pms)?;
+
+    if mode == LockMode::Locked {
+        let Some(locked) = Lockfile::read(&path)? else {
+            bail!(
+                "`--locked` requires a {LOCKFILE_NAME}; run `cargo agents sync --lock` to create one"
+            );
+        };
+        let changes = locked.diff(&resolved);
+        if !changes.is_empty() {
+            bail!(
+                "{LOCKFILE_NAME} is out of date, so nothing was installed:\n  {}\n\
+                 run `cargo agents sync` to update it",
+                changes.join("\n  ")
+            );
+        }
+        return Ok(());
+    }
+
+    if options.dry_run {
+        if Lockfile::read(&path).ok().flatten().as_ref() != Some(&resolved) {
+            plan("write", &path, None);
+        }
+        return Ok(());
+    }
+    if resolved.write(&path)? {
+        tracing::info!(
+            report = %crate::report::ReportEvent::Info {
+                message: format!("wrote {}", display_path(&path)),
+            },
+        );
+    }
     Ok(())
 }
 
@@ -554,38 +853,59 @@ pub async fn sync(sym: &Symposium, deps: &Arc<WorkspaceDeps>, update: UpdateLeve
 /// Register hooks for all configured agents. Uses `home_dir` (global scope).
 /// Called from `init` after writing the user config.
 pub async fn register_hooks(sym: &Symposium, out: &Output) -> Result<()> {
+    // Only the meta-server goes in the home configs: the HTTP/SSE plugin
+    // servers it can't proxy are workspace-specific, and `sync` writes them
+    // to each workspace's own agent configs.
     let registry = plugins::load_registry(sym).await;
-    let mcp_servers: Vec<sacp::schema::McpServer> = registry
-        .plugins
-        .iter()
-        .flat_map(|p| p.plugin.mcp_servers.iter().map(|s| s.server.clone()))
-        .collect();
-
-    let server_names: Vec<&str> = mcp_servers
-        .iter()
-        .map(|s| match s {
-            sacp::schema::McpServer::Stdio(s) => s.name.as_str(),
-            sacp::schema::McpServer::Http(s) => s.name.as_str(),
-            sacp::schema::McpServer::Sse(s) => s.name.as_str(),
-            _ => panic!("unsupported McpServer variant"),
-        })
-        .collect();
+    let mcp_servers = [crate::mcp_serve::registration_entry()];
+    let legacy_mcp_names = crate::mcp_serve::legacy_entry_names(&registry.plugins);
 
     let agent_names: Vec<String> = sym.config.agents.iter().map(|a| a.name.clone()).collect();
+    let agents = agent_names
+        .iter()
+        .map(|name| Agent::resolve(name, &sym.config.agent_profiles))
+        .collect::<Result<Vec<_>>>()?;
+    migrate_legacy_mcp_entries(sym, &agents, sym.home_dir(), &legacy_mcp_names, out);
 
-    for agent_name in &agent_names {
-        let agent = Agent::from_config_name(agent_name)?;
+    for agent in &agents {
         agent.register_hooks(sym.home_dir(), sym, out)?;
         agent.register_global_mcp_servers(sym.home_dir(), &mcp_servers, out)?;
     }
 
     // Unregister hooks for agents no longer configured
-    for &agent in Agent::all() {
+    let mcp_names: Vec<&str> = std::iter::once(crate::mcp_serve::SERVER_NAME)
+        .chain(legacy_mcp_names.iter().map(String::as_str))
+        .collect();
+    for agent in Agent::known(&sym.config.agent_profiles) {
         if !agent_names.contains(&agent.config_name().to_string()) {
             agent.unregister_hooks(sym.home_dir(), sym, out);
-            let _ = agent.unregister_global_mcp_servers(sym.home_dir(), &server_names, out);
+            let _ = agent.unregister_global_mcp_servers(sym.home_dir(), &mcp_names, out);
         }
     }
 
     Ok(())
 }
+
+/// Remove, once per hook root, the per-plugin MCP entries that releases
+/// before the meta-server registered with each agent directly. Left in
+/// place, agents would see those tools twice: directly and through
+/// `symposium`.
+fn migrate_legacy_mcp_entries(
+    sym: &Symposium,
+    agents: &[Agent],
+    hook_root: &Path,
+    legacy_names: &[String],
+    out: &Output,
+) {
+    if !crate::state::needs_mcp_migration(sym.config_dir(), hook_root) {
+        return;
+    }
+    let names: Vec<&str> = legacy_names.iter().map(String::as_str).collect();
+    for agent in agents {
+        if let Err(e) = agent.unregister_global_mcp_servers(hook_root, &names, out) {
+            tracing::warn!(agent = %agent.config_name(), error = %e, "failed to remove legacy MCP entries");
+            return;
+        }
+    }
+    crate::state::record_mcp_migration(sym.config_dir(), hook_root);
+}
@@ -11,6 +11,7 @@
 //! Every entry point here is best-effort: a failure to read or write the log
 //! must never break a hook, so errors are logged and swallowed.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::{self, OpenOptions};
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
@@ -48,10 +49,13 @@ pub enum EventKind {
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         plugins: Vec<String>,
     },
-    /// The user submitted a prompt.
+    /// The user submitted a prompt. Only the skills symposium pointed the
+    /// agent at are kept, never the prompt.
     UserPrompt {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         session_id: Option<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        skills: Vec<String>,
     },
     /// The agent invoked a tool (named, but with no arguments captured).
     ToolUse {
@@ -59,6 +63,49 @@ pub enum EventKind {
         session_id: Option<String>,
         tool: String,
     },
+    /// The agent read a file from a skill symposium installed. Only the
+    /// skill's name is kept, not the path that was read.
+    SkillRead {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        session_id: Option<String>,
+        agent: String,
+        skill: String,
+    },
+    /// A plugin hook was dispatched.
+    HookDispatch {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        session_id: Option<String>,
+        plugin: String,
+        hook: String,
+        event: String,
+        duration_ms: u64,
+        /// `None` when the hook could not run, timed out, or was killed by
+        /// a signal.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        exit_code: Option<i32>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        timed_out: bool,
+    },
+    /// A sync finished, installing and removing this many skill directories
+    /// across the configured agents.
+    Sync { installed: usize, removed: usize },
+    /// The user decided whether a dependency plugin may run.
+    Consent {
+        plugin: String,
+        decision: ConsentDecision,
+    },
+}
+
+/// A consent decision recorded by [`EventKind::Consent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentDecision {
+    /// Enabled, through the consent prompt or `cargo agents use`.
+    Granted,
+    /// Declined at the consent prompt.
+    Declined,
+    /// Withdrawn with `cargo agents use --remove`.
+    Withdrawn,
 }
 
 impl TelemetryEvent {
@@ -76,6 +123,10 @@ impl TelemetryEvent {
             EventKind::SessionStart { .. } => "session_start",
             EventKind::UserPrompt { .. } => "user_prompt",
             EventKind::ToolUse { .. } => "tool_use",
+            EventKind::SkillRead { .. } => "skill_read",
+            EventKind::HookDispatch { .. } => "hook_dispatch",
+            EventKind::Sync { .. } => "sync",
+            EventKind::Consent { .. } => "consent",
         }
     }
 }
@@ -122,6 +173,14 @@ pub fn record_kind(config_dir: &Path, kind: EventKind) {
     record(config_dir, &TelemetryEvent::now(kind));
 }
 
+/// Record `kind` if the user has opted in. Every recording site goes through
+/// here, so the opt-in is checked in one place.
+pub fn record_if_enabled(sym: &crate::config::Symposium, kind: EventKind) {
+    if sym.config.telemetry.enabled {
+        record_kind(sym.config_dir(), kind);
+    }
+}
+
 /// Parse the date out of an `events-YYYY-MM-DD.jsonl` filename.
 fn file_date(path: &Path) -> Option<NaiveDate> {
     let stem = path.file_name()?.to_str()?;
@@ -254,6 +313,342 @@ pub fn status_text(config_dir: &Path, enabled: bool) -> String {
     out
 }
 
+/// The events recorded between `since` and `until` (inclusive UTC dates;
+/// either end open when `None`), oldest first.
+pub fn events_between(
+    config_dir: &Path,
+    since: Option<NaiveDate>,
+    until: Option<NaiveDate>,
+) -> Vec<TelemetryEvent> {
+    read_events(config_dir)
+        .into_iter()
+        .filter(|e| {
+            let day = e.at.date_naive();
+            since.is_none_or(|s| day >= s) && until.is_none_or(|u| day <= u)
+        })
+        .collect()
+}
+
+/// Aggregated telemetry for `telemetry summary`: one table per plugin, agent,
+/// tool and day.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Summary {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<NaiveDate>,
+    pub events: usize,
+    pub plugins: BTreeMap<String, PluginStats>,
+    pub agents: BTreeMap<String, AgentStats>,
+    pub tools: BTreeMap<String, ToolStats>,
+    pub days: BTreeMap<NaiveDate, DayStats>,
+}
+
+/// Per-plugin totals.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginStats {
+    /// Sessions that started with the plugin applicable.
+    pub sessions: usize,
+    pub hook_runs: usize,
+    /// Hook runs that timed out, could not run, or exited non-zero.
+    pub hook_failures: usize,
+    pub hook_ms_total: u64,
+    pub consents_granted: usize,
+    pub consents_declined: usize,
+}
+
+/// Per-agent totals.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentStats {
+    pub sessions: usize,
+    pub skill_reads: usize,
+}
+
+/// Per-tool totals.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolStats {
+    pub uses: usize,
+}
+
+/// Per-day totals.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayStats {
+    pub events: usize,
+    pub sessions: usize,
+    pub prompts: usize,
+    pub skill_reads: usize,
+    pub syncs: usize,
+}
+
+impl Summary {
+    /// Aggregate `events`, already filtered to the `since`..=`until` range.
+    pub fn of(
+        events: &[TelemetryEvent],
+        since: Option<NaiveDate>,
+        until: Option<NaiveDate>,
+    ) -> Self {
+        let mut summary = Summary {
+            since,
+            until,
+            events: events.len(),
+            ..Summary::default()
+        };
+        let mut sessions_by_day: BTreeMap<NaiveDate, BTreeSet<&str>> = BTreeMap::new();
+
+        for event in events {
+            let day_key = event.at.date_naive();
+            let day = summary.days.entry(day_key).or_default();
+            day.events += 1;
+            match &event.kind {
+                EventKind::SessionStart {
+                    session_id,
+                    agent,
+                    plugins,
+                } => {
+                    if let Some(id) = session_id {
+                        sessions_by_day.entry(day_key).or_default().insert(id);
+                    }
+                    summary.agents.entry(agent.clone()).or_default().sessions += 1;
+                    for plugin in plugins {
+                        summary.plugins.entry(plugin.clone()).or_default().sessions += 1;
+                    }
+                }
+                EventKind::UserPrompt { .. } => day.prompts += 1,
+                EventKind::ToolUse { tool, .. } => {
+                    summary.tools.entry(tool.clone()).or_default().uses += 1;
+                }
+                EventKind::SkillRead { agent, .. } => {
+                    day.skill_reads += 1;
+                    summary.agents.entry(agent.clone()).or_default().skill_reads += 1;
+                }
+                EventKind::HookDispatch {
+                    plugin,
+                    duration_ms,
+                    exit_code,
+                    ..
+                } => {
+                    let stats = summary.plugins.entry(plugin.clone()).or_default();
+                    stats.hook_runs += 1;
+                    stats.hook_ms_total += duration_ms;
+                    if *exit_code != Some(0) {
+                        stats.hook_failures += 1;
+                    }
+                }
+                EventKind::Sync { .. } => day.syncs += 1,
+                EventKind::Consent { plugin, decision } => {
+                    let stats = summary.plugins.entry(plugin.clone()).or_default();
+                    match decision {
+                        ConsentDecision::Granted => stats.consents_granted += 1,
+                        ConsentDecision::Declined => stats.consents_declined += 1,
+                        ConsentDecision::Withdrawn => {}
+                    }
+                }
+            }
+        }
+        for (day, ids) in sessions_by_day {
+            summary.days.entry(day).or_default().sessions = ids.len();
+        }
+        summary
+    }
+
+    /// Render the summary as plain-text tables.
+    pub fn render(&self) -> String {
+        use std::fmt::Write as _;
+        let mut out = String::new();
+        let range = match (self.since, self.until) {
+            (None, None) => "all recorded events".to_string(),
+            (Some(s), None) => format!("since {s}"),
+            (None, Some(u)) => format!("until {u}"),
+            (Some(s), Some(u)) => format!("{s} … {u}"),
+        };
+        let _ = writeln!(out, "Telemetry summary ({range}): {} event(s)", self.events);
+        if self.events == 0 {
+            return out;
+        }
+
+        let plugins = self.plugins.iter().map(|(name, s)| {
+            let avg = if s.hook_runs == 0 {
+                "-".to_string()
+            } else {
+                format!("{}", s.hook_ms_total / s.hook_runs as u64)
+            };
+            vec![
+                name.clone(),
+                s.sessions.to_string(),
+                s.hook_runs.to_string(),
+                s.hook_failures.to_string(),
+                avg,
+                format!("{}/{}", s.consents_granted, s.consents_declined),
+            ]
+        });
+        let agents = self.agents.iter().map(|(name, s)| {
+            vec![
+                name.clone(),
+                s.sessions.to_string(),
+                s.skill_reads.to_string(),
+            ]
+        });
+        let tools = self
+            .tools
+            .iter()
+            .map(|(name, s)| vec![name.clone(), s.uses.to_string()]);
+        let days = self.days.iter().map(|(day, s)| {
+            vec![
+                day.to_string(),
+                s.events.to_string(),
+                s.sessions.to_string(),
+                s.prompts.to_string(),
+                s.skill_reads.to_string(),
+                s.syncs.to_string(),
+            ]
+        });
+
+        out.push_str(&table(
+            &[
+                "plugin",
+                "sessions",
+                "hook runs",
+                "failures",
+                "avg ms",
+                "consent +/-",
+            ],
+            plugins.collect(),
+        ));
+        out.push_str(&table(
+            &["agent", "sessions", "skill reads"],
+            agents.collect(),
+        ));
+        out.push_str(&table(&["tool", "uses"], tools.collect()));
+        out.push_str(&table(
+            &[
+                "day",
+                "events",
+                "sessions",
+                "prompts",
+                "skill reads",
+                "syncs",
+            ],
+            days.collect(),
+        ));
+        out
+    }
+}
+
+/// A blank line and a left-aligned table, or nothing when there are no rows.
+fn table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    if rows.is_empty() {
+        return String::new();
+    }
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: Vec<&str>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        format!("  {}\n", padded.join("  ").trim_end())
+    };
+    let mut out = String::from("\n");
+    out.push_str(&line(headers.to_vec()));
+    for row in &rows {
+        out.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    out
+}
+
+/// File formats for `telemetry export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// One JSON object per line, as stored.
+    Jsonl,
+    /// One row per event; columns are the union of the events' fields.
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Jsonl => "jsonl",
+            ExportFormat::Csv => "csv",
+        }
+    }
+}
+
+/// Render `events` in `format`, for a file the user shares deliberately.
+pub fn export(events: &[TelemetryEvent], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Jsonl => events
+            .iter()
+            .filter_map(|e| serde_json::to_string(e).ok())
+            .map(|line| line + "\n")
+            .collect(),
+        ExportFormat::Csv => export_csv(events),
+    }
+}
+
+fn export_csv(events: &[TelemetryEvent]) -> String {
+    let rows: Vec<serde_json::Map<String, serde_json::Value>> = events
+        .iter()
+        .filter_map(|e| match serde_json::to_value(e) {
+            Ok(serde_json::Value::Object(map)) => Some(map),
+            _ => None,
+        })
+        .collect();
+
+    // `at` and `kind` lead; the remaining fields follow alphabetically.
+    let mut columns = vec!["at".to_string(), "kind".to_string()];
+    let rest: BTreeSet<&String> = rows
+        .iter()
+        .flat_map(|row| row.keys())
+        .filter(|k| *k != "at" && *k != "kind")
+        .collect();
+    columns.extend(rest.into_iter().cloned());
+
+    let mut out = columns
+        .iter()
+        .map(|c| csv_field(c))
+        .collect::<Vec<_>>()
+        .join(",");
+    out.push('\n');
+    for row in &rows {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| match row.get(column) {
+                None | Some(serde_json::Value::Null) => String::new(),
+                Some(serde_json::Value::String(s)) => csv_field(s),
+                Some(serde_json::Value::Array(items)) => csv_field(
+                    &items
+                        .iter()
+                        .map(|item| {
+                            item.as_str()
+                                .map_or_else(|| item.to_string(), str::to_string)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(";"),
+                ),
+                Some(other) => csv_field(&other.to_string()),
+            })
+            .collect();
+        out.push_str(&cells.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quote a CSV field when it holds a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 fn human_bytes(bytes: u64) -> String {
     const KIB: u64 = 1024;
     const MIB: u64 = 1024 * KIB;
@@ -288,6 +683,32 @@ mod tests {
         assert_eq!(back.kind_name(), "tool_use");
     }
 
+    #[test]
+    fn new_kinds_serialize_flat() {
+        let line = serde_json::to_string(&TelemetryEvent::now(EventKind::Consent {
+            plugin: "tokio".into(),
+            decision: ConsentDecision::Declined,
+        }))
+        .unwrap();
+        assert!(line.contains(r#""kind":"consent""#), "line = {line}");
+        assert!(line.contains(r#""decision":"declined""#), "line = {line}");
+
+        let line = serde_json::to_string(&TelemetryEvent::now(EventKind::HookDispatch {
+            session_id: None,
+            plugin: "p".into(),
+            hook: "h".into(),
+            event: "PreToolUse".into(),
+            duration_ms: 12,
+            exit_code: None,
+            timed_out: true,
+        }))
+        .unwrap();
+        assert!(line.contains(r#""timed_out":true"#), "line = {line}");
+        assert!(!line.contains("exit_code"), "line = {line}");
+        let back: TelemetryEvent = serde_json::from_str(&line).unwrap();
+        assert_eq!(back.kind_name(), "hook_dispatch");
+    }
+
     #[test]
     fn records_append_and_read_back() {
         let tmp = tempfile::tempdir().unwrap();
@@ -304,6 +725,7 @@ mod tests {
             dir,
             EventKind::UserPrompt {
                 session_id: Some("s1".into()),
+                skills: vec!["tokio-guidance".into()],
             },
         );
         record_kind(
@@ -342,6 +764,156 @@ mod tests {
         assert!(recent.exists(), "recent file should remain");
     }
 
+    fn at(day: &str, kind: EventKind) -> TelemetryEvent {
+        TelemetryEvent {
+            at: DateTime::parse_from_rfc3339(&format!("{day}T10:00:00Z"))
+                .unwrap()
+                .with_timezone(&Utc),
+            kind,
+        }
+    }
+
+    fn sample_events() -> Vec<TelemetryEvent> {
+        vec![
+            at(
+                "2026-06-22",
+                EventKind::SessionStart {
+                    session_id: Some("s1".into()),
+                    agent: "claude".into(),
+                    plugins: vec!["tokio".into()],
+                },
+            ),
+            at(
+                "2026-06-22",
+                EventKind::HookDispatch {
+                    session_id: Some("s1".into()),
+                    plugin: "tokio".into(),
+                    hook: "check".into(),
+                    event: "PreToolUse".into(),
+                    duration_ms: 30,
+                    exit_code: Some(0),
+                    timed_out: false,
+                },
+            ),
+            at(
+                "2026-06-23",
+                EventKind::HookDispatch {
+                    session_id: Some("s2".into()),
+                    plugin: "tokio".into(),
+                    hook: "check".into(),
+                    event: "PreToolUse".into(),
+                    duration_ms: 10,
+                    exit_code: Some(2),
+                    timed_out: false,
+                },
+            ),
+            at(
+                "2026-06-23",
+                EventKind::ToolUse {
+                    session_id: Some("s2".into()),
+                    tool: "Bash".into(),
+                },
+            ),
+            at(
+                "2026-06-23",
+                EventKind::SkillRead {
+                    session_id: Some("s2".into()),
+                    agent: "claude".into(),
+                    skill: "tokio-guidance".into(),
+                },
+            ),
+            at(
+                "2026-06-23",
+                EventKind::Consent {
+                    plugin: "serde".into(),
+                    decision: ConsentDecision::Declined,
+                },
+            ),
+        ]
+    }
+
+    #[test]
+    fn summary_aggregates_per_plugin_agent_tool_and_day() {
+        let summary = Summary::of(&sample_events(), None, None);
+        assert_eq!(summary.events, 6);
+
+        let tokio = &summary.plugins["tokio"];
+        assert_eq!(tokio.sessions, 1);
+        assert_eq!(tokio.hook_runs, 2);
+        assert_eq!(tokio.hook_failures, 1);
+        assert_eq!(tokio.hook_ms_total, 40);
+        assert_eq!(summary.plugins["serde"].consents_declined, 1);
+
+        assert_eq!(summary.agents["claude"].sessions, 1);
+        assert_eq!(summary.agents["claude"].skill_reads, 1);
+        assert_eq!(summary.tools["Bash"].uses, 1);
+
+        let day = NaiveDate::from_ymd_opt(2026, 6, 23).unwrap();
+        assert_eq!(summary.days[&day].events, 4);
+        assert_eq!(summary.days[&day].skill_reads, 1);
+
+        let text = summary.render();
+        assert!(text.contains("6 event(s)"), "{text}");
+        assert!(
+            text.contains("  tokio   1         2          1         20"),
+            "{text}"
+        );
+        assert!(text.contains("  2026-06-23"), "{text}");
+
+        let back: Summary =
+            serde_json::from_str(&serde_json::to_string(&summary).unwrap()).unwrap();
+        assert_eq!(back, summary);
+    }
+
+    #[test]
+    fn events_between_filters_by_day() {
+        let tmp = tempfile::tempdir().unwrap();
+        for event in sample_events() {
+            record(tmp.path(), &event);
+        }
+        let day = |d| NaiveDate::from_ymd_opt(2026, 6, d);
+        assert_eq!(events_between(tmp.path(), None, None).len(), 6);
+        assert_eq!(events_between(tmp.path(), day(23), None).len(), 4);
+        assert_eq!(events_between(tmp.path(), None, day(22)).len(), 2);
+        assert!(events_between(tmp.path(), day(24), None).is_empty());
+    }
+
+    #[test]
+    fn csv_export_has_one_column_per_field() {
+        let events = [
+            at(
+                "2026-06-22",
+                EventKind::SessionStart {
+                    session_id: Some("a,b".into()),
+                    agent: "claude".into(),
+                    plugins: vec!["tokio".into(), "serde".into()],
+                },
+            ),
+            at(
+                "2026-06-22",
+                EventKind::Sync {
+                    installed: 2,
+                    removed: 0,
+                },
+            ),
+        ];
+        let csv = export(&events, ExportFormat::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "at,kind,agent,installed,plugins,removed,session_id",
+                "2026-06-22T10:00:00Z,session_start,claude,,tokio;serde,,\"a,b\"",
+                "2026-06-22T10:00:00Z,sync,,2,,0,",
+            ]
+        );
+
+        let jsonl = export(&events, ExportFormat::Jsonl);
+        assert_eq!(jsonl.lines().count(), 2);
+        let back: TelemetryEvent = serde_json::from_str(jsonl.lines().next().unwrap()).unwrap();
+        assert_eq!(back.kind_name(), "session_start");
+    }
+
     #[test]
     fn status_text_reflects_enabled_and_storage() {
         let tmp = tempfile::tempdir().unwrap();
@@ -350,7 +922,13 @@ mod tests {
         assert!(empty.contains("Telemetry: disabled"));
         assert!(empty.contains("nothing yet"));
 
-        record_kind(dir, EventKind::UserPrompt { session_id: None });
+        record_kind(
+            dir,
+            EventKind::UserPrompt {
+                session_id: None,
+                skills: Vec::new(),
+            },
+        );
         let filled = status_text(dir, true);
         assert!(filled.contains("Telemetry: enabled"));
         assert!(filled.contains("1 event(s)"));
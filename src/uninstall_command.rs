@@ -0,0 +1,331 @@
+//! `cargo agents uninstall` — back symposium out of agent configs and
+//! workspaces.
+//!
+//! Removes what `init` and `sync` wrote: the hook entries and the MCP servers
+//! from every known agent's config, and every `.symposium`-marked skill
+//! directory. The MCP servers are `symposium`, the HTTP/SSE servers
+//! [workspace state] recorded a sync registering in that workspace, and —
+//! where sync hasn't yet migrated them — the per-plugin entries of releases
+//! before the meta-server. An entry the user added under a plugin server's
+//! name is left alone. Workspaces are the ones workspace state recorded a
+//! sync for, plus the current one. `--purge` also deletes the config and
+//! cache directories.
+//!
+//! The same pass runs twice: first against a dry-run [`Output`], which
+//! reports each change as a [`SyncPlanned`](ReportEvent::SyncPlanned) event,
+//! then — once confirmed — for real.
+//!
+//! [workspace state]: crate::workspace_state::known_workspaces
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+
+use crate::agents::Agent;
+use crate::config::Symposium;
+use crate::output::{Output, display_path};
+use crate::report::ReportEvent;
+
+/// What `uninstall` covers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UninstallScope {
+    /// The home-directory agent configs and every known workspace.
+    #[default]
+    Everything,
+    /// Only the current workspace (`--workspace`).
+    Workspace,
+    /// Only the home-directory agent configs (`--global`).
+    Global,
+}
+
+/// Options for [`uninstall`].
+#[derive(Debug, Clone, Default)]
+pub struct UninstallOptions {
+    pub scope: UninstallScope,
+    /// Also delete the config and cache directories.
+    pub purge: bool,
+    /// Report what would be removed, and stop.
+    pub dry_run: bool,
+    /// Skip the confirmation.
+    pub yes: bool,
+}
+
+/// What one uninstall pass works through.
+struct Targets {
+    /// The home directory, when the global agent configs are included.
+    home: Option<PathBuf>,
+    workspaces: Vec<PathBuf>,
+    /// Directories deleted outright by `--purge`.
+    purge: Vec<PathBuf>,
+    /// The per-plugin MCP entry names of releases before the meta-server.
+    legacy_mcp_names: Vec<String>,
+}
+
+/// The `cargo agents uninstall` entry point.
+pub async fn uninstall(
+    sym: &Symposium,
+    cwd: &Path,
+    out: &Output,
+    options: &UninstallOptions,
+) -> Result<()> {
+    let mut targets = targets(sym, cwd, options)?;
+    let registry = crate::plugins::load_registry(sym).await;
+    targets.legacy_mcp_names = crate::mcp_serve::legacy_entry_names(&registry.plugins);
+
+    let plan = Output::quiet().with_dry_run();
+    remove(sym, &targets, &plan);
+    if plan.planned_changes().is_empty() {
+        tracing::info!(
+            report = %ReportEvent::Info {
+                message: "nothing to uninstall".into(),
+            },
+        );
+        return Ok(());
+    }
+    if options.dry_run {
+        tracing::info!(
+            report = %ReportEvent::Info {
+                message: "dry run: nothing was changed".into(),
+            },
+        );
+        return Ok(());
+    }
+
+    if !options.yes {
+        if !out.is_interactive() {
+            bail!("re-run with `--yes` to remove the above");
+        }
+        let confirmed = dialoguer::Confirm::new()
+            .with_prompt("Remove everything listed above?")
+            .default(false)
+            .interact()?;
+        if !confirmed {
+            out.info("nothing was changed");
+            return Ok(());
+        }
+    }
+
+    remove(sym, &targets, out);
+    out.done("symposium uninstalled");
+    Ok(())
+}
+
+fn targets(sym: &Symposium, cwd: &Path, options: &UninstallOptions) -> Result<Targets> {
+    let current = crate::workspace_state::find_workspace_root(sym, cwd);
+    let global = options.scope != UninstallScope::Workspace;
+
+    let workspaces = match options.scope {
+        UninstallScope::Everything => {
+            let mut roots = crate::workspace_state::known_workspaces(sym);
+            roots.extend(current);
+            roots.sort();
+            roots.dedup();
+            roots
+        }
+        UninstallScope::Workspace => match current {
+            Some(root) => vec![root],
+            None => bail!("not in a Rust workspace"),
+        },
+        UninstallScope::Global => Vec::new(),
+    };
+
+    // The cache usually lives inside the config directory; deleting the
+    // config directory then takes it along.
+    let mut purge = Vec::new();
+    if options.purge && global {
+        let config_dir = sym.config_dir().to_path_buf();
+        if !sym.cache_dir().starts_with(&config_dir) {
+            purge.push(sym.cache_dir().to_path_buf());
+        }
+        purge.push(config_dir);
+    }
+
+    Ok(Targets {
+        home: global.then(|| sym.home_dir().to_path_buf()),
+        workspaces,
+        purge,
+        legacy_mcp_names: Vec::new(),
+    })
+}
+
+/// Remove everything in `targets`; under a dry-run `out`, report it instead.
+fn remove(sym: &Symposium, targets: &Targets, out: &Output) {
+    let agents = Agent::known(&sym.config.agent_profiles);
+
+    if let Some(home) = &targets.home {
+        let names = mcp_entry_names(sym, targets, home, &[]);
+        let server: Vec<&str> = names.iter().map(String::as_str).collect();
+        for agent in &agents {
+            agent.unregister_hooks(home, sym, out);
+            if let Err(e) = agent.unregister_global_mcp_servers(home, &server, out) {
+                out.warn(format!("{}: {e:#}", agent.display_name()));
+            }
+        }
+    }
+
+    for root in &targets.workspaces {
+        let recorded = crate::workspace_state::WorkspaceState::load(sym, root).mcp_servers;
+        let names = mcp_entry_names(sym, targets, root, &recorded);
+        let server: Vec<&str> = names.iter().map(String::as_str).collect();
+        // `sync` registers at the workspace root with the global-config
+        // writers; the project writers cover configs from earlier releases.
+        for agent in &agents {
+            agent.unregister_hooks(root, sym, out);
+            agent.unregister_project_hooks(root, sym, out);
+            for result in [
+                agent.unregister_global_mcp_servers(root, &server, out),
+                agent.unregister_project_mcp_servers(root, &server, out),
+            ] {
+                if let Err(e) = result {
+                    out.warn(format!("{}: {e:#}", agent.display_name()));
+                }
+            }
+        }
+        if !out.is_dry_run() && !recorded.is_empty() {
+            crate::workspace_state::record_mcp_servers(sym, root, Vec::new());
+        }
+        for dir in managed_skill_dirs(&agents, root) {
+            remove_dir(&dir, "skill", out);
+        }
+    }
+
+    for dir in &targets.purge {
+        remove_dir(dir, "symposium data", out);
+    }
+}
+
+/// The MCP entries symposium wrote under `root`: the meta-server, the
+/// `recorded` HTTP/SSE servers, and the legacy per-plugin entries unless
+/// sync already removed them there.
+fn mcp_entry_names(
+    sym: &Symposium,
+    targets: &Targets,
+    root: &Path,
+    recorded: &[String],
+) -> Vec<String> {
+    let mut names = vec![crate::mcp_serve::SERVER_NAME.to_string()];
+    names.extend(recorded.iter().cloned());
+    if crate::state::needs_mcp_migration(sym.config_dir(), root) {
+        names.extend(targets.legacy_mcp_names.iter().cloned());
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Every `.symposium`-marked directory in the agents' skill directories
+/// under `root`.
+fn managed_skill_dirs(agents: &[Agent], root: &Path) -> Vec<PathBuf> {
+    let mut parents: Vec<PathBuf> = agents
+        .iter()
+        .map(|agent| crate::sync::skills_parent_dir(agent, root))
+        .collect();
+    parents.sort();
+    parents.dedup();
+
+    let mut dirs = Vec::new();
+    for parent in parents {
+        let Ok(entries) = fs::read_dir(&parent) else {
+            continue;
+        };
+        let mut marked: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir() && crate::sync::has_symposium_marker(path))
+            .collect();
+        marked.sort();
+        dirs.extend(marked);
+    }
+    dirs
+}
+
+fn remove_dir(dir: &Path, detail: &str, out: &Output) {
+    if !dir.exists() {
+        return;
+    }
+    if out.is_dry_run() {
+        if out.plan_change(dir) {
+            tracing::info!(
+                report = %ReportEvent::SyncPlanned {
+                    action: "remove".into(),
+                    path: display_path(dir),
+                    detail: Some(detail.into()),
+                },
+            );
+        }
+        return;
+    }
+    match fs::remove_dir_all(dir) {
+        Ok(()) => tracing::info!(
+            report = %ReportEvent::SkillRemoved {
+                path: display_path(dir),
+            },
+        ),
+        Err(e) => tracing::info!(
+            report = %ReportEvent::Warning {
+                message: format!("failed to remove {}: {e}", display_path(dir)),
+            },
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_marked_skill_dirs_are_managed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skills = tmp.path().join(".claude/skills");
+        for name in ["ours", "theirs"] {
+            fs::create_dir_all(skills.join(name)).unwrap();
+        }
+        crate::sync::mark_generated_skill_directory(&skills.join("ours")).unwrap();
+
+        let dirs = managed_skill_dirs(&[Agent::Claude], tmp.path());
+        assert_eq!(dirs, [skills.join("ours")]);
+    }
+
+    #[test]
+    fn legacy_mcp_entries_are_removed_only_where_not_migrated() {
+        let tmp = tempfile::tempdir().unwrap();
+        let sym = Symposium::from_dir(tmp.path());
+        let mut targets = targets(&sym, tmp.path(), &UninstallOptions::default()).unwrap();
+        targets.legacy_mcp_names = vec!["old-server".to_string()];
+        let root = tmp.path().join("project");
+        let recorded = ["remote".to_string()];
+
+        assert_eq!(
+            mcp_entry_names(&sym, &targets, &root, &recorded),
+            ["old-server", "remote", "symposium"]
+        );
+        crate::state::record_mcp_migration(sym.config_dir(), &root);
+        assert_eq!(
+            mcp_entry_names(&sym, &targets, &root, &recorded),
+            ["remote", "symposium"]
+        );
+    }
+
+    #[test]
+    fn purge_skips_a_cache_inside_the_config_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        let sym = Symposium::from_dir(tmp.path());
+        let options = UninstallOptions {
+            scope: UninstallScope::Global,
+            purge: true,
+            ..UninstallOptions::default()
+        };
+        let global = targets(&sym, tmp.path(), &options).unwrap();
+        assert_eq!(global.purge, [sym.config_dir().to_path_buf()]);
+        assert!(global.workspaces.is_empty());
+
+        let options = UninstallOptions {
+            scope: UninstallScope::Workspace,
+            purge: true,
+            ..UninstallOptions::default()
+        };
+        // Outside a workspace there is nothing to scope to.
+        assert!(targets(&sym, tmp.path(), &options).is_err());
+    }
+}
@@ -32,6 +32,10 @@ pub async fn use_plugin(
     global: bool,
     update: UpdateLevel,
 ) -> Result<()> {
+    if !sym.policy().permits_plugin(name) {
+        bail!("`{name}` is denied by system policy");
+    }
+
     // A configured registry is a trust root: what it offers is already
     // enabled by configuration, so there is nothing to record. The exception
     // is a dormant plugin, for which `use` is exactly the wake-up call.
@@ -81,7 +85,15 @@ pub async fn use_plugin(
         );
     } else {
         sym.config.plugins.used.push(entry);
-        sym.save_config().context("failed to write user config")?;
+        sym.save_config_keys(&["plugins.use"])
+            .context("failed to write user config")?;
+        crate::telemetry::record_if_enabled(
+            sym,
+            crate::telemetry::EventKind::Consent {
+                plugin: name.to_string(),
+                decision: crate::telemetry::ConsentDecision::Granted,
+            },
+        );
         tracing::info!(
             report = %ReportEvent::PluginEnabled {
                 name: name.to_string(),
@@ -134,7 +146,15 @@ pub async fn remove_plugin(
         let scope = if global { "--global" } else { "this workspace" };
         bail!("no `use` entry for `{name}` ({scope}); see `cargo agents status`");
     }
-    sym.save_config().context("failed to write user config")?;
+    sym.save_config_keys(&["plugins.use"])
+        .context("failed to write user config")?;
+    crate::telemetry::record_if_enabled(
+        sym,
+        crate::telemetry::EventKind::Consent {
+            plugin: name.to_string(),
+            decision: crate::telemetry::ConsentDecision::Withdrawn,
+        },
+    );
     tracing::info!(
         report = %ReportEvent::PluginRemoved {
             name: name.to_string(),
@@ -0,0 +1,322 @@
+//! Team configuration committed in a workspace.
+//!
+//! A workspace can declare what its contributors should share in the
+//! `[workspace.metadata.symposium]` table of its root `Cargo.toml`:
+//!
+//! ```toml
+//! [workspace.metadata.symposium.plugins]
+//! auto-enable = ["sqlx"]
+//! use = ["team-conventions"]
+//! disable = ["noisy-plugin"]
+//!
+//! [[workspace.metadata.symposium.registry]]
+//! name = "acme"
+//! git = "https://github.com/acme/agent-plugins"
+//! ```
+//!
+//! The `[plugins]` section layers *under* the user's own (see
+//! [`PluginsConfig::layered_over`]): each contributor's own explicit
+//! decision still wins. A dependency plugin the team names in `auto-enable`
+//! is pre-consented and enabled without a prompt; its `use` entries only
+//! recommend, and its `auto-enable = ["*"]` is ignored, since neither names
+//! what it agrees to. Registries are trust roots, so a recommended one loads
+//! only once the user agrees to it ([`prompt_for_registry_consent`]); the
+//! answer is recorded by source in the user's `[workspace-registries]`.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::config::{PluginsConfig, RegistryConfig, Symposium};
+use crate::output::Output;
+use crate::report::ReportEvent;
+
+/// The `[workspace.metadata.symposium]` table.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceConfig {
+    /// Enablement recommended for everyone working in the workspace.
+    #[serde(default)]
+    pub plugins: PluginsConfig,
+
+    /// Registries recommended for the workspace. Relative `path` values
+    /// resolve against the workspace root.
+    #[serde(default, rename = "registry")]
+    pub registries: Vec<RegistryConfig>,
+}
+
+/// Which configuration layer a decision came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigLayer {
+    /// The user's `config.toml`.
+    User,
+    /// The workspace's `[workspace.metadata.symposium]`.
+    Workspace,
+}
+
+/// Read the team config from `workspace_root/Cargo.toml`. A missing table is
+/// `None`; a malformed one is warned about and ignored.
+pub fn load(workspace_root: &Path) -> Option<WorkspaceConfig> {
+    let manifest = workspace_root.join("Cargo.toml");
+    let table = match crate::crate_metadata::workspace_symposium_metadata(&manifest) {
+        Ok(table) => table?,
+        Err(e) => {
+            tracing::debug!(path = %manifest.display(), error = %e, "no workspace manifest");
+            return None;
+        }
+    };
+    match table.try_into() {
+        Ok(config) => Some(config),
+        Err(e) => {
+            tracing::warn!(
+                path = %manifest.display(),
+                error = %e,
+                "ignoring malformed [workspace.metadata.symposium]"
+            );
+            None
+        }
+    }
+}
+
+/// The source a registry consent is recorded under: the git URL, or the
+/// absolute path of a path registry.
+fn registry_source(cfg: &RegistryConfig, workspace_root: &Path) -> Option<String> {
+    if let Some(url) = &cfg.git {
+        return Some(url.clone());
+    }
+    cfg.path
+        .as_ref()
+        .map(|path| workspace_root.join(path).display().to_string())
+}
+
+/// The workspace's recommended registries, with path sources made absolute,
+/// paired with the source their consent is recorded under.
+fn recommended_registries(workspace_root: &Path) -> Vec<(String, RegistryConfig)> {
+    let Some(config) = load(workspace_root) else {
+        return Vec::new();
+    };
+    config
+        .registries
+        .into_iter()
+        .filter_map(|mut cfg| {
+            let source = registry_source(&cfg, workspace_root)?;
+            if cfg.git.is_none() {
+                cfg.path = Some(source.clone());
+            }
+            Some((source, cfg))
+        })
+        .collect()
+}
+
+/// The recommended registries the user has agreed to load.
+pub fn trusted_registries(sym: &Symposium, workspace_root: &Path) -> Vec<RegistryConfig> {
+    let trust = &sym.config.workspace_registries.trust;
+    recommended_registries(workspace_root)
+        .into_iter()
+        .filter(|(source, _)| trust.contains(source))
+        .map(|(_, cfg)| cfg)
+        .collect()
+}
+
+/// The recommended registries nobody has decided about yet, as
+/// `(source, registry)` pairs.
+pub fn pending_registries(sym: &Symposium, workspace_root: &Path) -> Vec<(String, RegistryConfig)> {
+    let decided = &sym.config.workspace_registries;
+    recommended_registries(workspace_root)
+        .into_iter()
+        .filter(|(source, _)| !decided.trust.contains(source) && !decided.decline.contains(source))
+        .collect()
+}
+
+/// Ask about each recommended registry not yet decided, and record the
+/// answers. Like the plugin
+/// [consent prompt](crate::discovery::prompt_for_consent), this is inert
+/// unless `out` is interactive and records only explicit answers.
+pub fn prompt_for_registry_consent(
+    sym: &mut Symposium,
+    workspace_root: &Path,
+    out: &Output,
+) -> Result<()> {
+    if !out.is_interactive() {
+        return Ok(());
+    }
+    let mut approved = Vec::new();
+    let mut declined = Vec::new();
+    for (source, cfg) in pending_registries(sym, workspace_root) {
+        let answer = dialoguer::Select::new()
+            .with_prompt(format!(
+                "This workspace recommends the plugin registry `{}` ({source}). Load it?",
+                cfg.name
+            ))
+            .items(["Ask me later", "Load it", "No — don't ask again"])
+            .default(0)
+            .interact_opt()
+            .context("consent prompt failed")?;
+        match answer {
+            Some(1) => approved.push(source),
+            Some(2) => declined.push(source),
+            Some(_) => {}
+            None => break,
+        }
+    }
+    apply_registry_consent(sym, &approved, &declined)
+}
+
+/// Record registry consent decisions in `[workspace-registries]` and save
+/// the config.
+pub fn apply_registry_consent(
+    sym: &mut Symposium,
+    approved: &[String],
+    declined: &[String],
+) -> Result<()> {
+    if approved.is_empty() && declined.is_empty() {
+        return Ok(());
+    }
+    let consent = &mut sym.config.workspace_registries;
+    for source in approved {
+        if !consent.trust.contains(source) {
+            consent.trust.push(source.clone());
+        }
+    }
+    for source in declined {
+        if !consent.decline.contains(source) {
+            consent.decline.push(source.clone());
+        }
+    }
+    sym.save_config_keys(&["workspace-registries.trust", "workspace-registries.decline"])
+        .context("failed to write user config")
+}
+
+/// Point at the recommended registries still awaiting a decision.
+pub fn report_pending_registries(sym: &Symposium, workspace_root: &Path) {
+    for (source, cfg) in pending_registries(sym, workspace_root) {
+        tracing::info!(
+            report = %ReportEvent::Info {
+                message: format!(
+                    "this workspace recommends the plugin registry `{}` ({source}); \
+                     run `cargo agents sync` in a terminal to review it",
+                    cfg.name
+                ),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::UseEntry;
+    use indoc::indoc;
+
+    fn workspace(dir: &Path, metadata: &str) {
+        std::fs::write(
+            dir.join("Cargo.toml"),
+            format!("[workspace]\nmembers = []\n\n{metadata}"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn loads_plugins_and_registries() {
+        let tmp = tempfile::tempdir().unwrap();
+        workspace(
+            tmp.path(),
+            indoc! {r#"
+                [workspace.metadata.symposium.plugins]
+                auto-enable = ["sqlx"]
+                disable = ["noisy"]
+
+                [[workspace.metadata.symposium.registry]]
+                name = "acme"
+                path = "agent-plugins"
+            "#},
+        );
+        let config = load(tmp.path()).unwrap();
+        assert_eq!(config.plugins.auto_enable, ["sqlx"]);
+        assert_eq!(config.plugins.disable, ["noisy"]);
+        assert_eq!(config.registries[0].name, "acme");
+
+        std::fs::write(tmp.path().join("Cargo.toml"), "[workspace]\n").unwrap();
+        assert_eq!(load(tmp.path()), None);
+    }
+
+    #[test]
+    fn user_decisions_outrank_the_team() {
+        let root = Path::new("/ws");
+        let team = PluginsConfig {
+            auto_enable: vec!["sqlx".into(), "tokio".into()],
+            used: vec![UseEntry::Global("conventions".into())],
+            disable: vec!["noisy".into(), "chatty".into()],
+        };
+        let user = PluginsConfig {
+            auto_enable: vec!["noisy".into()],
+            used: Vec::new(),
+            disable: vec!["sqlx".into(), "conventions".into()],
+        };
+        let merged = user.layered_over(&team, root);
+        // A team entry naming a plugin is pre-consent.
+        assert!(merged.is_auto_enabled("tokio"));
+        assert!(merged.is_disabled("sqlx"));
+        assert!(!merged.is_used_in("conventions", root));
+        assert!(!merged.is_disabled("noisy"));
+        assert!(merged.is_disabled("chatty"));
+    }
+
+    #[test]
+    fn the_team_cannot_consent_for_the_user() {
+        let root = Path::new("/ws");
+        let team = PluginsConfig {
+            auto_enable: vec!["*".into()],
+            used: vec![UseEntry::Global("evil-dep".into())],
+            disable: Vec::new(),
+        };
+        let merged = PluginsConfig::default().layered_over(&team, root);
+        assert!(!merged.is_auto_enabled("evil-dep"));
+        assert!(!merged.is_used_in("evil-dep", root));
+        assert!(!merged.has_enablement_entries());
+    }
+
+    #[test]
+    fn team_use_entries_still_wake_registry_plugins() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut sym = Symposium::from_dir(&tmp.path().join("home"));
+        let ws = tmp.path().join("ws");
+        std::fs::create_dir_all(&ws).unwrap();
+        workspace(
+            &ws,
+            indoc! {r#"
+                [workspace.metadata.symposium.plugins]
+                use = ["conventions", "noisy"]
+            "#},
+        );
+        sym.config.plugins.disable.push("noisy".into());
+        assert_eq!(sym.used_names_in(&ws), ["conventions"]);
+        assert!(!sym.plugins_in(&ws).is_used_in("conventions", &ws));
+    }
+
+    #[test]
+    fn recommended_registries_need_consent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut sym = Symposium::from_dir(&tmp.path().join("home"));
+        let ws = tmp.path().join("ws");
+        std::fs::create_dir_all(&ws).unwrap();
+        workspace(
+            &ws,
+            indoc! {r#"
+                [[workspace.metadata.symposium.registry]]
+                name = "acme"
+                path = "agent-plugins"
+            "#},
+        );
+        let source = ws.join("agent-plugins").display().to_string();
+
+        assert!(trusted_registries(&sym, &ws).is_empty());
+        assert_eq!(pending_registries(&sym, &ws).len(), 1);
+
+        apply_registry_consent(&mut sym, std::slice::from_ref(&source), &[]).unwrap();
+        assert!(pending_registries(&sym, &ws).is_empty());
+        let trusted = trusted_registries(&sym, &ws);
+        assert_eq!(trusted[0].path.as_deref(), Some(source.as_str()));
+    }
+}
@@ -22,6 +22,12 @@ pub struct WorkspaceState {
 
     #[serde(default, rename = "workspace-root")]
     pub workspace_root: Option<PathBuf>,
+
+    /// The HTTP/SSE MCP servers the last sync registered in this workspace's
+    /// agent configs, by name, so a later sync or `uninstall` removes only
+    /// entries symposium wrote.
+    #[serde(default, rename = "mcp-servers", skip_serializing_if = "Vec::is_empty")]
+    pub mcp_servers: Vec<String>,
 }
 
 impl WorkspaceState {
@@ -71,6 +77,45 @@ impl WorkspaceState {
     }
 }
 
+/// Record the HTTP/SSE MCP servers a sync registered in the workspace at
+/// `root`.
+pub fn record_mcp_servers(sym: &Symposium, root: &Path, names: Vec<String>) {
+    let mut state = WorkspaceState::load(sym, root);
+    if state.mcp_servers == names {
+        return;
+    }
+    state.mcp_servers = names;
+    state.workspace_root = Some(root.to_path_buf());
+    state.save(sym, root);
+}
+
+/// Record a successful sync of the workspace at `root`, so later hooks skip
+/// syncing until `Cargo.lock` changes again, and so the workspace is known
+/// to [`known_workspaces`].
+pub fn record_sync(sym: &Symposium, root: &Path) {
+    let mut state = WorkspaceState::load(sym, root);
+    state.record_sync(root);
+    state.workspace_root = Some(root.to_path_buf());
+    state.save(sym, root);
+}
+
+/// The roots of every workspace symposium has synced that still exists.
+pub fn known_workspaces(sym: &Symposium) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(sym.cache_dir().join("workspaces")) else {
+        return Vec::new();
+    };
+    let mut roots: Vec<PathBuf> = entries
+        .flatten()
+        .filter_map(|entry| fs::read_to_string(entry.path().join("state.json")).ok())
+        .filter_map(|contents| serde_json::from_str::<WorkspaceState>(&contents).ok())
+        .filter_map(|state| state.workspace_root)
+        .filter(|root| root.is_dir())
+        .collect();
+    roots.sort();
+    roots.dedup();
+    roots
+}
+
 /// Find the workspace root via `cargo locate-project --workspace`.
 /// Fast (~10-50ms) and follows cargo's actual workspace discovery logic.
 pub fn find_workspace_root(sym: &Symposium, cwd: &Path) -> Option<PathBuf> {
@@ -138,16 +183,33 @@ mod tests {
         let workspace = tmp.path().join("project");
         fs::create_dir_all(&workspace).unwrap();
 
-        let mut state = WorkspaceState::default();
-        state.last_sync_lock_mtime = Some(1234567890);
-        state.last_sync_battery_pack_mtime = Some(9876543210);
-        state.workspace_root = Some(workspace.clone());
+        let state = WorkspaceState {
+            last_sync_lock_mtime: Some(1234567890),
+            last_sync_battery_pack_mtime: Some(9876543210),
+            workspace_root: Some(workspace.clone()),
+            mcp_servers: vec!["remote".to_string()],
+        };
         state.save(&sym, &workspace);
 
         let loaded = WorkspaceState::load(&sym, &workspace);
         assert_eq!(loaded.last_sync_lock_mtime, Some(1234567890));
         assert_eq!(loaded.last_sync_battery_pack_mtime, Some(9876543210));
         assert_eq!(loaded.workspace_root, Some(workspace));
+        assert_eq!(loaded.mcp_servers, vec!["remote".to_string()]);
+    }
+
+    #[test]
+    fn recorded_syncs_are_known_workspaces() {
+        let tmp = tempfile::tempdir().unwrap();
+        let sym = Symposium::from_dir(tmp.path());
+        let [a, b, gone] = ["a", "b", "gone"].map(|name| tmp.path().join(name));
+        for root in [&b, &a, &gone] {
+            fs::create_dir_all(root).unwrap();
+            record_sync(&sym, root);
+        }
+        fs::remove_dir(&gone).unwrap();
+
+        assert_eq!(known_workspaces(&sym), [a, b]);
     }
 
     #[test]
@@ -162,6 +224,7 @@ mod tests {
             last_sync_lock_mtime: Some(lock_mtime),
             last_sync_battery_pack_mtime: None,
             workspace_root: None,
+            mcp_servers: Vec::new(),
         };
         assert!(state.sync_is_fresh(&workspace));
     }
@@ -180,6 +243,7 @@ mod tests {
             last_sync_lock_mtime: Some(lock_mtime),
             last_sync_battery_pack_mtime: Some(bp_mtime),
             workspace_root: None,
+            mcp_servers: Vec::new(),
         };
         assert!(state.sync_is_fresh(&workspace));
     }
@@ -195,6 +259,7 @@ mod tests {
             last_sync_lock_mtime: Some(0),
             last_sync_battery_pack_mtime: None,
             workspace_root: None,
+            mcp_servers: Vec::new(),
         };
         assert!(!state.sync_is_fresh(&workspace));
     }
@@ -213,6 +278,7 @@ mod tests {
             last_sync_lock_mtime: Some(lock_mtime),
             last_sync_battery_pack_mtime: None,
             workspace_root: None,
+            mcp_servers: Vec::new(),
         };
         assert!(!state.sync_is_fresh(&workspace));
     }
@@ -230,6 +296,7 @@ mod tests {
             last_sync_lock_mtime: Some(lock_mtime),
             last_sync_battery_pack_mtime: Some(0),
             workspace_root: None,
+            mcp_servers: Vec::new(),
         };
         assert!(!state.sync_is_fresh(&workspace));
     }
@@ -244,6 +311,7 @@ mod tests {
             last_sync_lock_mtime: Some(1234567890),
             last_sync_battery_pack_mtime: None,
             workspace_root: None,
+            mcp_servers: Vec::new(),
         };
         assert!(!state.sync_is_fresh(&workspace));
     }
@@ -1,4 +1,5 @@
-//! Git-sourced plugin artifacts: URL parsing, API client, and cache management.
+//! Git-sourced plugin artifacts: URL parsing, the GitHub API client and
+//! `git` CLI fetches, and cache management.
 
 use std::path::{Path, PathBuf};
 
@@ -13,9 +14,9 @@ const DEBOUNCE_DURATION: std::time::Duration = std::time::Duration::from_secs(60
 
 /// A parsed git source URL.
 ///
-/// Different URL forms that refer to the same logical repository (e.g.,
-/// `https://github.com/foo/bar` and `git@github.com:foo/bar.git`) parse
-/// to the same variant with the same fields, enabling deduplication.
+/// `https://github.com/...` URLs are fetched as tarballs through the GitHub
+/// API; every other URL is fetched with the local `git` CLI, so it works with
+/// any host the user's git can reach (and authenticate to).
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
 pub enum GitSource {
@@ -30,6 +31,17 @@ pub enum GitSource {
         /// Path within the repo. Empty string means repo root.
         subpath: String,
     },
+    /// Any other repository, cloned with the `git` CLI: `https://`,
+    /// `ssh://`, `git://`, or `file://` URLs, scp-style `user@host:path`,
+    /// and absolute paths to a (bare) repository.
+    Generic {
+        /// The URL handed to `git`, without the `#<ref>:<subpath>` fragment.
+        url: String,
+        /// Branch, tag, or full commit SHA. Empty string means default branch.
+        git_ref: String,
+        /// Path within the repo. Empty string means repo root.
+        subpath: String,
+    },
 }
 
 impl GitSource {
@@ -40,52 +52,141 @@ impl GitSource {
     pub fn repo_id(&self) -> String {
         match self {
             GitSource::GitHub { owner, repo, .. } => format!("{owner}/{repo}"),
+            GitSource::Generic { url, .. } => generic_repo_id(url),
         }
     }
 
-    /// The intra-repo subpath (the portion after `tree/<ref>/` in a GitHub URL).
-    /// Empty string if the URL points at the repo root.
+    /// The intra-repo subpath (the portion after `tree/<ref>/` in a GitHub URL,
+    /// or after `:` in a generic URL's fragment). Empty string if the URL
+    /// points at the repo root.
     pub fn subpath(&self) -> &str {
         match self {
-            GitSource::GitHub { subpath, .. } => subpath,
+            GitSource::GitHub { subpath, .. } | GitSource::Generic { subpath, .. } => subpath,
+        }
+    }
+
+    /// The requested branch, tag, or commit. Empty string means default branch.
+    pub fn git_ref(&self) -> &str {
+        match self {
+            GitSource::GitHub { git_ref, .. } | GitSource::Generic { git_ref, .. } => git_ref,
         }
     }
 
     /// Filesystem-safe cache directory name.
     fn cache_key(&self) -> String {
-        match self {
-            GitSource::GitHub {
-                owner,
-                repo,
-                git_ref,
-                subpath,
-            } => {
-                let mut key = format!("{owner}--{repo}");
-                if !git_ref.is_empty() {
-                    key.push_str(&format!("@{git_ref}"));
-                }
-                if !subpath.is_empty() {
-                    let path_slug = subpath.replace('/', "--");
-                    key.push_str(&format!("--{path_slug}"));
-                }
-                key
-            }
+        let mut key = match self {
+            GitSource::GitHub { owner, repo, .. } => format!("{owner}--{repo}"),
+            GitSource::Generic { url, .. } => path_slug(&generic_repo_id(url)),
+        };
+        let git_ref = self.git_ref();
+        if !git_ref.is_empty() {
+            key.push_str(&format!("@{}", path_slug(git_ref)));
+        }
+        let subpath = self.subpath();
+        if !subpath.is_empty() {
+            key.push_str(&format!("--{}", path_slug(subpath)));
         }
+        key
     }
 }
 
+/// `/` becomes `--`; anything else that is awkward in a file name becomes `_`.
+fn path_slug(s: &str) -> String {
+    s.replace('/', "--")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// The identity of a generic repository URL: scheme, user, and a trailing
+/// `.git` dropped, so `ssh://git@host/group/repo.git` and
+/// `git@host:group/repo` are both `host/group/repo`.
+fn generic_repo_id(url: &str) -> String {
+    let (rest, scp) = match url.split_once("://") {
+        Some((_, rest)) => (rest, false),
+        None => (url, !url.starts_with('/')),
+    };
+    let rest = match rest.split_once('@') {
+        Some((user, host)) if !user.contains('/') => host,
+        _ => rest,
+    };
+    let rest = if scp {
+        rest.replacen(':', "/", 1)
+    } else {
+        rest.to_string()
+    };
+    let rest = rest.trim_matches('/');
+    rest.strip_suffix(".git").unwrap_or(rest).to_string()
+}
+
 /// Parse a git URL into a structured [`GitSource`].
 ///
-/// Currently recognizes GitHub URLs:
+/// GitHub URLs:
 /// - `https://github.com/owner/repo`
 /// - `https://github.com/owner/repo/tree/branch/path/to/dir`
+///
+/// Any other `https://`, `http://`, `ssh://`, `git://`, or `file://` URL, an
+/// scp-style `user@host:path`, or an absolute path to a repository is a
+/// [`GitSource::Generic`]. A ref and subpath are pinned with a fragment in
+/// git's own `<rev>:<path>` shape: `#v1.2`, `#main:plugins/serde`, or
+/// `#:plugins` for a subpath on the default branch.
 pub fn parse_git_url(url: &str) -> Result<GitSource> {
     // Normalize: case-insensitive host matching
     let lower = url.to_lowercase();
     if lower.starts_with("https://github.com/") {
         return parse_github_https(url);
     }
-    bail!("unrecognized git URL: {url}")
+    parse_generic(url)
+}
+
+/// Parse a non-GitHub URL with an optional `#<ref>[:<subpath>]` fragment.
+fn parse_generic(url: &str) -> Result<GitSource> {
+    let (location, fragment) = match url.split_once('#') {
+        Some((location, fragment)) => (location, fragment),
+        None => (url, ""),
+    };
+    if !is_generic_location(location) {
+        bail!("unrecognized git URL: {url}");
+    }
+    let (git_ref, subpath) = fragment.split_once(':').unwrap_or((fragment, ""));
+    Ok(GitSource::Generic {
+        url: location.trim_end_matches('/').to_string(),
+        git_ref: git_ref.to_string(),
+        subpath: subpath.trim_matches('/').to_string(),
+    })
+}
+
+/// Whether `location` is something `git clone` accepts: a URL with a
+/// transport scheme, scp-style `[user@]host:path`, or an absolute path.
+/// Nothing starting with `-`, which git would read as an option, and no
+/// remote-helper `<transport>::<address>` (`ext::` runs arbitrary commands).
+fn is_generic_location(location: &str) -> bool {
+    if location.starts_with('-') {
+        return false;
+    }
+    let before_path = location.split('/').next().unwrap_or_default();
+    if before_path.contains("::") {
+        return false;
+    }
+    const SCHEMES: &[&str] = &["https://", "http://", "ssh://", "git://", "file://"];
+    let lower = location.to_lowercase();
+    if let Some(scheme) = SCHEMES.iter().find(|s| lower.starts_with(**s)) {
+        return location.len() > scheme.len();
+    }
+    if location.starts_with('/') {
+        return location.len() > 1;
+    }
+    // scp-style: a host (no `/`, longer than a drive letter) before the first `:`.
+    match location.split_once(':') {
+        Some((host, path)) => host.len() > 1 && !host.contains('/') && !path.is_empty(),
+        None => false,
+    }
 }
 
 /// Parse an `https://github.com/...` URL.
@@ -149,7 +250,10 @@ impl GitHubClient {
             repo,
             git_ref,
             ..
-        } = source;
+        } = source
+        else {
+            bail!("not a GitHub source: {}", source.repo_id());
+        };
         let ref_str = if git_ref.is_empty() { "HEAD" } else { git_ref };
         let url = format!(
             "https://api.github.com/repos/{}/{}/commits/{}",
@@ -184,7 +288,10 @@ impl GitHubClient {
             repo,
             git_ref,
             ..
-        } = source;
+        } = source
+        else {
+            bail!("not a GitHub source: {}", source.repo_id());
+        };
         let ref_str = if git_ref.is_empty() { "HEAD" } else { git_ref };
         let url = format!(
             "https://api.github.com/repos/{}/{}/tarball/{}",
@@ -216,6 +323,103 @@ impl GitHubClient {
     }
 }
 
+// --- git CLI ---
+
+/// Resolve the commit a generic source's ref names, with `git ls-remote`.
+/// A full 40-character SHA that the remote does not advertise as a ref is
+/// taken as a pinned commit.
+async fn resolve_generic_sha(url: &str, git_ref: &str) -> Result<String> {
+    let ref_str = if git_ref.is_empty() { "HEAD" } else { git_ref };
+    // Ask for the peeled pattern too, so an annotated tag resolves to its commit.
+    let peeled = format!("{ref_str}^{{}}");
+    let output = run_git(
+        None,
+        &["ls-remote", "--end-of-options", url, ref_str, &peeled],
+    )
+    .await?;
+    if let Some(sha) = parse_ls_remote_ref(&output, ref_str) {
+        return Ok(sha);
+    }
+    if ref_str.len() == 40 && ref_str.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Ok(ref_str.to_ascii_lowercase());
+    }
+    bail!("ref `{ref_str}` not found in {url} (pin commits by their full SHA)")
+}
+
+/// Pick the commit for `git_ref` out of `git ls-remote` output: an exact
+/// `HEAD`, branch, or tag match, preferring a tag's peeled (`^{}`) commit.
+fn parse_ls_remote_ref(output: &str, git_ref: &str) -> Option<String> {
+    let candidates = [
+        git_ref.to_string(),
+        format!("refs/heads/{git_ref}"),
+        format!("refs/tags/{git_ref}"),
+    ];
+    let mut found = None;
+    for line in output.lines() {
+        let Some((sha, name)) = line.split_once('\t') else {
+            continue;
+        };
+        if let Some(tag) = name.strip_suffix("^{}")
+            && candidates.iter().any(|c| c == tag)
+        {
+            return crate::parse_ls_remote_sha(sha);
+        }
+        if found.is_none() && candidates.iter().any(|c| c == name) {
+            found = crate::parse_ls_remote_sha(sha);
+        }
+    }
+    found
+}
+
+/// Check out `sha` of the repository at `url` into `dest`, without the
+/// `.git` directory: a shallow fetch of just that commit.
+async fn git_checkout(url: &str, sha: &str, dest: &Path) -> Result<()> {
+    run_git(None, &["init", "--quiet", &dest.display().to_string()]).await?;
+    run_git(
+        Some(dest),
+        &[
+            "fetch",
+            "--quiet",
+            "--depth",
+            "1",
+            "--end-of-options",
+            url,
+            sha,
+        ],
+    )
+    .await?;
+    run_git(Some(dest), &["checkout", "--quiet", "FETCH_HEAD"]).await?;
+    std::fs::remove_dir_all(dest.join(".git"))
+        .with_context(|| format!("failed to remove .git from {}", dest.display()))?;
+    Ok(())
+}
+
+/// Run the `git` CLI (in `dir`, when given), returning its stdout. Prompts
+/// are disabled so a missing credential fails instead of hanging, and the
+/// `ext` transport is refused whatever the user's git config allows.
+async fn run_git(dir: Option<&Path>, args: &[&str]) -> Result<String> {
+    let mut command = std::process::Command::new("git");
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+    command
+        .args(["-c", "protocol.ext.allow=never"])
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0");
+    let description = format!("git {}", args.join(" "));
+    let output = tokio::task::spawn_blocking(move || command.output())
+        .await
+        .context("git task panicked")?
+        .with_context(|| format!("failed to run `{description}`"))?;
+    if !output.status.success() {
+        bail!(
+            "`{description}` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 // --- Plugin cache manager ---
 
 /// Metadata stored alongside cached plugin artifacts.
@@ -326,14 +530,14 @@ impl GitCacheManager {
             // Fetch level: skip freshness check, always re-download
             if matches!(update, UpdateLevel::Fetch) {
                 tracing::info!(%cache_key, "force-fetching plugin source");
-                let sha = self.client.resolve_commit_sha(source).await?;
+                let sha = self.resolve_commit_sha(source).await?;
                 self.fetch_and_cache_with_sha(source, source_url, &plugin_dir, &meta_path, &sha)
                     .await?;
                 return Ok(plugin_dir);
             }
 
             // None (past debounce) or Check: check freshness via API
-            match self.client.resolve_commit_sha(source).await {
+            match self.resolve_commit_sha(source).await {
                 Ok(remote_sha) => {
                     if meta.commit_sha == remote_sha {
                         tracing::debug!(%cache_key, "plugin cache is fresh");
@@ -364,12 +568,20 @@ impl GitCacheManager {
         }
 
         // Download and extract (fresh fetch — need to resolve SHA)
-        let sha = self.client.resolve_commit_sha(source).await?;
+        let sha = self.resolve_commit_sha(source).await?;
         self.fetch_and_cache_with_sha(source, source_url, &plugin_dir, &meta_path, &sha)
             .await?;
         Ok(plugin_dir)
     }
 
+    /// Resolve the commit `source`'s ref currently names.
+    async fn resolve_commit_sha(&self, source: &GitSource) -> Result<String> {
+        match source {
+            GitSource::GitHub { .. } => self.client.resolve_commit_sha(source).await,
+            GitSource::Generic { url, git_ref, .. } => resolve_generic_sha(url, git_ref).await,
+        }
+    }
+
     async fn fetch_and_cache_with_sha(
         &self,
         source: &GitSource,
@@ -378,8 +590,6 @@ impl GitCacheManager {
         meta_path: &std::path::Path,
         sha: &str,
     ) -> Result<()> {
-        let tarball = self.client.download_tarball(source).await?;
-
         // Extract to a temp directory first, then move into place
         std::fs::create_dir_all(&self.cache_dir).with_context(|| {
             format!(
@@ -394,7 +604,13 @@ impl GitCacheManager {
             )
         })?;
 
-        extract_tarball(&tarball, temp_dir.path())?;
+        match source {
+            GitSource::GitHub { .. } => {
+                let tarball = self.client.download_tarball(source).await?;
+                extract_tarball(&tarball, temp_dir.path())?;
+            }
+            GitSource::Generic { url, .. } => git_checkout(url, sha, temp_dir.path()).await?,
+        }
 
         // If a subpath is specified, we need to find and move just that subtree
         let subpath = source.subpath();
@@ -417,7 +633,7 @@ impl GitCacheManager {
             std::fs::remove_dir_all(plugin_dir)
                 .with_context(|| format!("failed to remove old cache: {}", plugin_dir.display()))?;
         }
-        std::fs::create_dir_all(plugin_dir.parent().unwrap_or(plugin_dir))?;
+        std::fs::create_dir_all(plugin_dir)?;
 
         // Copy (not rename — source may be a subdirectory of temp_dir)
         copy_dir_recursive(&source_dir, plugin_dir)?;
@@ -520,3 +736,168 @@ fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<()
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generic(url: &str, git_ref: &str, subpath: &str) -> GitSource {
+        GitSource::Generic {
+            url: url.to_string(),
+            git_ref: git_ref.to_string(),
+            subpath: subpath.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_github_urls_stay_github() {
+        let source = parse_git_url("https://github.com/foo/bar/tree/main/plugins").unwrap();
+        assert!(matches!(source, GitSource::GitHub { .. }));
+        assert_eq!(source.repo_id(), "foo/bar");
+        assert_eq!(source.subpath(), "plugins");
+    }
+
+    #[test]
+    fn parse_generic_urls() {
+        assert_eq!(
+            parse_git_url("https://gitlab.com/group/sub/repo.git#v1.2").unwrap(),
+            generic("https://gitlab.com/group/sub/repo.git", "v1.2", "")
+        );
+        assert_eq!(
+            parse_git_url("ssh://git@git.example.com/team/plugins#main:serde/").unwrap(),
+            generic("ssh://git@git.example.com/team/plugins", "main", "serde")
+        );
+        assert_eq!(
+            parse_git_url("git@github.com:foo/bar.git#:skills").unwrap(),
+            generic("git@github.com:foo/bar.git", "", "skills")
+        );
+        assert_eq!(
+            parse_git_url("file:///srv/git/plugins.git").unwrap(),
+            generic("file:///srv/git/plugins.git", "", "")
+        );
+        assert_eq!(
+            parse_git_url("/srv/git/plugins.git#release").unwrap(),
+            generic("/srv/git/plugins.git", "release", "")
+        );
+    }
+
+    #[test]
+    fn parse_rejects_non_git_locations() {
+        assert!(parse_git_url("relative/path").is_err());
+        assert!(parse_git_url("C:\\repo").is_err());
+        assert!(parse_git_url("https://").is_err());
+        assert!(parse_git_url("").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_option_like_locations() {
+        assert!(parse_git_url("--upload-pack=touch /tmp/pwned:x").is_err());
+        assert!(parse_git_url("-oProxyCommand=evil:repo#main").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_remote_helper_locations() {
+        assert!(parse_git_url("ext::sh -c touch% /tmp/pwned").is_err());
+        assert!(parse_git_url("ext::sh -c 'touch /tmp/pwned'#main").is_err());
+        assert!(parse_git_url("fd::3").is_err());
+        // A `::` after the host is just part of the path.
+        assert!(parse_git_url("ssh://host/a::b").is_ok());
+    }
+
+    #[test]
+    fn generic_repo_id_ignores_transport() {
+        let ssh = parse_git_url("ssh://git@host.dev/group/repo.git").unwrap();
+        let scp = parse_git_url("git@host.dev:group/repo").unwrap();
+        let https = parse_git_url("https://host.dev/group/repo/").unwrap();
+        assert_eq!(ssh.repo_id(), "host.dev/group/repo");
+        assert_eq!(scp.repo_id(), "host.dev/group/repo");
+        assert_eq!(https.repo_id(), "host.dev/group/repo");
+        assert_eq!(
+            parse_git_url("file:///srv/git/plugins.git")
+                .unwrap()
+                .repo_id(),
+            "srv/git/plugins"
+        );
+    }
+
+    #[test]
+    fn generic_cache_key_is_filesystem_safe() {
+        let source = parse_git_url("ssh://git@host.dev:2222/group/repo.git#feat/x:a/b").unwrap();
+        assert_eq!(
+            source.cache_key(),
+            "host.dev_2222--group--repo@feat--x--a--b"
+        );
+    }
+
+    #[test]
+    fn ls_remote_ref_prefers_exact_and_peeled() {
+        let output = "\
+1111111111111111111111111111111111111111\tHEAD
+2222222222222222222222222222222222222222\trefs/heads/main
+3333333333333333333333333333333333333333\trefs/heads/release/v1
+4444444444444444444444444444444444444444\trefs/tags/v1
+5555555555555555555555555555555555555555\trefs/tags/v1^{}
+";
+        let sha = |r| parse_ls_remote_ref(output, r);
+        assert_eq!(sha("HEAD").unwrap(), "1".repeat(40));
+        assert_eq!(sha("main").unwrap(), "2".repeat(40));
+        assert_eq!(sha("v1").unwrap(), "5".repeat(40));
+        assert_eq!(sha("release"), None);
+    }
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .current_dir(dir)
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?} failed: {output:?}");
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    #[tokio::test]
+    async fn fetch_generic_repo_pins_ref_and_subpath() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(repo.join("plugins/serde")).unwrap();
+        git(&repo, &["init", "--quiet", "--initial-branch=main"]);
+        std::fs::write(repo.join("plugins/serde/SKILL.md"), "v1").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "--quiet", "-m", "v1"]);
+        git(&repo, &["tag", "-a", "v1", "-m", "v1"]);
+        let v1 = git(&repo, &["rev-parse", "HEAD"]);
+        std::fs::write(repo.join("plugins/serde/SKILL.md"), "v2").unwrap();
+        git(&repo, &["commit", "--quiet", "-am", "v2"]);
+        let v2 = git(&repo, &["rev-parse", "HEAD"]);
+
+        let cache = GitCacheManager::from_cache_dir(&tmp.path().join("cache"));
+        let base = format!("file://{}", repo.display());
+
+        let url = format!("{base}#v1:plugins/serde");
+        let dir = cache.fetch_url(&url, UpdateLevel::Check).await.unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("SKILL.md")).unwrap(), "v1");
+        assert!(!dir.join(".git").exists());
+        let meta = cache.read_meta_for(&dir).unwrap();
+        assert_eq!(meta.commit_sha, v1);
+        assert_eq!(meta.source_url, url);
+
+        let dir = cache.fetch_url(&base, UpdateLevel::Check).await.unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("plugins/serde/SKILL.md")).unwrap(),
+            "v2"
+        );
+        assert_eq!(cache.read_meta_for(&dir).unwrap().commit_sha, v2);
+
+        // A full SHA that no ref points at is fetched as-is.
+        let url = format!("{}#{v1}", repo.display());
+        let dir = cache.fetch_url(&url, UpdateLevel::Check).await.unwrap();
+        assert_eq!(cache.read_meta_for(&dir).unwrap().commit_sha, v1);
+
+        let err = cache
+            .fetch_url(&format!("{base}#nope"), UpdateLevel::Check)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("ref `nope` not found"), "{err}");
+    }
+}
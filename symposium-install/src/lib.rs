@@ -665,7 +665,8 @@ async fn remote_git_head_sha(url: &str) -> Option<String> {
     let url = url.to_string();
     tokio::task::spawn_blocking(move || {
         let output = std::process::Command::new("git")
-            .args(["ls-remote", &url, "HEAD"])
+            .args(["-c", "protocol.ext.allow=never"])
+            .args(["ls-remote", "--end-of-options", &url, "HEAD"])
             .output()
             .ok()?;
         if !output.status.success() {
@@ -817,8 +818,13 @@ pub async fn refresh_source_if_present(
 }
 
 /// Whether a source's bits are already on disk, computed from cache paths
-/// without acquiring anything. Drives [`refresh_source_if_present`].
-fn source_is_cached(ctx: &InstallContext, source: &Source, executable_hint: Option<&str>) -> bool {
+/// without acquiring anything. Drives [`refresh_source_if_present`]. A global
+/// cargo source is never reported cached: it lives outside our cache.
+pub fn source_is_cached(
+    ctx: &InstallContext,
+    source: &Source,
+    executable_hint: Option<&str>,
+) -> bool {
     match source {
         // Global cargo lives in the user's `~/.cargo/bin`, outside our cache;
         // we don't manage it, so the prewarm leaves it alone.
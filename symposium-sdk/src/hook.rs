@@ -236,11 +236,26 @@ pub struct StopInput {
     pub session_id: Option<String>,
     #[serde(default)]
     pub cwd: Option<String>,
+    /// `true` when the agent is already continuing because a stop hook
+    /// blocked an earlier stop. Symposium never overrides a block, so a gate
+    /// reads this to bound its own retries.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stop_hook_active: bool,
 }
 
 impl StopInput {
     pub fn new(session_id: Option<String>, cwd: Option<String>) -> Self {
-        Self { session_id, cwd }
+        Self {
+            session_id,
+            cwd,
+            stop_hook_active: false,
+        }
+    }
+
+    /// Mark the stop as one following an earlier blocked stop.
+    pub fn with_stop_hook_active(mut self, active: bool) -> Self {
+        self.stop_hook_active = active;
+        self
     }
 }
 
@@ -446,10 +461,30 @@ impl SessionStartOutput {
     }
 }
 
+/// Decision for a `Stop` hook.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StopDecision {
+    /// Let the agent end its turn (default).
+    #[default]
+    Allow,
+    /// Keep the agent working; the reason is given to it as its next instruction.
+    Block,
+}
+
+impl StopDecision {
+    fn is_allow(&self) -> bool {
+        *self == StopDecision::Allow
+    }
+}
+
 /// Output for a `Stop` event.
 #[non_exhaustive]
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StopOutput {
+    #[serde(default, skip_serializing_if = "StopDecision::is_allow")]
+    pub decision: StopDecision,
     #[serde(
         rename = "additionalContext",
         default,
@@ -461,13 +496,30 @@ pub struct StopOutput {
 impl StopOutput {
     /// Create an output with all fields specified.
     pub fn new(additional_context: Option<String>) -> Self {
-        Self { additional_context }
+        Self {
+            additional_context,
+            ..Default::default()
+        }
     }
 
     /// Create an output that injects additional context.
     pub fn context(text: impl Into<String>) -> Self {
         Self::new(Some(text.into()))
     }
+
+    /// Keep the agent from stopping, telling it why (e.g. "`cargo check`
+    /// fails: fix the errors before finishing").
+    pub fn block(reason: impl Into<String>) -> Self {
+        Self {
+            decision: StopDecision::Block,
+            additional_context: Some(reason.into()),
+        }
+    }
+
+    /// Whether this output keeps the agent working.
+    pub fn is_block(&self) -> bool {
+        self.decision == StopDecision::Block
+    }
 }
 
 // ── Handler trait ───────────────────────────────────────────────────────
@@ -531,7 +583,8 @@ pub trait HookHandler {
         Ok(SessionStartOutput::default())
     }
 
-    /// Called when an agent session/turn ends.
+    /// Called when an agent session/turn ends. Return [`StopOutput::block`]
+    /// to keep the agent working instead.
     async fn stop(&self, _event: &StopInput) -> anyhow::Result<StopOutput> {
         Ok(StopOutput::default())
     }
@@ -596,6 +649,6 @@ fn is_empty_output(output: &Output) -> bool {
         Output::PostToolUse(o) => o.additional_context.is_none(),
         Output::UserPromptSubmit(o) => o.additional_context.is_none(),
         Output::SessionStart(o) => o.additional_context.is_none(),
-        Output::Stop(o) => o.additional_context.is_none(),
+        Output::Stop(o) => o.additional_context.is_none() && !o.is_block(),
     }
 }
@@ -92,6 +92,7 @@ pub enum HookStep {
         tool_input: serde_json::Value,
         tool_response: serde_json::Value,
     },
+    Stop,
 }
 
 impl HookStep {
@@ -111,6 +112,7 @@ impl HookStep {
             Self::UserPromptSubmit { .. } => HookEvent::UserPromptSubmit,
             Self::PreToolUse { .. } => HookEvent::PreToolUse,
             Self::PostToolUse { .. } => HookEvent::PostToolUse,
+            Self::Stop => HookEvent::Stop,
         }
     }
 
@@ -145,6 +147,7 @@ impl HookStep {
                 session_id,
                 cwd,
             )),
+            Self::Stop => sym_types::InputEvent::Stop(sym_types::StopInput::new(session_id, cwd)),
         }
     }
 }
@@ -261,6 +264,17 @@ impl TestContext {
     pub async fn sync_with_report(
         &mut self,
         level: tracing::Level,
+    ) -> anyhow::Result<Vec<serde_json::Value>> {
+        self.sync_with_options_report(level, &symposium::sync::SyncOptions::default())
+            .await
+    }
+
+    /// [`sync_with_report`](Self::sync_with_report) with explicit
+    /// [`SyncOptions`](symposium::sync::SyncOptions) (e.g. a dry run).
+    pub async fn sync_with_options_report(
+        &mut self,
+        level: tracing::Level,
+        options: &symposium::sync::SyncOptions,
     ) -> anyhow::Result<Vec<serde_json::Value>> {
         use symposium::report::{ReportLayer, ReportMode};
         use tracing_subscriber::layer::SubscriberExt;
@@ -274,10 +288,11 @@ impl TestContext {
             .clone()
             .unwrap_or_else(|| self.sym.config_dir().to_path_buf());
 
-        symposium::sync::sync(
+        symposium::sync::sync_with(
             &self.sym,
             &self.sym.workspace_deps(&cwd),
             symposium::UpdateLevel::None,
+            options,
         )
         .await?;
 
@@ -0,0 +1,77 @@
+//! `cargo agents config`, and the commands that write the user config
+//! through the same path: edits land in `config.toml` without disturbing the
+//! rest of the file, and an invalid edit writes nothing.
+
+use symposium_testlib::{TestContext, TestMode, with_fixture};
+
+fn read_config(ctx: &TestContext) -> String {
+    std::fs::read_to_string(ctx.sym.config_dir().join("config.toml")).unwrap()
+}
+
+#[tokio::test]
+async fn set_get_and_unset_by_dotted_key() {
+    with_fixture(TestMode::SimulationOnly, &["plugins0"], async |mut ctx| {
+        ctx.symposium(&["config", "set", "plugins.auto-enable", r#"["serde"]"#])
+            .await?;
+        ctx.symposium(&["config", "set", "logging.level", "debug"])
+            .await?;
+        assert_eq!(ctx.sym.config.plugins.auto_enable, ["serde"]);
+        assert_eq!(ctx.sym.config.logging.level, "debug");
+        ctx.symposium(&["config", "get", "logging.level"]).await?;
+        ctx.symposium(&["config", "list"]).await?;
+
+        ctx.symposium(&["config", "unset", "plugins.auto-enable"])
+            .await?;
+        assert!(ctx.sym.config.plugins.auto_enable.is_empty());
+        assert!(
+            ctx.symposium(&["config", "get", "plugins.auto-enable"])
+                .await
+                .is_err(),
+            "an unset key has no value to get"
+        );
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn invalid_edits_write_nothing() {
+    with_fixture(TestMode::SimulationOnly, &["plugins0"], async |mut ctx| {
+        let before = read_config(&ctx);
+        for (key, value) in [
+            ("hook-timeout-secs", "soon"),
+            ("telemetry.enabld", "true"),
+            ("no-such-key", "1"),
+        ] {
+            assert!(
+                ctx.symposium(&["config", "set", key, value]).await.is_err(),
+                "{key} = {value}"
+            );
+        }
+        assert_eq!(read_config(&ctx), before);
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn telemetry_toggle_keeps_comments() {
+    with_fixture(TestMode::SimulationOnly, &["plugins0"], async |mut ctx| {
+        let config_path = ctx.sym.config_dir().join("config.toml");
+        let original = format!("# managed by hand\n{}", read_config(&ctx));
+        std::fs::write(&config_path, &original)?;
+
+        ctx.symposium(&["telemetry", "enable"]).await?;
+        assert!(ctx.sym.config.telemetry.enabled);
+        let config = read_config(&ctx);
+        assert!(config.starts_with(&original), "{config}");
+
+        ctx.symposium(&["telemetry", "disable"]).await?;
+        assert_eq!(read_config(&ctx), original);
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -242,3 +242,293 @@ async fn sync_custom_predicate_cross_plugin_fails() {
     .await
     .unwrap();
 }
+
+/// A crate plugin reached through a chained reference registers its own
+/// `[[predicate]]`: it gates the crate's own skills, and a registry plugin
+/// gated on it activates once resolution re-evaluates with it registered.
+/// A predicate nothing defines is reported as unresolved.
+#[tokio::test]
+async fn sync_registers_custom_predicates_from_crate_plugins() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["custom-predicate-crate0"],
+        async |mut ctx| {
+            write_script(&ctx.tempdir.join("crate-checker.sh"), "exit 0");
+
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let events = ctx.sync_with_report(tracing::Level::INFO).await?;
+            assert!(
+                events.iter().any(|e| e
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .is_some_and(|m| m.contains("`nobody_defines` is not defined"))),
+                "sync should report the unresolved predicate: {events:?}"
+            );
+
+            let skills_dir = ctx.workspace_root.as_ref().unwrap().join(".claude/skills");
+            assert!(skills_dir.join("crate-p-skill/SKILL.md").exists());
+            assert!(skills_dir.join("consumer-skill/SKILL.md").exists());
+            assert!(!skills_dir.join("orphan-skill").exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A crate-defined predicate is evaluated, not just registered: the checker
+/// passes only for the crate's own skill gate here.
+#[tokio::test]
+async fn sync_crate_plugin_custom_predicate_fails() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["custom-predicate-crate0"],
+        async |mut ctx| {
+            write_script(
+                &ctx.tempdir.join("crate-checker.sh"),
+                r#"[ "$1" = "skills" ]"#,
+            );
+
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let skills_dir = ctx.workspace_root.as_ref().unwrap().join(".claude/skills");
+            assert!(skills_dir.join("crate-p-skill/SKILL.md").exists());
+            assert!(!skills_dir.join("consumer-skill").exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Add a registry plugin that defines `crate_check` too, passing only for
+/// `consumer-plugin`'s gate, next to crate-p's own definition (which passes
+/// for everything).
+fn shadow_crate_check(ctx: &symposium_testlib::TestContext) -> anyhow::Result<()> {
+    write_script(&ctx.tempdir.join("crate-checker.sh"), "exit 0");
+    let checker = ctx.tempdir.join("registry-checker.sh");
+    write_script(&checker, r#"[ "$1" = "consumer" ]"#);
+
+    let plugin_dir = ctx.sym.config_dir().join("plugins/registry-check-plugin");
+    std::fs::create_dir_all(&plugin_dir)?;
+    std::fs::write(
+        plugin_dir.join("SYMPOSIUM.toml"),
+        format!(
+            indoc::indoc! {r#"
+                name = "registry-check-plugin"
+
+                [[installations]]
+                name = "registry-checker"
+                executable = "{}"
+
+                [[predicate]]
+                name = "crate_check"
+                command = "registry-checker"
+            "#},
+            checker.display()
+        ),
+    )?;
+    Ok(())
+}
+
+/// A registry plugin's predicate outranks a crate plugin's of the same name:
+/// sync evaluates every `crate_check` gate with the registry's definition and
+/// warns that the crate's is ignored.
+#[tokio::test]
+async fn sync_prefers_registry_predicate_over_crate_definition() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["custom-predicate-crate0"],
+        async |mut ctx| {
+            shadow_crate_check(&ctx)?;
+
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let events = ctx.sync_with_report(tracing::Level::INFO).await?;
+            let messages: Vec<&str> = events
+                .iter()
+                .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+                .collect();
+            assert!(
+                messages.iter().any(|m| m.contains(
+                    "`crate_check` defined by `crate-p` is ignored: \
+                     registry plugin `registry-check-plugin` already defines it"
+                )),
+                "{messages:#?}"
+            );
+            assert!(!messages.iter().any(|m| m.contains("skipping both")));
+
+            let skills_dir = ctx.workspace_root.as_ref().unwrap().join(".claude/skills");
+            assert!(skills_dir.join("consumer-skill/SKILL.md").exists());
+            assert!(!skills_dir.join("crate-p-skill").exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// The same precedence holds where predicates register only on demand, as
+/// for `status` and hooks: the registry's definition still resolves.
+#[tokio::test]
+async fn status_prefers_registry_predicate_over_crate_definition() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["custom-predicate-crate0"],
+        async |mut ctx| {
+            shadow_crate_check(&ctx)?;
+
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let deps = ctx.sym.workspace_deps(&workspace_root);
+            let entries = symposium::status_command::workspace_status(&ctx.sym, &deps).await?;
+            let consumer = entries
+                .iter()
+                .find(|e| e.name == "consumer-plugin")
+                .expect("consumer-plugin present");
+            assert_eq!(
+                consumer.state,
+                symposium::status_command::StatusState::Active,
+                "{entries:?}"
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Every file under `dir` with its contents, for before/after comparisons.
+fn snapshot(dir: &Path) -> Vec<(std::path::PathBuf, Vec<u8>)> {
+    let mut files = Vec::new();
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else {
+                files.push((path.clone(), std::fs::read(&path).unwrap_or_default()));
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// `sync --dry-run` neither installs custom predicates nor touches the cache:
+/// the predicate is reported as not installed and its gate evaluates false.
+#[tokio::test]
+async fn sync_dry_run_leaves_the_cache_untouched() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["custom-predicate0"],
+        async |mut ctx| {
+            let script_path = ctx.tempdir.join("bp-checker.sh");
+            write_script(&script_path, "exit 0");
+
+            // Installing the predicate copies its executable into the cache.
+            let witness = ctx.sym.cache_dir().join("bp-checker-installed");
+            let manifest = ctx
+                .sym
+                .config_dir()
+                .join("plugins/bp-plugin/SYMPOSIUM.toml");
+            let original = std::fs::read_to_string(&manifest)?;
+            let installed = format!(
+                "install_commands = [\"cp {} {}\"]\nexecutable = \"{}\"",
+                script_path.display(),
+                witness.display(),
+                witness.display()
+            );
+            let rewritten: Vec<&str> = original
+                .lines()
+                .map(|line| {
+                    if line.starts_with("executable = ") {
+                        installed.as_str()
+                    } else {
+                        line
+                    }
+                })
+                .collect();
+            std::fs::write(&manifest, rewritten.join("\n"))?;
+
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            // The `cargo metadata` memo is written by the dependency resolver
+            // the caller hands to sync, not by sync itself.
+            let root = ctx.workspace_root.clone().unwrap();
+            ctx.sym.workspace_deps(&root).load();
+            let before = snapshot(ctx.sym.cache_dir());
+
+            let events = ctx
+                .sync_with_options_report(
+                    tracing::Level::INFO,
+                    &symposium::sync::SyncOptions {
+                        dry_run: true,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            assert!(
+                events.iter().any(|e| e["kind"] == "info"
+                    && e["message"]
+                        .as_str()
+                        .is_some_and(|m| m.contains("`battery_pack` is not installed"))),
+                "{events:#?}"
+            );
+            assert_eq!(snapshot(ctx.sym.cache_dir()), before);
+
+            // A real sync does install it.
+            ctx.symposium(&["sync"]).await?;
+            assert!(witness.exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A dry run after a real sync evaluates the predicates that sync installed,
+/// so it plans no removal of the skills they gate.
+#[tokio::test]
+async fn sync_dry_run_evaluates_installed_predicates() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["custom-predicate0"],
+        async |mut ctx| {
+            write_script(&ctx.tempdir.join("bp-checker.sh"), "exit 0");
+
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+            let skills_dir = ctx.workspace_root.as_ref().unwrap().join(".claude/skills");
+            assert!(skills_dir.join("bp-skill/SKILL.md").exists());
+
+            let events = ctx
+                .sync_with_options_report(
+                    tracing::Level::INFO,
+                    &symposium::sync::SyncOptions {
+                        dry_run: true,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            assert!(
+                !events
+                    .iter()
+                    .any(|e| e["kind"] == "sync_planned" && e["action"] == "remove"),
+                "{events:#?}"
+            );
+            assert!(
+                !events.iter().any(|e| e["message"]
+                    .as_str()
+                    .is_some_and(|m| m.contains("is not installed"))),
+                "{events:#?}"
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
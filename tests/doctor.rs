@@ -0,0 +1,170 @@
+//! End-to-end tests for `cargo agents doctor`: each check reports against a
+//! real fixture setup, and breaking the setup turns the matching check into
+//! a problem with a suggested fix.
+
+use symposium::doctor_command::{Finding, Severity, diagnose};
+use symposium_testlib::{TestContext, TestMode, with_fixture};
+
+/// A `cargo` stand-in that answers `cargo search` with `latest`.
+fn mock_cargo_script(latest: &str) -> String {
+    format!(
+        r#"#!/bin/sh
+case "$1" in
+    search)
+        echo 'symposium = "{latest}"    # AI the Rust Way'
+        ;;
+    *)
+        exec cargo "$@"
+        ;;
+esac
+"#
+    )
+}
+
+async fn findings(ctx: &TestContext, check: &str) -> Vec<Finding> {
+    let cwd = ctx.workspace_root.clone().unwrap();
+    diagnose(&ctx.sym, &cwd)
+        .await
+        .into_iter()
+        .filter(|f| f.check == check)
+        .collect()
+}
+
+fn severities(findings: &[Finding]) -> Vec<Severity> {
+    findings.iter().map(|f| f.severity).collect()
+}
+
+/// Without agents there is nothing to hook into, and doctor says so.
+#[tokio::test]
+async fn no_agents_is_an_error() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.set_mock_cargo(&mock_cargo_script(symposium::state::CURRENT_VERSION));
+            let agents = findings(&ctx, "agents").await;
+            assert_eq!(severities(&agents), [Severity::Error], "{agents:?}");
+            assert_eq!(agents[0].fix.as_deref(), Some("run `cargo agents init`"));
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// After a sync the hooks and MCP entry are in place; removing the agent's
+/// settings file makes both checks fail.
+#[tokio::test]
+async fn missing_hooks_are_reported() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.set_mock_cargo(&mock_cargo_script(symposium::state::CURRENT_VERSION));
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let hooks = findings(&ctx, "hooks").await;
+            assert_eq!(severities(&hooks), [Severity::Ok], "{hooks:?}");
+            let mcp = findings(&ctx, "mcp").await;
+            assert_eq!(severities(&mcp), [Severity::Ok], "{mcp:?}");
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let settings = workspace_root.join(".claude/settings.json");
+            std::fs::remove_file(&settings)?;
+            let hooks = findings(&ctx, "hooks").await;
+            assert_eq!(severities(&hooks), [Severity::Error], "{hooks:?}");
+            assert!(hooks[0].message.contains("settings.json"), "{hooks:?}");
+            assert_eq!(hooks[0].fix.as_deref(), Some("run `cargo agents sync`"));
+            let mcp = findings(&ctx, "mcp").await;
+            assert_eq!(severities(&mcp), [Severity::Warning], "{mcp:?}");
+
+            // Checking changed nothing.
+            assert!(!settings.exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Hooks left in the home directory by an earlier global-scope setup run
+/// alongside the project's, so doctor flags them with the cleanup command.
+#[tokio::test]
+async fn hooks_left_in_the_other_scope_are_reported() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.set_mock_cargo(&mock_cargo_script(symposium::state::CURRENT_VERSION));
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+            assert_eq!(severities(&findings(&ctx, "hooks").await), [Severity::Ok]);
+
+            symposium::sync::register_hooks(&ctx.sym, &symposium::output::Output::quiet()).await?;
+            let hooks = findings(&ctx, "hooks").await;
+            assert_eq!(
+                severities(&hooks),
+                [Severity::Ok, Severity::Warning],
+                "{hooks:?}"
+            );
+            assert!(hooks[1].message.contains("run twice"), "{hooks:?}");
+            assert_eq!(
+                hooks[1].fix.as_deref(),
+                Some("run `cargo agents uninstall --global`, then `cargo agents sync`")
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A newer release on the registry is reported with the upgrade command.
+#[tokio::test]
+async fn outdated_binary_is_reported() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.set_mock_cargo(&mock_cargo_script(symposium::state::CURRENT_VERSION));
+            assert_eq!(severities(&findings(&ctx, "version").await), [Severity::Ok]);
+
+            ctx.set_mock_cargo(&mock_cargo_script("99.0.0"));
+            let version = findings(&ctx, "version").await;
+            assert_eq!(severities(&version), [Severity::Warning], "{version:?}");
+            assert!(version[0].message.contains("99.0.0"), "{version:?}");
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A plugin manifest that fails to load shows up as a registry warning.
+#[tokio::test]
+async fn invalid_manifests_are_reported() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.set_mock_cargo(&mock_cargo_script(symposium::state::CURRENT_VERSION));
+            assert_eq!(
+                severities(&findings(&ctx, "registry").await),
+                [Severity::Ok]
+            );
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            std::fs::write(workspace_root.join("SYMPOSIUM.toml"), "name = [")?;
+            let registry = findings(&ctx, "registry").await;
+            assert_eq!(severities(&registry), [Severity::Warning], "{registry:?}");
+            assert!(
+                registry[0].message.contains("SYMPOSIUM.toml"),
+                "{registry:?}"
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
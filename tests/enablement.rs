@@ -401,6 +401,186 @@ async fn status_reports_candidate_then_used() {
     .unwrap();
 }
 
+/// Enablement in the workspace's `[workspace.metadata.symposium]` applies to
+/// everyone in the workspace and is attributed to it, but the user's own
+/// decision still wins.
+#[tokio::test]
+async fn workspace_config_layers_under_the_user() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["auto-enable0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let manifest = workspace_root.join("Cargo.toml");
+            let mut toml = std::fs::read_to_string(&manifest)?;
+            toml.push_str(indoc::indoc! {r#"
+
+                [workspace]
+                exclude = ["crate-a"]
+
+                [workspace.metadata.symposium.plugins]
+                auto-enable = ["crate-a"]
+                disable = ["noisy-crate"]
+            "#});
+            std::fs::write(&manifest, toml)?;
+
+            let deps = ctx.sym.workspace_deps(&workspace_root);
+            let entries = symposium::status_command::workspace_status(&ctx.sym, &deps).await?;
+            let enabled = entries
+                .iter()
+                .find(|e| e.name == "crate-a")
+                .expect("crate-a discovered");
+            assert_eq!(enabled.state, StatusState::Active);
+            assert_eq!(enabled.root, "`[plugins] auto-enable` (workspace config)");
+            let declined = entries
+                .iter()
+                .find(|e| e.name == "noisy-crate")
+                .expect("team-declined entry present");
+            assert_eq!(declined.state, StatusState::Declined);
+            assert!(declined.root.contains("workspace config"), "{declined:?}");
+            assert!(!read_config(&ctx).contains("crate-a"));
+
+            // The user's own answer outranks the team's.
+            symposium::discovery::apply_consent(&mut ctx.sym, &[], &["crate-a".to_string()])?;
+            let deps = ctx.sym.workspace_deps(&workspace_root);
+            let entries = symposium::status_command::workspace_status(&ctx.sym, &deps).await?;
+            let declined = entries
+                .iter()
+                .find(|e| e.name == "crate-a")
+                .expect("crate-a present");
+            assert_eq!(declined.state, StatusState::Declined);
+            assert_eq!(declined.root, "declined (`[plugins] disable`)");
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A checked-in manifest cannot consent for the user: a team `use` entry or
+/// `auto-enable = ["*"]` leaves a dependency plugin awaiting consent.
+#[tokio::test]
+async fn workspace_config_cannot_consent_for_the_user() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["auto-enable0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let manifest = workspace_root.join("Cargo.toml");
+            let mut toml = std::fs::read_to_string(&manifest)?;
+            toml.push_str(indoc::indoc! {r#"
+
+                [workspace]
+                exclude = ["crate-a"]
+
+                [workspace.metadata.symposium.plugins]
+                auto-enable = ["*"]
+                use = ["crate-a"]
+            "#});
+            std::fs::write(&manifest, toml)?;
+
+            let deps = ctx.sym.workspace_deps(&workspace_root);
+            let entries = symposium::status_command::workspace_status(&ctx.sym, &deps).await?;
+            let entry = entries
+                .iter()
+                .find(|e| e.name == "crate-a")
+                .expect("crate-a discovered");
+            assert_eq!(entry.state, StatusState::Candidate);
+
+            ctx.symposium(&["sync"]).await?;
+            let skills_dir = workspace_root.join(".claude/skills");
+            assert!(find_installed_skills(&skills_dir, "a-guidance").is_empty());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// The system policy outranks the user: a forbidden `auto-enable = ["*"]` is
+/// ignored, and a denied name is reported as such, refused by `use`, and
+/// never installed.
+#[tokio::test]
+async fn system_policy_outranks_the_user() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["auto-enable0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let skills_dir = workspace_root.join(".claude/skills");
+            let state_of = async |ctx: &TestContext| {
+                let deps = ctx.sym.workspace_deps(&workspace_root);
+                symposium::status_command::workspace_status(&ctx.sym, &deps)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .find(|e| e.name == "crate-a")
+                    .expect("crate-a listed")
+                    .state
+            };
+
+            ctx.sym.config.plugins.auto_enable = vec!["*".into()];
+            ctx.sym.system.policy.allow_auto_enable_all = false;
+            assert_eq!(state_of(&ctx).await, StatusState::Candidate);
+
+            ctx.sym.config.plugins.auto_enable = vec!["crate-a".into()];
+            ctx.sym.system.policy.deny_plugins = vec!["crate_a".into()];
+            assert_eq!(state_of(&ctx).await, StatusState::Denied);
+
+            ctx.symposium(&["sync"]).await?;
+            assert!(find_installed_skills(&skills_dir, "a-guidance").is_empty());
+            assert!(ctx.symposium(&["use", "crate-a"]).await.is_err());
+
+            ctx.sym.system.policy.deny_plugins.clear();
+            ctx.symposium(&["sync"]).await?;
+            find_installed_skill(&skills_dir, "a-guidance");
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// `status` reports each version-constrained chained edge: active when the
+/// crate's resolved version meets the requirement, skipped when it does not.
+#[tokio::test]
+async fn status_reports_chained_version_requirements() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["chained-version0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+
+            let deps = ctx.sym.workspace_deps(&workspace_root);
+            let entries = symposium::status_command::workspace_status(&ctx.sym, &deps).await?;
+            // crate-w also embeds skills, so discovery lists it as a
+            // candidate too; the edge's outcome is its own entry.
+            let skipped = entries
+                .iter()
+                .find(|e| e.name == "crate-w" && e.state == StatusState::Skipped)
+                .expect("crate-w edge skipped");
+            assert_eq!(skipped.version.as_deref(), Some("0.1.0"));
+            assert!(skipped.root.contains("requires crate-w >=1"), "{skipped:?}");
+
+            let chained = entries
+                .iter()
+                .find(|e| e.name == "crate-v" && e.state == StatusState::Active)
+                .expect("crate-v edge active");
+            assert!(
+                chained.root.contains("chained from `vouch-plugin`"),
+                "{chained:?}"
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
 /// A registry plugin nothing gates is reported dormant until `use` names it.
 #[tokio::test]
 async fn status_reports_dormant_registry_plugin() {
@@ -451,6 +631,38 @@ async fn status_reports_dormant_registry_plugin() {
     .unwrap();
 }
 
+/// A registry plugin gated on a custom predicate is reported from the same
+/// resolution sync uses, which installs and evaluates the predicate.
+#[tokio::test]
+async fn status_evaluates_custom_predicate_gates() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["custom-predicate0"],
+        async |mut ctx| {
+            let script = ctx.tempdir.join("bp-checker.sh");
+            std::fs::write(&script, "#!/bin/sh\nexit 0\n")?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+            }
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+
+            let deps = ctx.sym.workspace_deps(&workspace_root);
+            let entries = symposium::status_command::workspace_status(&ctx.sym, &deps).await?;
+            let plugin = entries
+                .iter()
+                .find(|e| e.name == "bp-plugin")
+                .expect("bp-plugin present");
+            assert_eq!(plugin.state, StatusState::Active, "{entries:?}");
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
 // ── consent ──────────────────────────────────────────────────────────
 
 /// The consent prompt must never block on stdin outside a terminal session.
@@ -0,0 +1,2 @@
+// versioned-host depends on crate-w 0.1.0 and crate-v 0.2.0; a vouch plugin
+// references both with version requirements, only one of which holds.
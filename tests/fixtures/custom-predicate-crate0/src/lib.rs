@@ -0,0 +1 @@
+// host depends on crate-p, whose plugin defines a custom predicate.
@@ -0,0 +1,20 @@
+#!/bin/sh
+# Minimal package manager speaking the symposium PM protocol: it knows one
+# package, `widgets`, whose content lives under `internal-packages/`.
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":\([0-9][0-9]*\).*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*) result='{"protocol_version":1}' ;;
+    *'"method":"list_deps"'*) result='[]' ;;
+    *'"method":"active_plugins"'*) result='[]' ;;
+    *'"method":"load_plugin"'*'"name":"widgets"'*)
+      result='[{"id":{"pm":"internal","name":"widgets","version":"1.0.0"},"path":"$TEST_DIR/internal-packages/widgets"}]'
+      ;;
+    *'"method":"load_plugin"'*) result='[]' ;;
+    *)
+      printf '{"jsonrpc":"2.0","id":%s,"error":{"code":-32601,"message":"method not found"}}\n' "$id"
+      continue
+      ;;
+  esac
+  printf '{"jsonrpc":"2.0","id":%s,"result":%s}\n' "$id" "$result"
+done
@@ -0,0 +1,11 @@
+#!/bin/sh
+result=alone
+for _ in 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25; do
+    if [ -e "$1/d-started" ]; then
+        result=saw-d
+        break
+    fi
+    sleep 0.2
+done
+echo "$result" > "$1/c-result"
+echo '{"PostToolUse":{"additionalContext":"c-output"}}'
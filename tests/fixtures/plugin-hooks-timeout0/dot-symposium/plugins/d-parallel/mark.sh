@@ -0,0 +1,3 @@
+#!/bin/sh
+touch "$1/d-started"
+echo '{"PostToolUse":{"additionalContext":"d-output"}}'
@@ -0,0 +1,2 @@
+#!/bin/sh
+echo '{"Stop":{"decision":"block","additionalContext":"stop-gate: cargo check fails"}}'
@@ -22,13 +22,17 @@ async fn cargo_agents_help_lists_plugin_vended() {
                 Usage: cargo agents [OPTIONS] [COMMAND]
 
                 Commands for humans:
+                config        Read and edit the user config (get, set, unset, list)
+                doctor        Diagnose hooks, MCP registrations, caches and plugins
                 init          Set up user-wide configuration
                 plugin        Manage plugins
+                registry      Manage plugin registries (list, add, remove, pin)
                 search        Search configured registries for plugins
                 self-update   Update symposium to the latest version
                 status        Show which plugins are enabled for this workspace, and why
                 sync          Synchronize skills with workspace dependencies
-                telemetry     Manage opt-in usage telemetry (status, enable, disable, show)
+                telemetry     Manage opt-in usage telemetry (status, enable, disable, show, summary, export)
+                uninstall     Remove symposium's hooks, MCP registrations and skills
                 use           Enable a plugin by name and sync it into the workspace
 
                 Commands for agents:
@@ -39,7 +39,7 @@ fn find_installed_skills(parent: &Path, skill_name: &str) -> Vec<PathBuf> {
 }
 
 /// Locate the unique installed skill directory by name. Panics if 0 or
-/// >1 directories match. Use `find_installed_skills` when the test cares
+/// \>1 directories match. Use `find_installed_skills` when the test cares
 /// about how many were installed.
 fn find_installed_skill(parent: &Path, skill_name: &str) -> PathBuf {
     let mut hits = find_installed_skills(parent, skill_name);
@@ -379,6 +379,87 @@ async fn removing_agent_removes_hooks() {
     .unwrap();
 }
 
+/// Cursor gets each skill as a folder under `.cursor/rules/` with a derived
+/// `.mdc` rule carrying the skill's description.
+#[tokio::test]
+async fn sync_installs_cursor_rules() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "cursor"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let skill_dir =
+                find_installed_skill(&workspace_root.join(".cursor/rules"), "serde-guidance");
+            let dir_name = skill_dir.file_name().unwrap().to_str().unwrap();
+            let rule = std::fs::read_to_string(skill_dir.join(format!("{dir_name}.mdc")))?;
+            assert!(
+                rule.starts_with("---\ndescription: ") && rule.contains("alwaysApply: false"),
+                "unexpected rule: {rule}"
+            );
+
+            // The derived rule does not make the next sync see a changed skill.
+            let before = std::fs::metadata(skill_dir.join("SKILL.md"))?.modified()?;
+            ctx.symposium(&["sync"]).await?;
+            let after = std::fs::metadata(skill_dir.join("SKILL.md"))?.modified()?;
+            assert_eq!(before, after, "unchanged skill should not be reinstalled");
+
+            let hooks = std::fs::read_to_string(workspace_root.join(".cursor/hooks.json"))?;
+            assert!(hooks.contains("cargo-agents hook cursor pre-tool-use"));
+            assert!(workspace_root.join(".cursor/mcp.json").exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// An `[[agent-profile]]` agent is configured and synced like a built-in one,
+/// with the paths its profile describes.
+#[tokio::test]
+async fn sync_uses_agent_profile_layout() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.sym.config.agent_profiles.push(
+                toml::from_str(indoc::indoc! {r#"
+                    name = "windsurf"
+                    schema = "claude"
+                    skills = ".windsurf/skills"
+                    hooks = ".windsurf/hooks.json"
+                    mcp = { path = ".windsurf/mcp.json", key = "servers" }
+                "#})
+                .unwrap(),
+            );
+            ctx.symposium(&["init", "--add-agent", "windsurf"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let workspace_root = ctx.workspace_root.as_ref().unwrap();
+            find_installed_skill(&workspace_root.join(".windsurf/skills"), "serde-guidance");
+
+            let hooks = std::fs::read_to_string(workspace_root.join(".windsurf/hooks.json"))?;
+            assert!(
+                hooks.contains("cargo-agents hook claude pre-tool-use"),
+                "hooks should use the profile's schema: {hooks}"
+            );
+
+            let mcp: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+                workspace_root.join(".windsurf/mcp.json"),
+            )?)?;
+            assert!(
+                mcp["servers"]["symposium"].is_object(),
+                "MCP server should be under the profile's key: {mcp}"
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
 /// `--add-agent` is additive to existing agents.
 #[tokio::test]
 async fn add_agent_is_additive() {
@@ -415,9 +496,27 @@ async fn add_agent_is_additive() {
     .unwrap();
 }
 
-/// `sync` filters MCP servers by their `depends-on` predicates.
+/// Helper: names of the plugin MCP servers the meta-server would proxy for
+/// the fixture workspace.
+async fn applicable_server_names(ctx: &symposium_testlib::TestContext) -> Vec<String> {
+    let workspace_root = ctx.workspace_root.as_ref().unwrap();
+    let deps = ctx.sym.workspace_deps(workspace_root);
+    symposium::mcp_serve::applicable_servers(&ctx.sym, &deps)
+        .await
+        .into_iter()
+        .map(|s| match s {
+            sacp::schema::McpServer::Stdio(s) => s.name,
+            sacp::schema::McpServer::Http(s) => s.name,
+            sacp::schema::McpServer::Sse(s) => s.name,
+            _ => panic!("unsupported McpServer variant"),
+        })
+        .collect()
+}
+
+/// `sync` registers the single `symposium` meta-server entry rather than the
+/// plugin servers themselves.
 #[tokio::test]
-async fn sync_filters_mcp_servers_by_crates() {
+async fn sync_registers_only_meta_server() {
     with_fixture(
         TestMode::SimulationOnly,
         &["mcp-filtering0", "workspace0"],
@@ -426,28 +525,153 @@ async fn sync_filters_mcp_servers_by_crates() {
             ctx.symposium(&["sync"]).await?;
 
             let workspace_root = ctx.workspace_root.as_ref().unwrap();
+            let settings: Value = serde_json::from_str(&std::fs::read_to_string(
+                workspace_root.join(".claude/settings.json"),
+            )?)?;
+            let servers = settings["mcpServers"].as_object().unwrap();
+            assert_eq!(
+                servers.keys().collect::<Vec<_>>(),
+                vec!["symposium"],
+                "only the meta-server should be registered: {settings}"
+            );
+            assert_eq!(servers["symposium"]["command"], "cargo-agents");
+            assert_eq!(
+                servers["symposium"]["args"],
+                serde_json::json!(["mcp-serve"])
+            );
+
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Plugin HTTP/SSE servers can't be proxied by the meta-server, so `sync`
+/// registers them with the agent directly, next to `symposium`.
+#[tokio::test]
+async fn sync_registers_http_plugin_servers_directly() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-filtering0", "workspace0"],
+        async |mut ctx| {
+            let plugin_dir = ctx.sym.config_dir().join("plugins/http-plugin");
+            std::fs::create_dir_all(&plugin_dir)?;
+            std::fs::write(
+                plugin_dir.join("SYMPOSIUM.toml"),
+                indoc::indoc! {r#"
+                    name = "http-plugin"
+                    depends-on = ["*"]
+
+                    [[mcp_servers]]
+                    type = "http"
+                    name = "remote-server"
+                    url = "http://localhost:8080/mcp"
+                    headers = []
+                "#},
+            )?;
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let workspace_root = ctx.workspace_root.as_ref().unwrap();
+            let settings: Value = serde_json::from_str(&std::fs::read_to_string(
+                workspace_root.join(".claude/settings.json"),
+            )?)?;
+            let servers = settings["mcpServers"].as_object().unwrap();
+            assert_eq!(
+                servers.keys().collect::<Vec<_>>(),
+                vec!["remote-server", "symposium"],
+                "{settings}"
+            );
+            assert_eq!(servers["remote-server"]["url"], "http://localhost:8080/mcp");
+
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Releases before the meta-server registered each plugin server under its
+/// own name. The first `sync` removes those entries, leaving unrelated ones.
+#[tokio::test]
+async fn sync_removes_legacy_plugin_mcp_entries() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-filtering0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
             let settings_path = workspace_root.join(".claude/settings.json");
-            let settings = std::fs::read_to_string(&settings_path)?;
+            std::fs::create_dir_all(settings_path.parent().unwrap())?;
+            std::fs::write(
+                &settings_path,
+                serde_json::json!({
+                    "mcpServers": {
+                        "serde-server": { "command": "/usr/bin/true", "args": ["--stdio"] },
+                        "missing-crate-server": { "command": "/usr/bin/true", "args": [] },
+                        "user-server": { "command": "/usr/bin/true", "args": [] },
+                    }
+                })
+                .to_string(),
+            )?;
+            ctx.symposium(&["sync"]).await?;
+
+            let settings: Value = serde_json::from_str(&std::fs::read_to_string(&settings_path)?)?;
+            let servers = settings["mcpServers"].as_object().unwrap();
+            assert_eq!(
+                servers.keys().collect::<Vec<_>>(),
+                vec!["symposium", "user-server"],
+                "{settings}"
+            );
+
+            // The migration runs once: an entry added afterwards is the
+            // user's own and stays.
+            let mut settings = settings;
+            settings["mcpServers"]["serde-server"] =
+                serde_json::json!({ "command": "/usr/bin/true", "args": [] });
+            std::fs::write(&settings_path, settings.to_string())?;
+            ctx.symposium(&["sync"]).await?;
+            let settings: Value = serde_json::from_str(&std::fs::read_to_string(&settings_path)?)?;
+            assert!(settings["mcpServers"].get("serde-server").is_some());
+
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// The meta-server's backing set is filtered by the servers' `depends-on`
+/// predicates.
+#[tokio::test]
+async fn meta_server_filters_mcp_servers_by_crates() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-filtering0", "workspace0"],
+        async |ctx| {
+            let names = applicable_server_names(&ctx).await;
 
-            // always-server (depends-on = ["*"]) → registered
+            // always-server (depends-on = ["*"]) → proxied
             assert!(
-                settings.contains("always-server"),
-                "wildcard MCP server should be registered"
+                names.contains(&"always-server".to_string()),
+                "wildcard MCP server should be proxied: {names:?}"
             );
-            // serde-server (depends-on = ["serde"]) → registered (serde is in workspace0)
+            // serde-server (depends-on = ["serde"]) → proxied (serde is in workspace0)
             assert!(
-                settings.contains("serde-server"),
-                "serde MCP server should be registered"
+                names.contains(&"serde-server".to_string()),
+                "serde MCP server should be proxied: {names:?}"
             );
-            // inherited-server (no crates, inherits from plugin) → registered
+            // inherited-server (no crates, inherits from plugin) → proxied
             assert!(
-                settings.contains("inherited-server"),
-                "inherited MCP server should be registered"
+                names.contains(&"inherited-server".to_string()),
+                "inherited MCP server should be proxied: {names:?}"
             );
-            // missing-crate-server (depends-on = ["reqwest"]) → NOT registered
+            // missing-crate-server (depends-on = ["reqwest"]) → NOT proxied
             assert!(
-                !settings.contains("missing-crate-server"),
-                "reqwest MCP server should NOT be registered"
+                !names.contains(&"missing-crate-server".to_string()),
+                "reqwest MCP server should NOT be proxied: {names:?}"
             );
 
             Ok(())
@@ -457,6 +681,108 @@ async fn sync_filters_mcp_servers_by_crates() {
     .unwrap();
 }
 
+/// With `hook-scope = "global"` every workspace shares the home-directory
+/// config, so what `sync` writes there must not depend on the workspace:
+/// syncing a second workspace leaves it byte-for-byte unchanged, while each
+/// workspace's meta-server still proxies only its own servers. An HTTP server
+/// the meta-server can't proxy is registered in the workspace that uses it,
+/// and removed from there once it stops applying.
+#[tokio::test]
+async fn global_scope_mcp_registration_is_workspace_independent() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-filtering0", "workspace0"],
+        async |mut ctx| {
+            let plugin_dir = ctx.sym.config_dir().join("plugins/http-plugin");
+            std::fs::create_dir_all(&plugin_dir)?;
+            std::fs::write(
+                plugin_dir.join("SYMPOSIUM.toml"),
+                indoc::indoc! {r#"
+                    name = "http-plugin"
+                    depends-on = ["*"]
+
+                    [[mcp_servers]]
+                    type = "http"
+                    name = "serde-remote"
+                    depends-on = ["serde"]
+                    url = "http://localhost:8080/mcp"
+                    headers = []
+                "#},
+            )?;
+            let settings_path = ctx.sym.home_dir().join(".claude/settings.json");
+            let home_servers = || -> anyhow::Result<Vec<String>> {
+                let parsed: Value =
+                    serde_json::from_str(&std::fs::read_to_string(&settings_path)?)?;
+                Ok(parsed["mcpServers"]
+                    .as_object()
+                    .unwrap()
+                    .keys()
+                    .cloned()
+                    .collect())
+            };
+            // `init` registers only the meta-server in the home configs.
+            ctx.sym.config.hook_scope = symposium::config::HookScope::Global;
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            assert_eq!(home_servers()?, vec!["symposium"]);
+            ctx.symposium(&["sync"]).await?;
+
+            let settings = std::fs::read_to_string(&settings_path)?;
+            assert_eq!(home_servers()?, vec!["symposium"]);
+            assert!(
+                applicable_server_names(&ctx)
+                    .await
+                    .contains(&"serde-server".to_string())
+            );
+            let first = ctx.workspace_root.clone().unwrap();
+            let project_servers = |root: &std::path::Path| -> Vec<String> {
+                std::fs::read_to_string(root.join(".claude/settings.json"))
+                    .ok()
+                    .and_then(|s| serde_json::from_str::<Value>(&s).ok())
+                    .and_then(|v| v["mcpServers"].as_object().cloned())
+                    .map(|servers| servers.keys().cloned().collect())
+                    .unwrap_or_default()
+            };
+            assert_eq!(project_servers(&first), vec!["serde-remote"]);
+
+            // A second workspace without serde.
+            let other = ctx.tempdir.join("other");
+            std::fs::create_dir_all(other.join("src"))?;
+            std::fs::write(
+                other.join("Cargo.toml"),
+                "[package]\nname = \"other\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+            )?;
+            std::fs::write(other.join("src/lib.rs"), "")?;
+            ctx.workspace_root = Some(other.clone());
+            ctx.symposium(&["sync"]).await?;
+
+            assert_eq!(std::fs::read_to_string(&settings_path)?, settings);
+            assert!(project_servers(&other).is_empty());
+            assert_eq!(project_servers(&first), vec!["serde-remote"]);
+            assert!(
+                !applicable_server_names(&ctx)
+                    .await
+                    .contains(&"serde-server".to_string())
+            );
+
+            // The first workspace drops serde: its HTTP server goes with it.
+            let manifest = first.join("Cargo.toml");
+            let without_serde =
+                std::fs::read_to_string(&manifest)?.replace("serde = \"1.0\"\n", "");
+            std::fs::write(&manifest, without_serde)?;
+            // The resolution cache is keyed on `Cargo.lock`'s mtime, which has
+            // one-second resolution; a missing lock forces a fresh resolve.
+            std::fs::remove_file(first.join("Cargo.lock"))?;
+            ctx.workspace_root = Some(first.clone());
+            ctx.symposium(&["sync"]).await?;
+            assert!(project_servers(&first).is_empty());
+            assert_eq!(std::fs::read_to_string(&settings_path)?, settings);
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
 /// `sync` does not install skills targeting transitive dependencies.
 /// workspace0 has tokio as a direct dep; mio is a transitive dep of tokio.
 #[tokio::test]
@@ -485,6 +811,128 @@ async fn sync_excludes_transitive_deps() {
     .unwrap();
 }
 
+/// Skill groups can opt in to the rest of the resolved graph:
+/// `depends-on(.., transitive)` sees crates reached through other
+/// dependencies, `feature(..)` sees enabled features, and
+/// `dev-depends-on(..)` only matches `[dev-dependencies]` entries.
+#[tokio::test]
+async fn sync_evaluates_dependency_graph_predicates() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["dependency-graph0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let skills_dir = ctx.workspace_root.as_ref().unwrap().join(".claude/skills");
+            for skill in ["serde-core-guidance", "serde-std-guidance"] {
+                assert!(
+                    !find_installed_skills(&skills_dir, skill).is_empty(),
+                    "{skill} should be installed"
+                );
+            }
+            assert!(
+                find_installed_skills(&skills_dir, "serde-test-guidance").is_empty(),
+                "serde is not a dev-dependency of workspace0"
+            );
+
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// `feature(..)` sees the features a default build enables. A member feature
+/// nobody turns on must not make it match, even though the dependencies
+/// behind it still count.
+#[tokio::test]
+async fn feature_predicates_ignore_non_default_member_features() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["dependency-graph0"],
+        async |mut ctx| {
+            let root = ctx.tempdir.join("features-ws");
+            let widget = root.join("widget");
+            std::fs::create_dir_all(widget.join("src"))?;
+            std::fs::write(
+                widget.join("Cargo.toml"),
+                indoc::indoc! {r#"
+                    [package]
+                    name = "widget"
+                    version = "0.1.0"
+                    edition = "2021"
+
+                    [features]
+                    default = ["fast"]
+                    fast = []
+                    extra = []
+                "#},
+            )?;
+            std::fs::write(widget.join("src/lib.rs"), "")?;
+            std::fs::create_dir_all(root.join("src"))?;
+            std::fs::write(
+                root.join("Cargo.toml"),
+                indoc::indoc! {r#"
+                    [package]
+                    name = "app"
+                    version = "0.1.0"
+                    edition = "2021"
+
+                    [dependencies]
+                    widget = { path = "widget" }
+
+                    [features]
+                    extra = ["widget/extra"]
+                "#},
+            )?;
+            std::fs::write(root.join("src/lib.rs"), "")?;
+
+            let plugin = ctx.sym.config_dir().join("plugins/widget-plugin");
+            for (group, skill) in [
+                ("fast", "widget-fast-guidance"),
+                ("extra", "widget-extra-guidance"),
+            ] {
+                let dir = plugin.join(group).join(skill);
+                std::fs::create_dir_all(&dir)?;
+                std::fs::write(
+                    dir.join("SKILL.md"),
+                    format!("---\nname: {skill}\ndescription: {skill}\n---\n\nBody.\n"),
+                )?;
+            }
+            std::fs::write(
+                plugin.join("SYMPOSIUM.toml"),
+                indoc::indoc! {r#"
+                    name = "widget-plugin"
+                    depends-on = ["widget"]
+
+                    [[skills]]
+                    predicates = ["feature(widget/fast)"]
+                    source.path = "fast"
+
+                    [[skills]]
+                    predicates = ["feature(widget/extra)"]
+                    source.path = "extra"
+                "#},
+            )?;
+
+            ctx.workspace_root = Some(root.clone());
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let skills_dir = root.join(".claude/skills");
+            assert!(!find_installed_skills(&skills_dir, "widget-fast-guidance").is_empty());
+            assert!(
+                find_installed_skills(&skills_dir, "widget-extra-guidance").is_empty(),
+                "`extra` is only enabled by a non-default member feature"
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
 /// `sync` installs skills defined inside a plugin's `[[skills]]` group
 /// with `source.path = "."`. The skill directory should resolve relative
 /// to the plugin's parent directory, not the TOML file path itself.
@@ -585,7 +1033,7 @@ async fn auto_enable_admits_a_dependencys_embedded_skills() {
             );
 
             ctx.sym.config.plugins.auto_enable.push("crate-a".into());
-            ctx.sym.save_config()?;
+            ctx.sym.save_config_keys(&["plugins.auto-enable"])?;
             ctx.symposium(&["sync"]).await?;
 
             let a_dir = find_installed_skill(&skills_dir, "a-guidance");
@@ -628,7 +1076,7 @@ async fn dormant_plugin_activates_only_once_used() {
                 .push(symposium::config::UseEntry::Global(
                     "gateless-plugin".into(),
                 ));
-            ctx.sym.save_config()?;
+            ctx.sym.save_config_keys(&["plugins.use"])?;
             ctx.symposium(&["sync"]).await?;
 
             let dir = find_installed_skill(&skills_dir, "gateless-guidance");
@@ -673,6 +1121,161 @@ async fn sync_installs_skill_via_chained_plugin() {
     .unwrap();
 }
 
+/// A `[[plugins]] source.cargo` edge's version requirement is enforced
+/// against the version the crate resolves to.
+///
+/// Fixture layout:
+/// - `versioned-host` depends on `crate-w` 0.1.0 and `crate-v` 0.2.0
+/// - `vouch-plugin` references `crate-w>=1` (unmet) and `crate-v` 0.2 (met)
+/// - each crate ships one skill
+#[tokio::test]
+async fn sync_skips_chained_plugin_outside_version_requirement() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["chained-version0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let events = ctx.sync_with_report(tracing::Level::INFO).await?;
+            assert!(
+                events.iter().any(
+                    |e| e
+                        .get("message")
+                        .and_then(|m| m.as_str())
+                        .is_some_and(|m| m.contains("requires crate-w >=1")
+                            && m.contains("resolved to 0.1.0"))
+                ),
+                "sync should warn about the unmet requirement: {events:?}"
+            );
+
+            let workspace_root = ctx.workspace_root.as_ref().unwrap();
+            let skills_dir = workspace_root.join(".claude/skills");
+            find_installed_skill(&skills_dir, "v-guidance");
+            let skipped = find_installed_skills(&skills_dir, "w-guidance");
+            assert!(skipped.is_empty(), "crate-w's skill installed: {skipped:?}");
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A registry plugin can provide a package-manager type with
+/// `[[package-manager]]`, and a `[[plugins]] source.<pm>` reference loads a
+/// package through it.
+///
+/// Fixture layout:
+/// - `internal-pm` declares the `internal` PM (a shell script speaking the PM
+///   protocol) and `[[plugins]] source.internal = "widgets"`
+/// - the script reports `widgets` at `internal-packages/widgets/`, which ships
+///   `skills/widget-guidance/SKILL.md`
+#[tokio::test]
+async fn sync_installs_skill_via_plugin_provided_package_manager() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["external-pm0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let workspace_root = ctx.workspace_root.as_ref().unwrap();
+            let skills_dir = workspace_root.join(".claude/skills");
+
+            let dir = find_installed_skill(&skills_dir, "widget-guidance");
+            let content = std::fs::read_to_string(dir.join("SKILL.md"))?;
+            assert!(content.contains("Use internal widgets like this"));
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A declared package manager no `[[plugins]] source.<pm>` names has nothing
+/// to load, so its binary is never started.
+#[tokio::test]
+async fn unnamed_plugin_package_manager_is_never_spawned() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["external-pm0", "workspace0"],
+        async |mut ctx| {
+            let plugin_dir = ctx.sym.config_dir().join("plugins/internal-pm");
+            let witness = ctx.tempdir.join("pm-started");
+            let wrapper = plugin_dir.join("wrapper.sh");
+            std::fs::write(
+                &wrapper,
+                format!(
+                    "#!/bin/sh\ntouch {}\nexec {}\n",
+                    witness.display(),
+                    plugin_dir.join("pm.sh").display()
+                ),
+            )?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(&wrapper, std::fs::Permissions::from_mode(0o755))?;
+            }
+            std::fs::write(
+                plugin_dir.join("SYMPOSIUM.toml"),
+                format!(
+                    "name = \"internal-pm\"\ndepends-on = [\"*\"]\n\n\
+                     [[package-manager]]\nname = \"internal\"\n\
+                     command = {{ script = \"{}\" }}\n",
+                    wrapper.display()
+                ),
+            )?;
+
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+            assert!(!witness.exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A `[[plugins]] source.<pm>` naming no package manager — here a misspelled
+/// `source.cargo` — fails the plugin's load instead of silently loading it
+/// without the reference.
+#[tokio::test]
+async fn misspelled_chained_source_is_a_load_error() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["external-pm0", "workspace0"],
+        async |mut ctx| {
+            let typo = ctx.sym.config_dir().join("plugins/typo-plugin");
+            std::fs::create_dir_all(typo.join("skills/typo-guidance"))?;
+            std::fs::write(
+                typo.join("SYMPOSIUM.toml"),
+                "name = \"typo-plugin\"\ndepends-on = [\"*\"]\n\n\
+                 [[skills]]\nsource.path = \"skills\"\n\n\
+                 [[plugins]]\nsource.crago = \"serde\"\n",
+            )?;
+            std::fs::write(
+                typo.join("skills/typo-guidance/SKILL.md"),
+                "---\nname: typo-guidance\ndescription: Never installed\n---\n",
+            )?;
+
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let events = ctx.sync_with_report(tracing::Level::INFO).await?;
+            assert!(
+                events.iter().any(|e| e["message"].as_str().is_some_and(|m| m
+                    .contains("skipping typo-plugin: [[plugins]] source.crago names no known package manager"))),
+                "{events:#?}"
+            );
+
+            // The plugin did not load; the one whose `source.internal` names a
+            // declared package manager still did.
+            let skills_dir = ctx.workspace_root.as_ref().unwrap().join(".claude/skills");
+            assert!(!skills_dir.join("typo-guidance").exists());
+            find_installed_skill(&skills_dir, "widget-guidance");
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
 /// `sync` loads a crate that ships its own `SYMPOSIUM.toml` as a first-class
 /// plugin through a `[[plugins]]` chained reference.
 ///
@@ -709,7 +1312,7 @@ async fn sync_installs_skill_via_crate_manifest() {
     .unwrap();
 }
 
-/// `sync` registers an MCP server declared by a crate reached through a
+/// The meta-server proxies an MCP server declared by a crate reached through a
 /// `[[plugins]]` chained reference — a crate-sourced plugin's MCP servers flow
 /// through the active plugin set, not just its skills.
 ///
@@ -719,23 +1322,15 @@ async fn sync_installs_skill_via_crate_manifest() {
 ///   "crate-f"` but declares nothing of its own
 /// - `crate-f` ships a `SYMPOSIUM.toml` declaring the `facet-server` MCP server
 #[tokio::test]
-async fn sync_registers_mcp_server_from_chained_crate() {
-    with_fixture(
-        TestMode::SimulationOnly,
-        &["crate-facets0"],
-        async |mut ctx| {
-            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
-            ctx.symposium(&["sync"]).await?;
-
-            let workspace_root = ctx.workspace_root.as_ref().unwrap();
-            let settings = std::fs::read_to_string(workspace_root.join(".claude/settings.json"))?;
-            assert!(
-                settings.contains("facet-server"),
-                "chained crate's MCP server should be registered:\n{settings}"
-            );
-            Ok(())
-        },
-    )
+async fn meta_server_proxies_mcp_server_from_chained_crate() {
+    with_fixture(TestMode::SimulationOnly, &["crate-facets0"], async |ctx| {
+        let names = applicable_server_names(&ctx).await;
+        assert!(
+            names.contains(&"facet-server".to_string()),
+            "chained crate's MCP server should be proxied: {names:?}"
+        );
+        Ok(())
+    })
     .await
     .unwrap();
 }
@@ -2085,6 +2680,158 @@ async fn auto_sync_reruns_when_cargo_lock_changes() {
     .unwrap();
 }
 
+/// Adding a dependency mid-session is noticed on the `PostToolUse` that made
+/// the edit: the workspace is re-resolved and synced, and the agent is told
+/// which skills the new crate brings in.
+#[tokio::test]
+async fn post_tool_use_resyncs_when_manifest_changes() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace-noserde0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let skills_dir = workspace_root.join(".claude/skills");
+
+            // Seed the workspace cache: no serde yet, so no serde skill.
+            let pre_tool = [symposium_testlib::HookStep::PreToolUse {
+                tool_name: "Bash".to_string(),
+                tool_input: serde_json::json!({}),
+            }];
+            ctx.prompt_or_hook("test", &pre_tool, symposium::hook_schema::HookAgent::Claude)
+                .await?;
+            assert!(find_installed_skills(&skills_dir, "serde-guidance").is_empty());
+
+            // The agent adds serde to the manifest.
+            let manifest = workspace_root.join("Cargo.toml");
+            let contents = std::fs::read_to_string(&manifest)?;
+            std::fs::write(&manifest, format!("{contents}serde = \"1.0\"\n"))?;
+
+            let edit = [symposium_testlib::HookStep::PostToolUse {
+                tool_name: "Edit".to_string(),
+                tool_input: serde_json::json!({ "file_path": manifest }),
+                tool_response: serde_json::json!({}),
+            }];
+            let result = ctx
+                .prompt_or_hook("test", &edit, symposium::hook_schema::HookAgent::Claude)
+                .await?;
+
+            find_installed_skill(&skills_dir, "serde-guidance");
+            assert!(
+                result.has_context_containing("`serde-guidance`"),
+                "PostToolUse should name the new skill: {:#?}",
+                result.hooks,
+            );
+
+            // The same edit reported again has nothing new to offer.
+            let result = ctx
+                .prompt_or_hook("test", &edit, symposium::hook_schema::HookAgent::Claude)
+                .await?;
+            assert!(
+                !result.has_context_containing("serde-guidance"),
+                "an unchanged workspace should report nothing: {:#?}",
+                result.hooks,
+            );
+
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A prompt naming a dependency gets a pointer to the installed skill for
+/// it, and only within the configured `prompt-context-budget`.
+#[tokio::test]
+async fn user_prompt_points_at_matching_skills() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let steps = [symposium_testlib::HookStep::user_prompt(
+                "How do I derive Serialize with serde?",
+            )];
+            let result = ctx
+                .prompt_or_hook("test", &steps, symposium::hook_schema::HookAgent::Claude)
+                .await?;
+            assert!(
+                result.has_context_containing(
+                    "The `serde-guidance` skill is available at .claude/skills/serde-guidance."
+                ),
+                "prompt should point at the serde skill: {:#?}",
+                result.hooks,
+            );
+
+            let steps = [symposium_testlib::HookStep::user_prompt(
+                "Fix the README typo",
+            )];
+            let result = ctx
+                .prompt_or_hook("test", &steps, symposium::hook_schema::HookAgent::Claude)
+                .await?;
+            assert!(!result.has_context_containing("serde-guidance"));
+
+            ctx.sym.config.prompt_context_budget = 0;
+            let steps = [symposium_testlib::HookStep::user_prompt("Use serde here")];
+            let result = ctx
+                .prompt_or_hook("test", &steps, symposium::hook_schema::HookAgent::Claude)
+                .await?;
+            assert!(
+                !result.has_context_containing("serde-guidance"),
+                "a zero budget turns the context off: {:#?}",
+                result.hooks,
+            );
+
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// An `[[agent-profile]]` agent's prompts point at the skills in the
+/// profile's own skills directory, though its hooks arrive under the schema
+/// of a built-in agent.
+#[tokio::test]
+async fn user_prompt_points_at_profile_agent_skills() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.sym.config.agent_profiles.push(
+                toml::from_str(indoc::indoc! {r#"
+                    name = "windsurf"
+                    schema = "claude"
+                    skills = ".windsurf/skills"
+                "#})
+                .unwrap(),
+            );
+            ctx.symposium(&["init", "--add-agent", "windsurf"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let steps = [symposium_testlib::HookStep::user_prompt(
+                "How do I derive Serialize with serde?",
+            )];
+            let result = ctx
+                .prompt_or_hook("test", &steps, symposium::hook_schema::HookAgent::Claude)
+                .await?;
+            assert!(
+                result.has_context_containing(
+                    "The `serde-guidance` skill is available at .windsurf/skills/serde-guidance."
+                ),
+                "prompt should point at the profile's skill: {:#?}",
+                result.hooks,
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
 /// `SessionStart` runs once per session and must re-sync skills regardless of
 /// whether `Cargo.lock` changed. A skill deleted while the freshness gate is
 /// satisfied is reinstalled on the next `SessionStart`, where a `PreToolUse`
@@ -2574,3 +3321,197 @@ async fn report_json_shows_skipped_skills() {
     .await
     .unwrap();
 }
+
+/// `sync --lock` records each active plugin's origin and a content hash per
+/// skill in `symposium.lock`; later plain syncs keep it up to date.
+#[tokio::test]
+async fn sync_lock_writes_lockfile() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["crate-path0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let lock_path = workspace_root.join("symposium.lock");
+            assert!(
+                !lock_path.exists(),
+                "a plain sync must not create a lockfile"
+            );
+
+            ctx.symposium(&["sync", "--lock"]).await?;
+            let lock = std::fs::read_to_string(&lock_path)?;
+            expect_test::expect![[r##"
+                # This file is generated by `cargo agents sync`.
+                # It is not intended for manual editing.
+
+                version = 1
+
+                [[plugin]]
+                name = "crate-x"
+                source = "cargo"
+                version = "0.1.0"
+
+                [[plugin.skill]]
+                name = "x-guidance"
+                hash = "sha256:32922fc5c745bc003d12dd0aefca317049f7c97305cd51d7e1b6aff5a4333fc8"
+
+                [[plugin]]
+                name = "crate-x-plugin"
+                source = "user-plugins"
+
+                [[plugin]]
+                name = "crate-z"
+                source = "cargo"
+                version = "0.1.0"
+
+                [[plugin.skill]]
+                name = "z-guidance"
+                hash = "sha256:0f162db94cf1aedc4918dab179130aa28326464cee081d33b89033cc6df9e1e5"
+
+                [[plugin]]
+                name = "crate-z-plugin"
+                source = "user-plugins"
+            "##]]
+            .assert_eq(&lock);
+
+            // Once present, a plain sync rewrites the lockfile as guidance changes.
+            let skill_md = workspace_root.join("crate-x/skills/x-guidance/SKILL.md");
+            std::fs::write(&skill_md, std::fs::read_to_string(&skill_md)? + "\nMore.\n")?;
+            ctx.symposium(&["sync"]).await?;
+            assert_ne!(std::fs::read_to_string(&lock_path)?, lock);
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// `sync --locked` refuses to install anything when the resolved guidance
+/// differs from `symposium.lock`, and requires the lockfile to exist.
+#[tokio::test]
+async fn sync_locked_refuses_changed_guidance() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["crate-path0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+
+            let err = ctx.symposium(&["sync", "--locked"]).await.unwrap_err();
+            assert!(
+                err.to_string().contains("requires a symposium.lock"),
+                "{err}"
+            );
+
+            ctx.symposium(&["sync", "--lock"]).await?;
+            ctx.symposium(&["sync", "--locked"]).await?;
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let skill_md = workspace_root.join("crate-x/skills/x-guidance/SKILL.md");
+            std::fs::write(
+                &skill_md,
+                "---\nname: x-guidance\ndescription: changed\n---\n",
+            )?;
+
+            let err = ctx.symposium(&["sync", "--locked"]).await.unwrap_err();
+            let message = err.to_string();
+            assert!(
+                message.contains(
+                    "plugin `crate-x` (cargo) changed: skill `x-guidance` content changed"
+                ),
+                "{message}"
+            );
+
+            let installed =
+                find_installed_skill(&workspace_root.join(".claude/skills"), "x-guidance");
+            let content = std::fs::read_to_string(installed.join("SKILL.md"))?;
+            assert!(
+                content.contains("Use crate-x like this"),
+                "`--locked` must not install the changed skill"
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// `sync --dry-run` reports the installs, removals, and config writes a sync
+/// would make as `sync_planned` events, and changes nothing on disk.
+#[tokio::test]
+async fn sync_dry_run_reports_plan_without_writing() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let claude_dir = workspace_root.join(".claude");
+            let dry_run = symposium::sync::SyncOptions {
+                dry_run: true,
+                ..Default::default()
+            };
+
+            let planned = |events: &[Value]| -> Vec<(String, String)> {
+                events
+                    .iter()
+                    .filter(|e| e["kind"] == "sync_planned")
+                    .map(|e| {
+                        let path = e["path"].as_str().unwrap();
+                        let rel = path
+                            .strip_prefix(&*workspace_root.to_string_lossy())
+                            .unwrap_or(path);
+                        (e["action"].as_str().unwrap().to_string(), rel.to_string())
+                    })
+                    .collect()
+            };
+
+            let events = ctx
+                .sync_with_options_report(tracing::Level::INFO, &dry_run)
+                .await?;
+            assert_eq!(
+                planned(&events),
+                vec![
+                    ("write".to_string(), "/.claude/settings.json".to_string()),
+                    (
+                        "install".to_string(),
+                        "/.claude/skills/serde-guidance".to_string()
+                    ),
+                ],
+                "{events:#?}"
+            );
+            assert!(
+                !events.iter().any(|e| e["kind"] == "skill_installed"),
+                "{events:#?}"
+            );
+            assert!(!claude_dir.exists(), "a dry run must not write anything");
+
+            // After a real sync, only the stale directory is left to act on.
+            ctx.symposium(&["sync"]).await?;
+            let stale = claude_dir.join("skills/fake-old-skill");
+            std::fs::create_dir_all(&stale)?;
+            std::fs::write(stale.join(".symposium"), "")?;
+
+            let events = ctx
+                .sync_with_options_report(tracing::Level::INFO, &dry_run)
+                .await?;
+            assert_eq!(
+                planned(&events),
+                vec![(
+                    "remove".to_string(),
+                    "/.claude/skills/fake-old-skill".to_string()
+                )],
+                "{events:#?}"
+            );
+            assert!(stale.exists(), "a dry run must not remove anything");
+
+            // The CLI flag drives the same path.
+            ctx.symposium(&["sync", "--dry-run"]).await?;
+            assert!(stale.exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
@@ -0,0 +1,210 @@
+//! Integration tests for the `mcp-serve` meta-server.
+//!
+//! Each test serves the meta-server in-process over an in-memory duplex pipe
+//! and drives it with an MCP client. The `mcp-meta-server0` fixture declares
+//! a plugin whose backing server is a small shell implementation of the MCP
+//! stdio protocol.
+
+use rmcp::ServiceExt;
+use rmcp::model::{CallToolRequestParams, CallToolResult};
+use rmcp::service::{RoleClient, RunningService};
+use symposium_testlib::{TestContext, TestMode, with_fixture};
+
+/// Start the meta-server for the fixture workspace and connect a client.
+async fn connect(ctx: &TestContext) -> anyhow::Result<RunningService<RoleClient, ()>> {
+    let (server_io, client_io) = tokio::io::duplex(64 * 1024);
+    let sym = ctx.sym.clone();
+    let cwd = ctx.workspace_root.clone().unwrap();
+    tokio::spawn(async move {
+        if let Err(e) = symposium::mcp_serve::serve(sym, &cwd, server_io).await {
+            eprintln!("meta-server failed: {e:#}");
+        }
+    });
+    Ok(().serve(client_io).await?)
+}
+
+async fn call(
+    client: &RunningService<RoleClient, ()>,
+    tool: &str,
+    arguments: serde_json::Value,
+) -> anyhow::Result<CallToolResult> {
+    let serde_json::Value::Object(arguments) = arguments else {
+        panic!("arguments must be an object");
+    };
+    Ok(client
+        .call_tool(CallToolRequestParams::new(tool.to_string()).with_arguments(arguments))
+        .await?)
+}
+
+fn text(result: &CallToolResult) -> String {
+    result
+        .content
+        .iter()
+        .filter_map(|c| c.as_text().map(|t| t.text.clone()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The meta-server advertises exactly `list_tools` and `execute`, with the
+/// workspace-filtered capability index in the `list_tools` description.
+#[tokio::test(flavor = "multi_thread")]
+async fn mcp_serve_advertises_two_tools() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-meta-server0", "workspace0"],
+        async |ctx| {
+            let client = connect(&ctx).await?;
+            let tools = client.list_all_tools().await?;
+            let names: Vec<&str> = tools.iter().map(|t| t.name.as_ref()).collect();
+            assert_eq!(names, vec!["list_tools", "execute"]);
+
+            let description = tools[0].description.as_deref().unwrap();
+            assert!(
+                description.contains("Available servers: mock-server"),
+                "{description}"
+            );
+            assert!(
+                !description.contains("reqwest-server"),
+                "servers gated on absent crates should not be indexed: {description}"
+            );
+            client.cancel().await?;
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// `list_tools` starts the backing server and renders its tools as
+/// TypeScript declarations.
+#[tokio::test(flavor = "multi_thread")]
+async fn list_tools_renders_typescript_declarations() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-meta-server0", "workspace0"],
+        async |ctx| {
+            let client = connect(&ctx).await?;
+            let result = call(&client, "list_tools", serde_json::json!({})).await?;
+            expect_test::expect![[r#"
+                declare namespace mock_server {
+                  /** Greet someone by name */
+                  function greet(params: {
+                    /** Who to greet */
+                    name: string;
+                  }): Promise<any>;
+                  /** Always fails */
+                  function fail(): Promise<any>;
+                }
+            "#]]
+            .assert_eq(&text(&result));
+            client.cancel().await?;
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// `execute` runs a script whose calls dispatch to the backing server, and
+/// returns the script's value as JSON.
+#[tokio::test(flavor = "multi_thread")]
+async fn execute_dispatches_to_backing_server() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-meta-server0", "workspace0"],
+        async |ctx| {
+            let client = connect(&ctx).await?;
+            let result = call(
+                &client,
+                "execute",
+                serde_json::json!({
+                    "script": "const a = await mock_server.greet({ name: 'a' });\n\
+                               const b = await mock_server.greet({ name: 'b' });\n\
+                               return [a.greeting, b.greeting];"
+                }),
+            )
+            .await?;
+            assert_ne!(result.is_error, Some(true), "{}", text(&result));
+            let value: serde_json::Value = serde_json::from_str(&text(&result))?;
+            assert_eq!(value, serde_json::json!(["hello", "hello"]));
+            client.cancel().await?;
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A backing tool error surfaces as a JavaScript exception; uncaught, it
+/// fails the `execute` call. Caught, the script continues.
+#[tokio::test(flavor = "multi_thread")]
+async fn execute_propagates_tool_errors() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-meta-server0", "workspace0"],
+        async |ctx| {
+            let client = connect(&ctx).await?;
+
+            let uncaught = call(
+                &client,
+                "execute",
+                serde_json::json!({ "script": "await mock_server.fail()" }),
+            )
+            .await?;
+            assert_eq!(uncaught.is_error, Some(true));
+            assert!(text(&uncaught).contains("mock failure"), "{}", text(&uncaught));
+
+            let caught = call(
+                &client,
+                "execute",
+                serde_json::json!({
+                    "script": "try { await mock_server.fail(); } catch (e) { return 'caught: ' + e.message; }"
+                }),
+            )
+            .await?;
+            assert_ne!(caught.is_error, Some(true), "{}", text(&caught));
+            assert!(text(&caught).contains("caught: mock-server.fail: mock failure"));
+            client.cancel().await?;
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// End to end: after `sync`, the agent's config holds a single `symposium`
+/// entry, and connecting to it reaches the plugin's tools.
+#[tokio::test(flavor = "multi_thread")]
+async fn agent_sees_one_server_exposing_plugin_tools() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-meta-server0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let settings: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+                workspace_root.join(".claude/settings.json"),
+            )?)?;
+            let servers = settings["mcpServers"].as_object().unwrap();
+            assert_eq!(servers.keys().collect::<Vec<_>>(), vec!["symposium"]);
+            assert_eq!(
+                servers["symposium"]["args"],
+                serde_json::json!(["mcp-serve"])
+            );
+
+            let client = connect(&ctx).await?;
+            let result = call(&client, "list_tools", serde_json::json!({})).await?;
+            assert!(
+                text(&result).contains("function greet("),
+                "{}",
+                text(&result)
+            );
+            client.cancel().await?;
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
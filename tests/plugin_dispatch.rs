@@ -2,6 +2,7 @@
 //! fire a hook event, and verify the resulting output.
 
 use serde_json::json;
+use symposium::hook_schema::symposium as sym_types;
 use symposium::hook_schema::{HookAgent, HookEvent};
 use symposium_testlib::{HookStep, TestMode, with_fixture};
 
@@ -36,6 +37,170 @@ async fn inline_shell_hook_emits_context() {
     .unwrap();
 }
 
+/// OpenCode's bridge payload dispatches plugin hooks too; a symposium-format
+/// hook's context comes back as the bridge's flat `additionalContext`.
+#[tokio::test(flavor = "multi_thread")]
+async fn opencode_bridge_payload_dispatches_plugin_hooks() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugin-hooks0"],
+        async |mut ctx| {
+            let result = ctx
+                .prompt_or_hook(
+                    "ignored",
+                    &[HookStep::PreToolUse {
+                        tool_name: "Bash".to_string(),
+                        tool_input: json!({"command": "ls"}),
+                    }],
+                    HookAgent::OpenCode,
+                )
+                .await?;
+
+            assert!(
+                result.has_context_containing("inline-shell-output"),
+                "expected `inline-shell-output` in OpenCode hook output, got: {:#?}",
+                result.outputs_for(HookEvent::PreToolUse),
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Cursor's shell hook maps to a `Bash` pre-tool-use, so plugin matchers
+/// written for `Bash` fire; Cursor has no context channel, so the context
+/// comes back as the message Cursor passes to the agent.
+#[tokio::test(flavor = "multi_thread")]
+async fn cursor_shell_payload_dispatches_plugin_hooks() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugin-hooks0"],
+        async |mut ctx| {
+            let result = ctx
+                .prompt_or_hook(
+                    "ignored",
+                    &[HookStep::PreToolUse {
+                        tool_name: "Bash".to_string(),
+                        tool_input: json!({"command": "ls"}),
+                    }],
+                    HookAgent::Cursor,
+                )
+                .await?;
+
+            let outputs = result.outputs_for(HookEvent::PreToolUse);
+            assert_eq!(
+                outputs[0].input["hook_event_name"], "beforeShellExecution",
+                "Bash should be sent as Cursor's shell hook: {outputs:#?}"
+            );
+            assert!(
+                outputs.iter().any(|h| h.output["agentMessage"]
+                    .as_str()
+                    .is_some_and(|m| m.contains("inline-shell-output"))),
+                "expected `inline-shell-output` in Cursor's agentMessage, got: {outputs:#?}",
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A symposium-format Stop hook that blocks is translated into each agent's
+/// own "keep working" output, with the hook's reason.
+#[tokio::test(flavor = "multi_thread")]
+async fn stop_block_translates_per_agent() {
+    for (agent, decision) in [
+        (HookAgent::Claude, "block"),
+        (HookAgent::Codex, "block"),
+        (HookAgent::Copilot, "block"),
+        (HookAgent::Gemini, "deny"),
+    ] {
+        with_fixture(
+            TestMode::SimulationOnly,
+            &["plugin-hooks0"],
+            async |mut ctx| {
+                let result = ctx
+                    .prompt_or_hook("ignored", &[HookStep::Stop], agent)
+                    .await?;
+
+                let outputs = result.outputs_for(HookEvent::Stop);
+                let output = &outputs[0].output;
+                assert_eq!(output["decision"], decision, "{agent:?}: {output:#?}");
+                assert_eq!(
+                    output["reason"], "stop-gate: cargo check fails",
+                    "{agent:?}: {output:#?}"
+                );
+                Ok(())
+            },
+        )
+        .await
+        .unwrap();
+    }
+}
+
+/// Cursor has no stop decision; a block becomes the follow-up message that
+/// resumes the agent.
+#[tokio::test(flavor = "multi_thread")]
+async fn stop_block_becomes_cursor_followup() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugin-hooks0"],
+        async |mut ctx| {
+            let result = ctx
+                .prompt_or_hook("ignored", &[HookStep::Stop], HookAgent::Cursor)
+                .await?;
+            let outputs = result.outputs_for(HookEvent::Stop);
+            assert_eq!(
+                outputs[0].output,
+                json!({"followup_message": "stop-gate: cargo check fails"})
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A stop that follows an earlier blocked stop (`stop_hook_active`) reaches
+/// the gates with the flag set, and their block is passed through: how many
+/// retries a gate allows is its own decision.
+#[tokio::test(flavor = "multi_thread")]
+async fn repeated_stop_block_is_passed_through() {
+    for agent in [HookAgent::Claude, HookAgent::Cursor] {
+        with_fixture(TestMode::SimulationOnly, &["plugin-hooks0"], async |ctx| {
+            let cwd = ctx.sym.config_dir().display().to_string();
+            let input = sym_types::InputEvent::Stop(
+                sym_types::StopInput::new(None, Some(cwd)).with_stop_hook_active(true),
+            );
+            let payload = agent
+                .event(HookEvent::Stop)
+                .unwrap()
+                .translate_input(&input);
+            let output = symposium::hook::execute_hook(
+                &ctx.sym,
+                agent,
+                HookEvent::Stop,
+                &payload.to_string()?,
+            )
+            .await?;
+            let output: serde_json::Value = if output.is_empty() {
+                json!({})
+            } else {
+                serde_json::from_slice(&output)?
+            };
+            let expected = match agent {
+                HookAgent::Cursor => json!({"followup_message": "stop-gate: cargo check fails"}),
+                _ => json!({"decision": "block", "reason": "stop-gate: cargo check fails"}),
+            };
+            assert_eq!(output, expected, "{agent:?}");
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+}
+
 /// A hook declared by a crate reached through a `[[plugins]]` chained
 /// reference fires — crate-sourced hooks dispatch through the active plugin
 /// set, not just skills. `crate-f`'s `facet-hook` emits `facet-hook-output`.
@@ -321,3 +486,109 @@ async fn matcher_filters_out_non_matching_hooks() {
     .await
     .unwrap();
 }
+
+/// A hook that outlives its `timeout` is killed and skipped; later plugins'
+/// hooks still run. `a-slow`'s Bash hook sleeps for 30s with `timeout = 1`.
+#[tokio::test(flavor = "multi_thread")]
+async fn hook_timeout_kills_and_continues() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugin-hooks-timeout0"],
+        async |mut ctx| {
+            let started = std::time::Instant::now();
+            let result = ctx
+                .prompt_or_hook(
+                    "ignored",
+                    &[HookStep::PreToolUse {
+                        tool_name: "Bash".to_string(),
+                        tool_input: json!({"command": "ls"}),
+                    }],
+                    HookAgent::Claude,
+                )
+                .await?;
+
+            assert!(
+                started.elapsed() < std::time::Duration::from_secs(20),
+                "timed-out hook was not killed: took {:?}",
+                started.elapsed(),
+            );
+            assert!(
+                result.has_context_containing("after-slow-output"),
+                "expected the next plugin's hook to run, got: {:#?}",
+                result.outputs_for(HookEvent::PreToolUse),
+            );
+            assert!(!result.has_context_containing("hung-output"));
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Hooks without their own `timeout` fall back to `hook-timeout-secs`.
+#[tokio::test(flavor = "multi_thread")]
+async fn hook_timeout_defaults_to_config() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugin-hooks-timeout0"],
+        async |mut ctx| {
+            let started = std::time::Instant::now();
+            let result = ctx
+                .prompt_or_hook(
+                    "ignored",
+                    &[HookStep::PreToolUse {
+                        tool_name: "Read".to_string(),
+                        tool_input: json!({"file_path": "x"}),
+                    }],
+                    HookAgent::Claude,
+                )
+                .await?;
+
+            assert!(
+                started.elapsed() < std::time::Duration::from_secs(20),
+                "timed-out hook was not killed: took {:?}",
+                started.elapsed(),
+            );
+            assert!(result.has_context_containing("after-slow-output"));
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Consecutive `parallel = true` hooks run concurrently, and their outputs
+/// merge in plugin order regardless of which finishes first. `c-parallel`
+/// waits for a marker that `d-parallel` writes, and `d-parallel`'s context
+/// wins because it comes later.
+#[tokio::test(flavor = "multi_thread")]
+async fn parallel_hooks_run_concurrently_and_merge_in_order() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugin-hooks-timeout0"],
+        async |mut ctx| {
+            let result = ctx
+                .prompt_or_hook(
+                    "ignored",
+                    &[HookStep::PostToolUse {
+                        tool_name: "Bash".to_string(),
+                        tool_input: json!({"command": "ls"}),
+                        tool_response: json!({}),
+                    }],
+                    HookAgent::Claude,
+                )
+                .await?;
+
+            let seen = std::fs::read_to_string(ctx.tempdir.join("c-result"))?;
+            assert_eq!(seen.trim(), "saw-d", "parallel hooks ran one after another");
+            assert!(
+                result.has_context_containing("d-output"),
+                "expected the later plugin's context to win, got: {:#?}",
+                result.outputs_for(HookEvent::PostToolUse),
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
@@ -0,0 +1,111 @@
+//! `cargo agents registry`: adding, removing and toggling registries edits
+//! `config.toml` in place, and a source is validated before it is recorded.
+
+use symposium_testlib::{TestContext, TestMode, with_fixture};
+
+fn read_config(ctx: &TestContext) -> String {
+    std::fs::read_to_string(ctx.sym.config_dir().join("config.toml")).unwrap()
+}
+
+#[tokio::test]
+async fn add_and_remove_preserve_the_rest_of_the_config() {
+    with_fixture(TestMode::SimulationOnly, &["plugins0"], async |mut ctx| {
+        let config_path = ctx.sym.config_dir().join("config.toml");
+        let original = format!("# managed by hand\n{}", read_config(&ctx));
+        std::fs::write(&config_path, &original)?;
+        let source = ctx.sym.config_dir().join("plugins");
+        let source = source.to_str().unwrap();
+
+        ctx.symposium(&["registry", "add", "acme", "--path", source])
+            .await?;
+        let config = read_config(&ctx);
+        assert!(config.starts_with(&original), "{config}");
+        assert!(config.contains("[[registry]]"), "{config}");
+        assert_eq!(ctx.sym.config.registries[0].name, "acme");
+        assert!(
+            ctx.symposium(&["registry", "add", "acme", "--path", source])
+                .await
+                .is_err(),
+            "names are unique"
+        );
+        assert!(
+            ctx.symposium(&["registry", "pin", "acme", "v1"])
+                .await
+                .is_err(),
+            "only git registries pin"
+        );
+
+        ctx.symposium(&["registry", "remove", "acme"]).await?;
+        assert_eq!(read_config(&ctx), original);
+        assert!(ctx.sym.config.registries.is_empty());
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn add_rejects_an_empty_source() {
+    with_fixture(TestMode::SimulationOnly, &["plugins0"], async |mut ctx| {
+        let empty = ctx.tempdir.join("empty");
+        std::fs::create_dir_all(&empty)?;
+        let before = read_config(&ctx);
+        let err = ctx
+            .symposium(&[
+                "registry",
+                "add",
+                "empty",
+                "--path",
+                empty.to_str().unwrap(),
+            ])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("no plugins or skills"), "{err:#}");
+        assert_eq!(read_config(&ctx), before);
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn builtin_registries_toggle_in_defaults() {
+    with_fixture(TestMode::SimulationOnly, &["plugins0"], async |mut ctx| {
+        ctx.symposium(&["registry", "remove", "user-plugins"])
+            .await?;
+        assert!(!ctx.sym.config.defaults.user_plugins);
+        assert!(read_config(&ctx).contains("user-plugins = false"));
+
+        ctx.symposium(&["registry", "add", "user-plugins"]).await?;
+        assert!(ctx.sym.config.defaults.user_plugins);
+        ctx.symposium(&["registry", "list"]).await?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+/// `remove` also edits a registry list spelled as an inline array.
+#[tokio::test]
+async fn remove_handles_an_inline_registry_array() {
+    with_fixture(TestMode::SimulationOnly, &["plugins0"], async |mut ctx| {
+        let config_path = ctx.sym.config_dir().join("config.toml");
+        let source = ctx.sym.config_dir().join("plugins");
+        let source = source.to_str().unwrap();
+        let original = read_config(&ctx);
+        std::fs::write(
+            &config_path,
+            format!("registry = [{{ name = \"acme\", path = {source:?} }}]\n{original}"),
+        )?;
+        ctx.sym.config.registries.push(toml::from_str(&format!(
+            "name = \"acme\"\npath = {source:?}\n"
+        ))?);
+
+        ctx.symposium(&["registry", "remove", "acme"]).await?;
+        assert!(!read_config(&ctx).contains("acme"), "{}", read_config(&ctx));
+        assert!(ctx.sym.config.registries.is_empty());
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
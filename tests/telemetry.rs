@@ -0,0 +1,249 @@
+//! End-to-end tests for telemetry recording: sync, session starts, tool uses,
+//! skill reads, plugin hook dispatch and consent decisions each land in the
+//! local event log, and nothing is recorded while telemetry is off.
+
+use serde_json::json;
+use symposium::hook_schema::HookAgent;
+use symposium::telemetry::{ConsentDecision, EventKind, TelemetryEvent, read_events};
+use symposium_testlib::{HookStep, TestContext, TestMode, with_fixture};
+
+fn events_of(ctx: &TestContext, kind: &str) -> Vec<TelemetryEvent> {
+    read_events(ctx.sym.config_dir())
+        .into_iter()
+        .filter(|e| e.kind_name() == kind)
+        .collect()
+}
+
+/// A sync records how many skills it installed, and reading a file from an
+/// installed skill records the skill by name — not the path that was read.
+#[tokio::test]
+async fn sync_and_skill_reads_are_recorded() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.sym.config.telemetry.enabled = true;
+            ctx.symposium(&["sync"]).await?;
+
+            let syncs = events_of(&ctx, "sync");
+            assert_eq!(syncs.len(), 1, "{syncs:?}");
+            let EventKind::Sync { installed, removed } = syncs[0].kind else {
+                unreachable!()
+            };
+            assert!(installed >= 1, "{syncs:?}");
+            assert_eq!(removed, 0);
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let skill_md = workspace_root.join(".claude/skills/serde-guidance/SKILL.md");
+            let steps = [HookStep::PostToolUse {
+                tool_name: "Read".to_string(),
+                tool_input: json!({ "file_path": skill_md }),
+                tool_response: json!({}),
+            }];
+            ctx.prompt_or_hook("test", &steps, HookAgent::Claude)
+                .await?;
+
+            let reads = events_of(&ctx, "skill_read");
+            assert_eq!(reads.len(), 1, "{reads:?}");
+            let EventKind::SkillRead { agent, skill, .. } = &reads[0].kind else {
+                unreachable!()
+            };
+            assert_eq!(agent, "claude");
+            assert_eq!(skill, "serde-guidance");
+            let line = serde_json::to_string(&reads[0])?;
+            assert!(!line.contains("SKILL.md"), "{line}");
+
+            // Reading anything else records nothing.
+            let steps = [HookStep::PostToolUse {
+                tool_name: "Read".to_string(),
+                tool_input: json!({ "file_path": workspace_root.join("Cargo.toml") }),
+                tool_response: json!({}),
+            }];
+            ctx.prompt_or_hook("test", &steps, HookAgent::Claude)
+                .await?;
+            assert_eq!(events_of(&ctx, "skill_read").len(), 1);
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// A session start records the agent and the plugins applicable to the
+/// workspace; a tool use records the tool's name but not its input.
+#[tokio::test]
+async fn session_starts_and_tool_uses_are_recorded() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.sym.config.telemetry.enabled = true;
+            let steps = [
+                HookStep::session_start(),
+                HookStep::PreToolUse {
+                    tool_name: "Bash".to_string(),
+                    tool_input: json!({"command": "cargo test --secret-flag"}),
+                },
+            ];
+            ctx.prompt_or_hook("test", &steps, HookAgent::Claude)
+                .await?;
+
+            let starts = events_of(&ctx, "session_start");
+            assert_eq!(starts.len(), 1, "{starts:?}");
+            let EventKind::SessionStart {
+                session_id,
+                agent,
+                plugins,
+            } = &starts[0].kind
+            else {
+                unreachable!()
+            };
+            assert_eq!(session_id.as_deref(), Some("test-session-id"));
+            assert_eq!(agent, "claude");
+            assert!(!plugins.is_empty(), "{starts:?}");
+
+            let uses = events_of(&ctx, "tool_use");
+            assert_eq!(uses.len(), 1, "{uses:?}");
+            let EventKind::ToolUse { tool, .. } = &uses[0].kind else {
+                unreachable!()
+            };
+            assert_eq!(tool, "Bash");
+            let line = serde_json::to_string(&uses[0])?;
+            assert!(!line.contains("secret-flag"), "{line}");
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Each dispatched plugin hook is recorded with its exit status.
+#[tokio::test(flavor = "multi_thread")]
+async fn hook_dispatch_is_recorded() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugin-hooks0"],
+        async |mut ctx| {
+            ctx.sym.config.telemetry.enabled = true;
+            ctx.prompt_or_hook(
+                "ignored",
+                &[HookStep::PreToolUse {
+                    tool_name: "Bash".to_string(),
+                    tool_input: json!({"command": "ls"}),
+                }],
+                HookAgent::Claude,
+            )
+            .await?;
+
+            let dispatches = events_of(&ctx, "hook_dispatch");
+            assert!(!dispatches.is_empty());
+            for dispatch in &dispatches {
+                let EventKind::HookDispatch {
+                    event,
+                    exit_code,
+                    timed_out,
+                    ..
+                } = &dispatch.kind
+                else {
+                    unreachable!()
+                };
+                assert_eq!(event, "PreToolUse");
+                assert_eq!(*exit_code, Some(0));
+                assert!(!timed_out);
+            }
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Consent answers are recorded per plugin.
+#[tokio::test]
+async fn consent_decisions_are_recorded() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["auto-enable0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.sym.config.telemetry.enabled = true;
+
+            symposium::discovery::apply_consent(
+                &mut ctx.sym,
+                &["crate-a".to_string()],
+                &["other-dep".to_string()],
+            )?;
+
+            let decisions: Vec<(String, ConsentDecision)> = events_of(&ctx, "consent")
+                .into_iter()
+                .map(|e| match e.kind {
+                    EventKind::Consent { plugin, decision } => (plugin, decision),
+                    _ => unreachable!(),
+                })
+                .collect();
+            assert_eq!(
+                decisions,
+                [
+                    ("crate-a".to_string(), ConsentDecision::Granted),
+                    ("other-dep".to_string(), ConsentDecision::Declined),
+                ]
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// With telemetry off (the default), nothing is written.
+#[tokio::test]
+async fn nothing_is_recorded_while_disabled() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.symposium(&["sync"]).await?;
+            assert!(read_events(ctx.sym.config_dir()).is_empty());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// `telemetry export` writes the recorded events to the requested file.
+#[tokio::test]
+async fn export_writes_recorded_events() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+            ctx.sym.config.telemetry.enabled = true;
+            ctx.symposium(&["sync"]).await?;
+
+            let out = ctx.sym.config_dir().join("export.csv");
+            let out_arg = out.to_string_lossy().into_owned();
+            ctx.symposium(&[
+                "telemetry",
+                "export",
+                "--format",
+                "csv",
+                "--output",
+                &out_arg,
+            ])
+            .await?;
+
+            let csv = std::fs::read_to_string(&out)?;
+            let mut lines = csv.lines();
+            assert!(lines.next().unwrap().starts_with("at,kind,"), "{csv}");
+            assert!(lines.any(|l| l.contains(",sync,")), "{csv}");
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
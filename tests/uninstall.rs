@@ -0,0 +1,142 @@
+//! End-to-end tests for `cargo agents uninstall`: after `init` and `sync`,
+//! uninstalling removes the hooks and the symposium-managed skills, and a
+//! dry run leaves everything in place.
+
+use symposium_testlib::{TestContext, TestMode, with_fixture};
+
+async fn init_and_sync(ctx: &mut TestContext) -> anyhow::Result<()> {
+    ctx.symposium(&["init", "--add-agent", "claude"]).await?;
+    ctx.symposium(&["sync"]).await?;
+    Ok(())
+}
+
+fn has_symposium_hooks(settings: &std::path::Path) -> bool {
+    std::fs::read_to_string(settings)
+        .map(|contents| contents.contains("cargo-agents"))
+        .unwrap_or(false)
+}
+
+#[tokio::test]
+async fn uninstall_removes_hooks_and_skills() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            init_and_sync(&mut ctx).await?;
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let settings = workspace_root.join(".claude/settings.json");
+            let skill = workspace_root.join(".claude/skills/serde-guidance");
+            assert!(has_symposium_hooks(&settings));
+            assert!(skill.exists());
+
+            // A skill the user wrote is left alone.
+            let own = workspace_root.join(".claude/skills/my-skill");
+            std::fs::create_dir_all(&own)?;
+
+            ctx.symposium(&["uninstall", "--yes"]).await?;
+            assert!(!has_symposium_hooks(&settings));
+            assert!(!skill.exists());
+            assert!(own.exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn uninstall_dry_run_changes_nothing() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            init_and_sync(&mut ctx).await?;
+
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let settings = workspace_root.join(".claude/settings.json");
+            let before = std::fs::read_to_string(&settings)?;
+
+            ctx.symposium(&["uninstall", "--dry-run"]).await?;
+            assert_eq!(std::fs::read_to_string(&settings)?, before);
+            assert!(
+                workspace_root
+                    .join(".claude/skills/serde-guidance")
+                    .exists()
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn uninstall_purge_removes_config_dir() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["plugins0", "workspace0"],
+        async |mut ctx| {
+            init_and_sync(&mut ctx).await?;
+            assert!(ctx.sym.config_dir().exists());
+
+            ctx.symposium(&["uninstall", "--global", "--purge", "--yes"])
+                .await?;
+            assert!(!ctx.sym.config_dir().exists());
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn uninstall_removes_only_the_mcp_entries_symposium_wrote() {
+    with_fixture(
+        TestMode::SimulationOnly,
+        &["mcp-filtering0", "workspace0"],
+        async |mut ctx| {
+            let plugin_dir = ctx.sym.config_dir().join("plugins/http-plugin");
+            std::fs::create_dir_all(&plugin_dir)?;
+            std::fs::write(
+                plugin_dir.join("SYMPOSIUM.toml"),
+                indoc::indoc! {r#"
+                    name = "http-plugin"
+                    depends-on = ["*"]
+
+                    [[mcp_servers]]
+                    type = "http"
+                    name = "serde-remote"
+                    depends-on = ["serde"]
+                    url = "http://localhost:8080/mcp"
+                    headers = []
+                "#},
+            )?;
+            init_and_sync(&mut ctx).await?;
+
+            // Entries the user added, one under a plugin server's name.
+            let workspace_root = ctx.workspace_root.clone().unwrap();
+            let settings = workspace_root.join(".claude/settings.json");
+            let mut value: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(&settings)?)?;
+            assert!(value["mcpServers"]["serde-remote"].is_object());
+            value["mcpServers"]["serde-server"] =
+                serde_json::json!({ "command": "/usr/bin/true", "args": [] });
+            value["mcpServers"]["user-server"] =
+                serde_json::json!({ "command": "/usr/bin/true", "args": [] });
+            std::fs::write(&settings, value.to_string())?;
+
+            ctx.symposium(&["uninstall", "--yes"]).await?;
+            let value: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(&settings)?)?;
+            let servers = value["mcpServers"].as_object().unwrap();
+            assert_eq!(
+                servers.keys().collect::<Vec<_>>(),
+                vec!["serde-server", "user-server"]
+            );
+            Ok(())
+        },
+    )
+    .await
+    .unwrap();
+}